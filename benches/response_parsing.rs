@@ -0,0 +1,88 @@
+//! Benchmarks for deserializing responses. The Directions benchmarks use a
+//! synthetic response with several alternative routes of many steps each, the
+//! case that `ParseMode::SummaryOnly` is meant for.
+//!
+//! Run with `cargo bench --bench response_parsing`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use google_maps::prelude::*;
+use std::hint::black_box;
+
+// -----------------------------------------------------------------------------
+
+const GEOCODING: &str = include_str!("../tests/fixtures/geocoding/ok.json");
+const PLACE_DETAILS: &str = include_str!("../tests/fixtures/place_details/ok.json");
+
+/// One step of a driving route.
+const STEP: &str = r#"{
+    "distance": { "text": "0.4 km", "value": 412 },
+    "duration": { "text": "1 min", "value": 41 },
+    "end_location": { "lat": 43.6533096, "lng": -79.3827656 },
+    "html_instructions": "Turn <b>right</b> onto <b>King St W</b>",
+    "maneuver": "turn-right",
+    "polyline": { "points": "_p~iF~ps|U_ulLnnqC_mqNvxq`@_p~iF~ps|U_ulLnnqC_mqNvxq`@" },
+    "start_location": { "lat": 43.4516395, "lng": -80.4925337 },
+    "travel_mode": "DRIVING"
+}"#;
+
+/// Returns a Directions response with `routes` alternative routes of one leg
+/// and `steps` steps each.
+fn directions_response(routes: usize, steps: usize) -> String {
+    let steps = vec![STEP; steps].join(",");
+    let route = format!(r#"{{
+        "bounds": {{
+            "northeast": {{ "lat": 43.6533096, "lng": -79.3827656 }},
+            "southwest": {{ "lat": 43.4516395, "lng": -80.4925337 }}
+        }},
+        "copyrights": "Map data ©2024 Google",
+        "legs": [{{
+            "distance": {{ "text": "107 km", "value": 106734 }},
+            "duration": {{ "text": "1 hour 12 mins", "value": 4297 }},
+            "end_address": "Toronto, ON, Canada",
+            "end_location": {{ "lat": 43.6533096, "lng": -79.3827656 }},
+            "start_address": "Kitchener, ON, Canada",
+            "start_location": {{ "lat": 43.4516395, "lng": -80.4925337 }},
+            "steps": [{steps}]
+        }}],
+        "overview_polyline": {{ "points": "_p~iF~ps|U_ulLnnqC_mqNvxq`@" }},
+        "summary": "ON-401 E",
+        "warnings": [],
+        "waypoint_order": []
+    }}"#);
+    let routes = vec![route; routes].join(",");
+    format!(r#"{{ "geocoded_waypoints": [], "routes": [{routes}], "status": "OK" }}"#)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+fn directions(c: &mut Criterion) {
+    let json = directions_response(3, 300);
+    let mut group = c.benchmark_group("directions response");
+    for parse_mode in [ParseMode::Full, ParseMode::SummaryOnly] {
+        group.bench_function(format!("{parse_mode:?}"), |b| {
+            b.iter_batched_ref(
+                || json.clone().into_bytes(),
+                |bytes| black_box(parse_mode.parse(bytes).unwrap()),
+                criterion::BatchSize::SmallInput,
+            ); // iter_batched_ref
+        }); // bench_function
+    } // for
+    group.finish();
+} // fn
+
+fn geocoding(c: &mut Criterion) {
+    c.bench_function("geocoding response", |b| {
+        b.iter(|| black_box(GEOCODING.parse::<GeocodingResponse>().unwrap()));
+    }); // bench_function
+} // fn
+
+fn place_details(c: &mut Criterion) {
+    c.bench_function("place details response", |b| {
+        b.iter(|| black_box(PLACE_DETAILS.parse::<PlaceDetailsResponse>().unwrap()));
+    }); // bench_function
+} // fn
+
+// -----------------------------------------------------------------------------
+
+criterion_group!(benches, directions, geocoding, place_details);
+criterion_main!(benches);
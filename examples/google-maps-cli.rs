@@ -0,0 +1,160 @@
+//! A small command-line client that exercises the crate end-to-end. Useful for
+//! checking that an API key has the required APIs enabled.
+//!
+//! The API key, timeouts and rate limits are read from the environment, see
+//! `ClientConfig::from_env`.
+//!
+//! ```sh
+//! export GOOGLE_MAPS_API_KEY=...
+//! cargo run --example google-maps-cli -- geocode "10 Downing St, London"
+//! cargo run --example google-maps-cli -- reverse 51.5034 -0.1276
+//! cargo run --example google-maps-cli -- directions "Toronto" "Montreal" driving
+//! cargo run --example google-maps-cli -- places "pizza in Brooklyn"
+//! cargo run --example google-maps-cli -- timezone 50.0909 14.4005
+//! ```
+
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+const USAGE: &str = "\
+usage: google-maps-cli <command> [arguments]
+
+commands:
+    geocode <address>
+    reverse <latitude> <longitude>
+    directions <origin> <destination> [bicycling|driving|transit|walking]
+    places <query>
+    timezone <latitude> <longitude>";
+
+// -----------------------------------------------------------------------------
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let arguments: Vec<String> = std::env::args().skip(1).collect();
+    let arguments: Vec<&str> = arguments.iter().map(String::as_str).collect();
+
+    let result = match arguments.as_slice() {
+        ["geocode", address] => geocode(address).await,
+        ["reverse", latitude, longitude] => reverse(latitude, longitude).await,
+        ["directions", origin, destination] => directions(origin, destination, "driving").await,
+        ["directions", origin, destination, mode] => directions(origin, destination, mode).await,
+        ["places", query] => places(query).await,
+        ["timezone", latitude, longitude] => timezone(latitude, longitude).await,
+        _ => {
+            eprintln!("{USAGE}");
+            std::process::exit(2);
+        }
+    }; // match
+
+    if let Err(error) = &result {
+        if let Some(error) = error.downcast_ref::<GoogleMapsError>() {
+            if let Some(help) = error.help() {
+                eprintln!("help: {help}");
+            } // if
+        } // if
+    } // if
+
+    result
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Parses a pair of command-line arguments into coordinates.
+fn parse_latlng(latitude: &str, longitude: &str) -> Result<LatLng, Box<dyn std::error::Error>> {
+    Ok(LatLng::try_from_f64(latitude.parse()?, longitude.parse()?)?)
+} // fn
+
+async fn geocode(address: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let response = google_maps::global()?
+        .geocoding()
+        .with_address(address)
+        .execute()
+        .await?;
+
+    for result in &response.results {
+        println!(
+            "{} ‧ {} ‧ {}",
+            result.formatted_address,
+            result.geometry.location,
+            result.place_id
+        );
+    } // for
+
+    Ok(())
+} // fn
+
+async fn reverse(latitude: &str, longitude: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let latlng = parse_latlng(latitude, longitude)?;
+
+    let response = google_maps::global()?
+        .reverse_geocoding(latlng)
+        .execute()
+        .await?;
+
+    if let Some(result) = response.closest_result_to(&latlng) {
+        println!("{} ‧ {}", result.formatted_address, result.place_id);
+    } // if
+
+    Ok(())
+} // fn
+
+async fn directions(
+    origin: &str,
+    destination: &str,
+    mode: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let travel_mode: TravelMode = mode.to_uppercase().parse()?;
+
+    let response = google_maps::global()?
+        .directions(
+            Location::from_address(origin),
+            Location::from_address(destination),
+        )
+        .with_travel_mode(travel_mode)
+        .execute()
+        .await?;
+
+    for route in &response.routes {
+        println!(
+            "{} ‧ {:.1} km ‧ {} min",
+            route.summary,
+            route.total_distance_meters() as f64 / 1_000.0,
+            route.total_duration().num_minutes()
+        );
+    } // for
+
+    Ok(())
+} // fn
+
+async fn places(query: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let response = google_maps::global()?
+        .text_search(query, 50_000_u32)
+        .execute()
+        .await?;
+
+    for place in &response.results {
+        println!(
+            "{} ‧ {}",
+            place.name.as_deref().unwrap_or_default(),
+            place.formatted_address.as_deref().unwrap_or_default()
+        );
+    } // for
+
+    Ok(())
+} // fn
+
+async fn timezone(latitude: &str, longitude: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let response = google_maps::global()?
+        .time_zone(parse_latlng(latitude, longitude)?, Utc::now())
+        .execute()
+        .await?;
+
+    println!(
+        "{} ‧ {}",
+        response.time_zone_id.map(|tz| tz.name().to_string()).unwrap_or_default(),
+        response.time_zone_name.unwrap_or_default()
+    );
+
+    Ok(())
+} // fn
@@ -0,0 +1,166 @@
+//! Contains the `BuiltRequest` struct. It is the validated, fully built form
+//! of a request, returned by each request's `try_build` method, so that input
+//! errors surface when the request is assembled rather than when it is sent.
+//! Returned by `inspect` with its headers, it shows exactly what will be sent.
+
+use crate::canonical_request::CanonicalRequest;
+
+// -----------------------------------------------------------------------------
+//
+/// A request that has been validated and built, and is ready to be sent.
+///
+/// Every request type has a `try_build` method that validates the request's
+/// parameters, builds its query string, and returns a `BuiltRequest`. Errors,
+/// such as an invalid combination of parameters, are returned as the
+/// module's own `Error` type. This lets a service reject bad user input
+/// synchronously, before queueing any work. The request itself may still be
+/// sent later with `execute`.
+///
+/// `try_build` runs the same checks as `execute`, without sending anything.
+/// Requests with no parameter combinations to validate always build; they
+/// have a `try_build` method so that every request can be built and queued
+/// the same way.
+///
+/// Every request type also has an `inspect` method. It returns the same
+/// `BuiltRequest` with the headers that `execute` sends, such as
+/// `Accept-Language` and the trace context, so that the request can be logged
+/// or sent with another HTTP client.
+///
+/// ## Example
+///
+/// ```rust
+/// let mut request = google_maps_client.directions(origin, destination);
+///
+/// match request.with_travel_mode(TravelMode::Transit).try_build() {
+///     Ok(built) => queue.push(built),
+///     Err(error) => return Err(error.into()),
+/// }
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BuiltRequest {
+    /// The URL of the service, without the query string.
+    service_url: String,
+
+    /// The URL-encoded query string, including the API key.
+    query: String,
+
+    /// The headers sent with the request. Only filled in by `inspect`.
+    headers: Vec<(String, String)>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl BuiltRequest {
+    /// Initializes a `BuiltRequest` from a service URL and a query string.
+
+    pub(crate) const fn new(service_url: String, query: String) -> Self {
+        Self { service_url, query, headers: Vec::new() }
+    } // fn
+
+    /// Adds the headers that the client sends with a request. See
+    /// `GoogleMapsClient::request_headers`.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn with_client_headers(
+        mut self,
+        client: &crate::client::GoogleMapsClient,
+        accept_language: Option<&str>,
+    ) -> Self {
+        self.headers = client.request_headers(accept_language);
+        self
+    } // fn
+
+    /// Adds the headers that the client sends with a request. Without the
+    /// `reqwest` feature the client sends no requests, and there are none.
+
+    #[cfg(not(feature = "reqwest"))]
+    pub(crate) const fn with_client_headers(
+        self,
+        _client: &crate::client::GoogleMapsClient,
+        _accept_language: Option<&str>,
+    ) -> Self {
+        self
+    } // fn
+
+    /// Returns the HTTP method of the request. Every request in this crate is
+    /// a `GET` request.
+
+    // A method, rather than a constant, so that transports don't have to
+    // assume it:
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn method(&self) -> &'static str {
+        "GET"
+    } // fn
+
+    /// Returns the URL-encoded query string, including the API key.
+
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    } // fn
+
+    /// Returns the full URL of the request, as it will be sent to Google.
+
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{}?{}", self.service_url, self.query)
+    } // fn
+
+    /// Returns the full URL of the request with the value of the `key`
+    /// parameter replaced by `REDACTED`, so that it can be logged.
+
+    #[must_use]
+    pub fn redacted_url(&self) -> String {
+        let query = self
+            .query
+            .split('&')
+            .map(|parameter| if parameter.starts_with("key=") { "key=REDACTED" } else { parameter })
+            .collect::<Vec<&str>>()
+            .join("&");
+        format!("{}?{query}", self.service_url)
+    } // fn
+
+    /// Returns the headers sent with the request, as lower-case name and value
+    /// pairs. Empty unless the request was built with `inspect`.
+
+    #[must_use]
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    } // fn
+
+    /// Returns the body of the request. Every request in this crate is a `GET`
+    /// request, so this is always `None`.
+
+    // A method, rather than a constant, so that transports don't have to
+    // assume it:
+    #[allow(clippy::unused_self)]
+    #[must_use]
+    pub const fn body(&self) -> Option<&[u8]> {
+        None
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl CanonicalRequest for BuiltRequest {
+    /// Returns the URL of the service, without the query string.
+    fn service_url(&self) -> String {
+        self.service_url.clone()
+    } // fn
+
+    /// Returns the query string. A `BuiltRequest` is always built.
+    fn built_query(&self) -> Option<&str> {
+        Some(&self.query)
+    } // fn
+
+    /// Returns the `Accept-Language` header, if `inspect` added one.
+    fn cache_variants(&self) -> Vec<String> {
+        self.headers
+            .iter()
+            .filter(|(name, _value)| name == "accept-language")
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect()
+    } // fn
+} // impl
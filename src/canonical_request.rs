@@ -0,0 +1,140 @@
+//! Contains the `CanonicalRequest` trait. It gives every request a canonical,
+//! byte-for-byte stable form, so that caches and request de-duplication don't
+//! depend on the order in which builder methods were called or on the version
+//! of this crate.
+
+// -----------------------------------------------------------------------------
+
+/// Query string parameters that don't change Google's response, and are left
+/// out of cache keys: the API key, the usage-reporting channel, and the
+/// autocomplete billing session.
+const NON_CACHEABLE_PARAMETERS: &[&str] = &["key", "channel", "sessiontoken"];
+
+// -----------------------------------------------------------------------------
+//
+/// A request that has a canonical form.
+///
+/// The canonical form of a request is its service URL followed by its query
+/// string parameters, sorted by name and then by value. Decimal numbers, such
+/// as latitudes and longitudes, are already serialized without trailing zeroes,
+/// so two requests for the same thing have the same canonical form no matter
+/// how they were built.
+///
+/// Values that hold several items, such as waypoints or place types, are
+/// separated by pipes inside a single parameter. Their order is kept as-is
+/// because it is meaningful to Google.
+///
+/// The canonical form is only available once the request has been built, with
+/// `build` (after `validate`, for requests that have it) or `query_url`. Until
+/// then these methods return `None`.
+///
+/// ## Example
+///
+/// ```rust
+/// use google_maps::prelude::*;
+///
+/// let mut request = google_maps_client.geocoding();
+/// let request = request.with_address("Toronto, ON").validate()?.build()?;
+///
+/// if let Some(cached) = cache.get(&request.cache_key().unwrap()) {
+///     return Ok(cached);
+/// }
+/// ```
+
+pub trait CanonicalRequest {
+    /// Returns the URL of the service that the request is sent to, without the
+    /// query string.
+    fn service_url(&self) -> String;
+
+    /// Returns the query string built by `build`, or `None` if the request
+    /// has not been built yet.
+    fn built_query(&self) -> Option<&str>;
+
+    /// Returns the canonical form of the request, including the API key and
+    /// every other parameter, as bytes. Use this as the input of a content
+    /// hash.
+    ///
+    /// This is not the request as it is sent: the parameters are sorted, so
+    /// it can't be used to sign a request URL. Google checks a URL signature
+    /// against the path and query string in the order they are sent.
+
+    fn canonical_bytes(&self) -> Option<Vec<u8>> {
+        self.built_query().map(|query| {
+            format!("{}?{}", self.service_url(), canonical_query(query, &[])).into_bytes()
+        }) // map
+    } // fn
+
+    /// Returns the settings, other than query string parameters, that change
+    /// the response, as `name=value` pairs. For example, the `Accept-Language`
+    /// header changes the language of the results. Empty by default.
+
+    fn cache_variants(&self) -> Vec<String> {
+        Vec::new()
+    } // fn
+
+    /// Returns a key that identifies the response to this request. It is the
+    /// canonical form of the request without the parameters that don't change
+    /// the response: `key`, `channel` and `sessiontoken`. Two clients with
+    /// different API keys share cache entries.
+    ///
+    /// The `cache_variants`, if any, follow the query string after a `#`, so
+    /// that requests sent with different `Accept-Language` headers, or parsed
+    /// differently, don't share cache entries.
+
+    fn cache_key(&self) -> Option<String> {
+        self.built_query().map(|query| {
+            let mut key = format!("{}?{}", self.service_url(), canonical_query(query, NON_CACHEABLE_PARAMETERS));
+            let variants = self.cache_variants();
+            if !variants.is_empty() {
+                key.push('#');
+                key.push_str(&variants.join("&"));
+            } // if
+            key
+        }) // map
+    } // fn
+} // trait
+
+// -----------------------------------------------------------------------------
+
+/// Sorts the parameters of a URL-encoded query string by name and then by
+/// value, leaving out empty parameters and the parameters named in `exclude`.
+/// Parameters are compared in their URL-encoded form, which is stable.
+
+fn canonical_query(query: &str, exclude: &[&str]) -> String {
+    let mut parameters: Vec<&str> = query
+        .split('&')
+        .filter(|parameter| !parameter.is_empty())
+        .filter(|parameter| !exclude.contains(&parameter_name(parameter)))
+        .collect();
+
+    parameters.sort_unstable_by_key(|parameter| {
+        (parameter_name(parameter), parameter.split_once('=').map(|(_name, value)| value))
+    }); // sort_unstable_by_key
+
+    parameters.join("&")
+} // fn
+
+/// Returns the cache variant for the `Accept-Language` header that is sent
+/// with a request: the request's own header or, if it has none, the client's.
+
+#[cfg(any(feature = "autocomplete", feature = "geocoding", feature = "places"))]
+pub(crate) fn accept_language_variant(
+    client: &crate::client::GoogleMapsClient,
+    accept_language: Option<&str>,
+) -> Vec<String> {
+    #[cfg(feature = "reqwest")]
+    let accept_language = accept_language.or(client.accept_language.as_deref());
+    #[cfg(not(feature = "reqwest"))]
+    let _ = client;
+
+    accept_language
+        .map(|accept_language| format!("accept-language={accept_language}"))
+        .into_iter()
+        .collect()
+} // fn
+
+/// Returns the name of a `name=value` query string parameter.
+
+fn parameter_name(parameter: &str) -> &str {
+    parameter.split_once('=').map_or(parameter, |(name, _value)| name)
+} // fn
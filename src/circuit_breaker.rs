@@ -0,0 +1,181 @@
+//! Contains the `CircuitBreaker` struct and the `CircuitState` enum. The
+//! circuit breaker stops the client from sending requests to an API that is
+//! failing, so that an outage at Google does not turn into a storm of retries
+//! from every instance of an application.
+
+use crate::request_rate::api::Api;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// The state of the circuit breaker for one API.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum CircuitState {
+    /// Requests are sent normally.
+    #[default]
+    Closed,
+    /// The API failed repeatedly. Requests fail immediately with
+    /// `GoogleMapsError::CircuitOpen` until the cool-down has elapsed.
+    Open,
+    /// The cool-down has elapsed. A single probe request is allowed through:
+    /// if it succeeds the circuit closes, otherwise it opens again.
+    HalfOpen,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for CircuitState {
+    /// Formats a `CircuitState` enum into a string that is presentable to the
+    /// end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Closed => write!(f, "Closed"),
+            Self::Open => write!(f, "Open"),
+            Self::HalfOpen => write!(f, "Half-Open"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The failure count and timers of the circuit breaker for one API.
+#[derive(Debug, Default)]
+struct Circuit {
+    /// The number of transient failures since the last success.
+    consecutive_failures: u32,
+    /// When the circuit was opened. `None` while the circuit is closed.
+    opened_at: Option<Instant>,
+    /// Whether a half-open probe request is in flight.
+    probing: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A per-API circuit breaker. Use `GoogleMapsClient::with_circuit_breaker` to
+/// enable it.
+///
+/// * After `failure_threshold` consecutive transient failures (network
+///   errors, HTTP `5xx` responses, and HTTP `429 Too Many Requests`) the
+///   circuit for that API _opens_, and requests to it fail immediately.
+///
+/// * Once `cool_down` has elapsed the circuit is _half-open_: one probe
+///   request is sent. If it succeeds the circuit _closes_ and requests resume,
+///   otherwise the circuit opens for another cool-down period.
+///
+/// Clones of a `CircuitBreaker`, including those in clones of the
+/// `GoogleMapsClient`, share their state.
+
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    /// The number of consecutive transient failures that opens the circuit.
+    pub failure_threshold: u32,
+
+    /// How long the circuit stays open before a probe request is allowed.
+    pub cool_down: Duration,
+
+    /// The circuit of each API that has been used.
+    circuits: Arc<Mutex<HashMap<Api, Circuit>>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl CircuitBreaker {
+    /// Initializes a `CircuitBreaker` with all circuits closed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_threshold` ‧ The number of consecutive transient failures
+    ///   that opens the circuit. A threshold of `0` is treated as `1`.
+    ///
+    /// * `cool_down` ‧ How long the circuit stays open before a probe request
+    ///   is allowed.
+
+    #[must_use]
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        } // CircuitBreaker
+    } // fn
+
+    /// Returns the current state of the circuit for the specified API.
+
+    #[must_use]
+    pub fn state(&self, api: &Api) -> CircuitState {
+        let Ok(circuits) = self.circuits.lock() else { return CircuitState::Closed };
+        match circuits.get(api) {
+            Some(Circuit { opened_at: Some(opened_at), probing, .. }) =>
+                if *probing || opened_at.elapsed() >= self.cool_down {
+                    CircuitState::HalfOpen
+                } else {
+                    CircuitState::Open
+                }, // if
+            _ => CircuitState::Closed,
+        } // match
+    } // fn
+
+    /// Closes all circuits and clears their failure counts.
+    pub fn reset(&self) {
+        if let Ok(mut circuits) = self.circuits.lock() {
+            circuits.clear();
+        } // if
+    } // fn
+
+    /// Returns whether a request to the specified API may be sent. When the
+    /// cool-down has elapsed, this admits the half-open probe request.
+    pub(crate) fn try_acquire(&self, api: &Api) -> bool {
+        let Ok(mut circuits) = self.circuits.lock() else { return true };
+        let circuit = circuits.entry(api.clone()).or_default();
+        match circuit.opened_at {
+            None => true,
+            // Restarting the cool-down when the probe is admitted means that,
+            // should the probe never complete, another probe is admitted
+            // after the next cool-down:
+            Some(opened_at) if opened_at.elapsed() >= self.cool_down => {
+                tracing::info!("{api} API circuit breaker is half-open, sending a probe request");
+                circuit.opened_at = Some(Instant::now());
+                circuit.probing = true;
+                true
+            }, // Some
+            Some(_) => false,
+        } // match
+    } // fn
+
+    /// Records a response that was not a transient failure. This closes the
+    /// circuit.
+    pub(crate) fn record_success(&self, api: &Api) {
+        let Ok(mut circuits) = self.circuits.lock() else { return };
+        if let Some(circuit) = circuits.get_mut(api) {
+            if circuit.opened_at.is_some() {
+                tracing::info!("{api} API circuit breaker closed");
+            } // if
+            *circuit = Circuit::default();
+        } // if
+    } // fn
+
+    /// Records a transient failure. This opens the circuit once the failure
+    /// threshold is reached, or re-opens it if the half-open probe failed.
+    pub(crate) fn record_failure(&self, api: &Api) {
+        let Ok(mut circuits) = self.circuits.lock() else { return };
+        let circuit = circuits.entry(api.clone()).or_default();
+        circuit.consecutive_failures = circuit.consecutive_failures.saturating_add(1);
+        if circuit.probing {
+            tracing::warn!("{api} API circuit breaker probe failed, re-opening the circuit");
+            circuit.probing = false;
+            circuit.opened_at = Some(Instant::now());
+        } else if circuit.opened_at.is_none()
+            && circuit.consecutive_failures >= self.failure_threshold
+        {
+            tracing::warn!(
+                "{api} API circuit breaker opened after {failures} consecutive failures",
+                failures = circuit.consecutive_failures,
+            );
+            circuit.opened_at = Some(Instant::now());
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,451 @@
+//! Contains the `ClassifiedError` enum and the `ClassifiableError` trait. Every
+//! API in this crate has its own status enum and error type. Classifying them
+//! into one shared set of categories lets an application decide how to react
+//! to a failure, such as retrying, alerting, or telling the user nothing was
+//! found, the same way for every API.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The category of a failed request, shared by every API.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ClassifiedError {
+    /// The request was valid but nothing matched it. For example a
+    /// `ZERO_RESULTS` status.
+    NoResults,
+    /// A location, place or other resource referenced by the request could
+    /// not be found. For example a `NOT_FOUND` status.
+    NotFound,
+    /// The request was malformed or its parameters were rejected. Retrying
+    /// the same request will not help. For example an `INVALID_REQUEST`
+    /// status, or a request that failed this crate's validation.
+    InvalidRequest,
+    /// The API key was rejected, the API is not enabled, or billing is not set
+    /// up. For example a `REQUEST_DENIED` status or an HTTP `403 Forbidden`
+    /// response.
+    Denied,
+    /// The request rate or a quota was exceeded. The request may succeed
+    /// later. For example an `OVER_QUERY_LIMIT` status or an HTTP `429 Too
+    /// Many Requests` response.
+    QuotaExceeded,
+    /// A temporary failure reported by Google, such as an HTTP `5xx`
+    /// response or an `UNKNOWN_ERROR` status, or an open circuit breaker. The
+    /// request may succeed if retried.
+    Transient,
+    /// The request could not be sent or its response could not be received,
+    /// for example because of a DNS failure, a refused connection or a
+    /// timeout. The request may succeed if retried. A client whose TLS
+    /// backend is not compiled in also fails this way, but won't recover.
+    Transport,
+    /// The response, or a code within it, could not be parsed. This usually
+    /// means that Google changed its response format, or the response was
+    /// truncated.
+    Parse,
+    /// Anything else, such as a stored response that could not be read.
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl ClassifiedError {
+    /// Returns whether a request that failed this way may succeed if it is
+    /// retried later.
+
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::QuotaExceeded | Self::Transient | Self::Transport)
+    } // fn
+
+    /// Classifies an HTTP status, as stored in the `HttpUnsuccessful` variant
+    /// of each API's error type. For example, `429 Too Many Requests`.
+
+    #[must_use]
+    pub fn from_http_status(status: &str) -> Self {
+        match status.split_whitespace().next().and_then(|code| code.parse::<u16>().ok()) {
+            Some(401 | 403) => Self::Denied,
+            Some(404) => Self::NotFound,
+            Some(429) => Self::QuotaExceeded,
+            Some(400..=499) => Self::InvalidRequest,
+            Some(500..=599) => Self::Transient,
+            _ => Self::Other,
+        } // match
+    } // fn
+
+    /// Classifies a status code returned by one of Google's APIs, for example
+    /// `OVER_QUERY_LIMIT` or `PERMISSION_DENIED`.
+
+    #[must_use]
+    pub fn from_status_code(code: &str) -> Self {
+        match code {
+            "ZERO_RESULTS" => Self::NoResults,
+            "NOT_FOUND" => Self::NotFound,
+            "INVALID_REQUEST"
+            | "INVALID_ARGUMENT"
+            | "MAX_ELEMENTS_EXCEEDED"
+            | "MAX_DIMENSIONS_EXCEEDED"
+            | "MAX_WAYPOINTS_EXCEEDED"
+            | "MAX_ROUTE_LENGTH_EXCEEDED" => Self::InvalidRequest,
+            "REQUEST_DENIED" | "PERMISSION_DENIED" | "OVER_DAILY_LIMIT" => Self::Denied,
+            "OVER_QUERY_LIMIT" | "RESOURCE_EXHAUSTED" => Self::QuotaExceeded,
+            "UNKNOWN_ERROR" => Self::Transient,
+            _ => Self::Other,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ClassifiedError {
+    /// Formats a `ClassifiedError` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NoResults => write!(f, "No Results"),
+            Self::NotFound => write!(f, "Not Found"),
+            Self::InvalidRequest => write!(f, "Invalid Request"),
+            Self::Denied => write!(f, "Denied"),
+            Self::QuotaExceeded => write!(f, "Quota Exceeded"),
+            Self::Transient => write!(f, "Transient"),
+            Self::Transport => write!(f, "Transport"),
+            Self::Parse => write!(f, "Parse"),
+            Self::Other => write!(f, "Other"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Classifies a status or error into a `ClassifiedError`.
+///
+/// Implemented for the status enum of every API, and for every error type in
+/// this crate, including `GoogleMapsError`.
+///
+/// ## Example
+///
+/// ```rust
+/// match google_maps_client.geocoding().with_address("…").execute().await {
+///     Ok(response) => println!("{} results", response.results.len()),
+///     Err(error) if error.is_retryable() => queue_for_retry(request),
+///     Err(error) => match error.classify() {
+///         ClassifiedError::Denied => alert_operations(&error),
+///         _ => tracing::error!("{error}"),
+///     },
+/// }
+/// ```
+
+pub trait ClassifiableError {
+    /// Returns the category of this status or error.
+    fn classify(&self) -> ClassifiedError;
+
+    /// Returns whether the request may succeed if it is retried later.
+    fn is_retryable(&self) -> bool {
+        self.classify().is_retryable()
+    } // fn
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+// Status enums. The status codes are classified by their string form, so that
+// a code shared by several APIs is classified the same way for each of them.
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl ClassifiableError for crate::directions::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl ClassifiableError for crate::distance_matrix::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl ClassifiableError for crate::distance_matrix::response::element_status::ElementStatus {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "elevation")]
+impl ClassifiableError for crate::elevation::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "geocoding")]
+impl ClassifiableError for crate::geocoding::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "places")]
+impl ClassifiableError for crate::places::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "autocomplete")]
+impl ClassifiableError for crate::places::place_autocomplete::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "roads")]
+impl ClassifiableError for crate::roads::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+#[cfg(feature = "time_zone")]
+impl ClassifiableError for crate::time_zone::response::status::Status {
+    fn classify(&self) -> ClassifiedError {
+        ClassifiedError::from_status_code(&String::from(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// Error types. Errors from Google are classified by their status, HTTP errors
+// by their HTTP status, and network errors as `Transport`. Codes that could not
+// be parsed are `Parse`, and the remaining errors are raised by this crate's
+// request validation. The matches list every variant, so that a new variant
+// has to be classified deliberately.
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl ClassifiableError for crate::directions::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            Self::TravelModeUnavailable(_, _) => ClassifiedError::NoResults,
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidAvoidCode(_)
+            | Self::InvalidCurrencyCode(_)
+            | Self::InvalidDrivingManeuverCode(_)
+            | Self::InvalidGeocoderStatusCode(_)
+            | Self::InvalidStatusCode(_)
+            | Self::InvalidTimeZoneName(_)
+            | Self::InvalidTrafficModelCode(_)
+            | Self::InvalidTransitModeCode(_)
+            | Self::InvalidTransitRoutePreferenceCode(_)
+            | Self::InvalidTravelModeCode(_)
+            | Self::InvalidUnitSystemCode(_)
+            | Self::InvalidVehicleTypeCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::ArrivalTimeIsForTransitOnly(_, _)
+            | Self::EitherAlternativesOrWaypoints(_)
+            | Self::EitherDepartureTimeOrArrivalTime(_, _)
+            | Self::EitherRestrictionsOrWaypoints(_, _)
+            | Self::EitherWaypointsOrTransitMode(_)
+            | Self::EmptyPolyline
+            | Self::InvalidDepartureTime(_)
+            | Self::InvalidHeading(_)
+            | Self::InvalidLocalDepartureTime(_, _)
+            | Self::QueryNotBuilt
+            | Self::RequestNotValidated
+            | Self::TooManyWaypoints(_)
+            | Self::TrafficModelIsForDrivingOnly(_, _)
+            | Self::TrafficModelRequiresDepartureTime(_)
+            | Self::TransitModeIsForTransitOnly(_, _)
+            | Self::TransitRoutePreferenceIsForTransitOnly(_, _)
+            | Self::ViaWaypointsWithOptimization(_) => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "distance_matrix")]
+impl ClassifiableError for crate::distance_matrix::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidAvoidCode(_)
+            | Self::InvalidElementStatusCode(_)
+            | Self::InvalidManeuverTypeCode(_)
+            | Self::InvalidStatusCode(_)
+            | Self::InvalidTrafficModelCode(_)
+            | Self::InvalidTransitModeCode(_)
+            | Self::InvalidTransitRoutePreferenceCode(_)
+            | Self::InvalidTravelModeCode(_)
+            | Self::InvalidUnitSystemCode(_)
+            | Self::InvalidVehicleTypeCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::ArrivalTimeIsForTransitOnly(_, _)
+            | Self::EitherAlternativesOrWaypoints(_)
+            | Self::EitherDepartureTimeOrArrivalTime(_, _)
+            | Self::EitherRestrictionsOrWaypoints(_, _)
+            | Self::EitherWaypointsOrTransitMode(_)
+            | Self::QueryNotBuilt
+            | Self::RequestNotValidated
+            | Self::TooManyWaypoints(_)
+            | Self::TransitModeIsForTransitOnly(_, _)
+            | Self::TransitRoutePreferenceIsForTransitOnly(_, _) => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "elevation")]
+impl ClassifiableError for crate::elevation::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidStatusCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::EitherPositionalOrSampledPath
+            | Self::EmptyPolyline
+            | Self::InvalidSamples(_)
+            | Self::QueryNotBuilt
+            | Self::RequestNotValidated => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "geocoding")]
+impl ClassifiableError for crate::geocoding::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidStatusCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::AddressOrComponentsRequired
+            | Self::InvalidBounds(_)
+            | Self::QueryNotBuilt
+            | Self::RequestNotValidated => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "places")]
+impl ClassifiableError for crate::places::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidBusinessStatusCode(_)
+            | Self::InvalidFieldCode(_)
+            | Self::InvalidPriceLevelCode(_)
+            | Self::InvalidRankByCode(_)
+            | Self::InvalidSecondaryHoursType(_)
+            | Self::InvalidSortOrderCode(_)
+            | Self::InvalidStatusCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::InvalidPriceLevel(_)
+            | Self::QueryNotBuilt
+            | Self::TooManyTiles(_)
+            | Self::UnspecifiedPriceLevel => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "autocomplete")]
+impl ClassifiableError for crate::places::place_autocomplete::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidAutocompleteType(_)
+            | Self::InvalidStatusCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::QueryNotBuilt => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "roads")]
+impl ClassifiableError for crate::roads::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidStatusCode(_)
+            | Self::InvalidUnitsCode(_)
+            | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::EitherPathOrPlaceIds
+            | Self::QueryNotBuilt => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+#[cfg(feature = "time_zone")]
+impl ClassifiableError for crate::time_zone::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::GoogleMapsService(status, _) => status.classify(),
+            Self::HttpUnsuccessful(status) => ClassifiedError::from_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::ReqwestMessage(_) => ClassifiedError::Transport,
+            Self::InvalidStatusCode(_) | Self::SimdJson(_) => ClassifiedError::Parse,
+            Self::QueryNotBuilt => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+impl ClassifiableError for crate::types::Error {
+    /// Errors in the types module are mostly raised while building or
+    /// validating a request. A missing or invalid API key is classified as
+    /// `Denied`, and a TLS backend that isn't compiled in as `Transport`.
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::MissingEnvironmentVariable(_) | Self::InvalidApiKey(_) => ClassifiedError::Denied,
+            #[cfg(feature = "reqwest")]
+            Self::UnavailableTlsBackend(_) => ClassifiedError::Transport,
+            _ => ClassifiedError::InvalidRequest,
+        } // match
+    } // fn
+} // impl
+
+impl ClassifiableError for crate::error::Error {
+    fn classify(&self) -> ClassifiedError {
+        match self {
+            Self::Type(error) => error.classify(),
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(error) => error.classify(),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(error) => error.classify(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(error) => error.classify(),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(error) => error.classify(),
+            #[cfg(feature = "places")]
+            Self::Places(error) => error.classify(),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(error) => error.classify(),
+            #[cfg(feature = "roads")]
+            Self::Roads(error) => error.classify(),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(error) => error.classify(),
+            Self::Stored(_) => ClassifiedError::Other,
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) => ClassifiedError::Transport,
+            #[cfg(feature = "reqwest")]
+            Self::CircuitOpen(_) => ClassifiedError::Transient,
+            #[cfg(feature = "polyline")]
+            Self::Polyline(_) => ClassifiedError::Parse,
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,79 @@
+//! Checks the format of Google Maps Platform API keys, so that a malformed
+//! key is reported when the client is built rather than as an unexplained
+//! `REQUEST_DENIED` response on the first request.
+
+use crate::client::GoogleMapsClient;
+use crate::types::Error as TypeError;
+
+// -----------------------------------------------------------------------------
+
+/// The prefix of every Google Cloud API key.
+const API_KEY_PREFIX: &str = "AIza";
+
+/// The length of a Google Cloud API key.
+const API_KEY_LENGTH: usize = 39;
+
+// -----------------------------------------------------------------------------
+
+/// Trims surrounding whitespace, such as the trailing newline of a key read
+/// from a secrets file, and checks the shape of the key.
+///
+/// Empty keys and keys containing characters that never appear in an API key
+/// are rejected. A key without the usual `AIza` prefix or 39-character length
+/// is accepted, since it may be meant for a proxy or a test server, but a
+/// warning is logged.
+pub fn validate_api_key(key: String) -> Result<String, TypeError> {
+    let key = if key.trim().len() == key.len() { key } else { key.trim().to_string() };
+
+    if key.is_empty() {
+        return Err(TypeError::InvalidApiKey("the key is empty".to_string()));
+    } // if
+
+    if let Some(character) = key
+        .chars()
+        .find(|character| !character.is_ascii_alphanumeric() && *character != '-' && *character != '_')
+    {
+        return Err(TypeError::InvalidApiKey(format!(
+            "the key contains the invalid character {character:?}"
+        )));
+    } // if
+
+    if !key.starts_with(API_KEY_PREFIX) || key.len() != API_KEY_LENGTH {
+        tracing::warn!(
+            "the Google Maps API key does not look like a Google Cloud API key, which starts \
+            with `{API_KEY_PREFIX}` and is {API_KEY_LENGTH} characters long"
+        );
+    } // if
+
+    Ok(key)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+    /// Initializes a `GoogleMapsClient` with the API key read from the named
+    /// environment variable. Surrounding whitespace is trimmed from the key.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` ‧ The name of the environment variable, for example
+    ///   `GOOGLE_MAPS_API_KEY`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new_from_env("GOOGLE_MAPS_API_KEY")?;
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// * `MissingEnvironmentVariable` if the variable is not set.
+    /// * `InvalidApiKey` if the key is empty or malformed.
+    /// * Returns an error if the `reqwest` client could not be built.
+
+    pub fn try_new_from_env(name: &str) -> Result<Self, crate::GoogleMapsError> {
+        let key = std::env::var(name)
+            .map_err(|_| TypeError::MissingEnvironmentVariable(name.to_string()))?;
+        Self::try_new(key)
+    } // fn
+} // impl
@@ -30,6 +30,9 @@ impl GoogleMapsClient {
     pub fn build(&self) -> Self {
         Self {
             key: self.key.clone(),
+            channel: self.channel.clone(),
+            language: self.language,
+            region: self.region,
         } // GoogleMapsClient
     } // fn
 
@@ -0,0 +1,566 @@
+//! Contains the `ClientConfig` struct. It captures the `GoogleMapsClient`
+//! settings in a serializable form so that deployments can configure the
+//! client from a TOML or JSON file, or from environment variables, without
+//! code changes.
+
+use crate::{client::GoogleMapsClient, request_rate::api::Api, types::Error as TypeError};
+use crate::client::impls::USER_AGENT;
+use crate::client::resolver::{IpPreference, ResolverOptions};
+use crate::client::retry_policy::RetryPolicy;
+use crate::client::transport_info::TlsBackend;
+use crate::types::{Language, Region};
+use reqwest::dns::Resolve;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+
+// -----------------------------------------------------------------------------
+
+/// Environment variable containing the Google Maps API key. Required.
+const ENV_API_KEY: &str = "GOOGLE_MAPS_API_KEY";
+
+/// Environment variable containing the request timeout, in seconds. Optional.
+const ENV_TIMEOUT: &str = "GOOGLE_MAPS_TIMEOUT_SECS";
+
+/// Environment variable containing the connection timeout, in seconds.
+/// Optional.
+const ENV_CONNECT_TIMEOUT: &str = "GOOGLE_MAPS_CONNECT_TIMEOUT_SECS";
+
+/// Environment variable containing the rate limits. Optional.
+///
+/// A comma-separated list of `api=requests/seconds` entries. For example,
+/// `all=50/1,places=10/1` limits all requests to 50 per second and Places API
+/// requests to 10 per second. Valid API names are `all`, `directions`,
+/// `distance_matrix`, `elevation`, `geocoding`, `time_zone`, `places` and
+/// `roads`, and the service names `place_autocomplete`, `query_autocomplete`,
+/// `place_details`, `place_photo`, `text_search`, `nearby_search`,
+/// `snap_to_roads`, `nearest_roads` and `speed_limits`.
+const ENV_RATE_LIMITS: &str = "GOOGLE_MAPS_RATE_LIMITS";
+
+/// Environment variable containing a custom `User-Agent` header. Optional.
+const ENV_USER_AGENT: &str = "GOOGLE_MAPS_USER_AGENT";
+
+/// Environment variable containing the channel sent with every request.
+/// Optional.
+const ENV_CHANNEL: &str = "GOOGLE_MAPS_CHANNEL";
+
+/// Environment variable containing the TLS backend, `native-tls` or `rustls`.
+/// Optional.
+const ENV_TLS_BACKEND: &str = "GOOGLE_MAPS_TLS_BACKEND";
+
+/// Environment variable containing the IP preference, for example
+/// `prefer-ipv4`. Optional.
+const ENV_IP_PREFERENCE: &str = "GOOGLE_MAPS_IP_PREFERENCE";
+
+/// Environment variable containing the default language, for example `fr`.
+/// Optional.
+const ENV_LANGUAGE: &str = "GOOGLE_MAPS_LANGUAGE";
+
+/// Environment variable containing the default region, for example `ca`.
+/// Optional.
+const ENV_REGION: &str = "GOOGLE_MAPS_REGION";
+
+/// Environment variable containing how long to keep retrying failed requests,
+/// in seconds. `0` disables retries. Optional.
+const ENV_MAX_RETRY_SECS: &str = "GOOGLE_MAPS_MAX_RETRY_SECS";
+
+/// Environment variable containing a base URL that replaces Google's servers,
+/// for example `http://127.0.0.1:8080`. Optional.
+const ENV_BASE_URL: &str = "GOOGLE_MAPS_BASE_URL";
+
+/// The request & connection timeout used when none is configured. Matches
+/// `GoogleMapsClient::try_new`.
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+// -----------------------------------------------------------------------------
+//
+/// A serializable description of a `GoogleMapsClient`. Use
+/// `GoogleMapsClient::from_config` to build a client from it.
+///
+/// ## Example
+///
+/// A TOML configuration file:
+///
+/// ```toml
+/// key = "YOUR_GOOGLE_API_KEY_HERE"
+/// timeout_secs = 10
+/// language = "fr"
+/// region = "ca"
+///
+/// [retry_policy]
+/// max_elapsed_secs = 30
+///
+/// [[rate_limits]]
+/// api = "All"
+/// requests = 50
+/// per_secs = 1
+/// ```
+///
+/// The `Debug` output redacts the API key, so a configuration may be logged.
+
+#[derive(Clone, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ClientConfig {
+    /// Your application's API key.
+    pub key: String,
+
+    /// Per-API request rate limits.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rate_limits: Vec<RateLimitConfig>,
+
+    /// Request timeout, in seconds. Defaults to 30 seconds.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// Connection timeout, in seconds. Defaults to 30 seconds.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+
+    /// `User-Agent` header sent with every request. Defaults to
+    /// `RustGoogleMaps/<version>`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// Channel sent with every request, for usage reports. Not sent by
+    /// default.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    /// TLS backend used to secure connections, when more than one was
+    /// compiled in. Defaults to `reqwest`'s default backend.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_backend: Option<TlsBackend>,
+
+    /// Which IP versions to connect over, and which to try first. Defaults
+    /// to the order the operating system's resolver returns.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_preference: Option<IpPreference>,
+
+    /// How requests that failed with a transient error are retried. Defaults
+    /// to retrying for up to 15 minutes.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// Language in which to return results, for requests that don't set
+    /// their own. Not sent by default.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// Region used to bias results, for requests that don't set their own.
+    /// Not sent by default.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// Base URL that replaces the scheme and host of Google's servers, for
+    /// example to send requests through a proxy. Defaults to Google's
+    /// servers.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+} // struct
+
+impl std::fmt::Debug for ClientConfig {
+    /// Formats the configuration with the API key redacted, so that it may be
+    /// logged.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("key", &"REDACTED")
+            .field("rate_limits", &self.rate_limits)
+            .field("timeout_secs", &self.timeout_secs)
+            .field("connect_timeout_secs", &self.connect_timeout_secs)
+            .field("user_agent", &self.user_agent)
+            .field("channel", &self.channel)
+            .field("tls_backend", &self.tls_backend)
+            .field("ip_preference", &self.ip_preference)
+            .field("retry_policy", &self.retry_policy)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("base_url", &self.base_url)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A request rate limit for one API, as used in `ClientConfig`. Equivalent to
+/// calling `GoogleMapsClient::with_rate(&api, requests, per_secs)`.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// The API the limit applies to.
+    pub api: Api,
+    /// The number of requests allowed per period.
+    pub requests: u16,
+    /// The length of the period, in seconds.
+    pub per_secs: u64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ClientConfig {
+    /// Reads the client configuration from environment variables.
+    ///
+    /// * `GOOGLE_MAPS_API_KEY` ‧ Required. Your application's API key.
+    /// * `GOOGLE_MAPS_TIMEOUT_SECS` ‧ Optional. Request timeout in seconds.
+    /// * `GOOGLE_MAPS_CONNECT_TIMEOUT_SECS` ‧ Optional. Connection timeout in
+    ///   seconds.
+    /// * `GOOGLE_MAPS_RATE_LIMITS` ‧ Optional. For example `all=50/1,places=10/1`.
+    /// * `GOOGLE_MAPS_USER_AGENT` ‧ Optional. Custom `User-Agent` header.
+    /// * `GOOGLE_MAPS_CHANNEL` ‧ Optional. Channel for usage reports.
+    /// * `GOOGLE_MAPS_TLS_BACKEND` ‧ Optional. `native-tls` or `rustls`.
+    /// * `GOOGLE_MAPS_IP_PREFERENCE` ‧ Optional. `any`, `prefer-ipv4`,
+    ///   `prefer-ipv6`, `ipv4-only` or `ipv6-only`.
+    /// * `GOOGLE_MAPS_MAX_RETRY_SECS` ‧ Optional. How long to keep retrying
+    ///   failed requests, in seconds. `0` disables retries.
+    /// * `GOOGLE_MAPS_LANGUAGE` ‧ Optional. Default language, for example `fr`.
+    /// * `GOOGLE_MAPS_REGION` ‧ Optional. Default region, for example `ca`.
+    /// * `GOOGLE_MAPS_BASE_URL` ‧ Optional. Base URL that replaces Google's
+    ///   servers, for example `http://127.0.0.1:8080`.
+    ///
+    /// ## Errors
+    ///
+    /// * `MissingEnvironmentVariable` if the API key is not set.
+    /// * `InvalidEnvironmentVariable` if an optional variable is set but cannot
+    ///   be parsed.
+
+    pub fn from_env() -> Result<Self, TypeError> {
+        let key = std::env::var(ENV_API_KEY)
+            .map_err(|_| TypeError::MissingEnvironmentVariable(ENV_API_KEY.to_string()))?;
+
+        let rate_limits = std::env::var(ENV_RATE_LIMITS).map_or_else(
+            |_| Ok(Vec::new()),
+            |value| parse_rate_limits(&value).ok_or_else(|| {
+                TypeError::InvalidEnvironmentVariable(ENV_RATE_LIMITS.to_string(), value.clone())
+            }),
+        )?;
+
+        let tls_backend = std::env::var(ENV_TLS_BACKEND).map_or(Ok(None), |value| {
+            TlsBackend::from_code(&value).map(Some).ok_or_else(|| {
+                TypeError::InvalidEnvironmentVariable(ENV_TLS_BACKEND.to_string(), value.clone())
+            }) // ok_or_else
+        })?; // map_or
+
+        let ip_preference = std::env::var(ENV_IP_PREFERENCE).map_or(Ok(None), |value| {
+            IpPreference::from_code(&value).map(Some).ok_or_else(|| {
+                TypeError::InvalidEnvironmentVariable(ENV_IP_PREFERENCE.to_string(), value.clone())
+            }) // ok_or_else
+        })?; // map_or
+
+        let language = std::env::var(ENV_LANGUAGE).map_or(Ok(None), |value| {
+            Language::try_from(value.trim()).map(Some).map_err(|_| {
+                TypeError::InvalidEnvironmentVariable(ENV_LANGUAGE.to_string(), value.clone())
+            }) // map_err
+        })?; // map_or
+
+        let region = std::env::var(ENV_REGION).map_or(Ok(None), |value| {
+            Region::try_from(value.trim()).map(Some).map_err(|_| {
+                TypeError::InvalidEnvironmentVariable(ENV_REGION.to_string(), value.clone())
+            }) // map_err
+        })?; // map_or
+
+        let retry_policy = env_secs(ENV_MAX_RETRY_SECS)?
+            .map(|secs| RetryPolicy::default().with_max_elapsed_secs(secs));
+
+        Ok(Self {
+            key,
+            rate_limits,
+            timeout_secs: env_secs(ENV_TIMEOUT)?,
+            connect_timeout_secs: env_secs(ENV_CONNECT_TIMEOUT)?,
+            user_agent: std::env::var(ENV_USER_AGENT).ok(),
+            channel: std::env::var(ENV_CHANNEL).ok(),
+            tls_backend,
+            ip_preference,
+            retry_policy,
+            language,
+            region,
+            base_url: std::env::var(ENV_BASE_URL).ok(),
+        }) // ClientConfig
+    } // fn
+
+    /// Returns the configured request timeout, or the default of 30 seconds.
+
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS))
+    } // fn
+
+    /// Returns the configured connection timeout, or the default of 30
+    /// seconds.
+
+    #[must_use]
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.connect_timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a `GoogleMapsClient` from a `ClientConfig`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::from_config(&ClientConfig::from_env()?)?;
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// * `UnavailableTlsBackend` if the configured TLS backend was not
+    ///   compiled in.
+    /// * Returns an error if the `reqwest` client could not be built.
+
+    pub fn from_config(config: &ClientConfig) -> Result<Self, crate::GoogleMapsError> {
+        Self::from_config_and_resolver(config, None)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes a `GoogleMapsClient` from a `ClientConfig`, resolving the
+    /// host names of Google's servers with a custom DNS resolver, for example
+    /// one that queries a specific DNS server. `ClientConfig::ip_preference`
+    /// is applied to the addresses it returns.
+    ///
+    /// To use a custom resolver with a `reqwest` client of your own, apply
+    /// `ResolverOptions::apply` to its builder instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::from_config_with_resolver(
+    ///     &ClientConfig::from_env()?,
+    ///     Arc::new(MyResolver),
+    /// )?;
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// * `UnavailableTlsBackend` if the configured TLS backend was not
+    ///   compiled in.
+    /// * Returns an error if the `reqwest` client could not be built.
+
+    pub fn from_config_with_resolver(
+        config: &ClientConfig,
+        resolver: Arc<dyn Resolve>,
+    ) -> Result<Self, crate::GoogleMapsError> {
+        Self::from_config_and_resolver(config, Some(resolver))
+    } // fn
+
+    /// Initializes a `GoogleMapsClient` from a `ClientConfig` and an optional
+    /// custom DNS resolver.
+    fn from_config_and_resolver(
+        config: &ClientConfig,
+        resolver: Option<Arc<dyn Resolve>>,
+    ) -> Result<Self, crate::GoogleMapsError> {
+        let user_agent = config.user_agent.clone().unwrap_or_else(|| USER_AGENT.to_string());
+
+        let builder = reqwest::Client::builder()
+            .user_agent(user_agent.clone())
+            .connect_timeout(config.connect_timeout())
+            .timeout(config.timeout())
+            .read_timeout(config.timeout());
+
+        let resolver_options = ResolverOptions {
+            ip_preference: config.ip_preference.unwrap_or_default(),
+            resolver,
+        }; // ResolverOptions
+        let builder = resolver_options.apply(builder);
+
+        let (builder, tls_backend) = match config.tls_backend {
+            None => (with_default_tls(builder), TlsBackend::default_backend()),
+            Some(backend) => (with_tls_backend(builder, backend)?, Some(backend)),
+        }; // match
+
+        let mut client = Self::try_with_reqwest_builder(config.key.clone(), builder)?;
+        client.tls_backend = tls_backend;
+        client.user_agent = user_agent;
+
+        if let Some(channel) = &config.channel {
+            client.with_channel(channel.clone());
+        } // if
+
+        if let Some(retry_policy) = config.retry_policy {
+            client.with_retry_policy(retry_policy);
+        } // if
+
+        client.language = config.language;
+        client.region = config.region;
+
+        if let Some(base_url) = &config.base_url {
+            client.with_base_url(base_url.clone());
+        } // if
+
+        for rate_limit in &config.rate_limits {
+            client.with_rate(
+                &rate_limit.api,
+                rate_limit.requests,
+                Duration::from_secs(rate_limit.per_secs),
+            );
+        } // for
+
+        Ok(client)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Selects a TLS backend on a `reqwest` client builder. Returns an error if
+/// the backend was not compiled in.
+pub(super) fn with_tls_backend(
+    builder: reqwest::ClientBuilder,
+    backend: TlsBackend,
+) -> Result<reqwest::ClientBuilder, TypeError> {
+    match backend {
+        #[cfg(any(
+            feature = "reqwest-native-tls",
+            feature = "reqwest-native-tls-vendored",
+            feature = "reqwest-native-tls-alpn",
+        ))]
+        TlsBackend::NativeTls => Ok(builder.use_native_tls()),
+        // `reqwest` can't select its default TLS by name, but it is always
+        // preferred when compiled in:
+        #[cfg(all(
+            feature = "reqwest-default-tls",
+            not(feature = "reqwest-native-tls"),
+            not(feature = "reqwest-native-tls-vendored"),
+            not(feature = "reqwest-native-tls-alpn"),
+        ))]
+        TlsBackend::NativeTls => Ok(builder),
+        #[cfg(any(
+            feature = "reqwest-rustls-tls",
+            feature = "reqwest-rustls-tls-manual-roots",
+            feature = "reqwest-rustls-tls-webpki-roots",
+            feature = "reqwest-rustls-tls-native-roots",
+            feature = "reqwest-middleware-rustls-tls",
+        ))]
+        TlsBackend::Rustls => Ok(use_rustls(builder)),
+        #[allow(unreachable_patterns)]
+        _ => {
+            // Without a TLS feature this is the only arm, so the builder is
+            // consumed here:
+            drop(builder);
+            Err(TypeError::UnavailableTlsBackend(backend))
+        }, // _
+    } // match
+} // fn
+
+/// Configures the default TLS backend on a `reqwest` client builder. Only
+/// `rustls` with the `rustls-platform-verifier` feature needs configuring.
+#[cfg(feature = "rustls-platform-verifier")]
+pub(super) fn with_default_tls(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    if TlsBackend::default_backend() == Some(TlsBackend::Rustls) {
+        use_rustls(builder)
+    } else {
+        builder
+    } // if
+} // fn
+
+/// Configures the default TLS backend on a `reqwest` client builder. Without
+/// the `rustls-platform-verifier` feature, `reqwest`'s defaults are used as-is.
+#[cfg(not(feature = "rustls-platform-verifier"))]
+pub(super) const fn with_default_tls(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    builder
+} // fn
+
+/// Selects `rustls` on a `reqwest` client builder.
+#[cfg(all(
+    any(
+        feature = "reqwest-rustls-tls",
+        feature = "reqwest-rustls-tls-manual-roots",
+        feature = "reqwest-rustls-tls-webpki-roots",
+        feature = "reqwest-rustls-tls-native-roots",
+        feature = "reqwest-middleware-rustls-tls",
+    ),
+    not(feature = "rustls-platform-verifier"),
+))]
+fn use_rustls(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    builder.use_rustls_tls()
+} // fn
+
+/// Selects `rustls` on a `reqwest` client builder, validating certificates
+/// with the operating system's verifier. Unlike a trust store, the verifier
+/// also checks revocation and enterprise policies where the platform
+/// supports it.
+#[cfg(feature = "rustls-platform-verifier")]
+fn use_rustls(builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+    use rustls_platform_verifier::BuilderVerifierExt;
+
+    let provider = std::sync::Arc::new(rustls::crypto::ring::default_provider());
+    match rustls::ClientConfig::builder_with_provider(provider).with_safe_default_protocol_versions() {
+        Ok(config) => {
+            let mut config = config.with_platform_verifier().with_no_client_auth();
+            // `reqwest` only negotiates HTTP/2 over a preconfigured TLS
+            // connection if the configuration offers it:
+            config.alpn_protocols = if cfg!(feature = "reqwest-http2") {
+                vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+            } else {
+                vec![b"http/1.1".to_vec()]
+            }; // if
+            builder.use_preconfigured_tls(config)
+        }, // Ok
+        Err(error) => {
+            tracing::warn!("the platform certificate verifier is unavailable: {error}");
+            builder.use_rustls_tls()
+        }, // Err
+    } // match
+} // fn
+
+/// Reads an optional number of seconds from the named environment variable.
+fn env_secs(name: &str) -> Result<Option<u64>, TypeError> {
+    std::env::var(name).map_or(Ok(None), |value| {
+        value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| TypeError::InvalidEnvironmentVariable(name.to_string(), value.clone()))
+    }) // map_or
+} // fn
+
+/// Parses a rate limit list such as `all=50/1,places=10/1`. Returns `None` if
+/// any entry is malformed.
+fn parse_rate_limits(value: &str) -> Option<Vec<RateLimitConfig>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (api, rate) = entry.split_once('=')?;
+            let (requests, per_secs) = rate.split_once('/')?;
+            let api = match api.trim().to_lowercase().as_str() {
+                "all" => Api::All,
+                "directions" => Api::Directions,
+                "distance_matrix" => Api::DistanceMatrix,
+                "elevation" => Api::Elevation,
+                "geocoding" => Api::Geocoding,
+                "time_zone" => Api::TimeZone,
+                "places" => Api::Places,
+                "roads" => Api::Roads,
+                "place_autocomplete" => Api::PlaceAutocomplete,
+                "query_autocomplete" => Api::QueryAutocomplete,
+                "place_details" => Api::PlaceDetails,
+                "place_photo" => Api::PlacePhoto,
+                "text_search" => Api::TextSearch,
+                "nearby_search" => Api::NearbySearch,
+                "snap_to_roads" => Api::SnapToRoads,
+                "nearest_roads" => Api::NearestRoads,
+                "speed_limits" => Api::SpeedLimits,
+                _ => return None,
+            }; // match
+            Some(RateLimitConfig {
+                api,
+                requests: requests.trim().parse().ok()?,
+                per_secs: per_secs.trim().parse().ok()?,
+            }) // RateLimitConfig
+        }) // map
+        .collect()
+} // fn
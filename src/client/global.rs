@@ -0,0 +1,65 @@
+//! Contains an optional, process-wide `GoogleMapsClient`. It's intended for
+//! small tools and scripts that don't want to pass a client through every
+//! function. Libraries and larger applications should construct and pass a
+//! `GoogleMapsClient` instead.
+
+use crate::client::{config::ClientConfig, GoogleMapsClient};
+use std::sync::OnceLock;
+
+// -----------------------------------------------------------------------------
+
+/// The process-wide client. It's initialized at most once, either explicitly
+/// by `set_global` or lazily from environment variables by `global`.
+static GLOBAL_CLIENT: OnceLock<GoogleMapsClient> = OnceLock::new();
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the process-wide `GoogleMapsClient`.
+///
+/// On first use, the client is built from environment variables using
+/// `ClientConfig::from_env`, unless one was already installed with
+/// `set_global`. The client is shared by every caller afterwards.
+///
+/// ## Example
+///
+/// ```rust
+/// // GOOGLE_MAPS_API_KEY=... cargo run
+/// let time_zone = google_maps::global()?
+///     .time_zone(LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512))?, Utc::now())
+///     .execute()
+///     .await?;
+/// ```
+///
+/// ## Errors
+///
+/// * Returns an error if the client has not been initialized yet and the
+///   environment does not contain a valid configuration, or if the `reqwest`
+///   client could not be built. The next call will try again.
+
+pub fn global() -> Result<&'static GoogleMapsClient, crate::GoogleMapsError> {
+    if let Some(client) = GLOBAL_CLIENT.get() {
+        return Ok(client);
+    } // if
+
+    let client = GoogleMapsClient::from_config(&ClientConfig::from_env()?)?;
+
+    // If another thread won the race, its client is returned and ours is
+    // dropped:
+    Ok(GLOBAL_CLIENT.get_or_init(|| client))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Installs the process-wide `GoogleMapsClient` returned by `global`. Use this
+/// instead of environment variables to configure the global client in code.
+///
+/// ## Errors
+///
+/// * Returns the client back if the global client has already been
+///   initialized.
+
+// The client is returned as-is, like `OnceLock::set` does:
+#[allow(clippy::result_large_err)]
+pub fn set_global(client: GoogleMapsClient) -> Result<(), GoogleMapsClient> {
+    GLOBAL_CLIENT.set(client)
+} // fn
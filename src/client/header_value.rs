@@ -0,0 +1,21 @@
+//! Checks header values set on the client or on a request, so that a value
+//! that can't be sent is reported when it is set rather than when every
+//! attempt to send the request fails.
+
+use crate::types::Error as TypeError;
+
+// -----------------------------------------------------------------------------
+
+/// Returns the value if it can be sent as the value of the `name` header.
+/// Header values may not contain control characters, such as line breaks.
+pub fn validate_header_value(name: &str, value: String) -> Result<String, TypeError> {
+    #[cfg(feature = "reqwest")]
+    if reqwest::header::HeaderValue::from_str(&value).is_err() {
+        return Err(TypeError::InvalidHeaderValue(name.to_string(), value));
+    } // if
+
+    #[cfg(not(feature = "reqwest"))]
+    let _ = name;
+
+    Ok(value)
+} // fn
@@ -1,16 +1,41 @@
 use crate::client::GoogleMapsClient;
+#[cfg(any(feature = "geocoding", feature = "time_zone", feature = "places", feature = "roads"))]
 use crate::types::LatLng;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+use std::borrow::Cow;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
 #[cfg(feature = "distance_matrix")]
 use crate::directions::request::waypoint::Waypoint;
 #[cfg(feature = "reqwest")]
+use crate::client::retry_policy::RetryPolicy;
+#[cfg(feature = "reqwest")]
+use crate::client::transport_info::TlsBackend;
+#[cfg(feature = "reqwest")]
 use crate::request_rate::RequestRate;
 #[cfg(feature = "time_zone")]
 use chrono::{DateTime, Utc};
 #[cfg(feature = "reqwest")]
 use reqwest::Response;
 
+// -----------------------------------------------------------------------------
+
+/// The `User-Agent` header sent with every request, unless the client's
+/// `reqwest` client was replaced.
+#[cfg(feature = "reqwest")]
+pub(super) const USER_AGENT: &str = concat!("RustGoogleMaps/", env!("CARGO_PKG_VERSION"));
+
+/// Returns a `reqwest` client builder with the default `User-Agent` header and
+/// timeouts used by `GoogleMapsClient::try_new`.
+#[cfg(feature = "reqwest")]
+pub(super) fn reqwest_builder() -> reqwest::ClientBuilder {
+    crate::client::config::with_default_tls(reqwest::Client::builder())
+        .user_agent(USER_AGENT)
+        .connect_timeout(std::time::Duration::from_secs(30))
+        .timeout(std::time::Duration::from_secs(30))
+        .read_timeout(std::time::Duration::from_secs(30))
+} // fn
+
 // =============================================================================
 
 impl GoogleMapsClient {
@@ -23,51 +48,83 @@ impl GoogleMapsClient {
     /// * `key` ‧ Your application's API key. This key identifies your
     ///   application for purposes of quota management. Learn how to [get a
     ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///   Surrounding whitespace, such as a trailing newline, is trimmed.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidApiKey` if the key is empty or contains characters that never
+    ///   appear in an API key.
+    /// * Returns an error if the `reqwest` client could not be built.
 
-    #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
+    #[cfg(feature = "reqwest")]
     pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!(
-                "RustGoogleMaps/{version}",
-                version = env!("CARGO_PKG_VERSION")
-            ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
+        Self::try_with_reqwest_builder(key.into(), reqwest_builder())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Validates the API key, then builds the client's `reqwest` client from
+    /// the given builder. Everything else takes its default setting.
+
+    #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
+    pub(super) fn try_with_reqwest_builder(
+        key: String,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<Self, crate::GoogleMapsError> {
+        let key = crate::client::api_key::validate_api_key(key)?;
+
+        let reqwest_client = builder.build()?;
 
         Ok(Self {
-            key: key.into(),
+            key,
+            channel: None,
+            language: None,
+            region: None,
             rate_limit: RequestRate::default(),
+            circuit_breaker: None,
+            retry_policy: RetryPolicy::default(),
+            base_url: None,
+            zero_results_as_error: false,
+            accept_language: None,
+            trace_context: None,
+            user_agent: USER_AGENT.to_string(),
+            user_agent_suffix: None,
+            api_client: None,
+            tls_backend: TlsBackend::default_backend(),
             reqwest_client,
         }) // GoogleMapsClient
     } // fn
 
     // -------------------------------------------------------------------------
     //
-    /// Initialize the settings needed for a Google Cloud Maps API transaction.
-    ///
-    /// ## Arguments
-    ///
-    /// * `key` ‧ Your application's API key. This key identifies your
-    /// application for purposes of quota management. Learn how to [get a
-    /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    /// Validates the API key, then builds the client's `reqwest` client from
+    /// the given builder. Everything else takes its default setting.
 
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
-    pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!(
-                "RustGoogleMaps/{version}",
-                version = env!("CARGO_PKG_VERSION")
-            ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
+    pub(super) fn try_with_reqwest_builder(
+        key: String,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<Self, crate::GoogleMapsError> {
+        let key = crate::client::api_key::validate_api_key(key)?;
+
+        let reqwest_client = builder.build()?;
 
         Ok(Self {
-            key: key.into(),
+            key,
+            channel: None,
+            language: None,
+            region: None,
             rate_limit: RequestRate::default(),
+            circuit_breaker: None,
+            retry_policy: RetryPolicy::default(),
+            base_url: None,
+            zero_results_as_error: false,
+            accept_language: None,
+            trace_context: None,
+            user_agent: USER_AGENT.to_string(),
+            user_agent_suffix: None,
+            api_client: None,
+            tls_backend: TlsBackend::default_backend(),
             reqwest_client: crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client),
         }) // GoogleMapsClient
     } // fn
@@ -91,6 +148,8 @@ impl GoogleMapsClient {
     #[cfg(feature = "reqwest")]
     #[deprecated(since = "3.4.2", note = "use `try_new` instead")]
     #[must_use]
+    // This deprecated constructor documents its panic; use `try_new` instead:
+    #[allow(clippy::unwrap_used)]
     pub fn new(key: impl Into<String>) -> Self {
         Self::try_new(key).unwrap()
     }
@@ -107,7 +166,7 @@ impl GoogleMapsClient {
 
     #[cfg(not(feature = "reqwest"))]
     pub fn new(key: impl Into<String>) -> Self {
-        Self { key: key.into() }
+        Self { key: key.into(), channel: None, language: None, region: None }
     } // fn
 
     // -------------------------------------------------------------------------
@@ -142,6 +201,44 @@ impl GoogleMapsClient {
         crate::directions::request::Request::new(self, origin.into(), destination.into())
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Directions API is a service that calculates directions between
+    /// locations. This method starts a Directions request whose parameter
+    /// combinations are checked at compile time rather than at run time. For
+    /// example, transit modes may only be set after selecting transit
+    /// directions, and an arrival time cannot be combined with a departure
+    /// time.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let directions = google_maps_client.typed_directions(
+    ///     // Origin: Canadian Museum of Nature
+    ///     Location::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+    ///     // Destination: Canada Science and Technology Museum
+    ///     Location::try_from_f32(45.403_509, -75.618_904)?,
+    /// )
+    /// .transit()
+    /// .with_transit_modes([TransitMode::Bus])
+    /// .execute()
+    /// .await?;
+    /// ```
+
+    #[cfg(feature = "directions")]
+    #[must_use]
+    pub fn typed_directions(
+        &self,
+        origin: impl Into<Location>,
+        destination: impl Into<Location>
+    ) -> crate::directions::request::typed::TypedRequest<'_> {
+        crate::directions::request::typed::TypedRequest::new(self, origin.into(), destination.into())
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Distance Matrix API is a service that provides travel distance and
@@ -320,10 +417,10 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "autocomplete")]
     #[must_use]
-    pub fn place_autocomplete(
-        &self,
-        input: impl Into<String>
-    ) -> crate::places::place_autocomplete::request::Request {
+    pub fn place_autocomplete<'a>(
+        &'a self,
+        input: impl Into<Cow<'a, str>>
+    ) -> crate::places::place_autocomplete::request::Request<'a> {
         crate::places::place_autocomplete::request::Request::new(self, input)
     } // fn
 
@@ -360,10 +457,10 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "autocomplete")]
     #[must_use]
-    pub fn query_autocomplete(
-        &self,
-        input: impl Into<String>
-    ) -> crate::places::query_autocomplete::request::Request {
+    pub fn query_autocomplete<'a>(
+        &'a self,
+        input: impl Into<Cow<'a, str>>
+    ) -> crate::places::query_autocomplete::request::Request<'a> {
         crate::places::query_autocomplete::request::Request::new(self, input)
     } // fn
 
@@ -453,11 +550,11 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "places")]
     #[must_use]
-    pub fn text_search(
-        &self,
-        query: impl Into<String>,
+    pub fn text_search<'a>(
+        &'a self,
+        query: impl Into<Cow<'a, str>>,
         radius: impl Into<u32>
-    ) -> crate::places::place_search::text_search::request::Request {
+    ) -> crate::places::place_search::text_search::request::Request<'a> {
         crate::places::place_search::text_search::request::Request::new(self, query, radius.into())
     } // fn
 
@@ -522,6 +619,36 @@ impl GoogleMapsClient {
         )
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Like `nearby_search`, but takes a `Circle`, whose radius is checked
+    /// against the limits of the _Nearby Search_ endpoint before the request
+    /// is built.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TypeError::InvalidRadiusForEndpoint` if the radius is `0`.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let downtown = Circle::from_center_km(LatLng::try_from_dec(dec!(53.54), dec!(-113.49))?, 1.5)?;
+    ///
+    /// let search_results = google_maps_client.nearby_search_in_circle(downtown)?
+    ///     .with_type(PlaceType::Restaurant)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "places")]
+    pub fn nearby_search_in_circle(
+        &self,
+        circle: crate::types::Circle
+    ) -> Result<crate::places::place_search::nearby_search::request::Request<'_>, crate::GoogleMapsError> {
+        circle.validate_for(crate::types::CircleEndpoint::NearbySearch)?;
+        Ok(self.nearby_search(circle.center(), circle.radius_m()))
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Places API **Place Details** service returns more details about a
@@ -554,14 +681,11 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "places")]
     #[must_use]
-    pub fn place_details(
-        &self,
-        place_id: impl Into<String>
-    ) -> crate::places::place_details::request::Request {
-        crate::places::place_details::request::Request::new(
-            self,
-            place_id.into()
-        )
+    pub fn place_details<'a>(
+        &'a self,
+        place_id: impl Into<Cow<'a, str>>
+    ) -> crate::places::place_details::request::Request<'a> {
+        crate::places::place_details::request::Request::new(self, place_id)
     } // fn
 
     // -------------------------------------------------------------------------
@@ -625,6 +749,45 @@ impl GoogleMapsClient {
         crate::roads::snap_to_roads::request::Request::new(self, path)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Roads API **Snap To Roads** service, taking the path as a
+    /// [geo](https://crates.io/crates/geo) crate `LineString`. See
+    /// `snap_to_roads` for more information.
+    ///
+    /// Use `Response::to_line_string` to get the snapped path back as a
+    /// `LineString`, and `Response::original_indices` to map its points to
+    /// the points of this path.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let snapped_points = google_maps_client
+    ///     .snap_line_string_to_roads(&gps_track)?
+    ///     .with_interpolation(true)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// let snapped_track = snapped_points.to_line_string()?;
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// * Returns an error if a coordinate of the `LineString` is not a valid
+    ///   latitude & longitude.
+
+    #[cfg(all(feature = "roads", feature = "geo"))]
+    pub fn snap_line_string_to_roads(
+        &self,
+        path: &geo_types::geometry::LineString<f64>
+    ) -> Result<crate::roads::snap_to_roads::request::Request<'_>, crate::GoogleMapsError> {
+        let path = path
+            .coords()
+            .map(LatLng::try_from)
+            .collect::<Result<Vec<LatLng>, _>>()?;
+        Ok(crate::roads::snap_to_roads::request::Request::new(self, path))
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Roads API **Nearest Roads** service returns individual road segments
@@ -675,11 +838,148 @@ impl GoogleMapsClient {
         crate::roads::snap_to_roads::request::Request::new(self, points)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Roads API **Speed Limits** service returns the posted speed limit
+    /// for a road segment. The Speed Limit service is available to all
+    /// customers with an Asset Tracking license.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments, it initiates a builder pattern. Use
+    /// the `for_path`, `for_place_ids` or `for_route` methods of the resulting
+    /// type to select the road segments.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let speed_limits = google_maps_client.speed_limits()
+    ///     .for_place_ids(vec!["ChIJX12duJAwGQ0Ra0d4Oi4jOGE", "ChIJLQcticc0GQ0RoHqCVjmGwbY"])
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "roads")]
+    #[must_use]
+    pub const fn speed_limits(&self) -> crate::roads::speed_limits::request::Request<'_> {
+        crate::roads::speed_limits::request::Request::new(self)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the posted speed limits along a _Directions API_ route. The
+    /// route is snapped to roads and the speed limits of the road segments are
+    /// returned in one call. Routes longer than 100 points are automatically
+    /// split into several requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route` ‧ A route returned by the _Directions API_.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let speed_limit_cache = SpeedLimitCache::default();
+    ///
+    /// let speed_limits = google_maps_client
+    ///     .speed_limits_for_route(&directions.routes[0])
+    ///     .with_cache(&speed_limit_cache)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(all(feature = "roads", feature = "directions"))]
+    #[must_use]
+    pub fn speed_limits_for_route(
+        &self,
+        route: &crate::directions::response::route::Route
+    ) -> crate::roads::speed_limits::request::Request<'_> {
+        let mut request = crate::roads::speed_limits::request::Request::new(self);
+        request.for_route(route);
+        request
+    } // fn
+
     #[cfg(feature = "reqwest")]
     pub async fn get_request(&self, url: &str) -> Result<Response, crate::ReqError> {
-        match self.reqwest_client.get(url).build() {
+        self.get_request_with_language(url, None).await
+    } // fn
+
+    /// Performs an HTTP get request, sending the specified `Accept-Language`
+    /// header. If `accept_language` is `None`, the client's
+    /// `Accept-Language` header is sent, if one is set. The trace context
+    /// headers are sent if a trace context provider is set. The request is
+    /// sent to the client's base URL, if one is set.
+    #[cfg(feature = "reqwest")]
+    pub async fn get_request_with_language(
+        &self,
+        url: &str,
+        accept_language: Option<&str>
+    ) -> Result<Response, crate::ReqError> {
+        let mut request = self.reqwest_client.get(self.rebase_url(url).as_ref());
+        for (name, value) in self.request_headers(accept_language) {
+            request = request.header(name, value);
+        } // for
+        match request.build() {
             Ok(request) => self.reqwest_client.execute(request).await,
             Err(error) => Err(crate::ReqError::from(error)),
-        }
-    }
+        } // match
+    } // fn
+
+    /// Returns `url` with the scheme and host of Google's servers replaced by
+    /// the client's base URL, if one is set. See `with_base_url()`.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn rebase_url<'u>(&self, url: &'u str) -> std::borrow::Cow<'u, str> {
+        const ORIGINS: [&str; 2] = ["https://maps.googleapis.com", "https://roads.googleapis.com"];
+
+        let rebased = self.base_url.as_ref().and_then(|base_url| {
+            ORIGINS
+                .iter()
+                .find_map(|origin| url.strip_prefix(origin))
+                .map(|path| format!("{base_url}{path}"))
+        }); // and_then
+        rebased.map_or(std::borrow::Cow::Borrowed(url), std::borrow::Cow::Owned)
+    } // fn
+
+    /// Returns the headers sent with a request: the specified
+    /// `Accept-Language` header or, if `accept_language` is `None`, the
+    /// client's, the identification headers if they are set, and the trace
+    /// context headers if a trace context provider is set. Header names are
+    /// lower-case.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn request_headers(&self, accept_language: Option<&str>) -> Vec<(String, String)> {
+        let mut headers = Vec::new();
+        if let Some(accept_language) = accept_language.or(self.accept_language.as_deref()) {
+            headers.push(("accept-language".to_string(), accept_language.to_string()));
+        } // if
+        if let Some(suffix) = &self.user_agent_suffix {
+            headers.push(("user-agent".to_string(), format!("{} {suffix}", self.user_agent)));
+        } // if
+        if let Some(api_client) = &self.api_client {
+            headers.push(("x-goog-api-client".to_string(), format!("{USER_AGENT} {api_client}")));
+        } // if
+        if let Some(trace_context) = self.trace_context.and_then(|provider| provider()) {
+            if trace_context.is_valid() {
+                headers.push(("traceparent".to_string(), trace_context.traceparent));
+                if let Some(tracestate) = trace_context.tracestate {
+                    headers.push(("tracestate".to_string(), tracestate));
+                } // if
+            } else {
+                tracing::warn!(
+                    "invalid traceparent `{traceparent}` or tracestate `{tracestate}`, \
+                    trace context not sent",
+                    traceparent = trace_context.traceparent,
+                    tracestate = trace_context.tracestate.unwrap_or_default(),
+                );
+            } // if
+        } // if
+        headers
+    } // fn
 } // impl
@@ -4,17 +4,56 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "reqwest")]
+mod api_key;
 mod build;
+#[cfg(any(feature = "autocomplete", feature = "geocoding", feature = "places", feature = "reqwest"))]
+pub mod header_value;
+#[cfg(feature = "reqwest")]
+pub mod config;
+#[cfg(feature = "reqwest")]
+pub mod global;
+#[cfg(feature = "reqwest")]
+pub mod resolver;
+#[cfg(feature = "reqwest")]
+pub mod retry_policy;
+#[cfg(feature = "reqwest")]
+pub mod transport_info;
 mod impls;
 #[cfg(feature = "reqwest")]
+mod with_accept_language;
+#[cfg(feature = "reqwest")]
+mod with_api_client;
+#[cfg(feature = "reqwest")]
+mod with_base_url;
+#[cfg(feature = "reqwest")]
+mod with_circuit_breaker;
+mod with_channel;
+mod with_language;
+#[cfg(feature = "reqwest")]
 mod with_rate;
+mod with_region;
 #[cfg(feature = "reqwest")]
 mod with_reqwest_client;
+#[cfg(feature = "reqwest")]
+mod with_retry_policy;
+#[cfg(feature = "reqwest")]
+mod with_trace_context;
+#[cfg(feature = "reqwest")]
+mod with_user_agent_suffix;
+#[cfg(feature = "reqwest")]
+mod with_zero_results_as_error;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "reqwest")]
+use crate::circuit_breaker::CircuitBreaker;
+#[cfg(feature = "reqwest")]
+use crate::client::retry_policy::RetryPolicy;
 #[cfg(feature = "reqwest")]
 use crate::request_rate::RequestRate;
+#[cfg(feature = "reqwest")]
+use crate::trace_context::TraceContextProvider;
 
 // -----------------------------------------------------------------------------
 //
@@ -35,6 +74,24 @@ use crate::request_rate::RequestRate;
 ///     .with_rate(&Api::All, 1, std::time::Duration::from_secs(2))
 ///     .build();
 /// ```
+///
+/// ## Sharing a client
+///
+/// Every request method takes `&self`, and the client's mutable state - its
+/// rate limiters and circuit breaker - is kept behind locks. A single client
+/// can therefore be shared between tasks by wrapping it in an `Arc`, with no
+/// further locking:
+///
+/// ```rust
+/// let client = Arc::new(GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?);
+/// let task_client = Arc::clone(&client);
+/// tokio::spawn(async move {
+///     task_client.geocoding().with_address("Toronto").execute().await
+/// });
+/// ```
+///
+/// Cloning a client is cheap, and clones share the same rate limiters and
+/// circuit breaker.
 
 #[derive(Clone, Debug)]
 pub struct GoogleMapsClient {
@@ -43,10 +100,78 @@ pub struct GoogleMapsClient {
     /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
     pub key: String,
 
+    /// A tag that is added to the usage reports of every request. Not sent
+    /// by default. See `with_channel()` for more information.
+    pub channel: Option<String>,
+
+    /// The language in which to return results, for requests that don't set
+    /// their own. Not sent by default. See `with_language()` for more
+    /// information.
+    pub language: Option<crate::types::Language>,
+
+    /// The region used to bias results, for requests that don't set their
+    /// own. Not sent by default. See `with_region()` for more information.
+    pub region: Option<crate::types::Region>,
+
     /// Rate limits for each of the Google Cloud Maps Platform APIs.
     #[cfg(feature = "reqwest")]
     pub rate_limit: RequestRate,
 
+    /// Stops requests to an API that is failing. Disabled by default. See
+    /// `with_circuit_breaker()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub circuit_breaker: Option<CircuitBreaker>,
+
+    /// How requests that failed with a transient error are retried. See
+    /// `with_retry_policy()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub retry_policy: RetryPolicy,
+
+    /// Replaces the scheme and host of Google's servers in every request, for
+    /// example to send requests through a proxy. Not set by default. See
+    /// `with_base_url()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub base_url: Option<String>,
+
+    /// Whether a `ZERO_RESULTS` status is returned as an error, as it was in
+    /// earlier versions. Disabled by default. See
+    /// `with_zero_results_as_error()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub zero_results_as_error: bool,
+
+    /// The `Accept-Language` header sent with every request. Not sent by
+    /// default. See `with_accept_language()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub accept_language: Option<String>,
+
+    /// Returns the W3C trace context headers sent with every request. Not
+    /// sent by default. See `with_trace_context()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub trace_context: Option<TraceContextProvider>,
+
+    /// The `User-Agent` header that the `reqwest` client was built with, or
+    /// this crate's own for a caller-supplied `reqwest` client. The
+    /// `user_agent_suffix` is appended to it.
+    #[cfg(feature = "reqwest")]
+    pub user_agent: String,
+
+    /// A product token appended to the `User-Agent` header of every request.
+    /// Not sent by default. See `with_user_agent_suffix()` for more
+    /// information.
+    #[cfg(feature = "reqwest")]
+    pub user_agent_suffix: Option<String>,
+
+    /// The `X-Goog-Api-Client` header sent with every request. Not sent by
+    /// default. See `with_api_client()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub api_client: Option<String>,
+
+    /// The TLS backend of the `reqwest` client, if it was built by this
+    /// crate. `None` for a caller-supplied `reqwest` client. See
+    /// `transport_info()` for more information.
+    #[cfg(feature = "reqwest")]
+    pub tls_backend: Option<crate::client::transport_info::TlsBackend>,
+
     /// Allows you to optionally provide your own pre-configured reqwest client
     /// that will be used by the Google Maps client.
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
@@ -57,3 +182,12 @@ pub struct GoogleMapsClient {
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub reqwest_client: reqwest::Client,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+// The client must stay shareable between tasks. This fails to compile if a
+// field that is not `Send` or `Sync` is ever added:
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<GoogleMapsClient>();
+};
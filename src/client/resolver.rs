@@ -0,0 +1,213 @@
+//! Contains the `ResolverOptions` struct and the `IpPreference` enum. They
+//! control how the host names of Google's servers are resolved to addresses,
+//! so that a client can avoid a broken IPv6 route to `googleapis.com` without
+//! a custom `reqwest` build.
+
+use futures::future::FutureExt;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use serde::{Deserialize, Serialize};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// Which IP versions a client connects over, and which it tries first.
+///
+/// `reqwest` connects with the _Happy Eyeballs_ algorithm: it tries the
+/// addresses of the first address's IP version, and starts trying the other
+/// version if no connection is made within 300 milliseconds. That delay is
+/// fixed by `hyper` and can't be changed, but putting the working IP version
+/// first means the broken one is only tried as a fallback.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IpPreference {
+    /// Addresses are tried in the order the resolver returned them. This is
+    /// the default.
+    #[default]
+    Any,
+    /// IPv4 addresses are tried first, with IPv6 as a fallback.
+    PreferIpv4,
+    /// IPv6 addresses are tried first, with IPv4 as a fallback.
+    PreferIpv6,
+    /// Only IPv4 addresses are tried.
+    Ipv4Only,
+    /// Only IPv6 addresses are tried.
+    Ipv6Only,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl IpPreference {
+    /// Orders and filters resolved addresses according to the preference. The
+    /// order of addresses of the same IP version is kept.
+
+    #[must_use]
+    pub fn apply(self, addresses: impl IntoIterator<Item = SocketAddr>) -> Vec<SocketAddr> {
+        let mut addresses: Vec<SocketAddr> = addresses.into_iter().collect();
+        match self {
+            Self::Any => {},
+            Self::PreferIpv4 => addresses.sort_by_key(SocketAddr::is_ipv6),
+            Self::PreferIpv6 => addresses.sort_by_key(SocketAddr::is_ipv4),
+            Self::Ipv4Only => addresses.retain(SocketAddr::is_ipv4),
+            Self::Ipv6Only => addresses.retain(SocketAddr::is_ipv6),
+        } // match
+        addresses
+    } // fn
+
+    /// Parses a preference name, as used by the `GOOGLE_MAPS_IP_PREFERENCE`
+    /// environment variable. Accepts `any`, `prefer-ipv4`, `prefer-ipv6`,
+    /// `ipv4-only` and `ipv6-only`, in any case.
+    pub(super) fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().replace('_', "-").as_str() {
+            "any" => Some(Self::Any),
+            "prefer-ipv4" => Some(Self::PreferIpv4),
+            "prefer-ipv6" => Some(Self::PreferIpv6),
+            "ipv4-only" => Some(Self::Ipv4Only),
+            "ipv6-only" => Some(Self::Ipv6Only),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+impl std::fmt::Display for IpPreference {
+    /// Formats the preference as its name, for example `prefer-ipv4`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Any => write!(f, "any"),
+            Self::PreferIpv4 => write!(f, "prefer-ipv4"),
+            Self::PreferIpv6 => write!(f, "prefer-ipv6"),
+            Self::Ipv4Only => write!(f, "ipv4-only"),
+            Self::Ipv6Only => write!(f, "ipv6-only"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Controls how a `reqwest` client resolves host names. Use
+/// `ResolverOptions::apply` to apply it to a `reqwest` client of your own.
+/// Clients built by this crate are configured with
+/// `ClientConfig::ip_preference` and
+/// `GoogleMapsClient::from_config_with_resolver` instead.
+///
+/// ## Example
+///
+/// ```rust
+/// let reqwest_client = ResolverOptions::new()
+///     .with_ip_preference(IpPreference::PreferIpv4)
+///     .apply(reqwest::Client::builder())
+///     .build()?;
+/// google_maps_client.with_reqwest_client(reqwest_client);
+/// ```
+
+#[derive(Clone, Default)]
+pub struct ResolverOptions {
+    /// Which IP versions to connect over, and which to try first.
+    pub ip_preference: IpPreference,
+
+    /// A custom DNS resolver. The operating system's resolver is used if this
+    /// is `None`.
+    pub resolver: Option<Arc<dyn Resolve>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ResolverOptions {
+    /// Returns the default options: the operating system's resolver, with
+    /// addresses tried in the order it returns them.
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Sets which IP versions to connect over, and which to try first.
+
+    #[must_use]
+    pub const fn with_ip_preference(mut self, ip_preference: IpPreference) -> Self {
+        self.ip_preference = ip_preference;
+        self
+    } // fn
+
+    /// Sets a custom DNS resolver, for example one that queries a specific
+    /// DNS server. The IP preference is applied to the addresses it returns.
+
+    #[must_use]
+    pub fn with_resolver(mut self, resolver: Arc<dyn Resolve>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    } // fn
+
+    /// Returns whether these are the default options, which leave `reqwest`'s
+    /// own resolver in place.
+
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.ip_preference == IpPreference::Any && self.resolver.is_none()
+    } // fn
+
+    /// Installs these options on a `reqwest` client builder. The builder is
+    /// returned unchanged if these are the default options.
+
+    pub fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        if self.is_default() {
+            builder
+        } else {
+            builder.dns_resolver(Arc::new(PreferenceResolver {
+                ip_preference: self.ip_preference,
+                resolver: self.resolver.clone(),
+            })) // dns_resolver
+        } // if
+    } // fn
+} // impl
+
+impl std::fmt::Debug for ResolverOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ResolverOptions")
+            .field("ip_preference", &self.ip_preference)
+            .field("resolver", &self.resolver.as_ref().map(|_| "custom"))
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// A `reqwest` resolver that applies an `IpPreference` to the addresses
+/// returned by another resolver, or by the operating system.
+struct PreferenceResolver {
+    ip_preference: IpPreference,
+    resolver: Option<Arc<dyn Resolve>>,
+} // struct
+
+impl Resolve for PreferenceResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let ip_preference = self.ip_preference;
+        let host = name.as_str().to_string();
+        let resolving = self.resolver.as_ref().map_or_else(
+            || resolve_system(host.clone()),
+            |resolver| resolver.resolve(name),
+        ); // map_or_else
+
+        Box::pin(resolving.map(move |addresses| {
+            let addresses = ip_preference.apply(addresses?);
+            if addresses.is_empty() {
+                Err(format!("no {ip_preference} addresses found for `{host}`").into())
+            } else {
+                Ok(Box::new(addresses.into_iter()) as Addrs)
+            } // if
+        })) // pin
+    } // fn
+} // impl
+
+/// Resolves a host name with the operating system's resolver. The lookup
+/// blocks, so it runs on `tokio`'s blocking thread pool, as `reqwest`'s own
+/// resolver does.
+fn resolve_system(host: String) -> Resolving {
+    let lookup = tokio::task::spawn_blocking(move || (host.as_str(), 0).to_socket_addrs());
+    Box::pin(lookup.map(|result| match result {
+        Ok(Ok(addresses)) => Ok(Box::new(addresses) as Addrs),
+        Ok(Err(error)) => Err(error.into()),
+        Err(join_error) => Err(join_error.into()),
+    })) // pin
+} // fn
@@ -0,0 +1,120 @@
+//! Contains the `RetryPolicy` struct. It controls how long a client keeps
+//! retrying requests that failed with a transient error, such as an HTTP 500
+//! or 429 response, or a dropped connection.
+
+use backoff::ExponentialBackoff;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The delay before the first retry, in milliseconds, used when none is
+/// configured. Matches the `backoff` crate's default.
+const DEFAULT_INITIAL_INTERVAL_MS: u64 = 500;
+
+/// The longest delay between two retries, in seconds, used when none is
+/// configured. Matches the `backoff` crate's default.
+const DEFAULT_MAX_INTERVAL_SECS: u64 = 60;
+
+/// How long to keep retrying, in seconds, used when none is configured.
+/// Matches the `backoff` crate's default of 15 minutes.
+const DEFAULT_MAX_ELAPSED_SECS: u64 = 900;
+
+// -----------------------------------------------------------------------------
+//
+/// How a client retries requests that failed with a transient error. The
+/// delay between retries grows exponentially, with some random jitter, from
+/// `initial_interval_ms` up to `max_interval_secs`. Retrying stops once
+/// `max_elapsed_secs` have passed since the first attempt.
+///
+/// The default policy is the `backoff` crate's default: retries start after
+/// half a second and stop after 15 minutes.
+///
+/// ## Example
+///
+/// ```rust
+/// let mut google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?;
+/// // Give up after 30 seconds:
+/// google_maps_client.with_retry_policy(RetryPolicy::default().with_max_elapsed_secs(30));
+/// ```
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// The delay before the first retry, in milliseconds.
+    pub initial_interval_ms: u64,
+
+    /// The longest delay between two retries, in seconds.
+    pub max_interval_secs: u64,
+
+    /// How long to keep retrying, in seconds, after the first attempt. `0`
+    /// disables retries.
+    pub max_elapsed_secs: u64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            initial_interval_ms: DEFAULT_INITIAL_INTERVAL_MS,
+            max_interval_secs: DEFAULT_MAX_INTERVAL_SECS,
+            max_elapsed_secs: DEFAULT_MAX_ELAPSED_SECS,
+        } // RetryPolicy
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl RetryPolicy {
+    /// Returns a policy that never retries. Every transient error is returned
+    /// to the caller straight away.
+
+    #[must_use]
+    pub const fn never() -> Self {
+        Self {
+            initial_interval_ms: DEFAULT_INITIAL_INTERVAL_MS,
+            max_interval_secs: DEFAULT_MAX_INTERVAL_SECS,
+            max_elapsed_secs: 0,
+        } // RetryPolicy
+    } // fn
+
+    /// Sets the delay before the first retry, in milliseconds.
+
+    #[must_use]
+    pub const fn with_initial_interval_ms(mut self, initial_interval_ms: u64) -> Self {
+        self.initial_interval_ms = initial_interval_ms;
+        self
+    } // fn
+
+    /// Sets the longest delay between two retries, in seconds.
+
+    #[must_use]
+    pub const fn with_max_interval_secs(mut self, max_interval_secs: u64) -> Self {
+        self.max_interval_secs = max_interval_secs;
+        self
+    } // fn
+
+    /// Sets how long to keep retrying, in seconds. `0` disables retries.
+
+    #[must_use]
+    pub const fn with_max_elapsed_secs(mut self, max_elapsed_secs: u64) -> Self {
+        self.max_elapsed_secs = max_elapsed_secs;
+        self
+    } // fn
+
+    /// Returns the `backoff` crate's description of this policy, as used by
+    /// the retry loop of every request.
+
+    #[must_use]
+    pub fn backoff(&self) -> ExponentialBackoff {
+        let initial_interval = Duration::from_millis(self.initial_interval_ms);
+        ExponentialBackoff {
+            current_interval: initial_interval,
+            initial_interval,
+            max_interval: Duration::from_secs(self.max_interval_secs),
+            max_elapsed_time: Some(Duration::from_secs(self.max_elapsed_secs)),
+            ..ExponentialBackoff::default()
+        } // ExponentialBackoff
+    } // fn
+} // impl
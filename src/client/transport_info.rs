@@ -0,0 +1,232 @@
+//! Contains the `TransportInfo` struct and the `TlsBackend` enum. They report
+//! how a `GoogleMapsClient` connects to Google's servers: which TLS backend it
+//! uses, where that backend finds its trusted root certificates, and which
+//! optional transport features were compiled in. This helps diagnose
+//! certificate errors in locked-down environments, such as corporate networks
+//! with TLS-inspecting proxies.
+
+use crate::client::GoogleMapsClient;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A TLS implementation that `reqwest` can use to secure connections.
+///
+/// Which backends are available depends on the crate's feature flags: the
+/// `reqwest-default-tls` and `reqwest-native-tls*` features compile in
+/// `NativeTls`, and the `reqwest-rustls-tls*` and `rustls-platform-verifier`
+/// features compile in `Rustls`.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TlsBackend {
+    /// The operating system's TLS library: `SChannel` on Windows, Secure
+    /// Transport on macOS and OpenSSL elsewhere. Certificates are always
+    /// validated against the operating system's trust store.
+    NativeTls,
+    /// The pure-Rust `rustls` library. Certificates are validated against the
+    /// roots selected by the `reqwest-rustls-tls*` feature flags, or by the
+    /// operating system's verifier with the `rustls-platform-verifier`
+    /// feature.
+    Rustls,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl TlsBackend {
+    /// Returns the TLS backends that were compiled in, in order of preference.
+
+    #[must_use]
+    pub fn available() -> Vec<Self> {
+        let mut backends = Vec::new();
+        if cfg!(any(
+            feature = "reqwest-default-tls",
+            feature = "reqwest-native-tls",
+            feature = "reqwest-native-tls-vendored",
+            feature = "reqwest-native-tls-alpn",
+        )) {
+            backends.push(Self::NativeTls);
+        } // if
+        if cfg!(any(
+            feature = "reqwest-rustls-tls",
+            feature = "reqwest-rustls-tls-manual-roots",
+            feature = "reqwest-rustls-tls-webpki-roots",
+            feature = "reqwest-rustls-tls-native-roots",
+            feature = "reqwest-middleware-rustls-tls",
+        )) {
+            backends.push(Self::Rustls);
+        } // if
+        backends
+    } // fn
+
+    /// Returns the TLS backend that `reqwest` uses when none is selected.
+    /// This is `NativeTls` if it was compiled in, and `Rustls` otherwise.
+    /// Returns `None` if no TLS backend was compiled in, in which case only
+    /// plain HTTP connections are possible.
+
+    #[must_use]
+    pub fn default_backend() -> Option<Self> {
+        Self::available().first().copied()
+    } // fn
+
+    /// Returns whether this TLS backend was compiled in.
+
+    #[must_use]
+    pub fn is_available(self) -> bool {
+        Self::available().contains(&self)
+    } // fn
+
+    /// Returns where this TLS backend finds its trusted root certificates.
+    /// An empty list means that no roots are built in, and certificates must
+    /// be added to a custom `reqwest` client.
+
+    #[must_use]
+    pub fn roots(self) -> Vec<TlsRoots> {
+        match self {
+            Self::NativeTls => vec![TlsRoots::System],
+            Self::Rustls if cfg!(feature = "rustls-platform-verifier") => vec![TlsRoots::Platform],
+            Self::Rustls => {
+                let mut roots = Vec::new();
+                if cfg!(feature = "reqwest-rustls-tls-native-roots") {
+                    roots.push(TlsRoots::System);
+                } // if
+                if cfg!(any(
+                    feature = "reqwest-rustls-tls",
+                    feature = "reqwest-rustls-tls-webpki-roots",
+                    feature = "reqwest-middleware-rustls-tls",
+                )) {
+                    roots.push(TlsRoots::WebPki);
+                } // if
+                roots
+            } // Rustls
+        } // match
+    } // fn
+
+    /// Parses a backend name, as used by the `GOOGLE_MAPS_TLS_BACKEND`
+    /// environment variable. Accepts `native-tls` and `rustls`, in any case.
+    pub(super) fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_lowercase().replace('_', "-").as_str() {
+            "native-tls" | "native" => Some(Self::NativeTls),
+            "rustls" => Some(Self::Rustls),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+impl std::fmt::Display for TlsBackend {
+    /// Formats the backend as its crate name, for example `rustls`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NativeTls => write!(f, "native-tls"),
+            Self::Rustls => write!(f, "rustls"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A source of trusted root certificates for a TLS backend.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TlsRoots {
+    /// The operating system's trust store. Certificates installed by an
+    /// administrator, such as those of a TLS-inspecting proxy, are trusted.
+    System,
+    /// The Mozilla root certificates bundled with the `webpki-roots` crate.
+    /// Certificates installed in the operating system are not trusted.
+    WebPki,
+    /// The operating system's certificate verifier, used by `rustls` with the
+    /// `rustls-platform-verifier` feature. Like `System`, certificates
+    /// installed by an administrator are trusted, and the platform's
+    /// revocation checks and policies also apply.
+    Platform,
+} // enum
+
+impl std::fmt::Display for TlsRoots {
+    /// Formats the roots as a short description, for example `system`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::System => write!(f, "system"),
+            Self::WebPki => write!(f, "webpki"),
+            Self::Platform => write!(f, "platform"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Describes how a `GoogleMapsClient` connects to Google's servers. See
+/// `GoogleMapsClient::transport_info`.
+///
+/// ## Example
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?;
+/// println!("{}", google_maps_client.transport_info());
+/// // tls=native-tls roots=system available=native-tls http2=true middleware=false
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TransportInfo {
+    /// The TLS backend used by the client's `reqwest` client. `None` if the
+    /// `reqwest` client was supplied by the caller, because its backend can't
+    /// be determined, or if no TLS backend was compiled in.
+    pub tls_backend: Option<TlsBackend>,
+
+    /// Where the TLS backend finds its trusted root certificates. Empty if
+    /// the TLS backend is unknown.
+    pub tls_roots: Vec<TlsRoots>,
+
+    /// The TLS backends that were compiled in, in order of preference.
+    pub available_tls_backends: Vec<TlsBackend>,
+
+    /// Whether HTTP/2 support was compiled in.
+    pub http2: bool,
+
+    /// Whether `reqwest-middleware` support was compiled in.
+    pub middleware: bool,
+} // struct
+
+impl std::fmt::Display for TransportInfo {
+    /// Formats the transport as a single line of `key=value` pairs, for logs.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let join = |items: Vec<String>| if items.is_empty() {
+            "none".to_string()
+        } else {
+            items.join(",")
+        }; // join
+        write!(
+            f,
+            "tls={} roots={} available={} http2={} middleware={}",
+            self.tls_backend.map_or_else(|| "unknown".to_string(), |backend| backend.to_string()),
+            join(self.tls_roots.iter().map(ToString::to_string).collect()),
+            join(self.available_tls_backends.iter().map(ToString::to_string).collect()),
+            self.http2,
+            self.middleware,
+        ) // write!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+    /// Returns how this client connects to Google's servers: the active TLS
+    /// backend and its trusted roots, and the transport features that were
+    /// compiled in. Log this when requests fail with certificate errors.
+    ///
+    /// The TLS backend is only known for clients whose `reqwest` client was
+    /// built by this crate, with `try_new` or `from_config`. It is reported
+    /// as `None` after `with_reqwest_client`.
+
+    #[must_use]
+    pub fn transport_info(&self) -> TransportInfo {
+        TransportInfo {
+            tls_backend: self.tls_backend,
+            tls_roots: self.tls_backend.map(TlsBackend::roots).unwrap_or_default(),
+            available_tls_backends: TlsBackend::available(),
+            http2: cfg!(any(feature = "reqwest-http2", feature = "reqwest-middleware-http2")),
+            middleware: cfg!(feature = "reqwest-middleware"),
+        } // TransportInfo
+    } // fn
+} // impl
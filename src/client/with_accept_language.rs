@@ -0,0 +1,42 @@
+use crate::client::header_value::validate_header_value;
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the `Accept-Language` header sent with every request.
+    ///
+    /// The Geocoding and Places APIs use the header to choose the language of
+    /// their results when a request does not set a `language`. Unlike the
+    /// `language` parameter, the header may list several languages with
+    /// quality values. Individual requests may override the header with
+    /// their own `with_accept_language()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `accept_language` ‧ The header value, for example `de-CH, de;q=0.9,
+    ///   en;q=0.5`.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidHeaderValue` if the value can't be sent as a header, for
+    ///   example because it contains a line break.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_accept_language("de-CH, de;q=0.9, en;q=0.5")?
+    ///     .build();
+    /// ```
+
+    pub fn with_accept_language(
+        &mut self,
+        accept_language: impl Into<String>
+    ) -> Result<&mut Self, crate::GoogleMapsError> {
+        self.accept_language = Some(validate_header_value("accept-language", accept_language.into())?);
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,40 @@
+use crate::client::header_value::validate_header_value;
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sends an `X-Goog-Api-Client` header with every request, identifying the
+    /// application the way Google's own client libraries identify themselves.
+    /// Not sent by default.
+    ///
+    /// The header becomes `RustGoogleMaps/<version> <api_client>`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api_client` ‧ One or more space-separated `name/version` tokens, for
+    ///   example `delivery-planner/2.4.1`.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidHeaderValue` if the value can't be sent as a header, for
+    ///   example because it contains a line break.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_api_client("delivery-planner/2.4.1")?
+    ///     .build();
+    /// ```
+
+    pub fn with_api_client(
+        &mut self,
+        api_client: impl Into<String>
+    ) -> Result<&mut Self, crate::GoogleMapsError> {
+        self.api_client = Some(validate_header_value("x-goog-api-client", api_client.into())?);
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sends every request to another server instead of Google's.
+    ///
+    /// The scheme and host of `https://maps.googleapis.com` and
+    /// `https://roads.googleapis.com` are replaced by the base URL, and the
+    /// rest of the request URL is kept. This is useful for sending requests
+    /// through a caching or auditing proxy, or to a mock server in tests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `base_url` ‧ The scheme, host and optional path prefix of the
+    ///   server, for example `http://127.0.0.1:8080` or
+    ///   `https://proxy.example.com/google`. A trailing `/` is ignored.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_base_url("https://proxy.example.com/google")
+    ///     .build();
+    /// ```
+
+    pub fn with_base_url(&mut self, base_url: impl Into<String>) -> &mut Self {
+        let base_url = base_url.into();
+        self.base_url = Some(base_url.trim_end_matches('/').to_string());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the channel sent with every request.
+    ///
+    /// Google adds the channel to the usage reports of the Directions,
+    /// Distance Matrix, Elevation, Geocoding, Time Zone, Places and Roads
+    /// APIs. Premium Plan customers use channels to separate the usage of
+    /// different applications, or of different customers of an application,
+    /// that share an API key. Individual requests may override the channel
+    /// with their own `with_channel()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `channel` ‧ The channel. It may contain ASCII letters, numbers,
+    ///   periods, underscores and hyphens.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_channel("my-app")
+    ///     .build();
+    /// ```
+
+    pub fn with_channel(&mut self, channel: impl Into<String>) -> &mut Self {
+        self.channel = Some(channel.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,90 @@
+use crate::{
+    circuit_breaker::{CircuitBreaker, CircuitState},
+    client::GoogleMapsClient,
+    request_rate::api::Api,
+}; // crate
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Enables a per-API circuit breaker. See `CircuitBreaker` for more
+    /// information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_threshold` ‧ The number of consecutive transient failures
+    ///   (network errors, HTTP `5xx` responses, and HTTP `429 Too Many
+    ///   Requests`) after which requests to an API fail immediately with
+    ///   `GoogleMapsError::CircuitOpen`. Each retry counts as an attempt.
+    ///
+    /// * `cool_down` ‧ How long requests fail immediately before a probe
+    ///   request is sent to check whether the API has recovered.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_circuit_breaker(5, std::time::Duration::from_secs(30))
+    ///     .build();
+    /// ```
+
+    pub fn with_circuit_breaker(
+        &mut self,
+        failure_threshold: u32,
+        cool_down: Duration
+    ) -> &mut Self {
+        self.circuit_breaker = Some(CircuitBreaker::new(failure_threshold, cool_down));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the state of the circuit breaker for the specified API, so that
+    /// it can be reported to a health check or metrics system. Always returns
+    /// `CircuitState::Closed` if the circuit breaker is not enabled.
+
+    #[must_use]
+    pub fn circuit_state(&self, api: &Api) -> CircuitState {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, |circuit_breaker| circuit_breaker.state(api))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an error if the circuit for the specified API is open.
+    pub(crate) fn check_circuit(&self, api: &Api) -> Result<(), crate::GoogleMapsError> {
+        match &self.circuit_breaker {
+            Some(circuit_breaker) if !circuit_breaker.try_acquire(api) => {
+                tracing::warn!("{api} API circuit breaker is open, request not sent");
+                Err(crate::GoogleMapsError::CircuitOpen(api.clone()))
+            }, // Some
+            _ => Ok(()),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Records the outcome of an HTTP request to the specified API with the
+    /// circuit breaker. Network errors, HTTP `5xx` responses, and HTTP `429 Too
+    /// Many Requests` responses count as failures.
+    pub(crate) fn record_circuit_outcome(
+        &self,
+        api: &Api,
+        response: &Result<reqwest::Response, crate::ReqError>
+    ) {
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            let failed = response.as_ref().map_or(true, |response| {
+                response.status().is_server_error() || response.status() == 429
+            });
+            if failed {
+                circuit_breaker.record_failure(api);
+            } else {
+                circuit_breaker.record_success(api);
+            } // if
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Language;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the language in which to return results, for every request that
+    /// accepts a `language` parameter and doesn't set its own.
+    ///
+    /// The Directions, Distance Matrix, Geocoding, Time Zone and Places APIs
+    /// accept a language. Individual requests may override it with their own
+    /// `with_language()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which to return results.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_language(Language::French)
+    ///     .build();
+    /// ```
+
+    pub fn with_language(&mut self, language: impl Into<Language>) -> &mut Self {
+        self.language = Some(language.into());
+        self
+    } // fn
+} // impl
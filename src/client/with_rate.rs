@@ -114,4 +114,26 @@ impl GoogleMapsClient {
         self.rate_limit.with_rate(api, requests, per_duration);
         self
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Changes the rate limit for the specified API while the client is in
+    /// use. It takes `&self`, so it can be called on a client that is shared
+    /// between tasks in an `Arc`. The arguments are the same as those of
+    /// `with_rate()`.
+    ///
+    /// The new rate is seen by every clone of this client. Requests that are
+    /// already waiting under the old rate finish waiting under it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let client = Arc::new(GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?);
+    /// // Later, after a quota increase:
+    /// client.set_rate(&Api::Geocoding, 50, Duration::from_secs(1));
+    /// ```
+
+    pub fn set_rate(&self, api: &Api, requests: u16, per_duration: Duration) {
+        self.rate_limit.set_rate(api, requests, per_duration);
+    } // fn
 } // impl
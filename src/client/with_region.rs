@@ -0,0 +1,32 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Region;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the region used to bias results, for every request that accepts a
+    /// `region` parameter and doesn't set its own.
+    ///
+    /// The Directions, Distance Matrix, Geocoding, Text Search, Place Details
+    /// and Place Autocomplete APIs accept a region. Individual requests may
+    /// override it with their own `with_region()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region, as a country code top-level domain.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_region(Region::Canada)
+    ///     .build();
+    /// ```
+
+    pub fn with_region(&mut self, region: impl Into<Region>) -> &mut Self {
+        self.region = Some(region.into());
+        self
+    } // fn
+} // impl
@@ -12,6 +12,11 @@ impl GoogleMapsClient {
     /// [Mause](https://github.com/Mause) mentioned that this feature could be
     /// useful for writing tests. Thanks for the suggestion!
     ///
+    /// The client's TLS backend can't be read from a `reqwest` client, so
+    /// `transport_info()` reports it as unknown afterwards. Neither can its
+    /// `User-Agent` header, so `with_user_agent_suffix()` appends to this
+    /// crate's own.
+    ///
     /// ## Arguments
     ///
     /// * `reqwest_client` ‧ A reqwest client built using the
@@ -32,12 +37,16 @@ impl GoogleMapsClient {
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
     pub fn with_reqwest_client(&mut self, reqwest_client: reqwest::Client) -> &mut Self {
         self.reqwest_client = crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client);
+        self.tls_backend = None;
+        self.user_agent = crate::client::impls::USER_AGENT.to_string();
         self
     } // fn
 
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub fn with_reqwest_client(&mut self, reqwest_client: reqwest::Client) -> &mut Self {
         self.reqwest_client = reqwest_client;
+        self.tls_backend = None;
+        self.user_agent = crate::client::impls::USER_AGENT.to_string();
         self
     } // fn
 
@@ -47,6 +56,8 @@ impl GoogleMapsClient {
         reqwest_client: reqwest_middleware::ClientWithMiddleware
     ) -> &mut Self {
         self.reqwest_client = crate::reqwest_maybe_middleware::Client::Middleware(reqwest_client);
+        self.tls_backend = None;
+        self.user_agent = crate::client::impls::USER_AGENT.to_string();
         self
     } // fn
 
@@ -56,6 +67,8 @@ impl GoogleMapsClient {
         reqwest_client: crate::reqwest_maybe_middleware::Client
     ) -> &mut Self {
         self.reqwest_client = reqwest_client;
+        self.tls_backend = None;
+        self.user_agent = crate::client::impls::USER_AGENT.to_string();
         self
     } // fn
 } // impl
@@ -0,0 +1,31 @@
+use crate::client::{retry_policy::RetryPolicy, GoogleMapsClient};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how requests that failed with a transient error are retried.
+    ///
+    /// Requests are retried after an HTTP 500 or 429 response, or when the
+    /// connection fails. By default they are retried for up to 15 minutes,
+    /// which is too long for many interactive applications.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_policy` ‧ The retry policy. Use `RetryPolicy::never()` to
+    ///   disable retries.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_retry_policy(RetryPolicy::default().with_max_elapsed_secs(30))
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    } // fn
+} // impl
@@ -0,0 +1,51 @@
+use crate::{client::GoogleMapsClient, trace_context::TraceContextProvider};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Propagates the application's distributed trace to Google by sending
+    /// W3C `traceparent` and `tracestate` headers with every request.
+    /// Disabled by default.
+    ///
+    /// The provider is called from inside each request's `tracing` span, so
+    /// it can read the current span with `tracing::Span::current()`. A
+    /// provider that returns `None` sends no headers. A `traceparent` that is
+    /// not well-formed is logged and not sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `provider` ‧ A function that returns the current trace context.
+    ///
+    /// ## Example:
+    ///
+    /// Using the `opentelemetry` and `tracing-opentelemetry` crates:
+    ///
+    /// ```rust
+    /// fn current_trace_context() -> Option<TraceContext> {
+    ///     use opentelemetry::propagation::TextMapPropagator;
+    ///     use opentelemetry_sdk::propagation::TraceContextPropagator;
+    ///     use tracing_opentelemetry::OpenTelemetrySpanExt;
+    ///
+    ///     let mut headers = std::collections::HashMap::new();
+    ///     TraceContextPropagator::new()
+    ///         .inject_context(&tracing::Span::current().context(), &mut headers);
+    ///
+    ///     let trace_context = TraceContext::new(headers.remove("traceparent")?);
+    ///     Some(match headers.remove("tracestate") {
+    ///         Some(tracestate) => trace_context.with_tracestate(tracestate),
+    ///         None => trace_context,
+    ///     })
+    /// }
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_trace_context(current_trace_context)
+    ///     .build();
+    /// ```
+
+    pub fn with_trace_context(&mut self, provider: TraceContextProvider) -> &mut Self {
+        self.trace_context = Some(provider);
+        self
+    } // fn
+} // impl
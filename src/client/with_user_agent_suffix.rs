@@ -0,0 +1,43 @@
+use crate::client::header_value::validate_header_value;
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Appends a product token to the `User-Agent` header sent with every
+    /// request, so that the application's traffic can be told apart in
+    /// proxies, logs and Google support escalations. Not sent by default.
+    ///
+    /// The suffix is appended to the client's `User-Agent` header, which is
+    /// `RustGoogleMaps/<version>` unless `ClientConfig::user_agent` was set.
+    /// It replaces the `User-Agent` of a client set with
+    /// `with_reqwest_client()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `suffix` ‧ One or more product tokens, for example
+    ///   `delivery-planner/2.4.1`.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidHeaderValue` if the value can't be sent as a header, for
+    ///   example because it contains a line break.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_user_agent_suffix("delivery-planner/2.4.1")?
+    ///     .build();
+    /// ```
+
+    pub fn with_user_agent_suffix(
+        &mut self,
+        suffix: impl Into<String>
+    ) -> Result<&mut Self, crate::GoogleMapsError> {
+        self.user_agent_suffix = Some(validate_header_value("user-agent", suffix.into())?);
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets whether a `ZERO_RESULTS` status from Google is returned as an
+    /// error.
+    ///
+    /// By default, a request that Google answers with `ZERO_RESULTS` returns
+    /// `Ok` with a response that has no results, because finding nothing is
+    /// not a failure. Enable this to restore the behaviour of earlier
+    /// versions, where such requests returned a `GoogleMapsService` error.
+    ///
+    /// This applies to the Directions, Geocoding, Places, Place Autocomplete,
+    /// Query Autocomplete and Time Zone APIs. The Distance Matrix, Elevation
+    /// and Roads APIs do not return `ZERO_RESULTS` for a whole request.
+    ///
+    /// A Directions API `ZERO_RESULTS` response that suggests other travel
+    /// modes is always returned as a `TravelModeUnavailable` error.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?
+    ///     .with_zero_results_as_error(true)
+    ///     .build();
+    /// ```
+
+    pub fn with_zero_results_as_error(&mut self, zero_results_as_error: bool) -> &mut Self {
+        self.zero_results_as_error = zero_results_as_error;
+        self
+    } // fn
+} // impl
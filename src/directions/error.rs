@@ -2,7 +2,7 @@
 
 // -----------------------------------------------------------------------------
 
-use crate::directions::response::status::Status;
+use crate::directions::{response::status::Status, travel_mode::TravelMode};
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -88,6 +88,17 @@ pub enum Error {
     /// `google_maps\src\directions\request\departure_time.rs` for more
     /// information.
     InvalidDepartureTime(String),
+    /// A `Waypoint::Heading` was given a heading outside of the range 0–359
+    /// degrees.
+    InvalidHeading(u16),
+    /// A local departure time could not be converted to UTC, because it does
+    /// not exist in the location's time zone (for example, it falls in the
+    /// gap of a daylight saving time change), or because the Time Zone API
+    /// did not return the location's time zone.
+    InvalidLocalDepartureTime(chrono::NaiveDateTime, String),
+    /// A stored route's polyline contains no points, so it has no origin or
+    /// destination.
+    EmptyPolyline,
     /// The query string must be built before the request may be sent to the
     /// Google Maps Directions API service.
     QueryNotBuilt,
@@ -95,19 +106,31 @@ pub enum Error {
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
+    /// A traffic model may only be specified in Driving travel mode.
+    TrafficModelIsForDrivingOnly(String, String),
+    /// A traffic model may only be specified when a departure time is
+    /// specified.
+    TrafficModelRequiresDepartureTime(String),
     /// Transit mode may only be specified in Transit travel mode.
     TransitModeIsForTransitOnly(String, String),
     /// Transit routing preference may only be specified in Transit travel mode.
     TransitRoutePreferenceIsForTransitOnly(String, String),
+    /// Google Maps Directions API service found no route for the requested
+    /// travel mode. These travel modes are available for the given waypoints
+    /// instead.
+    TravelModeUnavailable(Vec<TravelMode>, Option<String>),
+    /// Pass-through (`via:`) waypoints cannot be used with waypoint
+    /// optimization.
+    ViaWaypointsWithOptimization(usize),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -253,6 +276,21 @@ impl std::fmt::Display for Error {
                 `{invalid_departure_time_string}` is not a valid departure time. \
                 Valid departure times are `now`, or a UNIX timestamp."
             ),
+            Self::InvalidHeading(heading) => write!(f,
+                "Google Maps Directions API client: \
+                `{heading}` is not a valid waypoint heading. \
+                Valid headings are whole degrees from 0 (north) to 359, measured clockwise."
+            ),
+            Self::InvalidLocalDepartureTime(local_time, time_zone) => write!(f,
+                "Google Maps Directions API client: \
+                the local departure time `{local_time}` could not be converted to UTC \
+                in the `{time_zone}` time zone. \
+                The time may fall in a daylight saving time gap."
+            ),
+            Self::EmptyPolyline => write!(f,
+                "Google Maps Directions API client: \
+                the stored route's polyline contains no points."
+            ),
             Self::QueryNotBuilt => write!(f,
                 "Google Maps Directions API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -273,6 +311,16 @@ impl std::fmt::Display for Error {
                 Try again with {} fewer waypoint(s).",
                 waypoint_count,
                 waypoint_count - 25),
+            Self::TrafficModelIsForDrivingOnly(travel_mode, traffic_model) => write!(f,
+                "Google Maps Directions API client: \
+                The with_traffic_model() method may only be used when with_travel_mode() is set to `TravelMode::Driving`. \
+                The travel mode is set to `{travel_mode}` and the traffic model is set to `{traffic_model}`. \
+                Try again either with a travel mode of `TravelMode::Driving` or no traffic model."),
+            Self::TrafficModelRequiresDepartureTime(traffic_model) => write!(f,
+                "Google Maps Directions API client: \
+                The with_traffic_model() method may only be used when with_departure_time() has been set. \
+                The traffic model is set to `{traffic_model}` but no departure time is set. \
+                Try again either with a departure time or no traffic model."),
             Self::TransitModeIsForTransitOnly(travel_mode, transit_modes) => write!(f,
                 "Google Maps Directions API client: \
                 The with_transit_modes() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
@@ -283,6 +331,20 @@ impl std::fmt::Display for Error {
                 The with_transit_route_preference() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 The travel mode is set to `{travel_mode}` and the transit route preference is set to `{transit_route_preference}`. \
                 Try again either with a travel mode of `TravelMode::Transit` or no transit route preference."),
+            Self::TravelModeUnavailable(available_travel_modes, _) => write!(f,
+                "Google Maps Directions API service: \
+                No route could be found for the requested travel mode. \
+                Try again with one of the available travel modes: {}.",
+                available_travel_modes
+                    .iter()
+                    .map(std::string::ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")),
+            Self::ViaWaypointsWithOptimization(via_count) => write!(f,
+                "Google Maps Directions API client: \
+                The with_waypoint_optimization() method cannot be set to `true` if pass-through waypoints have been set. \
+                {via_count} pass-through (`Waypoint::Via`) waypoint(s) are set. \
+                Try again either with stopover waypoints only or no waypoint optimization."),
         } // match
     } // fn
 } // impl
@@ -311,3 +373,29 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// If no route could be found for the requested travel mode, returns the
+    /// most practical of the travel modes Google reported as available, so
+    /// the request can be retried with it.
+    ///
+    /// ```rust
+    /// match request.execute().await {
+    ///     Err(GoogleMapsError::Directions(error)) => if let Some(travel_mode) = error.suggest_alternate_mode() {
+    ///         request.with_travel_mode(travel_mode).execute().await
+    ///     }
+    ///     ...
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn suggest_alternate_mode(&self) -> Option<TravelMode> {
+        match self {
+            Self::TravelModeUnavailable(available_travel_modes, _) =>
+                TravelMode::most_practical(available_travel_modes),
+            _ => None,
+        } // match
+    } // fn
+} // impl
@@ -99,17 +99,25 @@ pub use crate::directions::{
         avoid::Avoid, departure_time::DepartureTime, location::Location,
         traffic_model::TrafficModel, transit_mode::TransitMode,
         transit_route_preference::TransitRoutePreference, unit_system::UnitSystem,
+        params::Params as DirectionsParams, typed::TypedRequest as TypedDirectionsRequest,
         waypoint::Waypoint, Request as DirectionsRequest,
     }, // crate::directions::request
     response::{
+        departure_sweep::{departure_times, DepartureSweep, SweepPoint},
         directions_distance::DirectionsDistance, directions_duration::DirectionsDuration,
-        driving_maneuver::DrivingManeuver, leg::Leg, overview_polyline::OverviewPolyline,
-        polyline::Polyline, route::Route, status::Status as DirectionsStatus, step::Step,
+        driving_maneuver::DrivingManeuver, eta::{Eta, EtaBasis}, leg::Leg, overview_polyline::OverviewPolyline,
+        parse_mode::ParseMode,
+        polyline::Polyline, route::Route, route_comparison::RouteComparison,
+        status::Status as DirectionsStatus, step::Step,
+        stored_route::StoredRoute,
         transit_agency::TransitAgency, transit_currency::TransitCurrency,
         transit_details::TransitDetails, transit_fare::TransitFare, transit_line::TransitLine,
         transit_stop::TransitStop, transit_time::TransitTime, transit_vehicle::TransitVehicle,
-        Response as DirectionsResponse,
+        warning::Warning as DirectionsWarning, Response as DirectionsResponse,
     }, // crate::directions::response
     travel_mode::TravelMode,
     vehicle_type::VehicleType,
 }; // use
+
+#[cfg(feature = "render")]
+pub use crate::directions::response::svg::SvgOptions;
@@ -27,7 +27,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// Note: the addition of restrictions does not preclude routes that include the
 /// restricted feature; it simply biases the result to more favorable routes.
 
-#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u8)]
 pub enum Avoid {
     /// Indicates that the calculated route should avoid ferries.
@@ -100,10 +100,10 @@ impl std::fmt::Display for Avoid {
 // -----------------------------------------------------------------------------
 
 impl std::convert::From<&Self> for Avoid {
-    /// Converts a borrowed `&Avoid` enum into an owned `Avoid` enum by cloning
+    /// Converts a borrowed `&Avoid` enum into an owned `Avoid` enum by copying
     /// it.
     fn from(avoid: &Self) -> Self {
-        avoid.clone()
+        *avoid
     } // fn
 } // impl
 
@@ -139,7 +139,7 @@ impl std::convert::TryFrom<&str> for Avoid {
     fn try_from(restriction_code: &str) -> Result<Self, Self::Error> {
         Ok(RESTRICTIONS_BY_CODE
             .get(restriction_code)
-            .cloned()
+            .copied()
             .ok_or_else(|| DirectionsError::InvalidAvoidCode(restriction_code.to_string()))?)
     } // fn
 } // impl
@@ -156,7 +156,7 @@ impl std::str::FromStr for Avoid {
     fn from_str(restriction_code: &str) -> Result<Self, Self::Err> {
         Ok(RESTRICTIONS_BY_CODE
             .get(restriction_code)
-            .cloned()
+            .copied()
             .ok_or_else(|| DirectionsError::InvalidAvoidCode(restriction_code.to_string()))?)
     } // fn
 } // impl
@@ -176,3 +176,25 @@ impl Avoid {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Avoid {
+    /// Returns the code that Google uses for this `Avoid`, for example
+    /// `ferries`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Avoid` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = RESTRICTIONS_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
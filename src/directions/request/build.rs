@@ -3,7 +3,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     // -------------------------------------------------------------------------
     //
     /// Builds the query string for the Google Maps Directions API based on the
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&'a mut self) -> Result<&'a mut Self, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
         if !self.validated {
             return Err(Error::RequestNotValidated);
@@ -45,16 +45,8 @@ impl<'a> Request<'a> {
         if !self.restrictions.is_empty() {
             query.push_str("&avoid=");
             query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .restrictions
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
+                &utf8_percent_encode(&self.restrictions.to_string(), NON_ALPHANUMERIC)
+                    .to_string(),
             ); // push_str
         } // if
 
@@ -65,7 +57,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Language key/value pair:
-        if let Some(language) = &self.language {
+        if let Some(language) = self.language.as_ref().or(self.client.language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         } // if
@@ -77,7 +69,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Region key/value pair:
-        if let Some(region) = &self.region {
+        if let Some(region) = self.region.as_ref().or(self.client.region.as_ref()) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         } // if
@@ -137,6 +129,12 @@ impl<'a> Request<'a> {
             ); // push_str
         } // if
 
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
         // Set query string in Request struct.
         self.query = Some(query);
 
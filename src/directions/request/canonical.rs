@@ -0,0 +1,24 @@
+use crate::canonical_request::CanonicalRequest;
+use crate::directions::{request::Request, response::parse_mode::ParseMode, OUTPUT_FORMAT, SERVICE_URL};
+
+// =============================================================================
+
+impl CanonicalRequest for Request<'_> {
+    /// Returns the URL of the service, without the query string.
+    fn service_url(&self) -> String {
+        format!("{SERVICE_URL}/{OUTPUT_FORMAT}")
+    } // fn
+
+    /// Returns the query string built by `build`, if any.
+    fn built_query(&self) -> Option<&str> {
+        self.query.as_deref()
+    } // fn
+
+    /// Returns the parse mode, unless the whole response is parsed.
+    fn cache_variants(&self) -> Vec<String> {
+        match self.parse_mode {
+            ParseMode::Full => Vec::new(),
+            ParseMode::SummaryOnly => vec!["parse_mode=summary_only".to_string()],
+        } // match
+    } // fn
+} // impl
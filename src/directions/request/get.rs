@@ -5,9 +5,8 @@ use crate::directions::{
 }; // use crate::directions
 use crate::error::Error as GoogleMapsError;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -44,10 +43,11 @@ impl<'a> DirectionsRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Directions, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            self.client.record_circuit_outcome(&Api::Directions, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -61,16 +61,51 @@ impl<'a> DirectionsRequest<'a> {
                         let bytes = text.map(String::into_bytes);
                         match bytes {
                             Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<DirectionsResponse>(&mut bytes) {
+                                match self.parse_mode.parse(&mut bytes) {
                                     Ok(deserialized) => {
+                                        // Surface any non-fatal warnings
+                                        // Google included in the response:
+                                        deserialized.log_warnings();
+                                        // Google silently drops traffic
+                                        // information it cannot provide:
+                                        if self.expects_traffic_durations()
+                                            && deserialized.status == DirectionsStatus::Ok
+                                            && !deserialized.has_traffic_durations()
+                                        {
+                                            tracing::warn!(
+                                                traffic_model = ?self.traffic_model,
+                                                departure_time = ?self.departure_time,
+                                                "Google Maps Directions API returned no `duration_in_traffic` \
+                                                for a driving request with a departure time",
+                                            ); // warn!
+                                        } // if
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == DirectionsStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
+                                        if deserialized.status == DirectionsStatus::Ok
+                                            || (deserialized.status == DirectionsStatus::ZeroResults
+                                            && deserialized.available_travel_modes.is_empty()
+                                            && !self.client.zero_results_as_error)
+                                        {
+                                            // If Google's response was "Ok", or
+                                            // found nothing and the client does
+                                            // not treat that as an error, return
+                                            // the struct deserialized from JSON:
                                             Ok(deserialized)
+                                        // Google found no route for the
+                                        // requested travel mode but suggested
+                                        // others. The error is permanent, but
+                                        // the caller may retry with another
+                                        // travel mode:
+                                        } else if deserialized.status == DirectionsStatus::ZeroResults
+                                            && !deserialized.available_travel_modes.is_empty()
+                                        {
+                                            let error = DirectionsError::TravelModeUnavailable(
+                                                deserialized.available_travel_modes,
+                                                deserialized.error_message,
+                                            );
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
                                         // Google API returned an error. This
                                         // indicates an issue with the request.
                                         // In most cases, retrying will not
@@ -108,9 +143,7 @@ impl<'a> DirectionsRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(DirectionsError::ReqwestMessage(
-                                    error.to_string(),
-                                )))
+                                Err(Permanent(DirectionsError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                     // We got a response from the server but it was not OK.
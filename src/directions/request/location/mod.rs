@@ -41,6 +41,17 @@ pub enum Location {
 
 // -----------------------------------------------------------------------------
 
+impl std::default::Default for Location {
+    /// Returns an empty address. It is only a placeholder for the `Default`
+    /// implementations of request parameters, and must be replaced before the
+    /// request is sent.
+    fn default() -> Self {
+        Self::Address(String::new())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::convert::From<&Self> for Location {
     /// Converts a borrowed `&Locations` enum into an owned `Location` enum by
     /// cloning it.
@@ -79,7 +90,7 @@ impl std::convert::From<&Location> for String {
 /// and destination.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Location {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to a latitude/longitude coordinate to calculate directions.
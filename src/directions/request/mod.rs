@@ -5,6 +5,7 @@
 
 pub mod avoid;
 mod build;
+mod canonical;
 pub mod departure_time;
 #[cfg(feature = "reqwest")]
 mod execute;
@@ -12,19 +13,28 @@ mod execute;
 mod get;
 pub mod location;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
 pub mod traffic_model;
 pub mod transit_mode;
 pub mod transit_route_preference;
+pub mod typed;
 pub mod unit_system;
 mod validate;
 pub mod waypoint;
+mod with_accessible_transit;
 mod with_alternatives;
 mod with_arrival_time;
+mod with_channel;
 mod with_departure_time;
+#[cfg(all(feature = "time_zone", feature = "reqwest"))]
+mod with_departure_time_local;
 mod with_language;
+mod with_parse_mode;
 mod with_region;
 mod with_restrictions;
+mod with_route_modifiers;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -37,12 +47,13 @@ mod with_waypoints;
 
 use crate::client::GoogleMapsClient;
 use crate::directions::request::{
-    avoid::Avoid, departure_time::DepartureTime, location::Location, traffic_model::TrafficModel,
+    departure_time::DepartureTime, location::Location, traffic_model::TrafficModel,
     transit_mode::TransitMode, transit_route_preference::TransitRoutePreference,
     unit_system::UnitSystem, waypoint::Waypoint,
 }; // crate::directions::request
+use crate::directions::response::parse_mode::ParseMode;
 use crate::directions::travel_mode::TravelMode;
-use crate::types::{Language, Region};
+use crate::types::{Language, Region, RouteRestrictions};
 use chrono::NaiveDateTime;
 
 // -----------------------------------------------------------------------------
@@ -51,7 +62,7 @@ use chrono::NaiveDateTime;
 /// _Directions API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Request<'a> {
     // Required parameters:
     // --------------------
@@ -91,7 +102,7 @@ pub struct Request<'a> {
 
     /// Features that routes should avoid. See file `avoid.rs` and method
     /// `with_restrictions()` for more information.
-    restrictions: Vec<Avoid>,
+    restrictions: RouteRestrictions,
 
     /// Assumptions to use when calculating time in traffic. See file
     /// `traffic_model.rs` and method `with_traffic_model()` for more
@@ -122,6 +133,15 @@ pub struct Request<'a> {
     /// `waypoint.rs` and method `with_waypoints()` for more information.
     waypoints: Vec<Waypoint>,
 
+    /// How much of the response is deserialized. See the method
+    /// `with_parse_mode()` for more information.
+    parse_mode: ParseMode,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// The URL-encoded query string that is passed to the Google Maps
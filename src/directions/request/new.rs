@@ -1,6 +1,8 @@
 use crate::{
     client::GoogleMapsClient,
     directions::request::{location::Location, Request},
+    directions::response::parse_mode::ParseMode,
+    types::RouteRestrictions,
 }; // use crate
 
 // =============================================================================
@@ -31,7 +33,7 @@ impl<'a> Request<'a> {
             departure_time: None,
             language: None,
             region: None,
-            restrictions: Vec::new(),
+            restrictions: RouteRestrictions::NONE,
             traffic_model: None,
             transit_modes: Vec::new(),
             transit_route_preference: None,
@@ -39,6 +41,8 @@ impl<'a> Request<'a> {
             unit_system: None,
             waypoint_optimization: false,
             waypoints: Vec::new(),
+            parse_mode: ParseMode::Full,
+            channel: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -0,0 +1,171 @@
+//! Contains the `Params` struct. It holds the parameters of a Directions API
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::{
+    departure_time::DepartureTime, location::Location,
+    traffic_model::TrafficModel, transit_mode::TransitMode,
+    transit_route_preference::TransitRoutePreference, unit_system::UnitSystem,
+    waypoint::Waypoint, Request,
+}; // crate::directions::request
+use crate::directions::response::parse_mode::ParseMode;
+use crate::directions::travel_mode::TravelMode;
+use crate::types::{Language, Region, RouteRestrictions};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Directions API_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: DirectionsParams = serde_json::from_str(r#"{
+///     "origin": { "Address": "Kitchener, ON" },
+///     "destination": { "Address": "Toronto, ON" },
+///     "travel_mode": "DRIVING"
+/// }"#)?;
+/// let directions = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "geo"), derive(Eq))]
+#[serde(default)]
+pub struct Params {
+    /// The address, latitude/longitude or place ID from which to calculate
+    /// directions.
+    pub origin: Location,
+
+    /// The address, latitude/longitude or place ID to which to calculate
+    /// directions.
+    pub destination: Location,
+
+    /// Whether more than one route may be returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<bool>,
+
+    /// The desired time of arrival for transit directions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrival_time: Option<NaiveDateTime>,
+
+    /// The desired time of departure.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_time: Option<DepartureTime>,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The region code, specified as a ccTLD two-character value, used to bias
+    /// results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// Features, such as tolls or highways, that routes should avoid.
+    #[serde(skip_serializing_if = "RouteRestrictions::is_empty")]
+    pub restrictions: RouteRestrictions,
+
+    /// The assumptions to use when calculating the time in traffic.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_model: Option<TrafficModel>,
+
+    /// The preferred modes of transit.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transit_modes: Vec<TransitMode>,
+
+    /// The preference for transit routes, such as fewer transfers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transit_route_preference: Option<TransitRoutePreference>,
+
+    /// The mode of transportation. Driving is used when it is not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_mode: Option<TravelMode>,
+
+    /// The unit system to use when displaying results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_system: Option<UnitSystem>,
+
+    /// Whether the waypoints may be rearranged into a more efficient order.
+    pub waypoint_optimization: bool,
+
+    /// Locations to pass through or stop over at, in order.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub waypoints: Vec<Waypoint>,
+
+    /// How much of the response to parse.
+    pub parse_mode: ParseMode,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            origin: self.origin,
+            destination: self.destination,
+            alternatives: self.alternatives,
+            arrival_time: self.arrival_time,
+            departure_time: self.departure_time,
+            language: self.language,
+            region: self.region,
+            restrictions: self.restrictions,
+            traffic_model: self.traffic_model,
+            transit_modes: self.transit_modes,
+            transit_route_preference: self.transit_route_preference,
+            travel_mode: self.travel_mode,
+            unit_system: self.unit_system,
+            waypoint_optimization: self.waypoint_optimization,
+            waypoints: self.waypoints,
+            parse_mode: self.parse_mode,
+            channel: self.channel,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            origin: self.origin.clone(),
+            destination: self.destination.clone(),
+            alternatives: self.alternatives,
+            arrival_time: self.arrival_time,
+            departure_time: self.departure_time.clone(),
+            language: self.language,
+            region: self.region,
+            restrictions: self.restrictions,
+            traffic_model: self.traffic_model.clone(),
+            transit_modes: self.transit_modes.clone(),
+            transit_route_preference: self.transit_route_preference.clone(),
+            travel_mode: self.travel_mode.clone(),
+            unit_system: self.unit_system.clone(),
+            waypoint_optimization: self.waypoint_optimization,
+            waypoints: self.waypoints.clone(),
+            parse_mode: self.parse_mode,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
@@ -170,3 +170,25 @@ impl TrafficModel {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl TrafficModel {
+    /// Returns the code that Google uses for this `TrafficModel`, for example
+    /// `best_guess`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `TrafficModel` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = TRAFFIC_MODELS_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
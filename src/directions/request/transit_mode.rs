@@ -176,3 +176,25 @@ impl TransitMode {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl TransitMode {
+    /// Returns the code that Google uses for this `TransitMode`, for example
+    /// `bus`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `TransitMode` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = TRANSIT_MODES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -158,3 +158,25 @@ impl TransitRoutePreference {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl TransitRoutePreference {
+    /// Returns the code that Google uses for this `TransitRoutePreference`, for example
+    /// `fewer_transfers`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `TransitRoutePreference` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = TRANSIT_ROUTE_PREFERENCE_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::built_request::BuiltRequest;
+use crate::canonical_request::CanonicalRequest;
+use crate::directions::request::Request;
+use crate::directions::error::Error;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Validates the Directions API request and builds its query string.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the error found by `validate`, if the combination of
+    ///   parameters is invalid.
+
+    pub fn try_build(&mut self) -> Result<BuiltRequest, Error> {
+        self.validate()?;
+        self.build()?;
+        Ok(BuiltRequest::new(
+            self.service_url(),
+            self.query.clone().unwrap_or_default(),
+        )) // BuiltRequest
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the request like `try_build`, with the headers that `execute`
+    /// sends.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the same errors as `try_build`.
+
+    pub fn inspect(&mut self) -> Result<BuiltRequest, Error> {
+        Ok(self.try_build()?.with_client_headers(self.client, None))
+    } // fn
+} // impl
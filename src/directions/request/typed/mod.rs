@@ -0,0 +1,330 @@
+//! A Directions API request builder that encodes its state in the type system
+//! ([session types](https://en.wikipedia.org/wiki/Session_type)). Invalid
+//! combinations of parameters are rejected at compile time, rather than by
+//! `validate()` at run time.
+//!
+//! For example:
+//!
+//! * `with_transit_modes()` and `with_transit_route_preference()` are only
+//!   available after `transit()` has been called.
+//!
+//! * `with_arrival_time()` is only available for transit directions, and only
+//!   if no departure time has been set. Likewise, `with_departure_time()` is
+//!   not available once an arrival time has been set.
+//!
+//! * `with_waypoints()` is not available for transit directions.
+//!
+//! * `with_traffic_model()` is only available for driving directions once a
+//!   departure time has been set.
+//!
+//! The travel mode must be selected before any time is set. Constraints that
+//! depend on values rather than types - such as the maximum number of
+//! waypoints - are still checked by `validate()` when the request is executed.
+//!
+//! ```rust
+//! let directions = google_maps_client
+//!     .typed_directions(
+//!         Location::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+//!         Location::from_address("1867 St Laurent Blvd, Ottawa, ON K1G 5A3"),
+//!     )
+//!     .transit()
+//!     .with_transit_modes([TransitMode::Bus, TransitMode::Train])
+//!     .with_arrival_time(NaiveDate::from_ymd(2030, 1, 1).and_hms(9, 0, 0))
+//!     .execute()
+//!     .await?;
+//! ```
+
+pub mod state;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::{
+    avoid::Avoid, departure_time::DepartureTime, location::Location, traffic_model::TrafficModel,
+    transit_mode::TransitMode, transit_route_preference::TransitRoutePreference,
+    unit_system::UnitSystem, waypoint::Waypoint, Request,
+}; // crate::directions::request
+use crate::directions::request::typed::state::{
+    AcceptsDepartureTime, AcceptsTrafficModel, AcceptsWaypoints, ArriveBy, Bicycling, DepartAt,
+    Driving, NoTime, TimeState, Transit, TravelModeState, Unspecified, Walking,
+}; // crate::directions::request::typed::state
+use crate::types::{Language, Region};
+use chrono::NaiveDateTime;
+use std::marker::PhantomData;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `TypedRequest` struct for documentation on how to build a
+/// compile-time checked _Directions API_ query**. It wraps the regular
+/// directions `Request`, and can be converted into one at any time with
+/// `into_request()`.
+///
+/// The `M` type parameter tracks the selected travel mode and the `T` type
+/// parameter tracks whether a departure or arrival time has been set. See the
+/// `state` module for the possible states.
+
+#[derive(Debug)]
+pub struct TypedRequest<'a, M: TravelModeState = Unspecified, T: TimeState = NoTime> {
+    /// The underlying directions request.
+    request: Request<'a>,
+
+    /// The compile-time state of the request.
+    state: PhantomData<(M, T)>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> TypedRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the data structure for the type-checked builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `origin` ‧ The address, latitude/longitude, or place ID from which
+    ///   you wish to calculate directions.
+    ///
+    /// * `destination` ‧ The address, latitude/longitude, or place ID to which
+    ///   you wish to calculate directions.
+
+    #[must_use]
+    pub const fn new(
+        client: &'a GoogleMapsClient,
+        origin: Location,
+        destination: Location
+    ) -> Self {
+        Self {
+            request: Request::new(client, origin, destination),
+            state: PhantomData,
+        } // TypedRequest
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// Travel mode selection. The travel mode must be selected first:
+
+impl<'a> TypedRequest<'a, Unspecified, NoTime> {
+    /// Requests standard driving directions using the road network. Enables
+    /// `with_traffic_model()` once a departure time has been set.
+    #[must_use]
+    pub fn driving(self) -> TypedRequest<'a, Driving, NoTime> {
+        self.transition()
+    } // fn
+
+    /// Requests walking directions via pedestrian paths & sidewalks (where
+    /// available).
+    #[must_use]
+    pub fn walking(self) -> TypedRequest<'a, Walking, NoTime> {
+        self.transition()
+    } // fn
+
+    /// Requests bicycling directions via bicycle paths & preferred streets
+    /// (where available).
+    #[must_use]
+    pub fn bicycling(self) -> TypedRequest<'a, Bicycling, NoTime> {
+        self.transition()
+    } // fn
+
+    /// Requests directions via public transit routes (where available).
+    /// Enables `with_transit_modes()`, `with_transit_route_preference()` and
+    /// `with_arrival_time()`, and disables `with_waypoints()`.
+    #[must_use]
+    pub fn transit(self) -> TypedRequest<'a, Transit, NoTime> {
+        self.transition()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// Parameters that are available in every state:
+
+impl<'a, M: TravelModeState, T: TimeState> TypedRequest<'a, M, T> {
+    /// Moves the underlying request into a new state, updating the travel mode
+    /// to match the new state.
+    fn transition<M2: TravelModeState, T2: TimeState>(mut self) -> TypedRequest<'a, M2, T2> {
+        self.request.travel_mode = M2::TRAVEL_MODE;
+        TypedRequest {
+            request: self.request,
+            state: PhantomData,
+        } // TypedRequest
+    } // fn
+
+    /// Specifies whether the directions service may provide more than one
+    /// route alternative in the response. See `Request::with_alternatives`.
+    #[must_use]
+    pub fn with_alternatives(mut self, alternatives: impl Into<bool>) -> Self {
+        self.request.alternatives = Some(alternatives.into());
+        self
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `Request::with_language`.
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.language = Some(language.into());
+        self
+    } // fn
+
+    /// Specifies the region code, specified as a ccTLD ("top-level domain")
+    /// two-character value. See `Request::with_region`.
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<Region>) -> Self {
+        self.request.region = Some(region.into());
+        self
+    } // fn
+
+    /// Indicates that the calculated route(s) should avoid the indicated
+    /// features. See `Request::with_restrictions`.
+    #[must_use]
+    pub fn with_restrictions<C, A>(mut self, restrictions: C) -> Self
+    where
+        C: IntoIterator<Item = A>,
+        A: Into<Avoid> {
+        self.request.restrictions = restrictions.into_iter().collect();
+        self
+    } // fn
+
+    /// Specifies the unit system to use when displaying results. See
+    /// `Request::with_unit_system`.
+    #[must_use]
+    pub fn with_unit_system(mut self, unit_system: impl Into<UnitSystem>) -> Self {
+        self.request.unit_system = Some(unit_system.into());
+        self
+    } // fn
+
+    /// Converts the type-checked request into a regular directions `Request`,
+    /// which may be further modified, inspected with `query_url()`, or
+    /// executed.
+    #[must_use]
+    pub fn into_request(self) -> Request<'a> {
+        self.request
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// Waypoints are not supported for transit directions:
+
+impl<M: AcceptsWaypoints, T: TimeState> TypedRequest<'_, M, T> {
+    /// Specifies intermediate locations to visit before arriving at the final
+    /// destination. Not available for transit directions. See
+    /// `Request::with_waypoints`.
+    #[must_use]
+    pub fn with_waypoints<C, W>(mut self, waypoints: C) -> Self
+    where
+        C: IntoIterator<Item = W>,
+        W: Into<Waypoint> {
+        self.request.waypoints = waypoints.into_iter().map(Into::into).collect();
+        self
+    } // fn
+
+    /// Specifies whether the waypoint order should be optimized or not. See
+    /// `Request::with_waypoint_optimization`.
+    #[must_use]
+    pub fn with_waypoint_optimization(mut self, waypoint_optimization: impl Into<bool>) -> Self {
+        self.request.waypoint_optimization = waypoint_optimization.into();
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// A departure time may only be set once, and not after an arrival time:
+
+impl<'a, M: AcceptsDepartureTime> TypedRequest<'a, M, NoTime> {
+    /// Specifies the desired time of departure. Not available once an arrival
+    /// time has been set. See `Request::with_departure_time`.
+    #[must_use]
+    pub fn with_departure_time(
+        mut self,
+        departure_time: impl Into<DepartureTime>
+    ) -> TypedRequest<'a, M, DepartAt> {
+        self.request.departure_time = Some(departure_time.into());
+        self.transition()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// A traffic model requires driving directions and a departure time:
+
+impl<M: AcceptsTrafficModel> TypedRequest<'_, M, DepartAt> {
+    /// Specifies the assumptions to use when calculating time in traffic. Only
+    /// available for driving directions once a departure time has been set.
+    /// See `Request::with_traffic_model`.
+    #[must_use]
+    pub fn with_traffic_model(mut self, traffic_model: impl Into<TrafficModel>) -> Self {
+        self.request.traffic_model = Some(traffic_model.into());
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// Transit-only parameters:
+
+impl<T: TimeState> TypedRequest<'_, Transit, T> {
+    /// Specifies one or more preferred modes of transit. Only available for
+    /// transit directions. See `Request::with_transit_modes`.
+    #[must_use]
+    pub fn with_transit_modes<C, M>(mut self, transit_modes: C) -> Self
+    where
+        C: IntoIterator<Item = M>,
+        M: Into<TransitMode> {
+        self.request.transit_modes = transit_modes.into_iter().map(Into::into).collect();
+        self
+    } // fn
+
+    /// Specifies preferences for transit routes. Only available for transit
+    /// directions. See `Request::with_transit_route_preference`.
+    #[must_use]
+    pub fn with_transit_route_preference(
+        mut self,
+        transit_route_preference: impl Into<TransitRoutePreference>
+    ) -> Self {
+        self.request.transit_route_preference = Some(transit_route_preference.into());
+        self
+    } // fn
+} // impl
+
+impl<'a> TypedRequest<'a, Transit, NoTime> {
+    /// Specifies the desired time of arrival for transit directions. Not
+    /// available once a departure time has been set. See
+    /// `Request::with_arrival_time`.
+    #[must_use]
+    pub fn with_arrival_time(
+        mut self,
+        arrival_time: impl Into<NaiveDateTime>
+    ) -> TypedRequest<'a, Transit, ArriveBy> {
+        self.request.arrival_time = Some(arrival_time.into());
+        self.transition()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl<M: TravelModeState, T: TimeState> TypedRequest<'_, M, T> {
+    /// Executes the query you've built. Value-dependent constraints, such as
+    /// the maximum number of waypoints, are still checked by `validate()`
+    /// before the request is sent.
+    pub async fn execute(
+        self
+    ) -> Result<crate::directions::response::Response, crate::error::Error> {
+        let mut request = self.request;
+        request.execute().await
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a, M: TravelModeState, T: TimeState> From<TypedRequest<'a, M, T>> for Request<'a> {
+    /// Converts a type-checked `TypedRequest` into a regular directions
+    /// `Request`.
+    fn from(typed_request: TypedRequest<'a, M, T>) -> Self {
+        typed_request.request
+    } // fn
+} // impl
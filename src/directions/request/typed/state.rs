@@ -0,0 +1,129 @@
+//! Marker types that encode the state of a `TypedRequest` in the type system.
+//! They carry no data and are never instantiated.
+
+use crate::directions::travel_mode::TravelMode;
+
+// -----------------------------------------------------------------------------
+
+mod sealed {
+    pub trait Sealed {}
+} // mod
+
+// -----------------------------------------------------------------------------
+//
+/// The travel mode state of a `TypedRequest`.
+
+pub trait TravelModeState: sealed::Sealed {
+    /// The travel mode that this state sends to Google, if any.
+    const TRAVEL_MODE: Option<TravelMode>;
+} // trait
+
+/// The time state of a `TypedRequest`: no time, a departure time, or an arrival
+/// time.
+
+pub trait TimeState: sealed::Sealed {}
+
+/// Travel modes that support intermediate waypoints. Waypoints are not
+/// supported for transit directions.
+
+pub trait AcceptsWaypoints: TravelModeState {}
+
+/// Travel modes for which a departure time may be specified.
+
+pub trait AcceptsDepartureTime: TravelModeState {}
+
+/// Travel modes for which a traffic model may be specified. Traffic models only
+/// apply to driving directions.
+
+pub trait AcceptsTrafficModel: TravelModeState {}
+
+// -----------------------------------------------------------------------------
+//
+/// No travel mode has been selected. Google will calculate driving directions.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Unspecified;
+
+/// Driving directions have been selected.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Driving;
+
+/// Walking directions have been selected.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Walking;
+
+/// Bicycling directions have been selected.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Bicycling;
+
+/// Public transit directions have been selected.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Transit;
+
+// -----------------------------------------------------------------------------
+//
+/// Neither a departure time nor an arrival time has been specified.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NoTime;
+
+/// A departure time has been specified. An arrival time may no longer be set.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DepartAt;
+
+/// An arrival time has been specified. A departure time may no longer be set.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ArriveBy;
+
+// -----------------------------------------------------------------------------
+
+impl sealed::Sealed for Unspecified {}
+impl sealed::Sealed for Driving {}
+impl sealed::Sealed for Walking {}
+impl sealed::Sealed for Bicycling {}
+impl sealed::Sealed for Transit {}
+impl sealed::Sealed for NoTime {}
+impl sealed::Sealed for DepartAt {}
+impl sealed::Sealed for ArriveBy {}
+
+impl TravelModeState for Unspecified {
+    const TRAVEL_MODE: Option<TravelMode> = None;
+}
+
+impl TravelModeState for Driving {
+    const TRAVEL_MODE: Option<TravelMode> = Some(TravelMode::Driving);
+}
+
+impl TravelModeState for Walking {
+    const TRAVEL_MODE: Option<TravelMode> = Some(TravelMode::Walking);
+}
+
+impl TravelModeState for Bicycling {
+    const TRAVEL_MODE: Option<TravelMode> = Some(TravelMode::Bicycling);
+}
+
+impl TravelModeState for Transit {
+    const TRAVEL_MODE: Option<TravelMode> = Some(TravelMode::Transit);
+}
+
+impl TimeState for NoTime {}
+impl TimeState for DepartAt {}
+impl TimeState for ArriveBy {}
+
+impl AcceptsWaypoints for Unspecified {}
+impl AcceptsWaypoints for Driving {}
+impl AcceptsWaypoints for Walking {}
+impl AcceptsWaypoints for Bicycling {}
+
+impl AcceptsDepartureTime for Unspecified {}
+impl AcceptsDepartureTime for Driving {}
+impl AcceptsDepartureTime for Transit {}
+
+impl AcceptsTrafficModel for Unspecified {}
+impl AcceptsTrafficModel for Driving {}
@@ -154,3 +154,25 @@ impl UnitSystem {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl UnitSystem {
+    /// Returns the code that Google uses for this `UnitSystem`, for example
+    /// `imperial`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `UnitSystem` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = UNIT_SYSTEMS_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
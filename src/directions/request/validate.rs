@@ -1,6 +1,10 @@
-use crate::directions::{error::Error, request::Request, travel_mode::TravelMode}; // crate::directions
+use crate::directions::{
+    error::Error,
+    request::{waypoint::Waypoint, Request},
+    travel_mode::TravelMode,
+}; // crate::directions
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -12,7 +16,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&'a mut self) -> Result<&'a mut Self, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         if let Some(travel_mode) = &self.travel_mode {
             // If the travel mode has been set to TravelMode::Transit...
             if *travel_mode == TravelMode::Transit {
@@ -67,14 +71,27 @@ impl<'a> Request<'a> {
             if !self.restrictions.is_empty() {
                 return Err(Error::EitherRestrictionsOrWaypoints(
                     self.waypoints.len(),
-                    self.restrictions
-                        .iter()
-                        .map(std::string::ToString::to_string)
-                        .collect::<Vec<String>>()
-                        .join("|"),
+                    self.restrictions.to_string(),
                 )); // Err
             } // if
 
+            // ...pass-through waypoints cannot be optimized:
+            if self.waypoint_optimization {
+                let via_count = self.waypoints.iter().filter(|waypoint| waypoint.is_via()).count();
+                if via_count > 0 {
+                    return Err(Error::ViaWaypointsWithOptimization(via_count));
+                } // if
+            } // if
+
+            // ...headings must be in the range 0-359 degrees:
+            if let Some(heading) = self.waypoints
+                .iter()
+                .filter_map(Waypoint::heading)
+                .find(|heading| *heading > 359)
+            {
+                return Err(Error::InvalidHeading(heading));
+            } // if
+
             // ...ensure that the number of waypoints is equal to or less than 25:
             if self.waypoints.len() > 25 {
                 return Err(Error::TooManyWaypoints(self.waypoints.len()));
@@ -92,6 +109,24 @@ impl<'a> Request<'a> {
             } // if
         } // if
 
+        // If a traffic model has been set...
+        if let Some(traffic_model) = &self.traffic_model {
+            // ...a departure time must be set:
+            if self.departure_time.is_none() {
+                return Err(Error::TrafficModelRequiresDepartureTime(traffic_model.to_string()));
+            } // if
+
+            // ...the travel mode must be driving:
+            if let Some(travel_mode) = &self.travel_mode {
+                if *travel_mode != TravelMode::Driving {
+                    return Err(Error::TrafficModelIsForDrivingOnly(
+                        travel_mode.to_string(),
+                        traffic_model.to_string(),
+                    )); // Err
+                } // if
+            } // if
+        } // if
+
         // Indicate that the request passed validation.
         self.validated = true;
 
@@ -47,6 +47,27 @@ pub enum Waypoint {
     /// See also: the Google Encoded Polyline encoding & decoding crate called
     /// [polyline](https://crates.io/crates/polyline).
     Polyline(String),
+    /// A waypoint that the route must reach on the same side of the road as
+    /// the coordinates. The route will be biased so that the vehicle arrives
+    /// with the location on its side of the road, given the driving side of
+    /// the country. This is sent to Google with the `side_of_road:` prefix and
+    /// only applies to driving directions.
+    SideOfRoad(LatLng),
+    /// A waypoint that the route must approach while travelling in the
+    /// specified direction. The heading is measured in whole degrees clockwise
+    /// from north and must be in the range 0–359. This is sent to Google as
+    /// `heading=<degrees>:<coordinates>` and only applies to driving
+    /// directions.
+    ///
+    /// Use `Waypoint::try_from_heading` to validate the heading on
+    /// construction.
+    Heading(u16, LatLng),
+    /// A pass-through waypoint. The route is altered to pass through the
+    /// location, but no stopover is made and no additional leg is created.
+    /// This is sent to Google with the `via:` prefix.
+    ///
+    /// Pass-through waypoints cannot be used with waypoint optimization.
+    Via(Box<Self>),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -62,6 +83,9 @@ impl std::convert::From<&Waypoint> for String {
             Waypoint::LatLng(latlng) => Self::from(latlng),
             Waypoint::PlaceId(place_id) => format!("place_id:{place_id}"),
             Waypoint::Polyline(polyline) => format!("enc:{polyline}:"),
+            Waypoint::SideOfRoad(latlng) => format!("side_of_road:{}", Self::from(latlng)),
+            Waypoint::Heading(heading, latlng) => format!("heading={heading}:{}", Self::from(latlng)),
+            Waypoint::Via(waypoint) => format!("via:{}", Self::from(waypoint.as_ref())),
         } // match
     } // fn
 } // impl
@@ -81,7 +105,7 @@ impl std::convert::From<&Self> for Waypoint {
 /// Used to specify pass throughs or stopovers at intermediate locations.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Waypoint {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to latitude & longitude coordinates to calculate directions.
@@ -120,6 +144,27 @@ pub enum Waypoint {
     /// [geo](https://crates.io/crates/geo) crate's
     /// [Point](https://docs.rs/geo/latest/geo/geometry/struct.Point.html) type.
     Point(geo_types::geometry::Point),
+    /// A waypoint that the route must reach on the same side of the road as
+    /// the coordinates. The route will be biased so that the vehicle arrives
+    /// with the location on its side of the road, given the driving side of
+    /// the country. This is sent to Google with the `side_of_road:` prefix and
+    /// only applies to driving directions.
+    SideOfRoad(LatLng),
+    /// A waypoint that the route must approach while travelling in the
+    /// specified direction. The heading is measured in whole degrees clockwise
+    /// from north and must be in the range 0–359. This is sent to Google as
+    /// `heading=<degrees>:<coordinates>` and only applies to driving
+    /// directions.
+    ///
+    /// Use `Waypoint::try_from_heading` to validate the heading on
+    /// construction.
+    Heading(u16, LatLng),
+    /// A pass-through waypoint. The route is altered to pass through the
+    /// location, but no stopover is made and no additional leg is created.
+    /// This is sent to Google with the `via:` prefix.
+    ///
+    /// Pass-through waypoints cannot be used with waypoint optimization.
+    Via(Box<Self>),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -150,6 +195,12 @@ impl std::convert::From<&Waypoint> for String {
                 latitude = point.y(),
                 longitude = point.x()
             ),
+
+            Waypoint::SideOfRoad(latlng) => format!("side_of_road:{}", Self::from(latlng)),
+
+            Waypoint::Heading(heading, latlng) => format!("heading={heading}:{}", Self::from(latlng)),
+
+            Waypoint::Via(waypoint) => format!("via:{}", Self::from(waypoint.as_ref())),
         } // match
     } // fn
 } // impl
@@ -168,6 +219,86 @@ impl Waypoint {
 
 // -----------------------------------------------------------------------------
 
+impl Waypoint {
+    /// Makes the given waypoint a pass-through waypoint. The route will pass
+    /// through the location without stopping, and no additional leg is added
+    /// to the route. This is the `via:` prefix of the Directions API.
+    ///
+    /// ```rust
+    /// .with_waypoint(Waypoint::via(Waypoint::from_address("Golden Gate Bridge")))
+    /// ```
+    ///
+    /// Pass-through waypoints cannot be combined with
+    /// `with_waypoint_optimization(true)`.
+    pub fn via(waypoint: impl Into<Self>) -> Self {
+        match waypoint.into() {
+            via @ Self::Via(_) => via,
+            waypoint => Self::Via(Box::new(waypoint)),
+        } // match
+    } // fn
+
+    /// Returns `true` if this is a pass-through (`via:`) waypoint.
+    #[must_use]
+    pub const fn is_via(&self) -> bool {
+        matches!(self, Self::Via(_))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Waypoint {
+    /// Creates a waypoint that the route must reach on the same side of the
+    /// road as the given coordinates. This is the `side_of_road:` modifier of
+    /// the Directions API and only applies to driving directions.
+    ///
+    /// ```rust
+    /// .with_waypoint(Waypoint::side_of_road(LatLng::try_from_dec(dec!(37.7663444), dec!(-122.4412006))?))
+    /// ```
+    #[must_use]
+    pub const fn side_of_road(latlng: LatLng) -> Self {
+        Self::SideOfRoad(latlng)
+    } // fn
+
+    /// Creates a waypoint that the route must approach while travelling in the
+    /// given direction. This is the `heading=` modifier of the Directions API
+    /// and only applies to driving directions.
+    ///
+    /// ## Arguments
+    ///
+    /// * `heading` ‧ The direction of travel, in whole degrees clockwise from
+    ///   north. Must be in the range 0–359.
+    ///
+    /// * `latlng` ‧ The location of the waypoint.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `InvalidHeading` if the heading is 360 or greater.
+    ///
+    /// ```rust
+    /// // Approach the loading dock heading east:
+    /// .with_waypoint(Waypoint::try_from_heading(90, loading_dock)?)
+    /// ```
+    pub fn try_from_heading(heading: u16, latlng: LatLng) -> Result<Self, GoogleMapsError> {
+        if heading > 359 {
+            return Err(crate::directions::error::Error::InvalidHeading(heading).into());
+        } // if
+        Ok(Self::Heading(heading, latlng))
+    } // fn
+
+    /// Returns the heading of a `Waypoint::Heading` waypoint, looking through
+    /// a pass-through wrapper if present.
+    #[must_use]
+    pub fn heading(&self) -> Option<u16> {
+        match self {
+            Self::Heading(heading, _) => Some(*heading),
+            Self::Via(waypoint) => waypoint.heading(),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl Waypoint {
     /// The place ID may only be specified if the request includes an API key or
     /// a Google Maps Platform Premium Plan client ID. You can retrieve place
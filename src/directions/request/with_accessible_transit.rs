@@ -0,0 +1,33 @@
+use crate::directions::request::{transit_route_preference::TransitRoutePreference, Request}; // crate::directions::request
+
+impl Request<'_> {
+    /// Biases transit directions towards routes that are easier for riders
+    /// with limited mobility.
+    ///
+    /// ## Description
+    ///
+    /// The Directions API does not accept an explicit wheelchair or step-free
+    /// parameter. The closest hint Google exposes is the
+    /// `TransitRoutePreference::LessWalking` routing preference, which this
+    /// method sets. It is shorthand for:
+    ///
+    /// ```rust
+    /// .with_transit_route_preference(TransitRoutePreference::LessWalking)
+    /// ```
+    ///
+    /// The Directions API doesn't report whether stops or vehicles are
+    /// wheelchair accessible. To compare the returned routes, see
+    /// `Route::walking_distance` and `Route::transfers`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_travel_mode(TravelMode::Transit)
+    /// .with_accessible_transit()
+    /// ```
+
+    pub fn with_accessible_transit(&mut self) -> &mut Self {
+        self.transit_route_preference = Some(TransitRoutePreference::LessWalking);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::directions::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies a channel for this request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `channel` ‧ A tag that Google adds to the usage reports of your
+    ///   Directions API requests. Channels let Premium Plan customers separate
+    ///   the usage of different applications, or of different customers of an
+    ///   application, that share an API key. A channel may contain ASCII
+    ///   letters, numbers, periods, underscores and hyphens. This overrides
+    ///   the channel set with `GoogleMapsClient::with_channel()`, if any.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_channel("checkout-page")
+    /// ```
+
+    pub fn with_channel(
+        &'a mut self,
+        channel: impl Into<String>
+    ) -> &'a mut Self {
+        // Set channel in Request struct.
+        self.channel = Some(channel.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -63,4 +63,18 @@ impl<'a> Request<'a> {
         self.departure_time = Some(departure_time.into());
         self
     } // fn
+
+    /// Returns a copy of this request that departs at the specified time. Any
+    /// arrival time is cleared, and the copy must be validated and built
+    /// again.
+    #[cfg(all(feature = "directions", feature = "reqwest"))]
+    pub(crate) fn at_departure_time(&self, departure_time: chrono::NaiveDateTime) -> Self {
+        Self {
+            departure_time: Some(DepartureTime::At(departure_time)),
+            arrival_time: None,
+            query: None,
+            validated: false,
+            ..self.clone()
+        } // Request
+    } // fn
 } // impl
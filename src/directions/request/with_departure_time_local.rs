@@ -0,0 +1,84 @@
+use crate::directions::{error::Error as DirectionsError, request::{departure_time::DepartureTime, Request}};
+use crate::error::Error as GoogleMapsError;
+use crate::types::LatLng;
+use chrono::{Duration, LocalResult, NaiveDateTime, TimeZone};
+
+impl<'a> Request<'a> {
+    /// Specifies the desired departure time in the local time of a location,
+    /// such as "9:00 AM at the origin."
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_time` ‧ The departure time, in the local time at `at`.
+    ///
+    /// * `at` ‧ The location whose local time `departure_time` is in. This is
+    ///   usually the origin.
+    ///
+    /// ## Description
+    ///
+    /// The Directions API expects departure times in UTC. This method looks up
+    /// the location's time zone with the Time Zone API, converts the departure
+    /// time to UTC, and sets it with `with_departure_time()`. Daylight saving
+    /// time is accounted for. Note that this sends a Time Zone API request,
+    /// which is billed separately.
+    ///
+    /// If the local time occurs twice, because clocks are turned back, the
+    /// earlier of the two is used.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns an error if the Time Zone API request fails.
+    ///
+    /// * Returns `InvalidLocalDepartureTime` if the local time does not exist
+    ///   at the location, because clocks are turned forward, or if the Time
+    ///   Zone API did not return a time zone for the location.
+    ///
+    /// ## Example
+    ///
+    /// * Departing at 9:00 AM, Sydney time, on January 1, 2030:
+    /// ```rust
+    /// .with_departure_time_local(
+    ///     NaiveDate::from_ymd(2030, 1, 1).and_hms(9, 0, 0),
+    ///     LatLng::try_from_dec(dec!(-33.8688), dec!(151.2093))?,
+    /// )
+    /// .await?
+    /// ```
+
+    pub async fn with_departure_time_local(
+        &'a mut self,
+        departure_time: NaiveDateTime,
+        at: impl Into<LatLng>
+    ) -> Result<&'a mut Self, GoogleMapsError> {
+        // The local time is close enough to the UTC time to find the time
+        // zone in effect:
+        let time_zone = self
+            .client
+            .time_zone(at, departure_time.and_utc())
+            .execute()
+            .await?;
+
+        let utc_departure_time = if let Some(tz) = time_zone.time_zone_id {
+            match tz.from_local_datetime(&departure_time) {
+                LocalResult::Single(date_time) | LocalResult::Ambiguous(date_time, _) =>
+                    date_time.naive_utc(),
+                LocalResult::None => Err(DirectionsError::InvalidLocalDepartureTime(
+                    departure_time,
+                    tz.name().to_string(),
+                ))?,
+            } // match
+        } else if let Some(utc_departure_time) = time_zone
+            .utc_offset()
+            .and_then(|offset| departure_time
+                .checked_sub_signed(Duration::seconds(i64::from(offset.local_minus_utc()))))
+        {
+            utc_departure_time
+        } else {
+            Err(DirectionsError::InvalidLocalDepartureTime(
+                departure_time,
+                time_zone.time_zone_name.unwrap_or_default(),
+            ))?
+        }; // if
+
+        Ok(self.with_departure_time(DepartureTime::At(utc_departure_time)))
+    } // fn
+} // impl
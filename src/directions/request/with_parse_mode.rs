@@ -0,0 +1,27 @@
+use crate::directions::{request::Request, response::parse_mode::ParseMode};
+
+impl<'a> Request<'a> {
+    /// Specifies how much of Google's response is deserialized.
+    ///
+    /// ## Arguments
+    ///
+    /// * `parse_mode` ‧ `ParseMode::SummaryOnly` skips the steps and overview
+    ///   polyline of each route, which is much faster for responses with
+    ///   many alternatives or steps. Defaults to `ParseMode::Full`.
+    ///
+    /// ## Example
+    ///
+    /// * Only parse route summaries, for a list of alternatives:
+    /// ```rust
+    /// .with_alternatives(true)
+    /// .with_parse_mode(ParseMode::SummaryOnly)
+    /// ```
+
+    pub fn with_parse_mode(
+        &'a mut self,
+        parse_mode: impl Into<ParseMode>
+    ) -> &'a mut Self {
+        self.parse_mode = parse_mode.into();
+        self
+    } // fn
+} // impl
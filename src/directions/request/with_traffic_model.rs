@@ -1,4 +1,6 @@
 use crate::directions::request::{traffic_model::TrafficModel, Request};
+#[cfg(feature = "reqwest")]
+use crate::directions::travel_mode::TravelMode;
 
 impl<'a> Request<'a> {
     /// Specifies the assumptions to use when calculating time in traffic.
@@ -55,4 +57,13 @@ impl<'a> Request<'a> {
         self.traffic_model = Some(traffic_model.into());
         self
     } // fn
+
+    /// Returns whether Google should return traffic-aware durations for this
+    /// request: the travel mode is driving and a departure time is set.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn expects_traffic_durations(&self) -> bool {
+        self.departure_time.is_some()
+            && self.travel_mode.as_ref().map_or(true, |mode| *mode == TravelMode::Driving)
+    } // fn
 } // impl
@@ -29,6 +29,15 @@ impl<'a> Request<'a> {
     /// * `Waypoint::Polyline`: Encoded polyline that can be specified by a set
     ///   of any of the above. (`lexeF{~wsZejrPjtye@:`)
     ///
+    /// * `Waypoint::SideOfRoad`: Coordinates that must be reached on the same
+    ///   side of the road. (`side_of_road:37.7663444,-122.4412006`)
+    ///
+    /// * `Waypoint::Heading`: Coordinates that must be approached travelling in
+    ///   the given direction, 0–359 degrees. (`heading=90:37.7663444,-122.4412006`)
+    ///
+    /// * `Waypoint::Via`: Wraps any of the above to route *through* the
+    ///   location without stopping. Use `Waypoint::via` to construct one.
+    ///
     /// [Waypoints](https://developers.google.com/maps/documentation/directions/intro#Waypoints)
     /// ----------------------------------------------------------------------------------------
     ///
@@ -0,0 +1,207 @@
+//! Contains the `DepartureSweep` struct. It is the result of running the same
+//! _Directions API_ request at a series of departure times, and shows how the
+//! travel time changes over the course of a day. This is a building block for
+//! picking the best time to dispatch a delivery or start a trip.
+
+use crate::directions::response::eta::EtaBasis;
+use crate::directions::response::Response;
+use chrono::{Duration, NaiveDateTime};
+
+// -----------------------------------------------------------------------------
+//
+/// The travel time of the recommended route for one departure time.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SweepPoint {
+    /// The departure time, in UTC.
+    pub departure_time: NaiveDateTime,
+
+    /// The travel time of the first route. Uses `duration_in_traffic` when
+    /// Google returned it, and the typical `duration` otherwise.
+    pub duration: Duration,
+
+    /// Whether traffic information was used for `duration`.
+    pub basis: EtaBasis,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// How the travel time of a route changes with the departure time. See
+/// `Request::sweep_departure_times`.
+///
+/// ## Example
+///
+/// ```rust
+/// let sweep = google_maps_client
+///     .directions(origin, destination)
+///     .sweep_departure_times(departure_times(start, end, Duration::minutes(15)), 96)
+///     .await;
+/// for (from, to) in sweep.windows(Duration::minutes(5)) {
+///     println!("leave between {from} and {to}");
+/// }
+/// ```
+
+#[derive(Debug, Default)]
+pub struct DepartureSweep {
+    /// The travel time for each departure time that returned a route, in
+    /// departure time order.
+    pub points: Vec<SweepPoint>,
+
+    /// The departure times whose request failed, with the error.
+    pub failures: Vec<(NaiveDateTime, crate::error::Error)>,
+
+    /// The departure times that were not requested because the request budget
+    /// ran out.
+    pub skipped: Vec<NaiveDateTime>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl DepartureSweep {
+    /// Builds a sweep from responses that were retrieved earlier, one for
+    /// each departure time. Responses without any routes are left out.
+
+    #[must_use]
+    pub fn from_responses(responses: impl IntoIterator<Item = (NaiveDateTime, Response)>) -> Self {
+        let mut points: Vec<SweepPoint> = responses
+            .into_iter()
+            .filter_map(|(departure_time, response)| {
+                let eta = response.routes.first()?.arrival_time(departure_time.and_utc());
+                Some(SweepPoint { departure_time, duration: eta.duration, basis: eta.basis })
+            }) // filter_map
+            .collect();
+        points.sort_by_key(|point| point.departure_time);
+
+        Self { points, ..Self::default() }
+    } // fn
+
+    /// Returns the departure time with the shortest travel time. The earliest
+    /// one is returned if several are equally fast.
+
+    #[must_use]
+    pub fn fastest(&self) -> Option<&SweepPoint> {
+        // `min_by_key` returns the first of several equal minimums:
+        self.points.iter().min_by_key(|point| point.duration)
+    } // fn
+
+    /// Returns the departure time with the longest travel time.
+
+    #[must_use]
+    pub fn slowest(&self) -> Option<&SweepPoint> {
+        self.points.iter().rev().max_by_key(|point| point.duration)
+    } // fn
+
+    /// Returns the spans of consecutive departure times whose travel time is
+    /// within `tolerance` of the fastest, as `(first, last)` departure times.
+    /// These are the windows in which to depart.
+
+    #[must_use]
+    pub fn windows(&self, tolerance: Duration) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+        let Some(fastest) = self.fastest() else {
+            return Vec::new();
+        }; // let
+        let limit = fastest.duration + tolerance;
+
+        let mut windows: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+        let mut in_window = false;
+        for point in &self.points {
+            if point.duration > limit {
+                in_window = false;
+            } else if in_window {
+                if let Some(window) = windows.last_mut() {
+                    window.1 = point.departure_time;
+                } // if
+            } else {
+                windows.push((point.departure_time, point.departure_time));
+                in_window = true;
+            } // if
+        } // for
+        windows
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns departure times from `start` to `end`, inclusive, `step` apart.
+/// Returns no times if `step` is not positive or `end` is before `start`.
+///
+/// ## Example
+///
+/// ```rust
+/// // Every 15 minutes over a day:
+/// let times = departure_times(start, start + Duration::days(1), Duration::minutes(15));
+/// ```
+
+#[must_use]
+pub fn departure_times(start: NaiveDateTime, end: NaiveDateTime, step: Duration) -> Vec<NaiveDateTime> {
+    if step <= Duration::zero() {
+        return Vec::new();
+    } // if
+    std::iter::successors(Some(start), |time| time.checked_add_signed(step))
+        .take_while(|time| *time <= end)
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "directions", feature = "reqwest"))]
+impl crate::directions::request::Request<'_> {
+    /// Runs this request once for each departure time, and returns how the
+    /// travel time of the first route changes with the departure time. Any
+    /// departure or arrival time already set on the request is replaced.
+    ///
+    /// The requests run concurrently, but are still subject to the client's
+    /// rate limits. At most `max_requests` are sent: the remaining departure
+    /// times are returned in `DepartureSweep::skipped`. A failed request does
+    /// not stop the others.
+    ///
+    /// For driving directions, Google only returns traffic-aware travel times
+    /// for departure times in the future.
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_times` ‧ The departure times to try, in UTC. See
+    ///   `departure_times` for building an evenly spaced series.
+    /// * `max_requests` ‧ The most requests to send.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let sweep = google_maps_client
+    ///     .directions(origin, destination)
+    ///     .with_travel_mode(TravelMode::Driving)
+    ///     .sweep_departure_times(departure_times(start, end, Duration::minutes(15)), 96)
+    ///     .await;
+    /// let best = sweep.fastest();
+    /// ```
+
+    pub async fn sweep_departure_times(
+        &self,
+        departure_times: impl IntoIterator<Item = NaiveDateTime>,
+        max_requests: usize,
+    ) -> DepartureSweep {
+        let mut departure_times: Vec<NaiveDateTime> = departure_times.into_iter().collect();
+        let skipped = departure_times.split_off(max_requests.min(departure_times.len()));
+
+        let mut requests: Vec<_> = departure_times
+            .iter()
+            .map(|departure_time| self.at_departure_time(*departure_time))
+            .collect();
+        let results = crate::join::execute_all(requests.iter_mut()).await;
+
+        let mut responses = Vec::new();
+        let mut failures = Vec::new();
+        for (departure_time, result) in departure_times.into_iter().zip(results) {
+            match result {
+                Ok(response) => responses.push((departure_time, response)),
+                Err(error) => failures.push((departure_time, error)),
+            } // match
+        } // for
+
+        DepartureSweep {
+            failures,
+            skipped,
+            ..DepartureSweep::from_responses(responses)
+        } // DepartureSweep
+    } // fn
+} // impl
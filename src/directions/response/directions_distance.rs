@@ -1,5 +1,6 @@
 //! A representation of distance as a numeric value and a display string.
 
+use crate::directions::response::localized_text::parse_distance;
 use serde::{Deserialize, Serialize};
 
 /// A representation of distance as a numeric value and a display string.
@@ -7,8 +8,45 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct DirectionsDistance {
     /// A string representation of the distance value, using the
-    /// `with_unit_system()` specified in the request.
+    /// `with_unit_system()` specified in the request. It is rounded and
+    /// localized for display. Use `value` for calculations.
     pub text: String,
-    /// Indicates the distance in meters.
+    /// Indicates the distance in meters. This is the exact distance, and is
+    /// the field to use for calculations, sorting and storage.
     pub value: u32,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsDistance {
+    /// Rebuilds a `DirectionsDistance` from its localized display text only,
+    /// for example `1,2 km`. The `value` is approximate, because the text is
+    /// rounded. Returns `None` if the text is not recognized. See
+    /// `localized_text::parse_distance` for the recognized formats.
+
+    #[must_use]
+    pub fn from_text(text: &str) -> Option<Self> {
+        parse_distance(text).map(|value| Self { text: text.to_string(), value })
+    } // fn
+
+    /// Returns the distance in meters.
+
+    #[must_use]
+    pub fn meters(&self) -> f64 {
+        f64::from(self.value)
+    } // fn
+
+    /// Returns the distance in kilometers.
+
+    #[must_use]
+    pub fn kilometers(&self) -> f64 {
+        self.meters() / 1_000.0
+    } // fn
+
+    /// Returns the distance in miles.
+
+    #[must_use]
+    pub fn miles(&self) -> f64 {
+        self.meters() / 1_609.344
+    } // fn
+} // impl
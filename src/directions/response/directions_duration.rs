@@ -1,5 +1,6 @@
 //! A representation of duration as a numeric value and a display string.
 
+use crate::directions::response::localized_text::parse_duration;
 use crate::serde::duration_to_seconds::duration_to_seconds;
 use crate::serde::seconds_to_duration::seconds_to_duration;
 use chrono::Duration;
@@ -9,12 +10,36 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct DirectionsDuration {
-    /// A string representation of the duration value.
+    /// A string representation of the duration value. It is rounded and
+    /// localized for display. Use `value` for calculations.
     pub text: String,
-    /// The duration in seconds.
+    /// The duration in seconds. This is the exact duration, and is the field
+    /// to use for calculations, sorting and storage.
     #[serde(
         deserialize_with = "seconds_to_duration",
         serialize_with = "duration_to_seconds"
     )]
     pub value: Duration,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsDuration {
+    /// Rebuilds a `DirectionsDuration` from its localized display text only,
+    /// for example `1 Std. 5 Min.`. The `value` is approximate, because the
+    /// text is rounded. Returns `None` if the text is not recognized. See
+    /// `localized_text::parse_duration` for the recognized formats.
+
+    #[must_use]
+    pub fn from_text(text: &str) -> Option<Self> {
+        parse_duration(text).map(|value| Self { text: text.to_string(), value })
+    } // fn
+
+    /// Returns the duration as a `std::time::Duration`. Negative durations,
+    /// which Google does not return, are clamped to zero.
+
+    #[must_use]
+    pub fn to_std(&self) -> std::time::Duration {
+        self.value.to_std().unwrap_or_default()
+    } // fn
+} // impl
@@ -222,4 +222,46 @@ impl DrivingManeuver {
             Self::UturnRight => "U-turn Right",
         } // match
     } // fn
+
+    /// Returns whether the maneuver is a turn: a left or right turn of any
+    /// sharpness, a U-turn, or a roundabout. Forks, ramps, merges and keeping
+    /// to one side are not turns.
+    #[must_use]
+    pub const fn is_turn(&self) -> bool {
+        matches!(
+            self,
+            Self::RoundaboutLeft
+                | Self::RoundaboutRight
+                | Self::TurnLeft
+                | Self::TurnRight
+                | Self::TurnSharpLeft
+                | Self::TurnSharpRight
+                | Self::TurnSlightLeft
+                | Self::TurnSlightRight
+                | Self::UturnLeft
+                | Self::UturnRight
+        ) // matches!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl DrivingManeuver {
+    /// Returns the code that Google uses for this `DrivingManeuver`, for example
+    /// `ferry`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `DrivingManeuver` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = DRIVING_MANEUVERS_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
 } // impl
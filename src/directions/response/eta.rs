@@ -0,0 +1,169 @@
+//! Contains the `Eta` struct and the `EtaBasis` enum. They estimate when a leg
+//! or route arrives, using traffic information when Google provides it.
+
+use crate::directions::response::{leg::Leg, route::Route, Response};
+use chrono::{DateTime, Duration, TimeZone};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The travel times an `Eta` was calculated from.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum EtaBasis {
+    /// Every leg had a `duration_in_traffic`, so the estimate reflects
+    /// traffic conditions.
+    Traffic,
+    /// Some legs had a `duration_in_traffic` and the rest used their typical
+    /// `duration`.
+    PartialTraffic,
+    /// No traffic information was available, so the estimate uses the typical
+    /// `duration`. Google only returns traffic information for driving
+    /// requests that specify a departure time.
+    Typical,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for EtaBasis {
+    /// Formats an `EtaBasis` enum into a string that is presentable to the end
+    /// user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Traffic => write!(f, "in current traffic"),
+            Self::PartialTraffic => write!(f, "partly in current traffic"),
+            Self::Typical => write!(f, "without traffic"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// An estimated time of arrival.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Eta<Tz: TimeZone> {
+    /// The estimated arrival time, in the time zone of the departure time.
+    pub arrival_time: DateTime<Tz>,
+
+    /// The estimated travel time.
+    pub duration: Duration,
+
+    /// Whether traffic information was used for the estimate.
+    pub basis: EtaBasis,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<Tz: TimeZone> Eta<Tz> {
+    /// Returns `true` if traffic information was used for every leg of the
+    /// estimate.
+    #[must_use]
+    pub fn uses_traffic(&self) -> bool {
+        self.basis == EtaBasis::Traffic
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Returns the estimated arrival time when departing at `departure`. Uses
+    /// `duration_in_traffic` when present, and falls back to `duration`
+    /// otherwise. `Eta::basis` tells which was used.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let eta = leg.eta(Utc::now());
+    /// println!("arriving at {} {}", eta.arrival_time, eta.basis);
+    /// ```
+
+    #[must_use]
+    pub fn eta<Tz: TimeZone>(&self, departure: DateTime<Tz>) -> Eta<Tz> {
+        let (duration, basis) = self.duration_in_traffic.as_ref().map_or(
+            (self.duration.value, EtaBasis::Typical),
+            |duration_in_traffic| (duration_in_traffic.value, EtaBasis::Traffic),
+        ); // map_or
+
+        Eta {
+            arrival_time: departure + duration,
+            duration,
+            basis,
+        } // Eta
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Returns the estimated arrival time at the end of the route when
+    /// departing at `departure`. Each leg uses its `duration_in_traffic` when
+    /// present, and falls back to its `duration` otherwise. `Eta::basis` tells
+    /// whether traffic information was available for all, some, or none of
+    /// the legs.
+    ///
+    /// Stopover time at waypoints is not included.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let eta = route.arrival_time(Utc::now());
+    /// if !eta.uses_traffic() {
+    ///     println!("estimate does not reflect current traffic");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn arrival_time<Tz: TimeZone>(&self, departure: DateTime<Tz>) -> Eta<Tz> {
+        let traffic_legs = self
+            .legs
+            .iter()
+            .filter(|leg| leg.duration_in_traffic.is_some())
+            .count();
+
+        let basis = if traffic_legs == 0 {
+            EtaBasis::Typical
+        } else if traffic_legs == self.legs.len() {
+            EtaBasis::Traffic
+        } else {
+            EtaBasis::PartialTraffic
+        }; // if
+
+        let duration = self
+            .total_duration_in_traffic()
+            .unwrap_or_else(|| self.total_duration());
+
+        Eta {
+            arrival_time: departure + duration,
+            duration,
+            basis,
+        } // Eta
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns `true` if any leg of any route has a `duration_in_traffic`.
+    ///
+    /// Google only returns traffic-aware durations for driving requests with
+    /// a departure time, and may silently omit them, for example when no
+    /// traffic data is available for the area. Check this before relying on
+    /// `duration_in_traffic` or on a `TrafficModel`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// if !response.has_traffic_durations() {
+    ///     println!("estimates do not reflect traffic");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn has_traffic_durations(&self) -> bool {
+        self.routes
+            .iter()
+            .flat_map(|route| route.legs.iter())
+            .any(|leg| leg.duration_in_traffic.is_some())
+    } // fn
+} // impl
@@ -51,11 +51,7 @@ impl std::convert::From<&GeocoderStatus> for String {
     /// status](https://developers.google.com/maps/documentation/directions/intro#GeocodedWaypoints)
     /// code.
     fn from(geocoder_status: &GeocoderStatus) -> Self {
-        match geocoder_status {
-            GeocoderStatus::Ok => Self::from("OK"),
-            GeocoderStatus::ZeroResults => Self::from("ZERO_RESULTS"),
-            GeocoderStatus::UnknownError => Self::from("UNKNOWN_ERROR"),
-        } // match
+        Self::from(geocoder_status.as_wire_str())
     } // fn
 } // impl
 
@@ -121,3 +117,32 @@ impl std::fmt::Display for GeocoderStatus {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl GeocoderStatus {
+    /// Returns the code that Google uses for this `GeocoderStatus`, for example
+    /// `OK`. `FromStr` parses the code back into the same variant.
+    ///
+    /// Unlike `Display`, which is meant for people, this returns the code as
+    /// Google sends it.
+
+    #[must_use]
+    pub const fn as_wire_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::ZeroResults => "ZERO_RESULTS",
+            Self::UnknownError => "UNKNOWN_ERROR",
+        } // match
+    } // fn
+
+    /// Returns an iterator over every `GeocoderStatus` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = GEOCODER_STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -0,0 +1,176 @@
+//! Parses the localized `text` of a distance or duration, such as `1,2 km` or
+//! `1 Std. 5 Min.`, back into a numeric value.
+//!
+//! Prefer the `value` fields of `DirectionsDistance` and `DirectionsDuration`
+//! whenever they are available. The `text` is rounded for display, so a value
+//! parsed from it is only approximate. These functions are meant for data that
+//! was stored with only its display text.
+//!
+//! English, German, French, Spanish, Italian, Portuguese, Dutch, Swedish,
+//! Russian, Japanese, Chinese and Korean texts are recognized. Both `.` and
+//! `,` are accepted as decimal separators.
+
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// Parses a localized distance text, such as `12.3 km`, `1,2 km`, `800 ft` or
+/// `3.4 mi`, into meters. Returns `None` if the text is not recognized.
+///
+/// ## Example
+///
+/// ```rust
+/// assert_eq!(parse_distance("1,2 km"), Some(1_200));
+/// assert_eq!(parse_distance("500 ft"), Some(152));
+/// ```
+
+#[must_use]
+pub fn parse_distance(text: &str) -> Option<u32> {
+    let meters = quantities(text)?
+        .into_iter()
+        .map(|(number, unit)| distance_unit(&unit).map(|meters| number * meters))
+        .sum::<Option<f64>>()?;
+    // The range check guarantees that the distance fits in a `u32`:
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    (0.0..=f64::from(u32::MAX)).contains(&meters).then(|| meters.round() as u32)
+} // fn
+
+/// Parses a localized duration text, such as `1 hour 5 mins`,
+/// `1 Std. 5 Min.`, `2 jours 3 heures` or `1時間5分`, into a `Duration`.
+/// Returns `None` if the text is not recognized.
+///
+/// ## Example
+///
+/// ```rust
+/// assert_eq!(parse_duration("1 h 5 min"), Duration::try_minutes(65));
+/// ```
+
+#[must_use]
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let seconds = quantities(text)?
+        .into_iter()
+        .map(|(number, unit)| duration_unit(&unit).map(|seconds| number * seconds))
+        .sum::<Option<f64>>()?;
+    // The range check guarantees that the duration fits in an `i64`:
+    #[allow(clippy::cast_possible_truncation)]
+    (0.0..=f64::from(u32::MAX)).contains(&seconds)
+        .then(|| Duration::try_seconds(seconds.round() as i64))
+        .flatten()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Returns the number of meters in a distance unit.
+fn distance_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "km" | "км" | "公里" | "キロ" | "킬로미터" => Some(1_000.0),
+        "m" | "м" | "米" | "公尺" | "미터" => Some(1.0),
+        "mi" | "mile" | "miles" | "ми" | "миль" | "英里" | "マイル" | "마일" => Some(1_609.344),
+        "ft" | "feet" | "foot" | "фут" | "футов" | "英尺" | "フィート" | "피트" => Some(0.3048),
+        _ => None,
+    } // match
+} // fn
+
+/// Returns the number of seconds in a duration unit.
+fn duration_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "day" | "days" | "d" | "tag" | "tage" | "tagen" | "jour" | "jours" | "j" | "día"
+        | "días" | "dia" | "dias" | "giorno" | "giorni" | "dag" | "dagen" | "dagar" | "день"
+        | "дня" | "дней" | "д" | "日" | "天" | "일" => Some(86_400.0),
+        "hour" | "hours" | "hr" | "hrs" | "h" | "std" | "stunde" | "stunden" | "heure"
+        | "heures" | "hora" | "horas" | "ora" | "ore" | "uur" | "u" | "timme" | "timmar"
+        | "час" | "часа" | "часов" | "ч" | "時間" | "小时" | "小時" | "시간" => Some(3_600.0),
+        "min" | "mins" | "minute" | "minutes" | "minuto" | "minutos" | "minuti" | "minuut"
+        | "minuten" | "minut" | "minuter" | "мин" | "分" | "分钟" | "分鐘" | "분" => Some(60.0),
+        "s" | "sec" | "secs" | "second" | "seconds" | "seg" | "segundos" | "secondi" | "сек"
+        | "秒" | "초" => Some(1.0),
+        _ => None,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Splits a text into `(number, unit)` pairs. Units are lower-cased and
+/// stripped of abbreviation dots. Words between a unit and the next number,
+/// such as `and`, are ignored. Returns `None` if the text has no numbers or a
+/// number without a unit.
+fn quantities(text: &str) -> Option<Vec<(f64, String)>> {
+    let mut quantities = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            chars.next();
+            continue;
+        } // if
+
+        // Read the number. Spaces are kept only when they group digits, as in
+        // `1 234 km`:
+        let mut number = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() || c == '.' || c == ',' {
+                number.push(c);
+                chars.next();
+            } else if c.is_whitespace() {
+                if chars.clone().nth(1).is_some_and(|next| next.is_ascii_digit()) {
+                    chars.next();
+                } else {
+                    break;
+                } // if
+            } else {
+                break;
+            } // if
+        } // while
+
+        // Read the unit, which runs up to the next number:
+        let mut unit = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() { break; }
+            unit.push(c);
+            chars.next();
+        } // while
+        let unit = unit
+            .split_whitespace()
+            .next()?
+            .trim_end_matches(['.', ','])
+            .to_lowercase();
+
+        quantities.push((parse_number(&number)?, unit));
+    } // while
+
+    (!quantities.is_empty()).then_some(quantities)
+} // fn
+
+/// Parses a number that may use `.` or `,` as its decimal separator, and `.`,
+/// `,` or spaces to group thousands.
+fn parse_number(number: &str) -> Option<f64> {
+    let number = number.trim_end_matches(['.', ',']);
+    let last_dot = number.rfind('.');
+    let last_comma = number.rfind(',');
+
+    let decimal = match (last_dot, last_comma) {
+        (Some(dot), Some(comma)) => Some(dot.max(comma)),
+        (Some(separator), None) | (None, Some(separator)) => {
+            let character = &number[separator..=separator];
+            // A single separator followed by exactly three digits groups
+            // thousands, as in `1,200 km` or `1.200 km`:
+            if number.matches(character).count() == 1 && number.len() - separator - 1 != 3 {
+                Some(separator)
+            } else {
+                None
+            } // if
+        }, // Some
+        (None, None) => None,
+    }; // match
+
+    let normalized: String = number
+        .char_indices()
+        .filter_map(|(index, c)| match c {
+            '0'..='9' => Some(c),
+            '.' | ',' if Some(index) == decimal => Some('.'),
+            _ => None,
+        }) // filter_map
+        .collect();
+
+    normalized.parse().ok()
+} // fn
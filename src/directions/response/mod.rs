@@ -2,17 +2,25 @@
 //! the Google Maps Platform. Look in here for more information about the data
 //! returned from Google's server and how to parse it with your program.
 
+pub mod departure_sweep;
 pub mod directions_distance;
 pub mod directions_duration;
 pub mod driving_maneuver;
+pub mod eta;
 pub mod geocoded_waypoint;
 pub mod geocoder_status;
 pub mod leg;
+pub mod localized_text;
 pub mod overview_polyline;
+pub mod parse_mode;
 pub mod polyline;
 pub mod route;
+pub mod route_comparison;
 pub mod status;
 pub mod step;
+pub mod stored_route;
+#[cfg(feature = "render")]
+pub mod svg;
 pub mod transit_agency;
 pub mod transit_currency;
 pub mod transit_details;
@@ -21,6 +29,7 @@ pub mod transit_line;
 pub mod transit_stop;
 pub mod transit_time;
 pub mod transit_vehicle;
+pub mod warning;
 
 use crate::directions::{
     response::{geocoded_waypoint::GeocodedWaypoint, route::Route, status::Status}, // response
@@ -30,7 +39,7 @@ use serde::{Deserialize, Serialize};
 
 /// Directions responses contain the following root elements.
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Response {
     /// Contains an array of available travel modes. This field is returned when
     /// a request specifies a travel `mode` and gets no results. The array
@@ -81,3 +90,72 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// When the requested travel mode returned no results, returns the most
+    /// practical of the `available_travel_modes`: driving, then transit, then
+    /// bicycling, then walking. Returns `None` if Google didn't suggest any
+    /// travel modes.
+
+    #[must_use]
+    pub fn suggest_alternate_mode(&self) -> Option<TravelMode> {
+        TravelMode::most_practical(&self.available_travel_modes)
+    } // fn
+
+    /// Returns the route with the lowest transit fare. Routes without a fare
+    /// are skipped.
+    ///
+    /// Fares in different currencies can't be compared, so `None` is returned
+    /// if the routes are priced in more than one currency, as well as when no
+    /// route has a fare.
+    /// ```rust
+    /// if let Some(route) = directions.cheapest_route_by_fare() {
+    ///     println!("{}", route.summary);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn cheapest_route_by_fare(&self) -> Option<&Route> {
+        let mut priced = self
+            .routes
+            .iter()
+            .filter_map(|route| route.fare.as_ref().map(|fare| (route, fare)));
+        let (first, first_fare) = priced.next()?;
+        priced
+            .try_fold((first, first_fare), |(cheapest, cheapest_fare), (route, fare)| {
+                if fare.currency != cheapest_fare.currency {
+                    None
+                } else if fare.value < cheapest_fare.value {
+                    Some((route, fare))
+                } else {
+                    Some((cheapest, cheapest_fare))
+                } // if
+            }) // try_fold
+            .map(|(cheapest, _fare)| cheapest)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`. A
+    /// `ZERO_RESULTS` response that lists other available travel modes
+    /// returns `TravelModeUnavailable`, as `execute()` does.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else if self.status == Status::ZeroResults && !self.available_travel_modes.is_empty() {
+            Err(crate::directions::error::Error::TravelModeUnavailable(
+                self.available_travel_modes.clone(),
+                self.error_message.clone(),
+            ))?
+        } else {
+            Err(crate::directions::error::Error::GoogleMapsService(
+                self.status.clone(),
+                self.error_message.clone(),
+            ))?
+        } // if
+    } // fn
+} // impl
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// See also: the Google Encoded Polyline encoding & decoding crate called
 /// [polyline](https://crates.io/crates/polyline).
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct OverviewPolyline {
     pub points: String,
 } // struct
@@ -0,0 +1,145 @@
+//! Contains the `ParseMode` enum. It selects how much of a Directions API
+//! response is deserialized.
+
+use crate::directions::{
+    response::{
+        directions_distance::DirectionsDistance, directions_duration::DirectionsDuration,
+        geocoded_waypoint::GeocodedWaypoint, leg::Leg, overview_polyline::OverviewPolyline,
+        route::Route, status::Status, transit_fare::TransitFare, transit_time::TransitTime,
+        Response,
+    }, // response
+    travel_mode::TravelMode,
+}; // crate::directions
+use crate::types::{Bounds, LatLng};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// How much of a Directions API response is deserialized.
+///
+/// The steps and polylines of a route make up most of a response. A request
+/// with alternatives for a long trip may contain hundreds of steps. Views that
+/// only list routes, with their summary, distance and duration, can skip them
+/// and parse the response many times faster.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum ParseMode {
+    /// The whole response is deserialized. This is the default.
+    #[default]
+    Full,
+    /// The steps of each leg and the overview polyline of each route are
+    /// skipped. `Leg::steps` is empty and `Route::overview_polyline` has no
+    /// points. Everything else, including leg distances and durations, is
+    /// deserialized.
+    SummaryOnly,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl ParseMode {
+    /// Parses a Google Maps Directions API JSON response into a `Response`
+    /// struct, using this parse mode.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns an error if the JSON could not be parsed.
+
+    pub fn parse(self, bytes: &mut [u8]) -> Result<Response, simd_json::Error> {
+        match self {
+            Self::Full => simd_json::serde::from_slice(bytes),
+            Self::SummaryOnly =>
+                simd_json::serde::from_slice::<SummaryResponse>(bytes).map(Response::from),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// The structs below mirror `Response`, `Route` and `Leg` without the `steps`
+// and `overview_polyline` fields. serde skips fields that a struct does not
+// declare without building them, which is where the time is saved.
+
+#[derive(Deserialize)]
+struct SummaryResponse {
+    #[serde(default)]
+    available_travel_modes: Vec<TravelMode>,
+    error_message: Option<String>,
+    #[serde(default)]
+    geocoded_waypoints: Vec<GeocodedWaypoint>,
+    #[serde(default)]
+    routes: Vec<SummaryRoute>,
+    status: Status,
+} // struct
+
+#[derive(Deserialize)]
+struct SummaryRoute {
+    bounds: Bounds,
+    copyrights: String,
+    fare: Option<TransitFare>,
+    #[serde(default)]
+    legs: Vec<SummaryLeg>,
+    summary: String,
+    #[serde(default)]
+    warnings: Vec<String>,
+    #[serde(default)]
+    waypoint_order: Vec<u8>,
+} // struct
+
+#[derive(Deserialize)]
+struct SummaryLeg {
+    arrival_time: Option<TransitTime>,
+    departure_time: Option<TransitTime>,
+    distance: DirectionsDistance,
+    duration: DirectionsDuration,
+    duration_in_traffic: Option<DirectionsDuration>,
+    end_address: String,
+    end_location: LatLng,
+    start_address: String,
+    start_location: LatLng,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl From<SummaryResponse> for Response {
+    fn from(response: SummaryResponse) -> Self {
+        Self {
+            available_travel_modes: response.available_travel_modes,
+            error_message: response.error_message,
+            geocoded_waypoints: response.geocoded_waypoints,
+            routes: response.routes.into_iter().map(Route::from).collect(),
+            status: response.status,
+        } // Response
+    } // fn
+} // impl
+
+impl From<SummaryRoute> for Route {
+    fn from(route: SummaryRoute) -> Self {
+        Self {
+            bounds: route.bounds,
+            copyrights: route.copyrights,
+            fare: route.fare,
+            legs: route.legs.into_iter().map(Leg::from).collect(),
+            overview_polyline: OverviewPolyline::default(),
+            summary: route.summary,
+            warnings: route.warnings,
+            waypoint_order: route.waypoint_order,
+        } // Route
+    } // fn
+} // impl
+
+impl From<SummaryLeg> for Leg {
+    fn from(leg: SummaryLeg) -> Self {
+        Self {
+            arrival_time: leg.arrival_time,
+            departure_time: leg.departure_time,
+            distance: leg.distance,
+            duration: leg.duration,
+            duration_in_traffic: leg.duration_in_traffic,
+            end_address: leg.end_address,
+            end_location: leg.end_location,
+            start_address: leg.start_address,
+            start_location: leg.start_location,
+            steps: Vec::new(),
+        } // Leg
+    } // fn
+} // impl
@@ -3,9 +3,12 @@
 use crate::directions::response::{
     leg::Leg,
     overview_polyline::OverviewPolyline,
+    step::Step,
     transit_fare::TransitFare
 };
+use crate::directions::travel_mode::TravelMode;
 use crate::types::Bounds;
+use chrono::Duration;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -176,6 +179,232 @@ impl Route {
 
 // -----------------------------------------------------------------------------
 
+impl Route {
+    /// Returns the total travel time of this route, summed over all legs.
+    /// ```rust
+    /// let eta = departure + route.total_duration();
+    /// ```
+
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.legs
+            .iter()
+            .fold(Duration::zero(), |total, leg| saturating_add(total, leg.duration.value))
+    } // fn
+
+    /// Returns the total travel time of this route in current traffic, summed
+    /// over all legs. Legs without traffic information contribute their
+    /// regular duration. Returns `None` if no leg has traffic information.
+    /// ```rust
+    /// let delay = route.total_duration_in_traffic().map(|d| d - route.total_duration());
+    /// ```
+
+    #[must_use]
+    pub fn total_duration_in_traffic(&self) -> Option<Duration> {
+        if self.legs.iter().all(|leg| leg.duration_in_traffic.is_none()) {
+            return None;
+        } // if
+        Some(self.legs.iter().fold(Duration::zero(), |total, leg| {
+            saturating_add(total, leg.duration_in_traffic.as_ref().unwrap_or(&leg.duration).value)
+        }))
+    } // fn
+
+    /// Returns the total length of this route in meters, summed over all
+    /// legs.
+    /// ```rust
+    /// let kilometers = route.total_distance_meters() as f64 / 1_000.0;
+    /// ```
+
+    #[must_use]
+    pub fn total_distance_meters(&self) -> u64 {
+        self.legs.iter().map(|leg| u64::from(leg.distance.value)).sum()
+    } // fn
+} // impl
+
+/// Adds two durations, saturating at `Duration::MAX` instead of panicking on
+/// overflow.
+fn saturating_add(total: Duration, duration: Duration) -> Duration {
+    total.checked_add(&duration).unwrap_or(Duration::MAX)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Returns an iterator over the steps of this route, across all legs, that
+    /// are travelled on a transit vehicle.
+    /// ```rust
+    /// let rides = route.transit_steps().count();
+    /// ```
+
+    pub fn transit_steps(&self) -> impl Iterator<Item = &Step> {
+        self.legs
+            .iter()
+            .flat_map(|leg| leg.steps.iter())
+            .filter(|step| step.transit_details.is_some())
+    } // fn
+
+    /// Returns the number of transfers between transit vehicles on this route.
+    /// A route with a single bus ride has zero transfers.
+    /// ```rust
+    /// let transfers = route.transfers();
+    /// ```
+
+    #[must_use]
+    pub fn transfers(&self) -> usize {
+        self.transit_steps().count().saturating_sub(1)
+    } // fn
+
+    /// Returns the total walking distance of this route, in meters, summed
+    /// over all walking steps of all legs.
+    /// ```rust
+    /// let walking_meters = route.walking_distance();
+    /// ```
+
+    #[must_use]
+    pub fn walking_distance(&self) -> u32 {
+        self.legs
+            .iter()
+            .flat_map(|leg| leg.steps.iter())
+            .filter(|step| step.travel_mode == TravelMode::Walking)
+            .map(|step| step.distance.value)
+            .fold(0, u32::saturating_add)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Returns the smallest bounding box containing the start and end of every
+    /// leg and step of this route.
+    ///
+    /// Unlike the `bounds` field, which is provided by Google, this is
+    /// recomputed from the legs and so reflects any changes made to them, for
+    /// example by `clip_to`. Falls back to the `bounds` field if the route has
+    /// no legs.
+    /// ```rust
+    /// let bounds = route.bounds();
+    /// ```
+
+    #[must_use]
+    pub fn bounds(&self) -> Bounds {
+        Bounds::from_points(self.legs.iter().flat_map(|leg| {
+            std::iter::once(&leg.start_location)
+                .chain(std::iter::once(&leg.end_location))
+                .chain(leg.steps.iter().flat_map(|step| [&step.start_location, &step.end_location]))
+        }))
+        .unwrap_or(self.bounds)
+    } // fn
+
+    /// Returns a copy of this route trimmed to the steps that overlap the
+    /// `viewport`, for displaying part of a long route on a map.
+    ///
+    /// A step is kept if the box spanned by its start and end locations
+    /// overlaps the viewport. Legs left without steps are dropped, and the
+    /// `bounds` field of the returned route is recomputed to fit what remains.
+    /// Fares, warnings and the overview polyline are carried over unchanged.
+    /// ```rust
+    /// let visible = route.clip_to(&map_viewport);
+    /// ```
+
+    #[must_use]
+    pub fn clip_to(&self, viewport: &Bounds) -> Self {
+        let mut route = self.clone();
+        route.legs = route
+            .legs
+            .into_iter()
+            .filter_map(|mut leg| {
+                leg.steps.retain(|step| {
+                    Bounds::from_points([&step.start_location, &step.end_location])
+                        .is_some_and(|extent| extent.intersects(viewport))
+                });
+                if leg.steps.is_empty() { None } else { Some(leg) }
+            })
+            .collect();
+        route.bounds = route.bounds();
+        route
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "polyline", feature = "geo"))]
+impl Route {
+    /// Decodes the overview polyline and simplifies it for rendering using the
+    /// Ramer–Douglas–Peucker algorithm. Points that deviate from the simplified
+    /// line by less than `tolerance_m` meters are removed.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the overview polyline is invalid.
+    /// ```rust
+    /// let line = route.simplify_overview(25.0)?;
+    /// ```
+
+    pub fn simplify_overview(
+        &self,
+        tolerance_m: f64
+    ) -> Result<geo_types::geometry::LineString<f64>, crate::error::Error> {
+        let line = self.overview_polyline.decode(5)?;
+        let points = line.0;
+        if points.len() < 3 {
+            return Ok(geo_types::geometry::LineString(points));
+        } // if
+
+        let mut keep = vec![false; points.len()];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+        let mut stack = vec![(0, points.len() - 1)];
+
+        while let Some((first, last)) = stack.pop() {
+            let farthest = (first + 1..last)
+                .map(|index| (index, distance_to_segment_m(points[index], points[first], points[last])))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((index, distance)) = farthest {
+                if distance > tolerance_m {
+                    keep[index] = true;
+                    stack.push((first, index));
+                    stack.push((index, last));
+                } // if
+            } // if
+        } // while
+
+        Ok(geo_types::geometry::LineString(
+            points
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(point, keep)| keep.then_some(point))
+                .collect(),
+        )) // Ok
+    } // fn
+} // impl
+
+/// Returns the distance in meters from `point` to the segment `start`-`end`,
+/// using an equirectangular projection centred on `start`. Coordinates are
+/// `x` = longitude and `y` = latitude, as decoded from a polyline.
+#[cfg(all(feature = "polyline", feature = "geo"))]
+fn distance_to_segment_m(
+    point: geo_types::Coord<f64>,
+    start: geo_types::Coord<f64>,
+    end: geo_types::Coord<f64>,
+) -> f64 {
+    const METERS_PER_DEGREE: f64 = 111_320.0;
+    let scale_x = METERS_PER_DEGREE * start.y.to_radians().cos();
+    let project = |coord: geo_types::Coord<f64>| {
+        ((coord.x - start.x) * scale_x, (coord.y - start.y) * METERS_PER_DEGREE)
+    };
+    let (px, py) = project(point);
+    let (ex, ey) = project(end);
+    let length_squared = ex.mul_add(ex, ey * ey);
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (px.mul_add(ex, py * ey) / length_squared).clamp(0.0, 1.0)
+    };
+    (px - t * ex).hypot(py - t * ey)
+} // fn
+
+// -----------------------------------------------------------------------------
+
 #[cfg(all(feature = "polyline", feature = "geo"))]
 impl Route {
     /// Attempts to convert a borrowed `&Route` struct to a
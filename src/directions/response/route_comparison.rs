@@ -0,0 +1,249 @@
+//! Contains the `RouteComparison` struct and the helpers that pick a route
+//! from the alternatives returned by a request made with
+//! `with_alternatives(true)`.
+
+use crate::directions::response::{driving_maneuver::DrivingManeuver, route::Route, Response};
+use chrono::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The phrases that Google uses, in English, to report tolls in a route's
+/// warnings, such as `This route has tolls.`, and in step instructions, such
+/// as `Toll road` or `Partial toll road`.
+const TOLL_PHRASES: &[&str] = &["has tolls", "toll road"];
+
+// -----------------------------------------------------------------------------
+//
+/// How a route differs from another route, usually the fastest of the
+/// alternatives. Use it to label alternatives the way Google Maps does, for
+/// example `5 min slower, has tolls`.
+///
+/// Its `Display` implementation writes such a label, in English.
+///
+/// ## Example
+///
+/// ```rust
+/// for comparison in directions.compare() {
+///     println!("{}: {comparison}", directions.routes[comparison.route_index].summary);
+/// }
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RouteComparison {
+    /// The position of the route in `Response::routes`.
+    pub route_index: usize,
+
+    /// How much longer the route takes than the other route. Negative if it is
+    /// faster. A route's duration in traffic is used when it has one.
+    pub duration_delta: Duration,
+
+    /// How much longer the route is than the other route, in meters. Negative
+    /// if it is shorter.
+    pub distance_delta_meters: i64,
+
+    /// How many more turns the route has than the other route. Negative if it
+    /// has fewer. See `Route::turns`.
+    pub turns_delta: i64,
+
+    /// Whether the route has tolls. See `Route::has_tolls`.
+    pub has_tolls: bool,
+
+    /// Whether the other route has tolls.
+    pub other_has_tolls: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RouteComparison {
+    /// Returns whether the route is as fast as, or faster than, the other
+    /// route.
+
+    #[must_use]
+    pub fn is_fastest(&self) -> bool {
+        self.duration_delta <= Duration::zero()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RouteComparison {
+    /// Formats the comparison as a short English label, such as `Fastest
+    /// route`, `5 min slower` or `12 min slower, 3.4 km shorter, no tolls`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let minutes = self.duration_delta.num_minutes();
+        let mut parts = vec![match minutes {
+            0 if self.is_fastest() => "Fastest route".to_string(),
+            0 => "Similar time".to_string(),
+            _ if minutes > 0 => format!("{minutes} min slower"),
+            _ => format!("{} min faster", minutes.unsigned_abs()),
+        }]; // vec!
+
+        // Distances under 100 m don't change the label:
+        if self.distance_delta_meters.unsigned_abs() >= 100 {
+            // Rounded to 100 m, so the conversion is exact enough:
+            #[allow(clippy::cast_precision_loss)]
+            let kilometers = self.distance_delta_meters.unsigned_abs() as f64 / 1_000.0;
+            let direction = if self.distance_delta_meters > 0 { "longer" } else { "shorter" };
+            parts.push(format!("{kilometers:.1} km {direction}"));
+        } // if
+
+        match (self.has_tolls, self.other_has_tolls) {
+            (true, false) => parts.push("has tolls".to_string()),
+            (false, true) => parts.push("no tolls".to_string()),
+            _ => (),
+        } // match
+
+        write!(f, "{}", parts.join(", "))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Returns the number of turns on this route: left and right turns of any
+    /// sharpness, U-turns and roundabouts. See `DrivingManeuver::is_turn`.
+    /// ```rust
+    /// let turns = route.turns();
+    /// ```
+
+    #[must_use]
+    pub fn turns(&self) -> usize {
+        self.legs
+            .iter()
+            .flat_map(|leg| leg.steps.iter())
+            .filter(|step| step.maneuver.as_ref().is_some_and(DrivingManeuver::is_turn))
+            .count()
+    } // fn
+
+    /// Returns whether this route has toll roads.
+    ///
+    /// The legacy Directions API has no toll field. Google reports tolls in
+    /// the route's warnings, such as `This route has tolls.`, and in the step
+    /// instructions, such as `Toll road`. Only those phrases are matched, so
+    /// a street such as `Tollgate Rd` is not mistaken for a toll road. Both
+    /// are localized, so tolls are only detected in English responses.
+    /// ```rust
+    /// let has_tolls = route.has_tolls();
+    /// ```
+
+    #[must_use]
+    pub fn has_tolls(&self) -> bool {
+        let mentions_tolls = |text: &str| {
+            let text = text.to_lowercase();
+            TOLL_PHRASES.iter().any(|phrase| contains_phrase(&text, phrase))
+        }; // closure
+        self.warnings.iter().map(String::as_str).any(mentions_tolls)
+            || self.legs
+                .iter()
+                .flat_map(|leg| leg.steps.iter())
+                .filter_map(|step| step.html_instructions.as_deref())
+                .any(mentions_tolls)
+    } // fn
+
+    /// Returns the travel time used to compare routes: the duration in
+    /// traffic if the route has one, else the regular duration.
+    fn comparable_duration(&self) -> Duration {
+        self.total_duration_in_traffic().unwrap_or_else(|| self.total_duration())
+    } // fn
+
+    /// Compares this route with another route. See `RouteComparison`.
+    ///
+    /// The comparison's `route_index` is `0`. `Response::compare` sets it.
+
+    #[must_use]
+    pub fn compare(&self, other: &Self) -> RouteComparison {
+        RouteComparison {
+            route_index: 0,
+            duration_delta: self.comparable_duration() - other.comparable_duration(),
+            distance_delta_meters: signed_delta(
+                self.total_distance_meters(),
+                other.total_distance_meters(),
+            ), // signed_delta
+            turns_delta: signed_delta(self.turns() as u64, other.turns() as u64),
+            has_tolls: self.has_tolls(),
+            other_has_tolls: other.has_tolls(),
+        } // RouteComparison
+    } // fn
+} // impl
+
+/// Returns `value - other` as a signed number, saturating at the bounds of an
+/// `i64`.
+fn signed_delta(value: u64, other: u64) -> i64 {
+    if value >= other {
+        i64::try_from(value - other).unwrap_or(i64::MAX)
+    } else {
+        i64::try_from(other - value).map_or(i64::MIN, |delta| -delta)
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the route with the shortest travel time. Durations in traffic
+    /// are used when available. Returns `None` if there are no routes.
+    /// ```rust
+    /// let fastest = directions.fastest_route();
+    /// ```
+
+    #[must_use]
+    pub fn fastest_route(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.comparable_duration())
+    } // fn
+
+    /// Returns the route with the shortest distance. Returns `None` if there
+    /// are no routes.
+    /// ```rust
+    /// let shortest = directions.shortest_route();
+    /// ```
+
+    #[must_use]
+    pub fn shortest_route(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.total_distance_meters())
+    } // fn
+
+    /// Returns the route with the fewest turns. See `Route::turns`. Returns
+    /// `None` if there are no routes.
+    /// ```rust
+    /// let simplest = directions.route_with_least_turns();
+    /// ```
+
+    #[must_use]
+    pub fn route_with_least_turns(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.turns())
+    } // fn
+
+    /// Compares every route with the fastest route, in the order of
+    /// `Response::routes`. Returns an empty list if there are no routes.
+    /// ```rust
+    /// let labels: Vec<String> = directions.compare().iter().map(ToString::to_string).collect();
+    /// ```
+
+    #[must_use]
+    pub fn compare(&self) -> Vec<RouteComparison> {
+        let Some(fastest) = self.fastest_route() else {
+            return Vec::new();
+        }; // let
+
+        self.routes
+            .iter()
+            .enumerate()
+            .map(|(route_index, route)| RouteComparison {
+                route_index,
+                ..route.compare(fastest)
+            }) // map
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns whether `text` contains `phrase` as whole words, so that `toll
+/// road` is found in `Partial toll road` but not in `Atoll roads`.
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric();
+    text.match_indices(phrase).any(|(start, _)| {
+        let end = start + phrase.len();
+        !text[..start].chars().next_back().is_some_and(is_word_char)
+            && !text[end..].chars().next().is_some_and(is_word_char)
+    }) // any
+} // fn
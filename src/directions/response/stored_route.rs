@@ -0,0 +1,267 @@
+//! Contains the `StoredRoute` struct. It holds the parts of a route that are
+//! worth persisting — the overview polyline and a little metadata — and
+//! rebuilds the route's geometry from them.
+
+use crate::directions::response::{overview_polyline::OverviewPolyline, route::Route};
+use crate::directions::travel_mode::TravelMode;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A route that was stored as its encoded overview polyline, plus optional
+/// metadata.
+///
+/// A `StoredRoute` can be built from a `Route` before it is persisted, or from
+/// an encoded polyline read back from storage. With the `polyline` and `geo`
+/// features it offers the same geometry helpers as `Route` — bounds, decoding
+/// and length — and can produce waypoints that steer a new _Directions API_
+/// request along the stored path, for example to re-route with current
+/// traffic.
+///
+/// ## Example
+///
+/// ```rust
+/// let stored = StoredRoute::new(encoded_polyline)
+///     .with_summary("I-5 S")
+///     .with_travel_mode(TravelMode::Driving);
+///
+/// let rerouted = google_maps_client
+///     .directions(stored.origin()?, stored.destination()?)
+///     .with_waypoints(stored.via_waypoints(20)?)
+///     .execute()
+///     .await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StoredRoute {
+    /// The [encoded polyline
+    /// representation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    /// of the route.
+    pub overview_polyline: OverviewPolyline,
+
+    /// A short textual description of the route, as provided by Google in
+    /// `Route::summary`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+
+    /// The travel mode the route was calculated for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_mode: Option<TravelMode>,
+
+    /// The total distance of the route, in meters, as calculated by Google.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_meters: Option<u64>,
+
+    /// The total duration of the route, in seconds, as calculated by Google.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_secs: Option<i64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl StoredRoute {
+    /// Initializes a `StoredRoute` from an encoded polyline, without metadata.
+
+    #[must_use]
+    pub fn new(encoded_polyline: impl Into<String>) -> Self {
+        Self {
+            overview_polyline: OverviewPolyline { points: encoded_polyline.into() },
+            ..Self::default()
+        } // StoredRoute
+    } // fn
+
+    /// Sets the summary of the route.
+
+    #[must_use]
+    pub fn with_summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = summary.into();
+        self
+    } // fn
+
+    /// Sets the travel mode the route was calculated for.
+
+    #[must_use]
+    pub fn with_travel_mode(mut self, travel_mode: impl Into<TravelMode>) -> Self {
+        self.travel_mode = Some(travel_mode.into());
+        self
+    } // fn
+
+    /// Sets the total distance of the route, in meters.
+
+    #[must_use]
+    pub const fn with_distance_meters(mut self, distance_meters: u64) -> Self {
+        self.distance_meters = Some(distance_meters);
+        self
+    } // fn
+
+    /// Sets the total duration of the route.
+
+    #[must_use]
+    pub const fn with_duration(mut self, duration: chrono::Duration) -> Self {
+        self.duration_secs = Some(duration.num_seconds());
+        self
+    } // fn
+
+    /// Returns the total duration of the route, if it was stored.
+
+    #[must_use]
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        self.duration_secs.map(chrono::Duration::seconds)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&Route> for StoredRoute {
+    /// Captures the overview polyline, summary, distance and duration of a
+    /// route. The travel mode is taken from the first step, if there is one.
+    fn from(route: &Route) -> Self {
+        Self {
+            overview_polyline: route.overview_polyline.clone(),
+            summary: route.summary.clone(),
+            travel_mode: route
+                .legs
+                .first()
+                .and_then(|leg| leg.steps.first())
+                .map(|step| step.travel_mode.clone()),
+            distance_meters: Some(route.total_distance_meters()),
+            duration_secs: Some(route.total_duration().num_seconds()),
+        } // StoredRoute
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<Route> for StoredRoute {
+    /// Captures the overview polyline, summary, distance and duration of a
+    /// route. The travel mode is taken from the first step, if there is one.
+    fn from(route: Route) -> Self {
+        Self::from(&route)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "polyline", feature = "geo"))]
+impl StoredRoute {
+    /// Decodes the overview polyline into a `geo_types::geometry::LineString`.
+    /// Google encodes polylines with a precision of `5`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+
+    pub fn decode(
+        &self,
+        precision: u32
+    ) -> Result<geo_types::geometry::LineString<f64>, crate::error::Error> {
+        self.overview_polyline.decode(precision)
+    } // fn
+
+    /// Decodes the overview polyline into its latitude & longitude points.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid or if a decoded point is
+    ///   out of range.
+
+    pub fn points(&self) -> Result<Vec<crate::types::LatLng>, crate::error::Error> {
+        self.decode(5)?
+            .0
+            .into_iter()
+            .map(|coord| crate::types::LatLng::try_from_f64(coord.y, coord.x))
+            .collect()
+    } // fn
+
+    /// Returns the smallest bounding box containing every point of the
+    /// polyline, or `None` if the polyline is empty.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+
+    pub fn bounds(&self) -> Result<Option<crate::types::Bounds>, crate::error::Error> {
+        Ok(crate::types::Bounds::from_points(&self.points()?))
+    } // fn
+
+    /// Returns the length of the polyline in meters, measured by great-circle
+    /// distance between consecutive points. The overview polyline is smoothed,
+    /// so this is usually slightly shorter than the distance Google reports.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+
+    pub fn length_meters(&self) -> Result<f64, crate::error::Error> {
+        Ok(self
+            .points()?
+            .windows(2)
+            .map(|pair| pair[0].haversine_distance(&pair[1]))
+            .sum())
+    } // fn
+
+    /// Returns the first point of the route, for use as the origin of a new
+    /// _Directions API_ request.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid, or `EmptyPolyline` if it
+    ///   has no points.
+
+    pub fn origin(&self) -> Result<crate::types::LatLng, crate::error::Error> {
+        self.points()?
+            .first()
+            .copied()
+            .ok_or_else(|| crate::directions::error::Error::EmptyPolyline.into())
+    } // fn
+
+    /// Returns the last point of the route, for use as the destination of a
+    /// new _Directions API_ request.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid, or `EmptyPolyline` if it
+    ///   has no points.
+
+    pub fn destination(&self) -> Result<crate::types::LatLng, crate::error::Error> {
+        self.points()?
+            .last()
+            .copied()
+            .ok_or_else(|| crate::directions::error::Error::EmptyPolyline.into())
+    } // fn
+
+    /// Returns up to `max_waypoints` pass-through waypoints, spread evenly
+    /// along the route between its origin and destination. Passing them to
+    /// `with_waypoints` steers a new request along the stored path without
+    /// splitting it into extra legs.
+    ///
+    /// The _Directions API_ accepts at most 25 waypoints per request.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+
+    pub fn via_waypoints(
+        &self,
+        max_waypoints: usize
+    ) -> Result<Vec<crate::directions::request::waypoint::Waypoint>, crate::error::Error> {
+        use crate::directions::request::waypoint::Waypoint;
+
+        let points = self.points()?;
+        let interior = points.get(1..points.len().saturating_sub(1)).unwrap_or_default();
+
+        let sampled: Vec<crate::types::LatLng> = if interior.len() <= max_waypoints {
+            interior.to_vec()
+        } else {
+            (0..max_waypoints)
+                .map(|index| interior[(index + 1) * interior.len() / (max_waypoints + 1)])
+                .collect()
+        }; // if
+
+        Ok(sampled
+            .into_iter()
+            .map(|latlng| Waypoint::Via(Box::new(Waypoint::LatLng(latlng))))
+            .collect())
+    } // fn
+} // impl
@@ -0,0 +1,258 @@
+//! Contains the `SvgOptions` struct. It renders the geometry of a route as a
+//! small SVG image, for emails and reports. No map tiles are drawn, only the
+//! path of the route, fitted to the image.
+
+use crate::directions::response::route::Route;
+use crate::types::LatLng;
+use rust_decimal::prelude::ToPrimitive;
+use std::fmt::Write;
+
+// -----------------------------------------------------------------------------
+//
+/// Controls how `Route::to_svg` draws a route.
+///
+/// Points are projected with the Web Mercator projection, as on Google Maps,
+/// and scaled uniformly so that the route fills the image inside the padding.
+///
+/// ## Example
+///
+/// ```rust
+/// let options = SvgOptions::new()
+///     .with_size(240, 120)
+///     .with_stroke("#1a73e8", 3.0);
+/// let svg = route.to_svg(&options)?;
+/// ```
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    /// The width of the image, in pixels.
+    pub width: u32,
+
+    /// The height of the image, in pixels.
+    pub height: u32,
+
+    /// The space, in pixels, between the route and the edges of the image.
+    pub padding: u32,
+
+    /// The colour of the route, as a CSS colour.
+    pub stroke: String,
+
+    /// The width of the route's line, in pixels.
+    pub stroke_width: f64,
+
+    /// The colour of the background, as a CSS colour. The background is
+    /// transparent when this is `None`.
+    pub background: Option<String>,
+
+    /// Whether to draw dots at the start and end of the route.
+    pub markers: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for SvgOptions {
+    /// Returns a 200 × 100 pixel image with a blue, 2 pixel wide route, a
+    /// transparent background and start and end markers.
+    fn default() -> Self {
+        Self {
+            width: 200,
+            height: 100,
+            padding: 8,
+            stroke: "#1a73e8".to_string(),
+            stroke_width: 2.0,
+            background: None,
+            markers: true,
+        } // SvgOptions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl SvgOptions {
+    /// Returns the default options. See `SvgOptions::default`.
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Sets the size of the image, in pixels.
+
+    #[must_use]
+    pub const fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    } // fn
+
+    /// Sets the space, in pixels, between the route and the edges of the
+    /// image.
+
+    #[must_use]
+    pub const fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    } // fn
+
+    /// Sets the colour, as a CSS colour, and the width, in pixels, of the
+    /// route's line.
+
+    #[must_use]
+    pub fn with_stroke(mut self, stroke: impl Into<String>, stroke_width: f64) -> Self {
+        self.stroke = stroke.into();
+        self.stroke_width = stroke_width;
+        self
+    } // fn
+
+    /// Sets the colour of the background, as a CSS colour.
+
+    #[must_use]
+    pub fn with_background(mut self, background: impl Into<String>) -> Self {
+        self.background = Some(background.into());
+        self
+    } // fn
+
+    /// Sets whether to draw dots at the start and end of the route.
+
+    #[must_use]
+    pub const fn with_markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+        self
+    } // fn
+
+    /// Renders a path, given as latitude & longitude points, as an SVG
+    /// document. An empty path renders an empty image.
+
+    #[must_use]
+    pub fn render(&self, points: &[LatLng]) -> String {
+        self.render_degrees(points.iter().map(|point| (
+            point.lat.to_f64().unwrap_or_default(),
+            point.lng.to_f64().unwrap_or_default(),
+        ))) // render_degrees
+    } // fn
+
+    /// Renders a path, given as latitude & longitude pairs in degrees, as an
+    /// SVG document.
+    fn render_degrees(&self, points: impl Iterator<Item = (f64, f64)>) -> String {
+        let projected: Vec<(f64, f64)> = points
+            .map(|(lat, lng)| mercator(lat, lng))
+            .collect();
+
+        let width = f64::from(self.width);
+        let height = f64::from(self.height);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+            viewBox=\"0 0 {width} {height}\">"
+        ); // format!
+
+        if let Some(background) = &self.background {
+            let _ = write!(
+                svg,
+                "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+                escape(background)
+            ); // write!
+        } // if
+
+        let pixels = self.fit(&projected);
+        if let (Some(first), Some(last)) = (pixels.first(), pixels.last()) {
+            let mut path = String::new();
+            for (index, (x, y)) in pixels.iter().enumerate() {
+                let command = if index == 0 { 'M' } else { 'L' };
+                let _ = write!(path, "{command}{x:.1} {y:.1}");
+            } // for
+
+            let stroke = escape(&self.stroke);
+            let _ = write!(
+                svg,
+                "<path d=\"{path}\" fill=\"none\" stroke=\"{stroke}\" stroke-width=\"{}\" \
+                stroke-linecap=\"round\" stroke-linejoin=\"round\"/>",
+                self.stroke_width
+            ); // write!
+
+            if self.markers {
+                let radius = self.stroke_width * 1.5;
+                for (x, y) in [first, last] {
+                    let _ = write!(
+                        svg,
+                        "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"{radius}\" fill=\"{stroke}\"/>"
+                    ); // write!
+                } // for
+            } // if
+        } // if
+
+        svg.push_str("</svg>");
+        svg
+    } // fn
+
+    /// Scales projected points uniformly so that they fill the image inside
+    /// the padding, and centers them.
+    fn fit(&self, projected: &[(f64, f64)]) -> Vec<(f64, f64)> {
+        let (min_x, max_x, min_y, max_y) = projected.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY, f64::NEG_INFINITY),
+            |(min_x, max_x, min_y, max_y), &(x, y)| {
+                (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+            }, // fold
+        ); // fold
+
+        let padding = f64::from(self.padding);
+        let inner_width = padding.mul_add(-2.0, f64::from(self.width)).max(0.0);
+        let inner_height = padding.mul_add(-2.0, f64::from(self.height)).max(0.0);
+
+        // A single point, or a perfectly straight north-south or east-west
+        // line, has no extent in one direction. It is centered in that
+        // direction instead of being scaled:
+        let extent_x = max_x - min_x;
+        let extent_y = max_y - min_y;
+        let scale = match (extent_x > 0.0, extent_y > 0.0) {
+            (true, true) => (inner_width / extent_x).min(inner_height / extent_y),
+            (true, false) => inner_width / extent_x,
+            (false, true) => inner_height / extent_y,
+            (false, false) => 0.0,
+        }; // match
+
+        let offset_x = padding + extent_x.mul_add(-scale, inner_width) / 2.0;
+        let offset_y = padding + extent_y.mul_add(-scale, inner_height) / 2.0;
+
+        projected
+            .iter()
+            // SVG's y axis points down, while northings point up:
+            .map(|&(x, y)| ((x - min_x).mul_add(scale, offset_x), (max_y - y).mul_add(scale, offset_y)))
+            .collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Renders the route's overview polyline as a small SVG image. See
+    /// `SvgOptions` for how the image is drawn.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+
+    pub fn to_svg(&self, options: &SvgOptions) -> Result<String, crate::error::Error> {
+        let line_string = self.overview_polyline.decode(5)?;
+        Ok(options.render_degrees(line_string.coords().map(|coord| (coord.y, coord.x))))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Projects a latitude & longitude with the Web Mercator projection. Both axes
+/// are in radians, with northings pointing up.
+fn mercator(lat: f64, lng: f64) -> (f64, f64) {
+    // Web Mercator is undefined at the poles, so latitudes are clamped to the
+    // limits that Google Maps uses:
+    let lat = lat.clamp(-85.051_128, 85.051_128).to_radians();
+    (lng.to_radians(), lat.tan().asinh())
+} // fn
+
+/// Escapes a string for use in an XML attribute.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+} // fn
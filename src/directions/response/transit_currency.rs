@@ -852,3 +852,25 @@ impl TransitCurrency {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl TransitCurrency {
+    /// Returns the code that Google uses for this `TransitCurrency`, for example
+    /// `AED`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `TransitCurrency` variant, in declaration
+    /// order. Variants that hold an unrecognized code are not included.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = TRANSIT_CURRENCIES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -0,0 +1,144 @@
+//! Contains the `Warning` enum. It collects the non-fatal problems Google
+//! reports in a Directions API response into a typed form.
+
+use crate::directions::{
+    response::{geocoder_status::GeocoderStatus, Response},
+    travel_mode::TravelMode,
+}; // crate::directions
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+// -----------------------------------------------------------------------------
+//
+/// A non-fatal problem reported in a Directions API response. These are
+/// returned alongside otherwise usable results and are easy to miss, but often
+/// foreshadow requests that will start failing, such as deprecated behaviour
+/// or addresses that only partially match.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Warning {
+    /// A warning attached to a route, to be displayed alongside the
+    /// directions. For example, `Walking directions are in beta. Use caution
+    /// – This route may be missing sidewalks or pedestrian paths.`
+    Route {
+        /// The position of the route in `Response::routes`.
+        route_index: usize,
+        /// The warning text, as provided by Google.
+        message: String,
+    },
+    /// The geocoder did not return an exact match for the origin,
+    /// destination, or a waypoint, although it was able to match part of it.
+    /// The resulting route may not go where you intended.
+    PartialMatch {
+        /// The position of the location in `Response::geocoded_waypoints`:
+        /// the origin, then any waypoints, then the destination.
+        waypoint_index: usize,
+    },
+    /// The origin, destination, or a waypoint could not be geocoded.
+    Geocoder {
+        /// The position of the location in `Response::geocoded_waypoints`.
+        waypoint_index: usize,
+        /// The geocoder status for the location.
+        status: GeocoderStatus,
+    },
+    /// The requested travel mode returned no results. These travel modes are
+    /// available in the countries of the waypoints instead.
+    TravelModeUnavailable {
+        /// The travel modes that are available.
+        available_travel_modes: Vec<TravelMode>,
+    },
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Warning {
+    /// Formats the warning into a message that may be presented to the user
+    /// or logged.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Route { route_index, message } => write!(f,
+                "Google Maps Directions API: route {route_index}: {message}"),
+            Self::PartialMatch { waypoint_index } => write!(f,
+                "Google Maps Directions API: location {waypoint_index} was only partially matched \
+                by the geocoder. Check the address for misspellings or missing components."),
+            Self::Geocoder { waypoint_index, status } => write!(f,
+                "Google Maps Directions API: location {waypoint_index} could not be geocoded: \
+                {status}"),
+            Self::TravelModeUnavailable { available_travel_modes } => write!(f,
+                "Google Maps Directions API: the requested travel mode is not available. \
+                Available travel modes: {}",
+                available_travel_modes
+                    .iter()
+                    .map(std::string::ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the non-fatal problems reported in this response: route
+    /// warnings, partially matched or failed geocodes, and unavailable travel
+    /// modes.
+    ///
+    /// ```rust
+    /// for warning in directions.warnings() {
+    ///     eprintln!("{warning}");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn warnings(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for (route_index, route) in self.routes.iter().enumerate() {
+            warnings.extend(route.warnings.iter().map(|message| Warning::Route {
+                route_index,
+                message: message.clone(),
+            })); // extend
+        } // for
+
+        for (waypoint_index, waypoint) in self.geocoded_waypoints.iter().enumerate() {
+            if waypoint.partial_match == Some(true) {
+                warnings.push(Warning::PartialMatch { waypoint_index });
+            } // if
+            if let Some(status) = &waypoint.geocoder_status {
+                if *status != GeocoderStatus::Ok {
+                    warnings.push(Warning::Geocoder {
+                        waypoint_index,
+                        status: status.clone(),
+                    }); // push
+                } // if
+            } // if
+        } // for
+
+        if !self.available_travel_modes.is_empty() {
+            warnings.push(Warning::TravelModeUnavailable {
+                available_travel_modes: self.available_travel_modes.clone(),
+            }); // push
+        } // if
+
+        warnings
+    } // fn
+
+    /// Emits a `tracing` warning event for each warning in this response.
+    /// Each distinct warning is only emitted once per process, so that a
+    /// recurring warning does not flood the logs.
+    pub fn log_warnings(&self) {
+        static LOGGED: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+        for warning in self.warnings() {
+            let message = warning.to_string();
+            let first_time = LOGGED
+                .get_or_init(|| Mutex::new(HashSet::new()))
+                .lock()
+                .map_or(true, |mut logged| logged.insert(message.clone()));
+            if first_time {
+                tracing::warn!("{message}");
+            } // if
+        } // for
+    } // fn
+} // impl
@@ -220,3 +220,25 @@ impl VehicleType {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl VehicleType {
+    /// Returns the code that Google uses for this `VehicleType`, for example
+    /// `BUS`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `VehicleType` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = VEHICLE_TYPES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -82,13 +82,13 @@ pub enum Error {
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
@@ -106,6 +106,7 @@ pub use crate::directions::{
 }; // crate::directions
 
 pub use crate::distance_matrix::{
-    request::Request as DistanceMatrixRequest, response::status::Status as DistanceMatrixStatus,
+    request::params::Params as DistanceMatrixParams, request::Request as DistanceMatrixRequest,
+    response::status::Status as DistanceMatrixStatus,
     response::Response as DistanceMatrixResponse,
 }; // crate::distance_matrix
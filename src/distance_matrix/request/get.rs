@@ -5,9 +5,8 @@ use crate::distance_matrix::{
 }; // use crate::distance_matrix
 use crate::error::Error as GoogleMapsError;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -44,10 +43,11 @@ impl<'a> DistanceMatrixRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::DistanceMatrix, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            self.client.record_circuit_outcome(&Api::DistanceMatrix, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -108,9 +108,7 @@ impl<'a> DistanceMatrixRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(DistanceMatrixError::ReqwestMessage(
-                                    error.to_string(),
-                                )))
+                                Err(Permanent(DistanceMatrixError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
@@ -4,18 +4,23 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
 mod validate;
 mod with_arrival_time;
+mod with_channel;
 mod with_departure_time;
 mod with_language;
 mod with_region;
 mod with_restrictions;
+mod with_route_modifiers;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -26,12 +31,12 @@ mod with_unit_system;
 
 use crate::client::GoogleMapsClient;
 use crate::directions::request::{
-    avoid::Avoid, departure_time::DepartureTime, traffic_model::TrafficModel,
+    departure_time::DepartureTime, traffic_model::TrafficModel,
     transit_mode::TransitMode, transit_route_preference::TransitRoutePreference,
     unit_system::UnitSystem, waypoint::Waypoint,
 }; // crate::directions::request
 use crate::directions::travel_mode::TravelMode;
-use crate::types::{Language, Region};
+use crate::types::{Language, Region, RouteRestrictions};
 use chrono::NaiveDateTime;
 
 // -----------------------------------------------------------------------------
@@ -77,7 +82,7 @@ pub struct Request<'a> {
 
     /// Features that routes should avoid. See file `avoid.rs` and method
     /// `with_restrictions()` for more information.
-    restrictions: Vec<Avoid>,
+    restrictions: RouteRestrictions,
 
     /// Assumptions to use when calculating time in traffic. See file
     /// `traffic_model.rs` and method `with_traffic_model()` for more
@@ -100,6 +105,11 @@ pub struct Request<'a> {
     /// and method `with_unit_system()` for more information.
     unit_system: Option<UnitSystem>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// The URL-encoded query string that is passed to the Google Maps
@@ -1,6 +1,6 @@
 use crate::{
     client::GoogleMapsClient, directions::request::waypoint::Waypoint,
-    distance_matrix::request::Request,
+    distance_matrix::request::Request, types::RouteRestrictions,
 }; // use
 
 // =============================================================================
@@ -31,12 +31,13 @@ impl Request<'_> {
             departure_time: None,
             language: None,
             region: None,
-            restrictions: Vec::new(),
+            restrictions: RouteRestrictions::NONE,
             traffic_model: None,
             transit_modes: Vec::new(),
             transit_route_preference: None,
             travel_mode: None,
             unit_system: None,
+            channel: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -1,7 +1,7 @@
 use crate::directions::travel_mode::TravelMode;
 use crate::distance_matrix::{error::Error, request::Request};
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         if let Some(travel_mode) = &self.travel_mode {
             // If the transit mode is not set to TravelMode::Transit...
             if *travel_mode != TravelMode::Transit {
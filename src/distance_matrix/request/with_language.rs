@@ -52,4 +52,12 @@ impl<'a> Request<'a> {
         self.language = Some(language.into());
         self
     } // fn
+
+    /// Returns the language that was set with `with_language`, if any. The
+    /// addresses and text of the response are localized in this language.
+
+    #[must_use]
+    pub const fn language(&self) -> Option<Language> {
+        self.language
+    } // fn
 } // impl
@@ -39,4 +39,11 @@ impl<'a> Request<'a> {
         self.region = Some(region.into());
         self
     } // fn
+
+    /// Returns the region that was set with `with_region`, if any.
+
+    #[must_use]
+    pub const fn region(&self) -> Option<Region> {
+        self.region
+    } // fn
 } // impl
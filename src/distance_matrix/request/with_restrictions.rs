@@ -50,8 +50,9 @@ impl<'a> Request<'a> {
     /// .with_restriction(Avoid::Highways)
     /// ```
     ///
-    /// * Multiple restrictions may be stacked together. This example avoids
-    ///   tolls and ferries:
+    /// * Multiple restrictions may be stacked together. Each call adds to the
+    ///   restrictions already set, while `with_restrictions()` replaces them.
+    ///   This example avoids tolls and ferries:
     ///
     /// ```rust
     /// .with_restriction(Avoid::Tolls)
@@ -63,7 +64,7 @@ impl<'a> Request<'a> {
         restriction: impl Into<Avoid>
     ) -> &'a mut Self {
         // Add restriction to Request struct.
-        self.restrictions = vec![restriction.into()];
+        self.restrictions.insert(restriction.into());
         // Return modified Request struct to caller.
         self
     } // fn
@@ -82,6 +83,12 @@ impl<'a> Request<'a> {
     /// ])
     /// ```
     ///
+    /// * A `RouteRestrictions` set may also be passed:
+    ///
+    /// ```rust
+    /// .with_restrictions(RouteRestrictions::TOLLS | RouteRestrictions::FERRIES)
+    /// ```
+    ///
     /// # Generics
     ///
     /// This method uses generics to improve ergonomics. The `C` generic is
@@ -97,7 +104,7 @@ impl<'a> Request<'a> {
         C: IntoIterator<Item = A>,
         A: Into<Avoid> {
         // Add restrictions to Request struct.
-        self.restrictions = restrictions.into_iter().map(Into::into).collect();
+        self.restrictions = restrictions.into_iter().collect();
         // Return modified Request struct to caller.
         self
     } // fn
@@ -0,0 +1,26 @@
+use crate::distance_matrix::request::Request;
+use crate::types::RouteModifiers;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specify the features that routes should avoid with API-agnostic
+    /// `RouteModifiers`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route_modifiers` ‧ The conditions the route should satisfy. They
+    ///   replace any restrictions set with `with_restrictions`. The
+    ///   _Distance Matrix API_ has no vehicle emission type parameter, so
+    ///   `vehicle_emission_type` is ignored.
+
+    pub fn with_route_modifiers(
+        &'a mut self,
+        route_modifiers: &RouteModifiers
+    ) -> &'a mut Self {
+        // Set restrictions in Request struct.
+        self.restrictions = route_modifiers.into();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
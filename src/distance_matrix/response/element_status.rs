@@ -152,3 +152,25 @@ impl ElementStatus {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl ElementStatus {
+    /// Returns the code that Google uses for this `ElementStatus`, for example
+    /// `MAX_ROUTE_LENGTH_EXCEEDED`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `ElementStatus` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = ELEMENT_STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -10,7 +10,10 @@ pub mod element_status;
 pub mod row;
 pub mod status;
 
-use crate::distance_matrix::response::{row::Row, status::Status};
+use crate::distance_matrix::response::{
+    element::Element, element_status::ElementStatus, row::Row, status::Status,
+}; // crate::distance_matrix::response
+use crate::types::InputIndex;
 use serde::{Deserialize, Serialize};
 
 /// Distance Matrix responses contain the following root elements.
@@ -61,3 +64,155 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the origin-destination pairs that did not produce a result, as
+    /// `(origin_index, destination_index, status)` tuples. The indices refer
+    /// to the origins and destinations of the request, in order.
+    ///
+    /// A response can have an `OK` top-level status while individual elements
+    /// failed, for example because an address could not be geocoded
+    /// (`NOT_FOUND`) or no route exists between the pair (`ZERO_RESULTS`).
+    /// Batch pipelines can use this to retry or flag only the failed pairs.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// for (origin, destination, status) in matrix.failed_elements() {
+    ///     tracing::warn!(
+    ///         "no result from {:?} to {:?}: {status}",
+    ///         matrix.origin_address(origin),
+    ///         matrix.destination_address(destination),
+    ///     );
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn failed_elements(&self) -> Vec<(usize, usize, ElementStatus)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(origin_index, row)| {
+                row.elements
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, element)| element.status != ElementStatus::Ok)
+                    .map(move |(destination_index, element)| {
+                        (origin_index, destination_index, element.status.clone())
+                    }) // map
+            }) // flat_map
+            .collect()
+    } // fn
+
+    /// Returns the address, as geocoded by Google, of the origin at the
+    /// specified position in the request. Returns `None` if the position is
+    /// out of range.
+
+    #[must_use]
+    pub fn origin_address(&self, origin_index: impl Into<InputIndex>) -> Option<&str> {
+        origin_index.into().of(&self.origin_addresses).map(String::as_str)
+    } // fn
+
+    /// Returns the address, as geocoded by Google, of the destination at the
+    /// specified position in the request. Returns `None` if the position is
+    /// out of range.
+
+    #[must_use]
+    pub fn destination_address(&self, destination_index: impl Into<InputIndex>) -> Option<&str> {
+        destination_index.into().of(&self.destination_addresses).map(String::as_str)
+    } // fn
+
+    /// Returns the element for the origin and destination at the specified
+    /// positions in the request. Returns `None` if either position is out of
+    /// range.
+
+    #[must_use]
+    pub fn element(
+        &self,
+        origin_index: impl Into<InputIndex>,
+        destination_index: impl Into<InputIndex>,
+    ) -> Option<&Element> {
+        destination_index.into().of(&origin_index.into().of(&self.rows)?.elements)
+    } // fn
+
+    /// Returns every element, as `(origin_index, destination_index, element)`
+    /// tuples, in row order. The indices refer to the origins and destinations
+    /// of the request, and can be passed to `origin_address` and
+    /// `destination_address`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// for (origin, destination, element) in matrix.elements() {
+    ///     if let Some(duration) = &element.duration {
+    ///         println!(
+    ///             "{} → {}: {}",
+    ///             matrix.origin_address(origin).unwrap_or("?"),
+    ///             matrix.destination_address(destination).unwrap_or("?"),
+    ///             duration.text,
+    ///         );
+    ///     }
+    /// }
+    /// ```
+
+    pub fn elements(&self) -> impl Iterator<Item = (InputIndex, InputIndex, &Element)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(origin_index, row)| {
+                row.elements.iter().enumerate().map(move |(destination_index, element)| {
+                    (InputIndex::new(origin_index), InputIndex::new(destination_index), element)
+                }) // map
+            }) // flat_map
+    } // fn
+
+    /// Returns every element with the origin and destination, of the
+    /// collections that were passed to the request, that it was calculated
+    /// for. `origins` and `destinations` must be the collections that were
+    /// passed to the request; elements that have no corresponding origin or
+    /// destination are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// for (origin, destination, element) in matrix.with_inputs(&origins, &destinations) {
+    ///     println!("{origin:?} → {destination:?}: {:?}", element.distance);
+    /// }
+    /// ```
+
+    pub fn with_inputs<'o, 'd, O, D>(
+        &self,
+        origins: &'o [O],
+        destinations: &'d [D],
+    ) -> impl Iterator<Item = (&'o O, &'d D, &Element)> {
+        self.elements().filter_map(|(origin_index, destination_index, element)| {
+            Some((origin_index.of(origins)?, destination_index.of(destinations)?, element))
+        }) // filter_map
+    } // fn
+
+    /// Returns whether the response has one row per origin address, and one
+    /// element per destination address in every row. Google always returns
+    /// an aligned matrix for an `OK` response; this guards against responses
+    /// that were truncated or edited.
+
+    #[must_use]
+    pub fn is_aligned(&self) -> bool {
+        self.rows.len() == self.origin_addresses.len()
+            && self.rows.iter().all(|row| row.elements.len() == self.destination_addresses.len())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::distance_matrix::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
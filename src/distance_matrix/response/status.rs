@@ -80,15 +80,7 @@ impl std::convert::From<&Status> for String {
     /// [status](https://developers.google.com/maps/documentation/distance-matrix/intro#top-level-status-codes)
     /// code.
     fn from(status: &Status) -> Self {
-        match status {
-            Status::InvalidRequest => Self::from("INVALID_REQUEST"),
-            Status::MaxElementsExceeded => Self::from("MAX_ELEMENTS_EXCEEDED"),
-            Status::Ok => Self::from("OK"),
-            Status::OverDailyLimit => Self::from("OVER_DAILY_LIMIT"),
-            Status::OverQueryLimit => Self::from("OVER_QUERY_LIMIT"),
-            Status::RequestDenied => Self::from("REQUEST_DENIED"),
-            Status::UnknownError => Self::from("UNKNOWN_ERROR"),
-        } // match
+        Self::from(status.as_wire_str())
     } // fn
 } // impl
 
@@ -160,3 +152,36 @@ impl std::fmt::Display for Status {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns the code that Google uses for this `Status`, for example
+    /// `INVALID_REQUEST`. `FromStr` parses the code back into the same variant.
+    ///
+    /// Unlike `Display`, which is meant for people, this returns the code as
+    /// Google sends it.
+
+    #[must_use]
+    pub const fn as_wire_str(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest => "INVALID_REQUEST",
+            Self::MaxElementsExceeded => "MAX_ELEMENTS_EXCEEDED",
+            Self::Ok => "OK",
+            Self::OverDailyLimit => "OVER_DAILY_LIMIT",
+            Self::OverQueryLimit => "OVER_QUERY_LIMIT",
+            Self::RequestDenied => "REQUEST_DENIED",
+            Self::UnknownError => "UNKNOWN_ERROR",
+        } // match
+    } // fn
+
+    /// Returns an iterator over every `Status` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -16,6 +16,8 @@ pub enum Error {
     /// A `sampled_path_request()` method cannot be used when `postional_request()`
     /// has been set.
     EitherPositionalOrSampledPath,
+    /// The encoded polyline given as a sampled path was empty.
+    EmptyPolyline,
     /// Google Maps Elevation API server generated an error. See the `Status`
     /// enum for more information.
     GoogleMapsService(Status, Option<String>),
@@ -25,6 +27,9 @@ pub enum Error {
     /// status code. See `google_maps\src\elevation\response\status.rs` for
     /// more information.
     InvalidStatusCode(String),
+    /// The number of samples in a sampled path request must be between 1 and
+    /// 512.
+    InvalidSamples(u16),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Elevation API server.
     QueryNotBuilt,
@@ -32,13 +37,13 @@ pub enum Error {
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -52,6 +57,10 @@ impl std::fmt::Display for Error {
                 "Google Maps Elevation API client: \
                 A for_sampled_path_request() method cannot be used when for_postional_request() has been set. \
                 Try again with only a positional request or only a sampled path request."),
+            Self::EmptyPolyline => write!(f,
+                "Google Maps Elevation API client: \
+                The encoded polyline for the sampled path request is empty. \
+                Try again with a polyline of at least one point."),
             Self::GoogleMapsService(status, error_message) => match error_message {
                 // If the Google Maps Elevation API server generated an error
                 // message, return that:
@@ -89,6 +98,10 @@ impl std::fmt::Display for Error {
                 Valid codes are `INVALID_REQUEST`, `OK`, `OVER_DAILY_LIMIT`, \
                 `OVER_QUERY_LIMIT`, `REQUEST_DENIED`, and `UNKNOWN_ERROR`."
                 ),
+            Self::InvalidSamples(samples) => write!(f,
+                "Google Maps Elevation API client: \
+                `{samples}` is not a valid number of samples. \
+                The number of samples must be between 1 and 512."),
             Self::RequestNotValidated => write!(f,
                 "Google Maps Elevation API client: \
                 The request must be validated before a query string may be built. \
@@ -86,6 +86,9 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::elevation::{
     error::Error as ElevationError,
-    request::{locations::Locations as ElevationLocations, Request as ElevationRequest}, // request
+    request::{
+        locations::Locations as ElevationLocations, params::Params as ElevationParams,
+        Request as ElevationRequest,
+    }, // request
     response::{point::Point, status::Status as ElevationStatus, Response as ElevationResponse}, // response
 }; // crate::elevation
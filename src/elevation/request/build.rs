@@ -1,6 +1,7 @@
 use crate::elevation::{error::Error, request::Request}; // crate::elevation
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     /// Builds the query string for the Google Maps Elevation API based on the
     /// input provided by the client.
     ///
@@ -8,7 +9,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
@@ -45,6 +46,12 @@ impl<'a> Request<'a> {
             query.push_str(&samples.to_string());
         } // if
 
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
         // Set query string in Request struct.
         self.query = Some(query);
 
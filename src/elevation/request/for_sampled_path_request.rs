@@ -54,6 +54,54 @@ impl<'a> Request<'a> {
         // Set the path in Request struct.
         self.path = Some(path);
         // Set the sample number in Request struct.
+        self.samples = Some(samples.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds the _sampled path request_ parameters to the Elevation API query,
+    /// using an encoded polyline as the path.
+    ///
+    /// This is the most compact way to request elevations along a route, and
+    /// allows up to 512 samples. For example, the `overview_polyline` of a
+    /// Directions API route may be passed as-is, without decoding and
+    /// re-encoding it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `encoded` ‧ The path, as an [encoded
+    ///   polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+    ///   An `enc:` prefix, as used in Google Maps URLs, is accepted and
+    ///   removed.
+    ///
+    /// * `samples` ‧ Specifies the number of sample points along the path
+    ///   for which to return elevation data. Must be between 1 and 512. This
+    ///   is checked by `validate`.
+    ///
+    /// ## Example:
+    ///
+    /// * 100 elevation samples along a Directions API route:
+    /// ```rust
+    /// let route = &directions.routes[0];
+    ///
+    /// let elevation = google_maps_client.elevation()
+    ///     .for_sampled_path_polyline(&route.overview_polyline.points, 100)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    pub fn for_sampled_path_polyline(
+        &'a mut self,
+        encoded: impl AsRef<str>,
+        samples: u16
+    ) -> &'a mut Self {
+        let encoded = encoded.as_ref();
+        let encoded = encoded.strip_prefix("enc:").unwrap_or(encoded);
+        // Set the path in Request struct.
+        self.path = Some(Locations::Polyline(encoded.to_string()));
+        // Set the sample number in Request struct.
         self.samples = Some(samples);
         // Return modified Request struct to caller.
         self
@@ -5,9 +5,8 @@ use crate::elevation::{
 };
 use crate::error::Error as GoogleMapsError;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -44,10 +43,11 @@ impl<'a> ElevationRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Elevation, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            self.client.record_circuit_outcome(&Api::Elevation, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -107,7 +107,7 @@ impl<'a> ElevationRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(ElevationError::ReqwestMessage(error.to_string())))
+                                Err(Permanent(ElevationError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
@@ -3,7 +3,6 @@
 //! or as an Encoded Polyline.
 
 use crate::types::LatLng;
-use rust_decimal_macros::dec;
 
 // -----------------------------------------------------------------------------
 //
@@ -142,7 +141,7 @@ impl std::convert::From<&Locations> for String {
 impl std::default::Default for Locations {
     /// Returns a reasonable default variant for the `Locations` enum type.
     fn default() -> Self {
-        Locations::LatLngs(vec![LatLng::try_from_dec(dec!(0.0), dec!(0.0)).unwrap()])
+        Locations::LatLngs(vec![LatLng::default()])
     } // fn
 } // impl
 
@@ -75,7 +75,7 @@ impl std::convert::From<&Locations> for String {
 /// pair, multiple latitude/longitude pairs, or an encoded polyline.
 
 #[cfg(feature = "geo")]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum Locations {
     /// A single or multiple
     /// [latitude/longitude](https://developers.google.com/maps/documentation/elevation/intro#Locations)
@@ -4,6 +4,7 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 mod for_positional_request;
@@ -12,8 +13,11 @@ mod for_sampled_path_request;
 mod get;
 pub mod locations;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
 mod validate;
+mod with_channel;
 
 // -----------------------------------------------------------------------------
 
@@ -53,7 +57,12 @@ pub struct Request<'a> {
     /// Specifies the number of sample points along a path for which to return
     /// elevation data. The samples parameter divides the given path into an
     /// ordered set of equidistant points along the path.
-    samples: Option<u8>,
+    samples: Option<u16>,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
 
     // Internal use only:
     // ------------------
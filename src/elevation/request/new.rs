@@ -24,6 +24,7 @@ impl Request<'_> {
             // Sampled path requests:
             path: None,
             samples: None,
+            channel: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -0,0 +1,83 @@
+//! Contains the `Params` struct. It holds the parameters of an Elevation API
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::elevation::request::{locations::Locations, Request};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of an _Elevation API_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: ElevationParams = serde_json::from_str(job)?;
+/// let elevation = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "geo"), derive(Eq))]
+#[serde(default)]
+pub struct Params {
+    /// The locations for which to return elevation data, for a positional
+    /// request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locations: Option<Locations>,
+
+    /// The path along which to return elevation data, for a sampled path
+    /// request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Locations>,
+
+    /// The number of sample points along the path.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samples: Option<u16>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            locations: self.locations,
+            path: self.path,
+            samples: self.samples,
+            channel: self.channel,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            locations: self.locations.clone(),
+            path: self.path.clone(),
+            samples: self.samples,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
@@ -1,6 +1,14 @@
-use crate::elevation::{error::Error, request::Request};
+use crate::elevation::{error::Error, request::{locations::Locations, Request}};
 
-impl<'a> Request<'a> {
+// -----------------------------------------------------------------------------
+
+/// The largest number of samples the Elevation API accepts for a sampled path
+/// request.
+const MAX_SAMPLES: u16 = 512;
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -12,7 +20,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         // If a "for positional request" has been set...
         if let Some(_locations) = &self.locations {
             // ...a "for sampled path request" cannot be set.
@@ -21,6 +29,20 @@ impl<'a> Request<'a> {
             }
         } // if
 
+        // A sampled path request must have between 1 and 512 samples.
+        if let Some(samples) = self.samples {
+            if !(1..=MAX_SAMPLES).contains(&samples) {
+                return Err(Error::InvalidSamples(samples));
+            } // if
+        } // if
+
+        // An encoded polyline path must not be empty.
+        if let Some(Locations::Polyline(polyline)) = &self.path {
+            if polyline.is_empty() {
+                return Err(Error::EmptyPolyline);
+            } // if
+        } // if
+
         // Indicated that the request passed validation.
         self.validated = true;
 
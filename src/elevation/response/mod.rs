@@ -6,6 +6,7 @@ pub mod point;
 pub mod status;
 
 use crate::elevation::response::{point::Point, status::Status};
+use crate::types::InputIndex;
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Elevation API is stored in this structure.
@@ -43,3 +44,64 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns whether two responses have the same status and message, and
+    /// their sample points are equal within `epsilon` meters. See
+    /// `Point::approx_eq`.
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.status == other.status
+            && self.error_message == other.error_message
+            && self.results.len() == other.results.len()
+            && self.results
+                .iter()
+                .zip(&other.results)
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    } // fn
+
+    /// Returns the sample point for the location at the specified position of
+    /// a _positional request_. Google returns one sample point per location,
+    /// in the order of the locations. This does not apply to _sampled path
+    /// requests_, whose sample points are spread along the path.
+
+    #[must_use]
+    pub fn result_for(&self, index: impl Into<InputIndex>) -> Option<&Point> {
+        index.into().of(&self.results)
+    } // fn
+
+    /// Returns each location of a _positional request_ with its sample point.
+    /// `locations` must be the locations that were passed to the request.
+    /// Returns `None` if the number of locations and sample points differ,
+    /// for example for a _sampled path request_.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// for (location, point) in response.with_inputs(&locations).unwrap_or_default() {
+    ///     println!("{location}: {} m", point.elevation);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn with_inputs<'a, T>(&self, locations: &'a [T]) -> Option<Vec<(&'a T, &Point)>> {
+        (locations.len() == self.results.len())
+            .then(|| locations.iter().zip(&self.results).collect())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::elevation::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
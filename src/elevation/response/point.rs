@@ -20,3 +20,22 @@ pub struct Point {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resolution: Option<f64>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl Point {
+    /// Returns whether two sample points are at the same location and their
+    /// elevations and resolutions differ by no more than `epsilon` meters.
+    /// `Point` can't implement `Eq` because elevations are floating-point
+    /// numbers, and re-computed elevations may differ in their last digits.
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.location == other.location
+            && (self.elevation - other.elevation).abs() <= epsilon
+            && match (self.resolution, other.resolution) {
+                (Some(a), Some(b)) => (a - b).abs() <= epsilon,
+                (a, b) => a.is_none() && b.is_none(),
+            } // match
+    } // fn
+} // impl
@@ -9,80 +9,384 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the root part of the Google Maps Platform API
 /// client.
+///
+/// Each variant that wraps another error returns it from
+/// `std::error::Error::source`, so the chain can be followed down to the
+/// underlying error. For example, a transport failure can be downcast to a
+/// `reqwest::Error`.
 
 #[derive(Debug, Diagnostic, Error)]
 #[diagnostic(url(docsrs))]
 pub enum Error {
     /// Error originating from the types and structs in the `google_maps` crate.
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::types))]
     Type(#[from] crate::types::Error),
 
     /// Error originating from the `directions` module in the `google_maps`
     /// crate.
     #[cfg(any(feature = "directions", feature = "distance_matrix"))]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::directions))]
     Directions(#[from] crate::directions::error::Error),
 
     /// Error originating from the `distance_matrix` module in the `google_maps`
     /// crate.
     #[cfg(feature = "distance_matrix")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::distance_matrix))]
     DistanceMatrix(#[from] crate::distance_matrix::error::Error),
 
     /// Error originating from the `elevation` module in the `google_maps`
     /// crate.
     #[cfg(feature = "elevation")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::elevation))]
     Elevation(#[from] crate::elevation::error::Error),
 
     /// Error originating from the `geocoding` module in the `google_maps`
     /// crate.
     #[cfg(feature = "geocoding")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::geocoding))]
     Geocoding(#[from] crate::geocoding::error::Error),
 
     /// Error originating from the `places` module in the `google_maps` crate.
     #[cfg(feature = "places")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::places))]
     Places(#[from] crate::places::error::Error),
 
     /// Error originating from the `place_autocomplete` module in the
     /// `google_maps` crate.
     #[cfg(feature = "autocomplete")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::place_autocomplete))]
     PlaceAutocomplete(#[from] crate::places::place_autocomplete::error::Error),
 
     /// Error originating from the `roads` module in the `google_maps` crate.
     #[cfg(feature = "roads")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::roads))]
     Roads(#[from] crate::roads::error::Error),
 
     /// Error originating from the `time_zone` module in the `google_maps`
     /// crate.
     #[cfg(feature = "time_zone")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::time_zone))]
     TimeZone(#[from] crate::time_zone::error::Error),
 
+    /// Error originating from the `stored` module in the `google_maps` crate.
+    #[error("{0}")]
+    #[diagnostic(code(google_maps::stored))]
+    Stored(#[from] crate::stored::error::Error),
+
     /// Error originating from the [reqwest](https://crates.io/crates/reqwest)
     /// crate.
     #[cfg(feature = "reqwest")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::reqwest))]
     Reqwest(#[from] reqwest::Error),
 
+    /// The request was not sent because the client's circuit breaker for the
+    /// API is open, after repeated failures.
+    #[cfg(feature = "reqwest")]
+    #[error("Google Maps {0} API client: the circuit breaker is open after repeated failures. \
+        Requests are not sent until the cool-down has elapsed.")]
+    #[diagnostic(code(google_maps::circuit_open))]
+    CircuitOpen(crate::request_rate::api::Api),
+
     /// Error originating from the [polyline](https://crates.io/crates/polyline)
     /// crate.
     #[cfg(feature = "polyline")]
-    #[error(transparent)]
+    #[error("{0}")]
     #[diagnostic(code(google_maps::polyline))]
     Polyline(#[from] polyline::errors::PolylineError),
 } // enum Error
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Returns actionable guidance for resolving this error, suitable for
+    /// logging or for display in an operations dashboard. For example, an
+    /// instruction to enable the API on the Google Cloud project along with a
+    /// link to the console.
+    ///
+    /// Returns `None` when there is no general advice to give, such as for
+    /// invalid request parameters that are already described by the error
+    /// message itself.
+    /// ```rust
+    /// if let Err(error) = google_maps_client.geocoding().with_address("…").execute().await {
+    ///     tracing::error!("{error}");
+    ///     if let Some(help) = error.help() {
+    ///         tracing::error!("{help}");
+    ///     }
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn help(&self) -> Option<String> {
+        match self {
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(error) => match error {
+                crate::directions::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&DIRECTIONS, &String::from(status)),
+                crate::directions::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&DIRECTIONS, status),
+                crate::directions::error::Error::TravelModeUnavailable(_, _) => Some(
+                    "Retry the request with the travel mode returned by `suggest_alternate_mode()`."
+                        .to_string()),
+                #[cfg(feature = "reqwest")]
+                crate::directions::error::Error::Reqwest(_)
+                | crate::directions::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // Directions
+
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(error) => match error {
+                crate::distance_matrix::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&DISTANCE_MATRIX, &String::from(status)),
+                crate::distance_matrix::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&DISTANCE_MATRIX, status),
+                #[cfg(feature = "reqwest")]
+                crate::distance_matrix::error::Error::Reqwest(_)
+                | crate::distance_matrix::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // DistanceMatrix
+
+            #[cfg(feature = "elevation")]
+            Self::Elevation(error) => match error {
+                crate::elevation::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&ELEVATION, &String::from(status)),
+                crate::elevation::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&ELEVATION, status),
+                #[cfg(feature = "reqwest")]
+                crate::elevation::error::Error::Reqwest(_)
+                | crate::elevation::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // Elevation
+
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(error) => match error {
+                crate::geocoding::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&GEOCODING, &String::from(status)),
+                crate::geocoding::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&GEOCODING, status),
+                #[cfg(feature = "reqwest")]
+                crate::geocoding::error::Error::Reqwest(_)
+                | crate::geocoding::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // Geocoding
+
+            #[cfg(feature = "places")]
+            Self::Places(error) => match error {
+                crate::places::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&PLACES, &String::from(status)),
+                crate::places::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&PLACES, status),
+                #[cfg(feature = "reqwest")]
+                crate::places::error::Error::Reqwest(_)
+                | crate::places::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // Places
+
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(error) => match error {
+                crate::places::place_autocomplete::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&PLACES, &String::from(status)),
+                crate::places::place_autocomplete::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&PLACES, status),
+                #[cfg(feature = "reqwest")]
+                crate::places::place_autocomplete::error::Error::Reqwest(_)
+                | crate::places::place_autocomplete::error::Error::ReqwestMessage(_) =>
+                    Some(network_help()),
+                _ => None,
+            }, // PlaceAutocomplete
+
+            #[cfg(feature = "roads")]
+            Self::Roads(error) => match error {
+                crate::roads::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&ROADS, &String::from(status)),
+                crate::roads::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&ROADS, status),
+                #[cfg(feature = "reqwest")]
+                crate::roads::error::Error::Reqwest(_)
+                | crate::roads::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // Roads
+
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(error) => match error {
+                crate::time_zone::error::Error::GoogleMapsService(status, _) =>
+                    service_help(&TIME_ZONE, &String::from(status)),
+                crate::time_zone::error::Error::HttpUnsuccessful(status) =>
+                    service_help(&TIME_ZONE, status),
+                #[cfg(feature = "reqwest")]
+                crate::time_zone::error::Error::Reqwest(_)
+                | crate::time_zone::error::Error::ReqwestMessage(_) => Some(network_help()),
+                _ => None,
+            }, // TimeZone
+
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) => Some(network_help()),
+
+            #[cfg(feature = "reqwest")]
+            Self::CircuitOpen(api) => Some(format!(
+                "The {api} API failed repeatedly. Check the Google Maps Platform status \
+                dashboard, and retry after the circuit breaker's cool-down."
+            )),
+
+            Self::Type(crate::types::Error::MissingEnvironmentVariable(name)) => Some(format!(
+                "Set the `{name}` environment variable before starting the application."
+            )),
+
+            Self::Type(crate::types::Error::InvalidApiKey(_)) => Some(
+                "Copy the API key from the Credentials page of the Google Cloud console: \
+                https://console.cloud.google.com/apis/credentials".to_string()),
+
+            #[cfg(feature = "reqwest")]
+            Self::Type(crate::types::Error::UnavailableTlsBackend(backend)) => Some(format!(
+                "Remove the TLS backend setting, or enable a feature flag that compiles in \
+                `{backend}`. The available backends are listed by \
+                `GoogleMapsClient::transport_info()`."
+            )),
+
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The display name and Google Cloud service name of a Google Maps Platform
+/// API, used to build remediation text.
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone",
+))]
+struct ServiceInfo {
+    name: &'static str,
+    service: &'static str,
+}
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+const DIRECTIONS: ServiceInfo = ServiceInfo { name: "Directions API", service: "directions-backend.googleapis.com" };
+#[cfg(feature = "distance_matrix")]
+const DISTANCE_MATRIX: ServiceInfo = ServiceInfo { name: "Distance Matrix API", service: "distance-matrix-backend.googleapis.com" };
+#[cfg(feature = "elevation")]
+const ELEVATION: ServiceInfo = ServiceInfo { name: "Elevation API", service: "elevation-backend.googleapis.com" };
+#[cfg(feature = "geocoding")]
+const GEOCODING: ServiceInfo = ServiceInfo { name: "Geocoding API", service: "geocoding-backend.googleapis.com" };
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+const PLACES: ServiceInfo = ServiceInfo { name: "Places API", service: "places-backend.googleapis.com" };
+#[cfg(feature = "roads")]
+const ROADS: ServiceInfo = ServiceInfo { name: "Roads API", service: "roads.googleapis.com" };
+#[cfg(feature = "time_zone")]
+const TIME_ZONE: ServiceInfo = ServiceInfo { name: "Time Zone API", service: "timezone-backend.googleapis.com" };
+
+/// Returns remediation text for a status code (such as `REQUEST_DENIED`) or
+/// an HTTP status (such as `403 Forbidden`) returned by a Google Maps Platform
+/// API.
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone",
+))]
+fn service_help(api: &ServiceInfo, code: &str) -> Option<String> {
+    let ServiceInfo { name, service } = api;
+    match code {
+        "REQUEST_DENIED" | "PERMISSION_DENIED" => Some(format!(
+            "Check that your API key is valid, that its restrictions allow this \
+            server, and that the {name} is enabled on your Google Cloud project: \
+            https://console.cloud.google.com/apis/library/{service}"
+        )),
+        "OVER_DAILY_LIMIT" => Some(format!(
+            "The API key is missing or invalid, billing is not enabled, or a \
+            self-imposed usage cap was exceeded for the {name}. Review billing \
+            and quotas: https://console.cloud.google.com/apis/api/{service}/quotas"
+        )),
+        "OVER_QUERY_LIMIT" | "RESOURCE_EXHAUSTED" => Some(format!(
+            "The {name} quota was exceeded. Lower the request rate with \
+            `GoogleMapsClient::with_rate` or raise the quota: \
+            https://console.cloud.google.com/apis/api/{service}/quotas"
+        )),
+        "UNKNOWN_ERROR" => Some(String::from(
+            "Google's server encountered an error. The request may succeed if \
+            you try again."
+        )),
+        code if code.starts_with("401") || code.starts_with("403") => Some(format!(
+            "The request was refused. Check that your API key is valid and that \
+            the {name} is enabled on your Google Cloud project: \
+            https://console.cloud.google.com/apis/library/{service}"
+        )),
+        code if code.starts_with("429") => Some(format!(
+            "Too many requests. Lower the request rate with \
+            `GoogleMapsClient::with_rate` or raise the {name} quota: \
+            https://console.cloud.google.com/apis/api/{service}/quotas"
+        )),
+        code if code.starts_with('5') => Some(String::from(
+            "Google's server encountered an error. The request may succeed if \
+            you try again later."
+        )),
+        _ => None,
+    } // match
+} // fn
+
+/// Returns remediation text for errors raised by the HTTP client.
+#[cfg(feature = "reqwest")]
+fn network_help() -> String {
+    String::from(
+        "The Google Maps Platform could not be reached. Check network \
+        connectivity, DNS, proxy and firewall settings, and that outbound HTTPS \
+        to `*.googleapis.com` is allowed."
+    )
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a Google Maps Platform API response into a `Result`, based on the
+/// status Google returned in the response body.
+///
+/// The `execute()` and `get()` methods already check the status, so this is
+/// only needed when a response was obtained some other way, such as
+/// deserializing a stored JSON response with `str::parse`. It is implemented
+/// for every response type, so that both workflows behave the same way.
+///
+/// ```rust
+/// let directions = json.parse::<DirectionsResponse>()?.error_for_status()?;
+/// ```
+
+pub trait IntoResult: Sized {
+    /// Returns an error if the response's status indicates that the request
+    /// failed, otherwise returns a reference to the response.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the same `GoogleMapsService` error that `execute()` would
+    ///   have returned for this response.
+    fn error_for_status_ref(&self) -> Result<&Self, Error>;
+
+    /// Returns an error if the response's status indicates that the request
+    /// failed, otherwise returns the response.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the same `GoogleMapsService` error that `execute()` would
+    ///   have returned for this response.
+    fn error_for_status(self) -> Result<Self, Error> {
+        self.error_for_status_ref()?;
+        Ok(self)
+    } // fn
+} // trait
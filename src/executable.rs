@@ -0,0 +1,103 @@
+//! Contains the `Executable` trait. It is implemented by every request type,
+//! so that code can run requests without knowing which API they are for. Use
+//! it to write generic wrappers, such as timing, caching or queueing, once for
+//! all of the Google Maps Platform APIs.
+
+use crate::error::Error as GoogleMapsError;
+use futures::future::BoxFuture;
+
+// -----------------------------------------------------------------------------
+//
+/// A request that can be executed.
+///
+/// Request builders are executed through a mutable reference, so this is
+/// implemented for `&mut` of each request builder. Requests that are consumed
+/// or only borrowed when executed, such as a `TypedDirectionsRequest` or a
+/// `MultiAreaSearch`, implement it for the value or shared reference instead.
+///
+/// The future is boxed, because `async fn` can't be used in traits with this
+/// crate's minimum supported Rust version.
+///
+/// ## Example
+///
+/// A wrapper that logs how long any request took:
+///
+/// ```rust
+/// async fn timed<'a, E: Executable<'a>>(request: E) -> Result<E::Response, google_maps::Error> {
+///     let start = std::time::Instant::now();
+///     let result = request.execute_boxed().await;
+///     println!("request took {:?}", start.elapsed());
+///     result
+/// }
+///
+/// let geocoding = timed(google_maps_client.geocoding().with_address("Toronto, ON")).await?;
+/// let time_zone = timed(
+///     google_maps_client.time_zone(location, Utc::now()).with_language(Language::English)
+/// ).await?;
+/// ```
+
+pub trait Executable<'a> {
+    /// The response that the request returns.
+    type Response;
+
+    /// Executes the request. This is the same as calling the request's
+    /// `execute` method, except that the future is boxed.
+    fn execute_boxed(self) -> BoxFuture<'a, Result<Self::Response, GoogleMapsError>>;
+} // trait
+
+// -----------------------------------------------------------------------------
+
+/// Implements `Executable` for a mutable reference to a request builder.
+macro_rules! executable {
+    ($feature:literal, $($request:ident)::+, $response:ty) => {
+        #[cfg(feature = $feature)]
+        impl<'a> Executable<'a> for &'a mut $($request)::+<'a> {
+            type Response = $response;
+
+            fn execute_boxed(self) -> BoxFuture<'a, Result<Self::Response, GoogleMapsError>> {
+                Box::pin(self.execute())
+            } // fn
+        } // impl
+    };
+} // macro_rules
+
+executable!("directions", crate::directions::request::Request, crate::directions::response::Response);
+executable!("distance_matrix", crate::distance_matrix::request::Request, crate::distance_matrix::response::Response);
+executable!("elevation", crate::elevation::request::Request, crate::elevation::response::Response);
+executable!("geocoding", crate::geocoding::forward::ForwardRequest, crate::geocoding::response::Response);
+executable!("geocoding", crate::geocoding::reverse::ReverseRequest, crate::geocoding::response::Response);
+executable!("time_zone", crate::time_zone::request::Request, crate::time_zone::response::Response);
+executable!("autocomplete", crate::places::place_autocomplete::request::Request, crate::places::place_autocomplete::response::Response);
+executable!("autocomplete", crate::places::query_autocomplete::request::Request, crate::places::place_autocomplete::response::Response);
+executable!("places", crate::places::place_search::text_search::request::Request, crate::places::place_search::text_search::response::Response);
+executable!("places", crate::places::place_search::nearby_search::request::Request, crate::places::place_search::nearby_search::response::Response);
+executable!("places", crate::places::place_details::request::Request, crate::places::place_details::response::Response);
+executable!("roads", crate::roads::snap_to_roads::request::Request, crate::roads::snap_to_roads::response::Response);
+executable!("roads", crate::roads::nearest_roads::request::Request, crate::roads::nearest_roads::response::Response);
+executable!("roads", crate::roads::speed_limits::request::Request, crate::roads::speed_limits::response::Response);
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "directions")]
+impl<'a, M, T> Executable<'a> for crate::directions::request::typed::TypedRequest<'a, M, T>
+where
+    M: crate::directions::request::typed::state::TravelModeState + Send + 'a,
+    T: crate::directions::request::typed::state::TimeState + Send + 'a,
+{
+    type Response = crate::directions::response::Response;
+
+    /// Executes the type-checked request, consuming it.
+    fn execute_boxed(self) -> BoxFuture<'a, Result<Self::Response, GoogleMapsError>> {
+        Box::pin(self.execute())
+    } // fn
+} // impl
+
+#[cfg(feature = "places")]
+impl<'a> Executable<'a> for &'a crate::places::place_search::multi_area_search::MultiAreaSearch<'a> {
+    type Response = crate::places::place_search::multi_area_search::MultiAreaSearchResponse;
+
+    /// Searches every tile and merges the results.
+    fn execute_boxed(self) -> BoxFuture<'a, Result<Self::Response, GoogleMapsError>> {
+        Box::pin(self.execute())
+    } // fn
+} // impl
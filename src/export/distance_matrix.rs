@@ -0,0 +1,91 @@
+use crate::distance_matrix::response::Response;
+use std::io::Write;
+
+// -----------------------------------------------------------------------------
+
+/// The columns written by `write_distance_matrix_csv`, in order.
+///
+/// * `origin_index` & `destination_index` ‧ The zero-based position of the
+///   origin and destination in the request.
+/// * `origin_address` & `destination_address` ‧ The addresses as geocoded by
+///   Google.
+/// * `status` ‧ The element status code, for example `OK` or `ZERO_RESULTS`.
+/// * `distance_*`, `duration_*` ‧ Empty when not returned for the element.
+/// * `fare_currency` & `fare_value` ‧ Empty unless a transit fare was
+///   returned.
+pub const DISTANCE_MATRIX_CSV_COLUMNS: [&str; 12] = [
+    "origin_index",
+    "destination_index",
+    "origin_address",
+    "destination_address",
+    "status",
+    "distance_meters",
+    "distance_text",
+    "duration_seconds",
+    "duration_text",
+    "duration_in_traffic_seconds",
+    "fare_currency",
+    "fare_value",
+];
+
+// -----------------------------------------------------------------------------
+//
+/// Writes a Distance Matrix response to CSV, one row per origin-destination
+/// element, preceded by a header row of `DISTANCE_MATRIX_CSV_COLUMNS`.
+///
+/// ## Arguments
+///
+/// * `response` ‧ The Distance Matrix API response to export.
+///
+/// * `writer` ‧ Where to write the CSV, for example a `std::fs::File`.
+///
+/// ## Errors
+///
+/// * Returns an error if writing to `writer` fails.
+
+pub fn write_distance_matrix_csv<W: Write>(
+    response: &Response,
+    writer: W,
+) -> std::io::Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(DISTANCE_MATRIX_CSV_COLUMNS)?;
+
+    for (origin_index, row) in response.rows.iter().enumerate() {
+        let origin_address = response
+            .origin_addresses
+            .get(origin_index)
+            .map_or("", String::as_str);
+
+        for (destination_index, element) in row.elements.iter().enumerate() {
+            let destination_address = response
+                .destination_addresses
+                .get(destination_index)
+                .map_or("", String::as_str);
+
+            csv.write_record([
+                origin_index.to_string(),
+                destination_index.to_string(),
+                origin_address.to_string(),
+                destination_address.to_string(),
+                String::from(&element.status),
+                element.distance.as_ref().map(|d| d.value.to_string()).unwrap_or_default(),
+                element.distance.as_ref().map(|d| d.text.clone()).unwrap_or_default(),
+                element.duration.as_ref().map(|d| d.value.num_seconds().to_string()).unwrap_or_default(),
+                element.duration.as_ref().map(|d| d.text.clone()).unwrap_or_default(),
+                element
+                    .duration_in_traffic
+                    .as_ref()
+                    .map(|d| d.value.num_seconds().to_string())
+                    .unwrap_or_default(),
+                element
+                    .fare
+                    .as_ref()
+                    .map(|fare| <&str>::from(&fare.currency).to_string())
+                    .unwrap_or_default(),
+                element.fare.as_ref().map(|fare| fare.value.to_string()).unwrap_or_default(),
+            ])?; // write_record
+        } // for
+    } // for
+
+    csv.flush()
+} // fn
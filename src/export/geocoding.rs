@@ -0,0 +1,101 @@
+use crate::geocoding::response::Response;
+use std::io::Write;
+
+// -----------------------------------------------------------------------------
+
+/// The columns written by `write_geocoding_csv`, in order.
+///
+/// * `query` ‧ The address, place ID or coordinates that was geocoded, as
+///   supplied by the caller.
+/// * `status` ‧ The response status code, for example `OK` or `ZERO_RESULTS`.
+/// * `result_index` ‧ The zero-based position of the result in the response.
+///   Empty when the response has no results.
+/// * `types` ‧ The result's address types, separated by `|`.
+pub const GEOCODING_CSV_COLUMNS: [&str; 10] = [
+    "query",
+    "status",
+    "result_index",
+    "formatted_address",
+    "place_id",
+    "latitude",
+    "longitude",
+    "location_type",
+    "partial_match",
+    "types",
+];
+
+// -----------------------------------------------------------------------------
+//
+/// Writes a batch of Geocoding API responses to CSV, one row per result,
+/// preceded by a header row of `GEOCODING_CSV_COLUMNS`. Responses without
+/// results still produce a single row with their status, so that failed
+/// lookups are not silently dropped.
+///
+/// ## Arguments
+///
+/// * `results` ‧ Pairs of the geocoded query and its response.
+///
+/// * `writer` ‧ Where to write the CSV, for example a `std::fs::File`.
+///
+/// ## Example
+///
+/// ```rust
+/// google_maps::export::write_geocoding_csv(
+///     addresses.iter().zip(responses.iter()),
+///     std::io::stdout(),
+/// )?;
+/// ```
+///
+/// ## Errors
+///
+/// * Returns an error if writing to `writer` fails.
+
+pub fn write_geocoding_csv<'r, I, Q, W>(results: I, writer: W) -> std::io::Result<()>
+where
+    I: IntoIterator<Item = (Q, &'r Response)>,
+    Q: AsRef<str>,
+    W: Write,
+{
+    let mut csv = csv::Writer::from_writer(writer);
+    csv.write_record(GEOCODING_CSV_COLUMNS)?;
+
+    for (query, response) in results {
+        let query = query.as_ref();
+        let status = String::from(&response.status);
+
+        if response.results.is_empty() {
+            let mut record = vec![String::new(); GEOCODING_CSV_COLUMNS.len()];
+            record[0] = query.to_string();
+            record[1] = status;
+            csv.write_record(&record)?;
+            continue;
+        } // if
+
+        for (result_index, result) in response.results.iter().enumerate() {
+            csv.write_record([
+                query.to_string(),
+                status.clone(),
+                result_index.to_string(),
+                result.formatted_address.clone(),
+                result.place_id.clone(),
+                result.geometry.location.lat().to_string(),
+                result.geometry.location.lng().to_string(),
+                result
+                    .geometry
+                    .location_type
+                    .as_ref()
+                    .map(String::from)
+                    .unwrap_or_default(),
+                result.partial_match.map(|p| p.to_string()).unwrap_or_default(),
+                result
+                    .types
+                    .iter()
+                    .map(String::from)
+                    .collect::<Vec<String>>()
+                    .join("|"),
+            ])?; // write_record
+        } // for
+    } // for
+
+    csv.flush()
+} // fn
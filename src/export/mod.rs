@@ -0,0 +1,33 @@
+//! Writers that export API responses to CSV with stable column schemas. This
+//! module is only available with the `export` feature.
+//!
+//! Column names and their order are part of this crate's public API: new
+//! columns are only ever appended, so downstream pipelines may rely on the
+//! header row.
+//!
+//! ## Example
+//!
+//! ```rust
+//! let matrix = google_maps_client
+//!     .distance_matrix(origins, destinations)
+//!     .execute()
+//!     .await?;
+//!
+//! google_maps::export::write_distance_matrix_csv(
+//!     &matrix,
+//!     std::fs::File::create("matrix.csv")?,
+//! )?;
+//! ```
+
+#[cfg(feature = "distance_matrix")]
+mod distance_matrix;
+#[cfg(feature = "geocoding")]
+mod geocoding;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "distance_matrix")]
+pub use crate::export::distance_matrix::{write_distance_matrix_csv, DISTANCE_MATRIX_CSV_COLUMNS};
+
+#[cfg(feature = "geocoding")]
+pub use crate::export::geocoding::{write_geocoding_csv, GEOCODING_CSV_COLUMNS};
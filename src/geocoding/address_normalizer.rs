@@ -0,0 +1,150 @@
+//! Contains the `AddressNormalizer` struct. It canonicalizes user-typed
+//! addresses before they are geocoded, and produces cache keys so that
+//! trivially different spellings of the same address share a cache entry.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// Words that introduce a unit, suite, or floor number within a street
+/// address. The word and the identifier that follows it are removed when
+/// `strip_unit_designators` is enabled.
+///
+/// Abbreviations that are also state codes or parts of place names, such as
+/// `FL` (Florida), `Ste` (Sainte) or `Lot` (a French department), are left
+/// out.
+const UNIT_DESIGNATORS: &[&str] = &[
+    "apartment", "apt", "bldg", "floor", "rm", "room", "suite", "unit", "#",
+];
+
+/// Whether a word looks like a unit identifier, such as `4B`, `200` or `A`.
+/// A designator is only removed when one follows it, so that words such as
+/// `Unit` in a street name are kept.
+fn is_unit_identifier(word: &str) -> bool {
+    let word = word.trim_end_matches([',', '.']);
+    word.chars().any(|character| character.is_ascii_digit())
+        || (word.chars().count() == 1 && word.chars().all(char::is_alphabetic))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Canonicalizes addresses typed by users.
+///
+/// * `normalize` trims the address, collapses runs of whitespace into a
+///   single space and removes whitespace before commas. Optionally, unit
+///   designators such as `Apt 4B`, `Suite 200` or `#12` are removed. The
+///   result is suitable for passing to `with_address`.
+///
+/// * `cache_key` additionally case-folds the normalized address. It is meant
+///   to be used as the key of a geocoding cache and should not be sent to
+///   Google.
+///
+/// The Geocoding API ignores unit, suite and floor numbers, so stripping them
+/// does not change the geocoded location but does improve cache hit rates.
+///
+/// ## Example
+///
+/// ```rust
+/// let normalizer = AddressNormalizer::new().with_strip_unit_designators(true);
+///
+/// assert_eq!(
+///     normalizer.cache_key("  1600 Amphitheatre   Pkwy , Apt 4B, Mountain View "),
+///     normalizer.cache_key("1600 amphitheatre pkwy, mountain view"),
+/// );
+/// ```
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AddressNormalizer {
+    /// Whether unit designators (and the identifier that follows them) are
+    /// removed from the address. Defaults to `false`.
+    pub strip_unit_designators: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl AddressNormalizer {
+    /// Initializes an `AddressNormalizer` that trims and collapses whitespace
+    /// only.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            strip_unit_designators: false,
+        } // AddressNormalizer
+    } // fn
+
+    /// Sets whether unit designators such as `Apt 4B`, `Suite 200` or `#12`
+    /// are removed from the address.
+    #[must_use]
+    pub const fn with_strip_unit_designators(mut self, strip_unit_designators: bool) -> Self {
+        self.strip_unit_designators = strip_unit_designators;
+        self
+    } // fn
+
+    /// Returns the normalized address. Case is preserved, so the result may be
+    /// sent to Google as-is.
+    #[must_use]
+    pub fn normalize(&self, address: &str) -> String {
+        let mut tokens: Vec<String> = Vec::new();
+        let mut words = address.split_whitespace();
+
+        while let Some(word) = words.next() {
+            // A lone comma belongs to the previous word:
+            if word.chars().all(|character| character == ',') {
+                if let Some(previous) = tokens.last_mut() {
+                    if !previous.ends_with(',') {
+                        previous.push(',');
+                    } // if
+                } // if
+                continue;
+            } // if
+
+            if self.strip_unit_designators {
+                let bare = word.trim_end_matches([',', '.']).to_lowercase();
+
+                // `#12`-style units are a single word. Other designators are
+                // followed by the unit identifier, which is also removed:
+                let removed = if bare.len() > 1 && bare.starts_with('#') {
+                    Some(word)
+                } else if UNIT_DESIGNATORS.contains(&bare.as_str())
+                    && !word.ends_with(',')
+                    && words.clone().next().is_some_and(is_unit_identifier)
+                {
+                    words.next()
+                } else {
+                    None
+                }; // if
+
+                if let Some(removed) = removed {
+                    // Keep the comma that separated the unit from the next
+                    // address component:
+                    if removed.ends_with(',') {
+                        if let Some(previous) = tokens.last_mut() {
+                            if !previous.ends_with(',') {
+                                previous.push(',');
+                            } // if
+                        } // if
+                    } // if
+                    continue;
+                } // if
+            } // if
+
+            tokens.push(word.to_string());
+        } // while
+
+        // Drop any trailing comma left behind by a removed unit:
+        if let Some(last) = tokens.last_mut() {
+            while last.ends_with(',') {
+                last.pop();
+            } // while
+        } // if
+
+        tokens.join(" ")
+    } // fn
+
+    /// Returns a key for caching the geocoding result of the address. This is
+    /// the normalized address, case-folded.
+    #[must_use]
+    pub fn cache_key(&self, address: &str) -> String {
+        self.normalize(address).to_lowercase()
+    } // fn
+} // impl
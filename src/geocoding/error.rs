@@ -21,6 +21,9 @@ pub enum Error {
     GoogleMapsService(Status, Option<String>),
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
+    /// The `bounds` viewport bias is inverted: its south-west corner is north
+    /// of its north-east corner.
+    InvalidBounds(crate::types::Bounds),
     /// API client library attempted to parse a string that contained an invalid
     /// status code.
     InvalidStatusCode(String),
@@ -31,13 +34,13 @@ pub enum Error {
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
 } // enum
 
 impl std::fmt::Display for Error {
@@ -83,6 +86,10 @@ impl std::fmt::Display for Error {
                 "Google Maps Geocoding API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            Self::InvalidBounds(bounds) => write!(f,
+                "Google Maps Geocoding API client: \
+                The bounds `{bounds}` are inverted. \
+                The south-west corner must not be north of the north-east corner."),
             Self::InvalidStatusCode(status_code) => write!(f,
                 "Google Maps Geocoding API client: \
                 `{status_code}` is not a valid status code. \
@@ -1,7 +1,7 @@
 use crate::{geocoding::error::Error, geocoding::forward::ForwardRequest};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest<'_> {
     /// Builds the query string for the Google Maps Geocoding API based on the
     /// input provided by the client.
     ///
@@ -9,7 +9,7 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
@@ -28,7 +28,7 @@ impl<'a> ForwardRequest<'a> {
         if let Some(address) = &self.address {
             query.push_str("&address=");
             query.push_str(
-                &utf8_percent_encode(&String::from(address), NON_ALPHANUMERIC).to_string(),
+                &utf8_percent_encode(address, NON_ALPHANUMERIC).to_string(),
             );
         }
 
@@ -36,7 +36,7 @@ impl<'a> ForwardRequest<'a> {
         if let Some(place_id) = &self.place_id {
             query.push_str("&place_id=");
             query.push_str(
-                &utf8_percent_encode(&String::from(place_id), NON_ALPHANUMERIC).to_string(),
+                &utf8_percent_encode(place_id, NON_ALPHANUMERIC).to_string(),
             );
         }
 
@@ -66,17 +66,23 @@ impl<'a> ForwardRequest<'a> {
         }
 
         // Language key/value pair:
-        if let Some(language) = &self.language {
+        if let Some(language) = self.language.as_ref().or(self.client.language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
 
         // Region key/value pair:
-        if let Some(region) = &self.region {
+        if let Some(region) = self.region.as_ref().or(self.client.region.as_ref()) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         }
 
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
         // Set query string in ForwardRequest struct.
         self.query = Some(query);
 
@@ -5,9 +5,8 @@ use crate::geocoding::{
     OUTPUT_FORMAT, SERVICE_URL,
 };
 use crate::request_rate::api::Api;
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -44,10 +43,11 @@ impl<'a> FordwardGeocodingRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Geocoding, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+            let response = self.client.get_request_with_language(&url, self.accept_language.as_deref()).await;
+            self.client.record_circuit_outcome(&Api::Geocoding, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -65,10 +65,14 @@ impl<'a> FordwardGeocodingRequest<'a> {
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == GeocodingStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
+                                        if deserialized.status == GeocodingStatus::Ok
+                                            || (deserialized.status == GeocodingStatus::ZeroResults
+                                            && !self.client.zero_results_as_error)
+                                        {
+                                            // If Google's response was "Ok", or
+                                            // found nothing and the client does
+                                            // not treat that as an error, return
+                                            // the struct deserialized from JSON:
                                             Ok(deserialized)
                                         // Google API returned an error. This
                                         // indicates an issue with the request.
@@ -107,7 +111,7 @@ impl<'a> FordwardGeocodingRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(GeocodingError::ReqwestMessage(error.to_string())))
+                                Err(Permanent(GeocodingError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
@@ -5,16 +5,21 @@
 //! geocoding converts a street address to latitude & longitude coordinates.
 
 mod build;
+mod canonical;
 pub mod component;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
 mod validate;
+mod with_accept_language;
 mod with_address;
 mod with_bounds;
+mod with_channel;
 mod with_components;
 mod with_language;
 mod with_place_id;
@@ -25,6 +30,7 @@ mod with_region;
 use crate::client::GoogleMapsClient;
 use crate::geocoding::forward::component::Component;
 use crate::types::{Bounds, Language, Region};
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -49,7 +55,7 @@ pub struct ForwardRequest<'a> {
     /// avoided. Please refer to [the
     /// FAQ](https://developers.google.com/maps/faq#geocoder_queryformat) for
     /// additional guidance.
-    address: Option<String>,
+    address: Option<Cow<'a, str>>,
 
     /// The place ID of the place for which you wish to obtain the
     /// human-readable address. The place ID is a unique identifier that can
@@ -59,7 +65,7 @@ pub struct ForwardRequest<'a> {
     /// get the address for a snapped point.
     /// For more information about place IDs, see the place [ID
     /// overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
-    place_id: Option<String>,
+    place_id: Option<Cow<'a, str>>,
 
     /// The bounding box of the viewport within which to bias geocode results
     /// more prominently. This parameter will only influence, not fully
@@ -77,6 +83,11 @@ pub struct ForwardRequest<'a> {
     /// The language in which to return results.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// The region code, specified as a ccTLD ("top-level domain") two-character
     /// value. This parameter will only influence, not fully restrict, results
     /// from the geocoder. (For more information see [Region
@@ -84,6 +95,11 @@ pub struct ForwardRequest<'a> {
     /// below.)
     region: Option<Region>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
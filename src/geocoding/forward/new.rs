@@ -24,7 +24,9 @@ impl ForwardRequest<'_> {
             bounds: None,
             components: Vec::new(),
             language: None,
+            accept_language: None,
             region: None,
+            channel: None,
             // Internal use only:
             validated: false,
             query: None,
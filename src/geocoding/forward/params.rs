@@ -0,0 +1,108 @@
+//! Contains the `Params` struct. It holds the parameters of a Geocoding API
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::geocoding::forward::{component::Component, ForwardRequest};
+use crate::types::{Bounds, Language, Region};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Geocoding API_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: GeocodingParams = serde_json::from_str(r#"{ "address": "10 Downing St" }"#)?;
+/// let location = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The street address to geocode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// The place ID to geocode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_id: Option<String>,
+
+    /// The bounding box of the viewport within which to bias results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounds: Option<Bounds>,
+
+    /// A components filter. Each component fully restricts the results.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The region code, specified as a ccTLD two-character value, used to bias
+    /// results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> ForwardRequest<'_> {
+        ForwardRequest {
+            client,
+            address: self.address.map(Cow::Owned),
+            place_id: self.place_id.map(Cow::Owned),
+            bounds: self.bounds,
+            components: self.components,
+            language: self.language,
+            accept_language: self.accept_language,
+            region: self.region,
+            channel: self.channel,
+            query: None,
+            validated: false,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ForwardRequest<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            address: self.address.as_deref().map(str::to_string),
+            place_id: self.place_id.as_deref().map(str::to_string),
+            bounds: self.bounds,
+            components: self.components.clone(),
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            region: self.region,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
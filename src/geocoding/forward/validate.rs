@@ -1,21 +1,28 @@
 use crate::geocoding::{error::Error, forward::ForwardRequest};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest<'_> {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Geocoding API will accept them - i.e. require an address or
     /// components to be specified. This function does not check parameter
     /// values for validity - i.e. it will not Latitudes/Longitudes are valid
-    /// and well-formed.
+    /// and well-formed - other than rejecting inverted `bounds`.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         // If a positional request has been set...
         if self.address.is_none() && self.place_id.is_none() && self.components.is_empty() {
             return Err(Error::AddressOrComponentsRequired);
+        } // if
+        // Bounds may cross the antimeridian, so only the latitudes can be
+        // checked:
+        if let Some(bounds) = &self.bounds {
+            if bounds.southwest.lat > bounds.northeast.lat {
+                return Err(Error::InvalidBounds(*bounds));
+            } // if
         } // if
           // Indicate that the request passed validation.
         self.validated = true;
@@ -1,4 +1,5 @@
 use crate::geocoding::forward::ForwardRequest;
+use std::borrow::Cow;
 
 impl<'a> ForwardRequest<'a> {
     /// Specifies the street address to geocode.
@@ -22,7 +23,7 @@ impl<'a> ForwardRequest<'a> {
 
     pub fn with_address(
         &'a mut self,
-        address: impl Into<String>
+        address: impl Into<Cow<'a, str>>
     ) -> &'a mut Self {
         // Set address in ForwardRequest struct.
         self.address = Some(address.into());
@@ -31,7 +31,17 @@ impl<'a> ForwardRequest<'a> {
     /// the San Fernando Valley of Los Angeles results in this geocode returning
     /// the neighborhood named "Winnetka" in that location.
     ///
-    /// ## Example
+    /// Bounds, region biasing and component filtering may be combined. Bounds
+    /// and region only _prefer_ results, while the `Component::Country` and
+    /// `Component::PostalCode` filters _restrict_ them: a country filter that
+    /// excludes the viewport makes the bias irrelevant. To strictly keep
+    /// results inside the viewport, filter the response with
+    /// `Response::results_within`.
+    ///
+    /// Validation fails with `Error::InvalidBounds` if the south-west corner
+    /// is north of the north-east corner.
+    ///
+    /// ## Examples
     ///
     /// * Specify bounding box for search area:
     /// ```
@@ -40,6 +50,27 @@ impl<'a> ForwardRequest<'a> {
     ///     northeast: LatLng::try_from_dec(dec!(51.503_440_5), dec!(-0.126_003_2))?,
     /// })
     /// ```
+    ///
+    /// * Find the "Winnetka" in the San Fernando Valley rather than the one
+    ///   near Chicago. The bounds appear in the query string as
+    ///   `bounds=34.172684,-118.604794|34.236144,-118.500938`, percent-encoded:
+    /// ```rust
+    /// let winnetka = google_maps_client
+    ///     .geocoding()
+    ///     .with_address("Winnetka")
+    ///     .with_bounds(Bounds {
+    ///         southwest: LatLng::try_from_dec(dec!(34.172_684), dec!(-118.604_794))?,
+    ///         northeast: LatLng::try_from_dec(dec!(34.236_144), dec!(-118.500_938))?,
+    ///     })
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    ///
+    /// * Prefer a viewport, but only accept results in the United States:
+    /// ```rust
+    /// .with_bounds(&san_fernando_valley)
+    /// .with_component(GeocodingComponent::Country(Country::UnitedStates))
+    /// ```
 
     pub fn with_bounds(
         &'a mut self,
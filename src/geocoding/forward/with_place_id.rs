@@ -1,4 +1,5 @@
 use crate::geocoding::forward::ForwardRequest;
+use std::borrow::Cow;
 
 impl<'a> ForwardRequest<'a> {
     /// Specifies the place id to geocode.
@@ -24,7 +25,7 @@ impl<'a> ForwardRequest<'a> {
 
     pub fn with_place_id(
         &'a mut self,
-        place_id: impl Into<String>
+        place_id: impl Into<Cow<'a, str>>
     ) -> &'a mut Self {
         // Set address in ForwardRequest struct.
         self.place_id = Some(place_id.into());
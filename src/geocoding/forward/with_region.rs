@@ -21,19 +21,44 @@ impl<'a> ForwardRequest<'a> {
     /// _prefers_ results for a specific domain; if more relevant results exist
     /// outside of this domain, they may be included.
     ///
-    /// For example, a directions request for "Toledo" to "Madrid" returns
-    /// appropriate results when `region` is set to `Region::Spain` and "Toledo"
-    /// is then interpreted as the Spanish city. A directions request for
-    /// "Toledo" to "Madrid" sent without a `region` parameter does not return
-    /// results, because "Toledo" is interpreted as the city in Ohio and not
-    /// Spain.
+    /// For example, a geocode for "Toledo" returns this city in Ohio, United
+    /// States. A geocode for "Toledo" with `region` set to `Region::Spain`
+    /// returns the city in Spain.
     ///
-    /// ## Example
+    /// The region is sent as its ccTLD ("top-level domain") value, which does
+    /// not always match the ISO 3166-1 country code. For example,
+    /// `Region::UnitedKingdom` is sent as `co.uk` while its country code is
+    /// `GB`.
+    ///
+    /// Region biasing only _prefers_ results. To _restrict_ results to a
+    /// country, use a `Component::Country` filter instead. Region biasing,
+    /// component filtering and `with_bounds` may be combined.
+    ///
+    /// ## Examples
     ///
     /// * Bias region to Canada:
     /// ```rust
     /// .with_region(Region::Canada)
     /// ```
+    ///
+    /// * Geocode the Spanish "Toledo" rather than the one in Ohio:
+    /// ```rust
+    /// let toledo = google_maps_client
+    ///     .geocoding()
+    ///     .with_address("Toledo")
+    ///     .with_region(Region::Spain)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    ///
+    /// * Only accept results in Spain. Unlike the region bias, a country
+    ///   component filter returns `ZERO_RESULTS` rather than a match
+    ///   elsewhere:
+    /// ```rust
+    /// .with_address("Toledo")
+    /// .with_region(Region::Spain)
+    /// .with_component(GeocodingComponent::Country(Country::Spain))
+    /// ```
 
     pub fn with_region(
         &'a mut self,
@@ -80,10 +80,13 @@
 //! mobile developers who want to use geocoding data within maps provided by one
 //! of the Google Maps Platform APIs.
 
+pub mod address_normalizer;
 pub mod error;
 pub mod forward;
 pub mod response;
 pub mod reverse;
+#[cfg(feature = "reqwest")]
+pub mod reverse_batch;
 
 // -----------------------------------------------------------------------------
 
@@ -93,13 +96,22 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 // -----------------------------------------------------------------------------
 
 pub use crate::geocoding::{
+    address_normalizer::AddressNormalizer,
     error::Error as GeocodingError,
-    forward::{component::Component as GeocodingComponent, ForwardRequest as GeocodingRequest}, // forward
+    forward::{
+        component::Component as GeocodingComponent, params::Params as GeocodingParams,
+        ForwardRequest as GeocodingRequest,
+    }, // forward
     response::{
-        geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
-        Response as GeocodingResponse,
+        address_descriptor::{AddressDescriptor, Area, Containment, Landmark, LocalizedText, SpatialRelationship},
+        geocoding::Geocoding, geocoding_delta::GeocodingDelta,
+        navigation_point::{NavigationPoint, NavigationTravelMode}, plus_code::PlusCode,
+        status::Status as GeocodingStatus, Response as GeocodingResponse,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{params::Params as GeocodingReverseParams, ReverseRequest as GeocodingReverseRequest},
 }; // geocoding
 
+#[cfg(feature = "reqwest")]
+pub use crate::geocoding::reverse_batch::ReverseGeocodeMatch;
+
 pub use crate::types::{AddressComponent, Geometry, LocationType};
@@ -0,0 +1,234 @@
+//! Contains the `AddressDescriptor` struct and its parts. An address
+//! descriptor describes a location relative to nearby landmarks and the areas
+//! that contain it, for example "near the Eiffel Tower". It is returned by
+//! reverse geocoding requests made with `with_address_descriptor(true)`.
+
+use crate::types::{Language, PlaceType};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A name, with the language it is written in.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct LocalizedText {
+    /// The text.
+    pub text: String,
+
+    /// The BCP-47 code of the language of the text, for example `en`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl LocalizedText {
+    /// Returns the text.
+
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    } // fn
+
+    /// Returns the language of the text, or `None` if Google did not report
+    /// one. Languages that this crate does not know are `Language::Other`.
+
+    #[must_use]
+    pub fn language(&self) -> Option<Language> {
+        self.language_code.as_deref().and_then(|code| code.parse().ok())
+    } // fn
+
+    /// Returns whether the text is written in the specified language. A text
+    /// in a regional variant, such as `en-AU`, is also written in the base
+    /// language `en`, and the other way around.
+
+    #[must_use]
+    pub fn is_in(&self, language: Language) -> bool {
+        self.match_quality(language).is_some()
+    } // fn
+
+    /// Picks the text that best suits a reader, from several texts that may
+    /// be in different languages, such as the names of the landmarks and
+    /// areas of an `AddressDescriptor`.
+    ///
+    /// The preferred languages are tried in order. For each, a text in
+    /// exactly that language is picked first, then a text in its base
+    /// language or one of its regional variants, then a text in a sibling
+    /// regional variant. If no text is in any preferred
+    /// language, the first text that isn't empty is picked. Returns `None`
+    /// only if there are no texts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let names = descriptor.landmarks.iter().map(|landmark| &landmark.display_name);
+    /// let name = LocalizedText::negotiate(names, &[Language::FrenchCanada, Language::English]);
+    /// ```
+
+    pub fn negotiate<'a>(
+        candidates: impl IntoIterator<Item = &'a Self>,
+        preferred: &[Language],
+    ) -> Option<&'a Self> {
+        let candidates: Vec<&Self> = candidates.into_iter().collect();
+        preferred
+            .iter()
+            .find_map(|language| candidates
+                .iter()
+                .filter_map(|text| text.match_quality(*language).map(|quality| (quality, *text)))
+                // The first of the best matches:
+                .min_by_key(|(quality, _)| std::cmp::Reverse(*quality))
+                .map(|(_, text)| text))
+            .or_else(|| candidates.iter().find(|text| !text.text.is_empty()).copied())
+            .or_else(|| candidates.first().copied())
+    } // fn
+
+    /// Returns how well the text's language matches the specified language:
+    /// `3` for the same language, `2` if one is the base language of the
+    /// other, such as `en` and `en-AU`, `1` for two regional variants of the
+    /// same base language, and `None` otherwise. Language codes are compared
+    /// case-insensitively.
+    fn match_quality(&self, language: Language) -> Option<u8> {
+        let code = self.language_code.as_deref()?.replace('_', "-").to_lowercase();
+        let wanted = language.as_wire_str().to_lowercase();
+        let base = |code: &str| code.split('-').next().unwrap_or_default().to_string();
+        if language == Language::Other || base(&code) != base(&wanted) {
+            None
+        } else if code == wanted {
+            Some(3)
+        } else if code == base(&code) || wanted == base(&wanted) {
+            Some(2)
+        } else {
+            Some(1)
+        } // if
+    } // fn
+} // impl
+
+impl std::fmt::Display for LocalizedText {
+    /// Formats the text, without its language.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// How the geocoded location relates to a landmark.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpatialRelationship {
+    /// The location is near the landmark. This is the default relationship
+    /// when nothing more specific applies.
+    #[default]
+    Near,
+    /// The location is within the landmark's spatial structure.
+    Within,
+    /// The location is directly beside the landmark.
+    Beside,
+    /// The location is directly across the road from the landmark.
+    AcrossTheRoad,
+    /// The location is on the same road as the landmark, but not beside or
+    /// across from it.
+    DownTheRoad,
+    /// The location is around the corner from the landmark.
+    AroundTheCorner,
+    /// The location is behind the landmark.
+    Behind,
+    /// A relationship that was added by Google after this version of the
+    /// crate was released.
+    #[serde(other)]
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// How the geocoded location relates to an area.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Containment {
+    /// The location is within the area, close to its center.
+    #[default]
+    Within,
+    /// The location is within the area, close to its edge.
+    Outskirts,
+    /// The location is outside the area, but close to it.
+    Near,
+    /// A containment that was added by Google after this version of the crate
+    /// was released.
+    #[serde(other)]
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// A well-known place near the geocoded location.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Landmark {
+    /// The place ID of the landmark.
+    pub place_id: String,
+
+    /// The name of the landmark.
+    #[serde(default)]
+    pub display_name: LocalizedText,
+
+    /// The types of the landmark.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<PlaceType>,
+
+    /// How the geocoded location relates to the landmark.
+    #[serde(default)]
+    pub spatial_relationship: SpatialRelationship,
+
+    /// The straight-line distance, in meters, from the geocoded location to
+    /// the landmark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub straight_line_distance_meters: Option<Decimal>,
+
+    /// The travel distance, in meters, along the road network from the
+    /// geocoded location to the landmark.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_distance_meters: Option<Decimal>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// An area, such as a neighbourhood or a campus, that contains or is near the
+/// geocoded location.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Area {
+    /// The place ID of the area.
+    pub place_id: String,
+
+    /// The name of the area.
+    #[serde(default)]
+    pub display_name: LocalizedText,
+
+    /// How the geocoded location relates to the area.
+    #[serde(default)]
+    pub containment: Containment,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Describes the geocoded location relative to nearby landmarks and the areas
+/// that contain it. Landmarks and areas are ranked by how useful they are for
+/// recognizing the location.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressDescriptor {
+    /// Landmarks near the geocoded location, most recognizable first.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub landmarks: Vec<Landmark>,
+
+    /// Areas that contain or are near the geocoded location, most precise
+    /// first.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub areas: Vec<Area>,
+} // struct
@@ -1,4 +1,7 @@
-use crate::geocoding::response::plus_code::PlusCode;
+use crate::geocoding::response::{
+    navigation_point::{NavigationPoint, NavigationTravelMode},
+    plus_code::PlusCode,
+}; // crate::geocoding::response
 use crate::types::{AddressComponent, Geometry, PlaceType};
 use serde::{Deserialize, Serialize};
 
@@ -36,6 +39,14 @@ pub struct Geocoding {
     /// data. See the `Geometry` struct declaration.
     pub geometry: Geometry,
 
+    /// Points on the road network, next to the geocoded location, where
+    /// navigation to or from the location should start or end. Routing a
+    /// delivery to a navigation point, rather than to `geometry.location`,
+    /// avoids arriving at the wrong side of a building.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub navigation_points: Vec<NavigationPoint>,
+
     /// Indicates that the geocoder did not return an exact match for the
     /// original request, though it was able to match part of the requested
     /// address. You may wish to examine the original request for misspellings
@@ -110,4 +121,19 @@ impl Geocoding {
                 .map(std::string::ToString::to_string)
         }) // and_then
     } // fn
+
+    /// Returns the first navigation point that can be reached by the specified
+    /// travel mode, or `None` if Google returned no such navigation point.
+    /// ```rust
+    /// let stop = geocoding.navigation_point_for(NavigationTravelMode::Drive);
+    /// ```
+    #[must_use]
+    pub fn navigation_point_for(
+        &self,
+        travel_mode: NavigationTravelMode
+    ) -> Option<&NavigationPoint> {
+        self.navigation_points
+            .iter()
+            .find(|navigation_point| navigation_point.allows(travel_mode))
+    } // fn
 } // impl
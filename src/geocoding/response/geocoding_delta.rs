@@ -0,0 +1,86 @@
+//! Contains the `GeocodingDelta` struct which describes how a geocoding result
+//! changed between two snapshots.
+
+use crate::geocoding::response::geocoding::Geocoding;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The differences between two snapshots of the same geocoding result, as
+/// produced by `Geocoding::diff`. Each flag is `true` when the corresponding
+/// field differs between the old and the new snapshot.
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct GeocodingDelta {
+    /// The address components changed.
+    pub address_components: bool,
+    /// The human-readable address changed.
+    pub formatted_address: bool,
+    /// The geocoded latitude & longitude changed.
+    pub location: bool,
+    /// The precision of the geocoded location changed. For example, from
+    /// `RangeInterpolated` to `Rooftop`.
+    pub location_type: bool,
+    /// The recommended viewport or the bounding box changed.
+    pub viewport: bool,
+    /// The partial match flag changed.
+    pub partial_match: bool,
+    /// The place ID changed.
+    pub place_id: bool,
+    /// The plus code changed.
+    pub plus_code: bool,
+    /// The address types changed.
+    pub types: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl GeocodingDelta {
+    /// Returns `true` if no fields changed between the two snapshots.
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !(self.address_components
+            || self.formatted_address
+            || self.location
+            || self.location_type
+            || self.viewport
+            || self.partial_match
+            || self.place_id
+            || self.plus_code
+            || self.types)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Geocoding {
+    /// Compares two snapshots of the same geocoding result and returns the
+    /// fields that changed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let delta = Geocoding::diff(&cached, &fresh);
+    /// if delta.location {
+    ///     reindex(&fresh);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn diff(old: &Self, new: &Self) -> GeocodingDelta {
+        GeocodingDelta {
+            address_components: old.address_components != new.address_components,
+            formatted_address: old.formatted_address != new.formatted_address,
+            location: old.geometry.location != new.geometry.location,
+            location_type: old.geometry.location_type != new.geometry.location_type,
+            viewport: old.geometry.viewport != new.geometry.viewport
+                || old.geometry.bounds != new.geometry.bounds,
+            partial_match: old.partial_match != new.partial_match,
+            place_id: old.place_id != new.place_id,
+            plus_code: old.plus_code != new.plus_code,
+            types: old.types != new.types,
+        } // GeocodingDelta
+    } // fn
+} // impl
@@ -2,13 +2,19 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
+pub mod address_descriptor;
 pub mod geocoding;
+pub mod geocoding_delta;
+pub mod navigation_point;
 pub mod plus_code;
 pub mod status;
 
 // -----------------------------------------------------------------------------
 
-use crate::geocoding::response::{geocoding::Geocoding, status::Status};
+use crate::geocoding::response::{
+    address_descriptor::AddressDescriptor, geocoding::Geocoding, status::Status,
+}; // crate::geocoding::response
+use crate::types::{Bounds, LatLng};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -18,6 +24,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Response {
+    /// Describes the location of a reverse geocoding request relative to
+    /// nearby landmarks and areas. Only returned when the request was made
+    /// with `with_address_descriptor(true)`, and only in regions where Google
+    /// supports address descriptors.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_descriptor: Option<AddressDescriptor>,
+
     /// When the geocoder returns a status code other than `OK`, there may be an
     /// additional `error_message` field within the Geocoding response object.
     /// This field contains more detailed information about the reasons behind
@@ -43,6 +57,67 @@ pub struct Response {
 
 // -----------------------------------------------------------------------------
 
+impl Response {
+    /// Returns the result whose location is closest to the given coordinates,
+    /// measured by great-circle distance.
+    ///
+    /// Google ranks reverse geocoding results by relevance and result type,
+    /// not by distance, so the first result is not necessarily the nearest
+    /// one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let nearest = google_maps_client
+    ///     .reverse_geocoding(latlng)
+    ///     .execute()
+    ///     .await?
+    ///     .closest_result_to(&latlng);
+    /// ```
+    #[must_use]
+    pub fn closest_result_to(&self, latlng: &LatLng) -> Option<&Geocoding> {
+        self.results.iter().min_by(|a, b| {
+            a.geometry.location.haversine_distance(latlng)
+                .total_cmp(&b.geometry.location.haversine_distance(latlng))
+        }) // min_by
+    } // fn
+
+    /// Returns the results whose location lies within the given bounds.
+    ///
+    /// `ForwardRequest::with_bounds` only _biases_ results towards a viewport,
+    /// and Google may still return better matches from elsewhere. Filtering
+    /// the response with the same bounds turns the bias into a restriction.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let san_fernando_valley = Bounds {
+    ///     southwest: LatLng::try_from_dec(dec!(34.172_684), dec!(-118.604_794))?,
+    ///     northeast: LatLng::try_from_dec(dec!(34.236_144), dec!(-118.500_938))?,
+    /// };
+    ///
+    /// let winnetka = google_maps_client
+    ///     .geocoding()
+    ///     .with_address("Winnetka")
+    ///     .with_bounds(&san_fernando_valley)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// // Only the Los Angeles neighbourhood, never the Chicago suburb:
+    /// let local_results: Vec<&Geocoding> = winnetka
+    ///     .results_within(&san_fernando_valley)
+    ///     .collect();
+    /// ```
+
+    pub fn results_within<'b>(&'b self, bounds: &'b Bounds) -> impl Iterator<Item = &'b Geocoding> {
+        self.results
+            .iter()
+            .filter(|result| bounds.contains(&result.geometry.location))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Response {
     type Err = simd_json::Error;
     /// Parse a Google Maps Geocoding API JSON `String` into a `Response`
@@ -52,3 +127,16 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::geocoding::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
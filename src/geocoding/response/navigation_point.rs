@@ -0,0 +1,72 @@
+//! Contains the `NavigationPoint` struct and the `NavigationTravelMode` enum.
+//! A navigation point is a location on a road where navigation to a geocoded
+//! address should end, for example the entrance of a driveway.
+
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The travel modes that a navigation point can be reached by.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NavigationTravelMode {
+    /// Driving, including two-wheelers.
+    Drive,
+    /// Walking.
+    Walk,
+    /// A travel mode that was added by Google after this version of the crate
+    /// was released.
+    #[serde(other)]
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for NavigationTravelMode {
+    /// Formats a `NavigationTravelMode` enum into a string that is
+    /// presentable to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Drive => write!(f, "Drive"),
+            Self::Walk => write!(f, "Walk"),
+            Self::Other => write!(f, "Other"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A point on a road segment, next to the geocoded location, where navigation
+/// should start or end. Delivery and ride-hailing applications should route to
+/// a navigation point rather than to the geocoded location itself, which may
+/// be in the middle of a building.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct NavigationPoint {
+    /// The location of the navigation point.
+    pub location: LatLng,
+
+    /// The travel modes that the navigation point cannot be reached by. An
+    /// empty list means that it can be reached by any travel mode.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub restricted_travel_modes: Vec<NavigationTravelMode>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl NavigationPoint {
+    /// Returns whether the navigation point can be reached by the specified
+    /// travel mode.
+    ///
+    /// ```rust
+    /// let curbside = result.navigation_points.iter().find(|point| point.allows(NavigationTravelMode::Drive));
+    /// ```
+
+    #[must_use]
+    pub fn allows(&self, travel_mode: NavigationTravelMode) -> bool {
+        !self.restricted_travel_modes.contains(&travel_mode)
+    } // fn
+} // impl
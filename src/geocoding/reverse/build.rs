@@ -1,7 +1,7 @@
 use crate::geocoding::reverse::ReverseRequest;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest<'_> {
     /// Builds the query string for the Google Maps Geocoding API based on the
     /// input provided by the client.
     ///
@@ -9,7 +9,7 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut ReverseRequest {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -23,7 +23,7 @@ impl<'a> ReverseRequest<'a> {
         // string:
 
         // Language key/value pair:
-        if let Some(language) = &self.language {
+        if let Some(language) = self.language.as_ref().or(self.client.language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         } // if
@@ -62,6 +62,17 @@ impl<'a> ReverseRequest<'a> {
             ); // push_str
         } // if
 
+        // Address descriptor key/value pair:
+        if self.address_descriptor {
+            query.push_str("&enable_address_descriptor=true");
+        } // if
+
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
         // Set query string in ReverseRequest struct.
         self.query = Some(query);
 
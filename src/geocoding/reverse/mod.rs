@@ -5,12 +5,21 @@
 //! geocoding converts latitude & longitude coordinates to a street address.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
+mod prefer_poi;
+mod prefer_road;
+mod prefer_street_address;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_address_descriptor;
+mod with_channel;
 mod with_language;
 mod with_location_types;
 mod with_result_types;
@@ -44,6 +53,11 @@ pub struct ReverseRequest<'a> {
     /// The language in which to return results.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// A filter of one or more location types. If the parameter contains
     /// multiple location types, the API returns all addresses that match any of
     /// the types. A note about processing: The `location_type` parameter does
@@ -62,6 +76,15 @@ pub struct ReverseRequest<'a> {
     /// the specified address type(s).
     result_types: Vec<PlaceType>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
+    /// Whether to return an address descriptor, which describes the location
+    /// relative to nearby landmarks and areas.
+    address_descriptor: bool,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
@@ -26,8 +26,11 @@ impl<'a> ReverseRequest<'a> {
             latlng,
             // Optional parameters:
             language: None,
+            accept_language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            channel: None,
+            address_descriptor: false,
             // Internal use only:
             query: None,
         } // struct
@@ -60,8 +63,11 @@ impl<'a> ReverseRequest<'a> {
             latlng: LatLng::try_from(coordinate)?,
             // Optional parameters:
             language: None,
+            accept_language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            channel: None,
+            address_descriptor: false,
             // Internal use only:
             query: None,
         }) // struct
@@ -94,8 +100,11 @@ impl<'a> ReverseRequest<'a> {
             latlng: LatLng::try_from(point)?,
             // Optional parameters:
             language: None,
+            accept_language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            channel: None,
+            address_descriptor: false,
             // Internal use only:
             query: None,
         }) // struct
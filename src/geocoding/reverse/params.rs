@@ -0,0 +1,98 @@
+//! Contains the `Params` struct. It holds the parameters of a Geocoding API
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::geocoding::reverse::ReverseRequest;
+use crate::types::{Language, LatLng, LocationType, PlaceType};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Geocoding API_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: GeocodingReverseParams = serde_json::from_str(job)?;
+/// let addresses = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The latitude and longitude for which to find the closest, human-readable
+    /// address.
+    pub latlng: LatLng,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The location types to restrict the results to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub location_types: Vec<LocationType>,
+
+    /// The address types to restrict the results to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub result_types: Vec<PlaceType>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+
+    /// Whether to return an address descriptor with the results.
+    pub address_descriptor: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> ReverseRequest<'_> {
+        ReverseRequest {
+            client,
+            latlng: self.latlng,
+            language: self.language,
+            accept_language: self.accept_language,
+            location_types: self.location_types,
+            result_types: self.result_types,
+            channel: self.channel,
+            address_descriptor: self.address_descriptor,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ReverseRequest<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            latlng: self.latlng,
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            location_types: self.location_types.clone(),
+            result_types: self.result_types.clone(),
+            channel: self.channel.clone(),
+            address_descriptor: self.address_descriptor,
+        } // Params
+    } // fn
+} // impl
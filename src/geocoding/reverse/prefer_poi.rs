@@ -0,0 +1,31 @@
+use crate::{geocoding::reverse::ReverseRequest, types::PlaceType};
+
+impl<'a> ReverseRequest<'a> {
+    /// Prefers named points of interest over street addresses and roads. This
+    /// is a shortcut for `with_result_types([PlaceType::PointOfInterest,
+    /// PlaceType::Park, PlaceType::Airport, PlaceType::NaturalFeature])`.
+    ///
+    /// ## Description
+    ///
+    /// Points of interest are prominent local entities such as "Empire State
+    /// Building" or "Eiffel Tower". Use this method to label a location with a
+    /// landmark rather than an address.
+    ///
+    /// If no point of interest is close enough to the location, the API
+    /// returns `ZERO_RESULTS`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .prefer_poi()
+    /// ```
+
+    pub fn prefer_poi(&'a mut self) -> &'a mut Self {
+        self.with_result_types([
+            PlaceType::PointOfInterest,
+            PlaceType::Park,
+            PlaceType::Airport,
+            PlaceType::NaturalFeature,
+        ])
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::{geocoding::reverse::ReverseRequest, types::PlaceType};
+
+impl<'a> ReverseRequest<'a> {
+    /// Prefers the nearest road over street addresses and points of interest.
+    /// This is a shortcut for
+    /// `with_result_types([PlaceType::Route, PlaceType::Intersection])`.
+    ///
+    /// ## Description
+    ///
+    /// Useful when the location is a vehicle position and the street it's
+    /// travelling on matters more than the closest building.
+    ///
+    /// If no road is close enough to the location, the API returns
+    /// `ZERO_RESULTS`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .prefer_road()
+    /// ```
+
+    pub fn prefer_road(&'a mut self) -> &'a mut Self {
+        self.with_result_types([PlaceType::Route, PlaceType::Intersection])
+    } // fn
+} // impl
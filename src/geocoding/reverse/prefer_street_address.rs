@@ -0,0 +1,35 @@
+use crate::{geocoding::reverse::ReverseRequest, types::PlaceType};
+
+impl<'a> ReverseRequest<'a> {
+    /// Prefers precise street addresses over roads, areas and points of
+    /// interest. This is a shortcut for
+    /// `with_result_types([PlaceType::StreetAddress, PlaceType::Premise, PlaceType::Subpremise])`.
+    ///
+    /// ## Description
+    ///
+    /// By default, the reverse geocoder returns every kind of result near the
+    /// location, for example the route, the neighborhood and the postal code,
+    /// alongside the street address. Use this method when you want the
+    /// nearest deliverable address.
+    ///
+    /// If no street address is close enough to the location, the API returns
+    /// `ZERO_RESULTS`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let address = google_maps_client
+    ///     .reverse_geocoding(LatLng::try_from_dec(dec!(40.714_224), dec!(-73.961_452))?)
+    ///     .prefer_street_address()
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    pub fn prefer_street_address(&'a mut self) -> &'a mut Self {
+        self.with_result_types([
+            PlaceType::StreetAddress,
+            PlaceType::Premise,
+            PlaceType::Subpremise,
+        ])
+    } // fn
+} // impl
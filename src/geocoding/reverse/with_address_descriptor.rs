@@ -0,0 +1,33 @@
+use crate::geocoding::reverse::ReverseRequest;
+
+// =============================================================================
+
+impl<'a> ReverseRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies whether the response should include an address descriptor.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address_descriptor` ‧ Whether to return an address descriptor in
+    ///   `Response::address_descriptor`. It describes the location relative to
+    ///   nearby landmarks and the areas that contain it, for example "across
+    ///   the road from the city hall". Address descriptors are only available
+    ///   in some regions.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_address_descriptor(true)
+    /// ```
+
+    pub fn with_address_descriptor(
+        &'a mut self,
+        address_descriptor: bool
+    ) -> &'a mut Self {
+        // Set address descriptor flag in ReverseRequest struct.
+        self.address_descriptor = address_descriptor;
+        // Return modified ReverseRequest struct to caller.
+        self
+    } // fn
+} // impl
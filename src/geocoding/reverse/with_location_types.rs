@@ -63,7 +63,8 @@ impl<'a> ReverseRequest<'a> {
         &'a mut self,
         location_type: impl Into<LocationType>
     ) -> &'a mut Self {
-        self.location_types = vec![location_type.into()];
+        // Add location type to ReverseRequest struct.
+        self.location_types.push(location_type.into());
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
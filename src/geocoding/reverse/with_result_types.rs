@@ -118,7 +118,7 @@ impl<'a> ReverseRequest<'a> {
         result_type: impl Into<PlaceType>
     ) -> &'a mut Self {
         // Add result type to ReverseRequest struct.
-        self.result_types = vec![result_type.into()];
+        self.result_types.push(result_type.into());
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
@@ -143,7 +143,7 @@ impl<'a> ReverseRequest<'a> {
     ///   neighborhood and a locality:
     ///
     /// ```rust
-    /// .with_components(&[
+    /// .with_result_types(&[
     ///     PlaceType::Neighborhood,
     ///     PlaceType::Locality,
     /// ])
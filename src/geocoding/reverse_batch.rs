@@ -0,0 +1,204 @@
+//! Contains the `ReverseGeocodeMatch` struct and the
+//! `GoogleMapsClient::reverse_geocode_batch` method. Batches of points, such
+//! as GPS fixes from a fleet of vehicles, often contain many points that are
+//! a few meters apart. Reverse geocoding each of them pays for the same
+//! address many times over, so nearby points are clustered first.
+
+use crate::client::GoogleMapsClient;
+use crate::geocoding::response::geocoding::Geocoding;
+use crate::types::LatLng;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The number of reverse geocoding requests that are in flight at once. The
+/// client's rate limits still apply.
+const CONCURRENCY: usize = 8;
+
+// -----------------------------------------------------------------------------
+//
+/// The reverse geocoding result for one point of a batch.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReverseGeocodeMatch {
+    /// The point, as passed to `reverse_geocode_batch`.
+    pub point: LatLng,
+
+    /// The centroid of the cluster the point belongs to. This is the location
+    /// that was reverse geocoded.
+    pub centroid: LatLng,
+
+    /// Google's best result for the centroid. `None` if Google found no
+    /// address, for example for a point at sea.
+    pub result: Option<Geocoding>,
+
+    /// The distance, in meters, from `point` to the location of `result`.
+    /// Use this to discard results that are too far from the point to be
+    /// useful.
+    pub distance_m: Option<f64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ReverseGeocodeMatch {
+    /// Returns whether two matches are for the same point, centroid and
+    /// result, and their distances differ by no more than `epsilon` meters.
+    /// `ReverseGeocodeMatch` can't implement `Eq` because the distance is a
+    /// floating-point number.
+
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.point == other.point
+            && self.centroid == other.centroid
+            && self.result == other.result
+            && match (self.distance_m, other.distance_m) {
+                (Some(a), Some(b)) => (a - b).abs() <= epsilon,
+                (a, b) => a.is_none() && b.is_none(),
+            } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+    /// Reverse geocodes a batch of points, looking up each cluster of nearby
+    /// points only once.
+    ///
+    /// Each point joins the first cluster whose first point is within
+    /// `precision_m` meters of it, or starts a new cluster. The centroid of
+    /// each cluster is reverse geocoded, several clusters at a time, and the
+    /// result is mapped back to every point in the cluster. A larger
+    /// `precision_m` means fewer requests but less precise addresses. Points
+    /// in a cluster are at most `2 × precision_m` meters apart.
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` ‧ The points to reverse geocode.
+    ///
+    /// * `precision_m` ‧ The clustering radius, in meters. `0.0` only merges
+    ///   identical points.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the first error encountered. A point for which Google found
+    ///   no address is not an error, see `ReverseGeocodeMatch::result`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let matches = google_maps_client
+    ///     .reverse_geocode_batch(gps_fixes, 25.0)
+    ///     .await?;
+    ///
+    /// for m in matches {
+    ///     if let Some(result) = &m.result {
+    ///         println!("{}: {}", m.point, result.formatted_address);
+    ///     }
+    /// }
+    /// ```
+
+    pub async fn reverse_geocode_batch(
+        &self,
+        points: impl IntoIterator<Item = impl Into<LatLng>>,
+        precision_m: f64,
+    ) -> Result<Vec<ReverseGeocodeMatch>, crate::GoogleMapsError> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let points: Vec<LatLng> = points.into_iter().map(Into::into).collect();
+        let clusters = cluster_points(&points, precision_m);
+
+        tracing::info!(
+            "reverse geocoding {points} points as {clusters} clusters",
+            points = points.len(),
+            clusters = clusters.len(),
+        );
+
+        let results: Vec<Option<Geocoding>> = futures::stream::iter(clusters.iter())
+            .map(|cluster| async move {
+                let response = self.reverse_geocoding(cluster.centroid).execute().await?;
+                Ok::<_, crate::GoogleMapsError>(response.results.into_iter().next())
+            }) // map
+            .buffered(CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut matches: Vec<Option<ReverseGeocodeMatch>> = vec![None; points.len()];
+        for (cluster, result) in clusters.iter().zip(results) {
+            for &index in &cluster.members {
+                let point = points[index];
+                matches[index] = Some(ReverseGeocodeMatch {
+                    point,
+                    centroid: cluster.centroid,
+                    distance_m: result
+                        .as_ref()
+                        .map(|result| point.haversine_distance(&result.geometry.location)),
+                    result: result.clone(),
+                }); // ReverseGeocodeMatch
+            } // for
+        } // for
+
+        Ok(matches.into_iter().flatten().collect())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// A group of nearby points that are reverse geocoded together.
+struct Cluster {
+    /// The first point of the cluster. Points within the precision radius of
+    /// it join the cluster.
+    seed: LatLng,
+    /// The positions of the cluster's points in the batch.
+    members: Vec<usize>,
+    /// The mean position of the cluster's points.
+    centroid: LatLng,
+} // struct
+
+/// Groups points that are within `precision_m` meters of a cluster's first
+/// point.
+fn cluster_points(points: &[LatLng], precision_m: f64) -> Vec<Cluster> {
+    let mut clusters: Vec<Cluster> = Vec::new();
+
+    for (index, point) in points.iter().enumerate() {
+        match clusters
+            .iter_mut()
+            .find(|cluster| cluster.seed.haversine_distance(point) <= precision_m)
+        {
+            Some(cluster) => cluster.members.push(index),
+            None => clusters.push(Cluster { seed: *point, members: vec![index], centroid: *point }),
+        } // match
+    } // for
+
+    for cluster in &mut clusters {
+        cluster.centroid = centroid(cluster.seed, cluster.members.iter().map(|&index| &points[index]));
+    } // for
+
+    clusters
+} // fn
+
+/// Returns the mean position of the points. Longitudes are averaged relative
+/// to `seed`, so that a cluster straddling the antimeridian is not averaged to
+/// the other side of the world.
+fn centroid<'p>(seed: LatLng, points: impl ExactSizeIterator<Item = &'p LatLng>) -> LatLng {
+    let count = Decimal::from(points.len().max(1));
+    let (lat_sum, lng_offset_sum) = points.fold((dec!(0), dec!(0)), |(lat, lng), point| {
+        let mut offset = point.lng - seed.lng;
+        if offset > dec!(180) {
+            offset -= dec!(360);
+        } else if offset < dec!(-180) {
+            offset += dec!(360);
+        } // if
+        (lat + point.lat, lng + offset)
+    }); // fold
+
+    let mut lng = seed.lng + lng_offset_sum / count;
+    if lng > dec!(180) {
+        lng -= dec!(360);
+    } else if lng < dec!(-180) {
+        lng += dec!(360);
+    } // if
+
+    LatLng::try_from_dec(lat_sum / count, lng).unwrap_or(seed)
+} // fn
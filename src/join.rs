@@ -0,0 +1,84 @@
+//! Contains the `join2`, `join3` and `execute_all` functions. They run several
+//! requests concurrently, and can mix requests for different APIs, such as a
+//! geocoding request and a time zone request. Any `Executable` request can be
+//! passed to them.
+//!
+//! The requests are still sent through their `GoogleMapsClient`, so they
+//! share its rate limiter, retry policy and circuit breaker. Running many
+//! requests at once will not exceed the configured rate limits: requests that
+//! are over the limit wait for their turn.
+
+use crate::error::Error as GoogleMapsError;
+
+pub use crate::executable::Executable;
+
+// -----------------------------------------------------------------------------
+
+/// Executes two requests concurrently, and returns both results once both
+/// requests have finished. The requests may be for different APIs.
+///
+/// ## Example
+///
+/// ```rust
+/// let (geocoding, time_zone) = google_maps::join2(
+///     google_maps_client.geocoding().with_address("Toronto, ON"),
+///     &mut google_maps_client.time_zone(location, Utc::now()),
+/// ).await;
+/// ```
+
+pub async fn join2<'a, A, B>(
+    a: A,
+    b: B,
+) -> (
+    Result<A::Response, GoogleMapsError>,
+    Result<B::Response, GoogleMapsError>,
+)
+where
+    A: Executable<'a>,
+    B: Executable<'a>,
+{
+    futures::future::join(a.execute_boxed(), b.execute_boxed()).await
+} // fn
+
+/// Executes three requests concurrently, and returns all three results once
+/// every request has finished. The requests may be for different APIs.
+
+pub async fn join3<'a, A, B, C>(
+    a: A,
+    b: B,
+    c: C,
+) -> (
+    Result<A::Response, GoogleMapsError>,
+    Result<B::Response, GoogleMapsError>,
+    Result<C::Response, GoogleMapsError>,
+)
+where
+    A: Executable<'a>,
+    B: Executable<'a>,
+    C: Executable<'a>,
+{
+    futures::future::join3(a.execute_boxed(), b.execute_boxed(), c.execute_boxed()).await
+} // fn
+
+/// Executes any number of requests of the same kind concurrently, and returns
+/// their results in the order of the requests. A failed request does not stop
+/// the others.
+///
+/// ## Example
+///
+/// ```rust
+/// let mut requests: Vec<_> = addresses
+///     .iter()
+///     .map(|address| google_maps_client.geocoding().with_address(address))
+///     .collect();
+/// let results = google_maps::execute_all(requests.iter_mut()).await;
+/// ```
+
+pub async fn execute_all<'a, E>(
+    requests: impl IntoIterator<Item = E>,
+) -> Vec<Result<E::Response, GoogleMapsError>>
+where
+    E: Executable<'a>,
+{
+    futures::future::join_all(requests.into_iter().map(Executable::execute_boxed)).await
+} // fn
@@ -19,7 +19,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! google_maps = "3.7"
+//! google_maps = "4.0"
 //! ```
 //!
 //! Optionally, add `rust_decimal = "1"` and `rust_decimal_macros = "1"` for
@@ -52,6 +52,10 @@
 //! * `geo` ‧ support for the rust [geo](https://crates.io/crates/geo) ecosystem
 //! * `polyline` ‧ allows easy type conversions from a `Route` or `Step` to a geo
 //!   [LineString](https://docs.rs/geo-types/0.7.13/geo_types/geometry/struct.LineString.html)
+//! * `render` ‧ renders a `Route` as a small SVG image, for emails and reports
+//! * `export` ‧ CSV writers for Distance Matrix and Geocoding results
+//! * `h3` ‧ `LatLng` to [H3](https://h3geo.org/) cell conversions and bucketing
+//! * `geohash` ‧ `LatLng` to geohash conversions and bucketing
 //!
 //! Note: the `autocomplete` feature covers the Places API autocomplete-related
 //! services:
@@ -65,6 +69,26 @@
 //!
 //! * `reqwest-native-tls` ‧ Enables TLS functionality provided by `native-tls`.
 //! * `reqwest-rustls` ‧ Enables TLS functionality provided by `rustls`.
+//! * `reqwest-rustls-tls-native-roots` ‧ Enables `rustls`, validating
+//!   certificates against the operating system's trust store instead of the
+//!   bundled Mozilla roots.
+//! * `rustls-platform-verifier` ‧ Enables `rustls`, verifying certificates
+//!   with the operating system's own verifier, which also honours its
+//!   revocation checks and enterprise policies.
+//!
+//! When more than one TLS backend is compiled in, `ClientConfig::tls_backend`
+//! selects one at run time. `GoogleMapsClient::transport_info()` reports the
+//! backend that a client uses.
+//!
+//! ### Async Runtime Feature Flags
+//!
+//! For use with `reqwest` only. `tokio` is used when both or neither are
+//! enabled. The rate limiter's timer works with any runtime.
+//!
+//! * `rt-tokio` ‧ Retry timers use `tokio`. This is the default.
+//! * `rt-async-std` ‧ Retry timers use `async-std`. Since
+//!   `reqwest` performs its I/O on a `tokio` reactor, enable `async-std`'s
+//!   `tokio1` feature in your application.
 //!
 //! ### Default Feature Flags
 //!
@@ -88,6 +112,7 @@
 //!
 //!     # reqwest default features:
 //!     "reqwest",
+//!     "rt-tokio",
 //!     "reqwest-default-tls",
 //!     "reqwest-http2",
 //!     "reqwest-brotli",
@@ -109,11 +134,12 @@
 //!
 //! ```toml
 //! google_maps = {
-//!     version = "3.7",
+//!     version = "4.0",
 //!     default-features = false,
 //!     features = [
 //!         "directions",
 //!         "reqwest",
+//!         "rt-tokio",
 //!         "reqwest-rustls",
 //!         "reqwest-brotli"
 //!     ]
@@ -373,9 +399,8 @@
 //! - [ ] [Places API](https://developers.google.com/places/web-service/intro).
 //!     Only partly implemented. If you would like to have any missing pieces
 //!     implemented, please contact me.
-//! - [ ] [Roads API](https://developers.google.com/maps/documentation/roads/intro).
-//!     Only partly implemented. If you would like to have any missing pieces
-//!     implemented, please contact me.
+//! - [x] [Roads API](https://developers.google.com/maps/documentation/roads/intro).
+//!     Snap to roads, nearest roads, and speed limits are implemented.
 //!
 //! # Author's Note
 //!
@@ -392,7 +417,7 @@
 //! there some work.
 
 #![forbid(unsafe_code)]
-#![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
+#![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo, clippy::unwrap_used)]
 #![allow(
     clippy::cast_precision_loss,
     clippy::empty_line_after_doc_comments, // bah!
@@ -408,10 +433,14 @@
 
 // Common / global modules:
 
+pub mod built_request;
+pub mod canonical_request;
+pub mod classified_error;
 mod client;
 pub mod error;
 pub mod prelude;
 mod serde;
+pub mod stored;
 pub mod types;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
@@ -436,8 +465,13 @@ pub mod time_zone;
 // Re-exports. Not great for organization but needed for backward compatibility.
 
 pub use crate::{
+    built_request::BuiltRequest,
+    canonical_request::CanonicalRequest,
+    classified_error::{ClassifiableError, ClassifiedError},
     client::GoogleMapsClient as ClientSettings, client::GoogleMapsClient,
-    error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
+    error::Error as GoogleMapsError, error::Error, error::IntoResult,
+    stored::{Storable, Stored, Upgrades},
+    types::error::Error as TypeError,
 }; // crate
 
 #[cfg(any(feature = "geocoding", feature = "places"))]
@@ -449,6 +483,8 @@ pub use crate::types::address_component::AddressComponent;
     feature = "places"
 ))]
 pub use crate::types::bounds::Bounds;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub use crate::types::circle::{Circle, CircleEndpoint};
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -457,14 +493,8 @@ pub use crate::types::bounds::Bounds;
 pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places",
-    feature = "time_zone"
-))]
+#[cfg(any(feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub use crate::types::input_index::{InputIndex, OriginalIndex};
 pub use crate::types::language::Language;
 #[cfg(any(
     feature = "autocomplete",
@@ -487,23 +517,81 @@ pub use crate::types::location_type::LocationType;
     feature = "places"
 ))]
 pub use crate::types::place_type::PlaceType;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places"
-))]
 pub use crate::types::region::Region;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_modifiers::{RouteModifiers, VehicleEmissionType};
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_restrictions::RouteRestrictions;
+#[cfg(all(
+    any(feature = "h3", feature = "geohash", feature = "geo"),
+    any(feature = "geocoding", feature = "places")
+))]
+pub use crate::types::spatial_bucket::Locatable;
+#[cfg(all(feature = "geo", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_cluster::{cluster_places, PlaceCluster};
+#[cfg(all(feature = "h3", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_h3;
+#[cfg(all(feature = "geohash", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_geohash;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::travel_mode::TravelMode;
 
 // Optional dependencies:
 
+#[cfg(feature = "export")]
+pub mod export;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "reqwest")]
+pub mod circuit_breaker;
+
+#[cfg(feature = "reqwest")]
+pub mod executable;
+
+#[cfg(feature = "reqwest")]
+pub mod join;
+
 #[cfg(feature = "reqwest")]
 mod request_rate;
 
+#[cfg(feature = "reqwest")]
+pub mod trace_context;
+
+#[cfg(feature = "reqwest")]
+mod runtime;
+
 #[cfg(feature = "reqwest")]
 pub use crate::request_rate::api::Api;
 
+#[cfg(feature = "reqwest")]
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+
+#[cfg(feature = "reqwest")]
+pub use crate::executable::Executable;
+
+#[cfg(feature = "reqwest")]
+pub use crate::join::{execute_all, join2, join3};
+
+#[cfg(feature = "reqwest")]
+pub use crate::trace_context::{TraceContext, TraceContextProvider};
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::config::{ClientConfig, RateLimitConfig};
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::transport_info::{TlsBackend, TlsRoots, TransportInfo};
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::resolver::{IpPreference, ResolverOptions};
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::retry_policy::RetryPolicy;
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::global::{global, set_global};
+
 #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
 type ReqError = reqwest_maybe_middleware::Error;
 
@@ -0,0 +1,269 @@
+//! Contains the `Attributions` struct and the `Attributed` trait. They collect
+//! the attributions that Google requires to be displayed alongside Places API
+//! data, and render them as HTML or plain text.
+
+use crate::places::{place_photo::strip_html_tags, Place, PlacePhoto, PlaceReview};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The attribution to Google that must accompany Places API data that is not
+/// displayed on a Google map.
+pub const GOOGLE_ATTRIBUTION: &str = "Powered by Google";
+
+// -----------------------------------------------------------------------------
+//
+/// The author of user-contributed content, such as a review. Google requires
+/// the author's name, and a link to their profile when one is provided, to be
+/// displayed with the content.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AuthorAttribution {
+    /// The name of the author.
+    pub display_name: String,
+
+    /// The URL of the author's profile, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+
+    /// The URL of the author's profile photo, if available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_uri: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl From<&PlaceReview> for AuthorAttribution {
+    /// Returns the attribution of a review's author.
+    fn from(review: &PlaceReview) -> Self {
+        Self {
+            display_name: review.author_name.clone(),
+            uri: review.author_url.clone(),
+            photo_uri: review.profile_photo_url.clone(),
+        } // AuthorAttribution
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The attributions collected from one or more Places API responses.
+///
+/// Google requires that, wherever Places data is displayed:
+///
+/// * Data shown without a Google map is attributed to Google.
+/// * Third-party attributions, returned in `html_attributions`, are shown
+///   with the listing or photo they came from, keeping their links.
+/// * User-contributed content, such as reviews, is shown with its author.
+///
+/// Use `to_html` or `to_text` to render a block that meets these requirements.
+/// See the [Places API
+/// policies](https://developers.google.com/maps/documentation/places/web-service/policies)
+/// for the full requirements.
+///
+/// ## Example
+///
+/// ```rust
+/// let details = google_maps_client
+///     .place_details("ChIJIyEbn74oOIgRBlO4ZfvF4qg")
+///     .execute()
+///     .await?;
+///
+/// let footer = details.attributions().to_html();
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Attributions {
+    /// Third-party attributions, as HTML fragments provided by Google. Each
+    /// distinct attribution appears once, in the order it was first found.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub html: Vec<String>,
+
+    /// The authors of user-contributed content. Each distinct author appears
+    /// once, in the order they were first found.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<AuthorAttribution>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Attributions {
+    /// Adds HTML attributions, skipping any that were already collected.
+    pub fn add_html(&mut self, html_attributions: impl IntoIterator<Item = impl AsRef<str>>) {
+        for html in html_attributions {
+            let html = html.as_ref();
+            if !self.html.iter().any(|collected| collected == html) {
+                self.html.push(html.to_string());
+            } // if
+        } // for
+    } // fn
+
+    /// Adds an author attribution, unless it was already collected.
+    pub fn add_author(&mut self, author: AuthorAttribution) {
+        if !self.authors.contains(&author) {
+            self.authors.push(author);
+        } // if
+    } // fn
+
+    /// Adds the attributions collected from another source.
+    pub fn extend(&mut self, other: Self) {
+        self.add_html(&other.html);
+        for author in other.authors {
+            self.add_author(author);
+        } // for
+    } // fn
+
+    /// Returns `true` if there are no third-party or author attributions. The
+    /// attribution to Google is still required.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.html.is_empty() && self.authors.is_empty()
+    } // fn
+
+    /// Renders the attributions as an HTML block. The attribution to Google
+    /// is always included, followed by a list of the third-party and author
+    /// attributions, if there are any. Third-party attributions are inserted
+    /// as provided by Google, and author names and links are escaped.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<div class=\"google-maps-attributions\">");
+        html.push_str("<span>");
+        html.push_str(GOOGLE_ATTRIBUTION);
+        html.push_str("</span>");
+
+        if !self.is_empty() {
+            html.push_str("<ul>");
+            for attribution in &self.html {
+                html.push_str("<li>");
+                html.push_str(attribution);
+                html.push_str("</li>");
+            } // for
+            for author in &self.authors {
+                html.push_str("<li>");
+                let name = escape_html(&author.display_name);
+                html.push_str(&author.uri.as_ref().map_or_else(
+                    || name.clone(),
+                    |uri| format!("<a href=\"{uri}\">{name}</a>", uri = escape_html(uri)),
+                )); // push_str
+                html.push_str("</li>");
+            } // for
+            html.push_str("</ul>");
+        } // if
+
+        html.push_str("</div>");
+        html
+    } // fn
+
+    /// Renders the attributions as plain text, one per line, for surfaces
+    /// that can't render HTML. The attribution to Google comes first. HTML
+    /// tags are removed from third-party attributions, and author profile
+    /// links are shown in parentheses.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![GOOGLE_ATTRIBUTION.to_string()];
+        lines.extend(self.html.iter().map(|html| strip_html_tags(html)));
+        lines.extend(self.authors.iter().map(|author| {
+            author.uri.as_ref().map_or_else(
+                || author.display_name.clone(),
+                |uri| format!("{name} ({uri})", name = author.display_name),
+            ) // map_or_else
+        })); // extend
+        lines.join("\n")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Attributions {
+    /// Formats the attributions as plain text. See `to_text`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Places API data that carries attributions which must be displayed with it.
+
+pub trait Attributed {
+    /// Collects the attributions of this value and everything it contains,
+    /// such as the photos and reviews of a place.
+    fn attributions(&self) -> Attributions;
+} // trait
+
+// -----------------------------------------------------------------------------
+
+impl Attributed for PlacePhoto {
+    fn attributions(&self) -> Attributions {
+        let mut attributions = Attributions::default();
+        attributions.add_html(&self.html_attributions);
+        attributions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Attributed for Place {
+    fn attributions(&self) -> Attributions {
+        let mut attributions = Attributions::default();
+        for photo in &self.photos {
+            attributions.extend(photo.attributions());
+        } // for
+        for review in &self.reviews {
+            attributions.add_author(AuthorAttribution::from(review));
+        } // for
+        attributions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Attributed for crate::places::place_details::Response {
+    fn attributions(&self) -> Attributions {
+        let mut attributions = Attributions::default();
+        attributions.add_html(&self.html_attributions);
+        if let Some(place) = &self.result {
+            attributions.extend(place.attributions());
+        } // if
+        attributions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Attributed for crate::places::place_search::text_search::Response {
+    fn attributions(&self) -> Attributions {
+        let mut attributions = Attributions::default();
+        attributions.add_html(&self.html_attributions);
+        for place in &self.results {
+            attributions.extend(place.attributions());
+        } // for
+        attributions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Attributed for crate::places::place_search::nearby_search::Response {
+    fn attributions(&self) -> Attributions {
+        let mut attributions = Attributions::default();
+        attributions.add_html(&self.html_attributions);
+        for place in &self.results {
+            attributions.extend(place.attributions());
+        } // for
+        attributions
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Escapes the characters that are significant in HTML text and attribute
+/// values.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+} // fn
@@ -139,3 +139,25 @@ impl BusinessStatus {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl BusinessStatus {
+    /// Returns the code that Google uses for this `BusinessStatus`, for example
+    /// `OPERATIONAL`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `BusinessStatus` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
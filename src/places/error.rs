@@ -28,6 +28,16 @@ pub enum Error {
     /// `google_maps\src\places\business_status.rs` for more information.
     InvalidBusinessStatusCode(String),
     /// API client library attempted to parse a string that contained an invalid
+    /// price level code. See `google_maps\src\places\price_level.rs` for more
+    /// information.
+    InvalidPriceLevelCode(String),
+    /// API client library attempted to convert a legacy price level above `4`
+    /// into a `PriceLevel`.
+    InvalidPriceLevel(u8),
+    /// API client library attempted to convert `PriceLevel::Unspecified` into
+    /// a legacy price level.
+    UnspecifiedPriceLevel,
+    /// API client library attempted to parse a string that contained an invalid
     /// secondary hours type code. See
     /// `google_maps\src\places\secondary_hours_type.rs` for more information.
     InvalidSecondaryHoursType(String),
@@ -46,15 +56,19 @@ pub enum Error {
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
+    /// A `MultiAreaSearch` would need more than the maximum number of tiles,
+    /// which is given. Use a larger radius, smaller bounds or
+    /// `with_max_tiles`.
+    TooManyTiles(usize),
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -103,6 +117,16 @@ impl std::fmt::Display for Error {
                 `{status_code}` is not a valid business status code. \
                 Valid codes are `OPERATIONAL`, `CLOSED_TEMPORARILY`, and \
                 `CLOSED_PERMANENTLY`."),
+            Self::InvalidPriceLevelCode(price_level_code) => write!(f, "Google Maps Places API client: \
+                `{price_level_code}` is not a valid price level code. \
+                Valid codes are `PRICE_LEVEL_UNSPECIFIED`, `PRICE_LEVEL_FREE`, \
+                `PRICE_LEVEL_INEXPENSIVE`, `PRICE_LEVEL_MODERATE`, \
+                `PRICE_LEVEL_EXPENSIVE`, and `PRICE_LEVEL_VERY_EXPENSIVE`."),
+            Self::InvalidPriceLevel(price_level) => write!(f, "Google Maps Places API client: \
+                `{price_level}` is not a valid price level. \
+                Valid price levels are 0 to 4."),
+            Self::UnspecifiedPriceLevel => write!(f, "Google Maps Places API client: \
+                an unspecified price level has no legacy price level equivalent."),
             Self::InvalidRankByCode(type_code) => write!(f, "Google Maps Places API client: \
                 `{type_code}` is not a valid rankby order type. \
                 Valid codes are `prominence`, and `distance`."),
@@ -126,6 +150,9 @@ impl std::fmt::Display for Error {
             Self::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Self::TooManyTiles(max_tiles) => write!(f, "Google Maps Places API client library: \
+                The search area needs more than {max_tiles} tiles. \
+                Use a larger radius, smaller bounds, or raise the limit with `with_max_tiles`."),
             #[cfg(feature = "reqwest")]
             Self::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "reqwest")]
@@ -0,0 +1,137 @@
+//! Contains the `GoogleMapsLinks` struct. It holds links that open a place in
+//! the consumer Google Maps app or website, for example to get directions to
+//! the place or to write a review of it.
+
+use crate::places::Place;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The [Maps URLs](https://developers.google.com/maps/documentation/urls/get-started)
+/// endpoint that searches for, and shows, a place.
+const SEARCH_URL: &str = "https://www.google.com/maps/search/?api=1";
+
+/// The [Maps URLs](https://developers.google.com/maps/documentation/urls/get-started)
+/// endpoint that shows directions.
+const DIRECTIONS_URL: &str = "https://www.google.com/maps/dir/?api=1";
+
+/// The URL that opens the review form of a place.
+const WRITE_REVIEW_URL: &str = "https://search.google.com/local/writereview";
+
+/// The URL that lists the reviews of a place.
+const REVIEWS_URL: &str = "https://search.google.com/local/reviews";
+
+// -----------------------------------------------------------------------------
+//
+/// Links that open a place in Google Maps. On mobile devices they open the
+/// Google Maps app when it is installed.
+///
+/// The links are built from the place's `place_id`, `name` and `url`, so
+/// request `Field::google_maps_links()` in a Place Details request. The field
+/// names mirror the `googleMapsLinks` object of the _Places API (New)_.
+///
+/// ## Example
+///
+/// ```rust
+/// if let Some(directions) = place.directions_link() {
+///     println!("<a href=\"{directions}\">Directions</a>");
+/// }
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct GoogleMapsLinks {
+    /// A link that shows the place.
+    pub place_uri: String,
+
+    /// A link that shows directions to the place. The origin is the user's
+    /// current location.
+    pub directions_uri: String,
+
+    /// A link that opens the form to write a review of the place.
+    pub write_a_review_uri: String,
+
+    /// A link that lists the reviews of the place.
+    pub reviews_uri: String,
+
+    /// A link that shows the photos of the place. The Places API doesn't
+    /// return one, and the place's `url` only shows the place, so this is
+    /// `None` for links built from a `Place`. Kept to mirror the
+    /// `googleMapsLinks` object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photos_uri: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsLinks {
+    /// Builds the links of a place. Returns `None` if the place has no
+    /// `place_id`.
+
+    #[must_use]
+    pub fn from_place(place: &Place) -> Option<Self> {
+        let place_id = place.place_id.as_deref()?;
+        let encoded_id = utf8_percent_encode(place_id, NON_ALPHANUMERIC).to_string();
+        // Maps URLs requires a query or destination, but the place ID takes
+        // precedence over it:
+        let encoded_name = utf8_percent_encode(
+            place.name.as_deref().or(place.formatted_address.as_deref()).unwrap_or(place_id),
+            NON_ALPHANUMERIC,
+        ).to_string();
+
+        Some(Self {
+            place_uri: place.url.clone().unwrap_or_else(||
+                format!("{SEARCH_URL}&query={encoded_name}&query_place_id={encoded_id}")),
+            directions_uri: format!(
+                "{DIRECTIONS_URL}&destination={encoded_name}&destination_place_id={encoded_id}"),
+            write_a_review_uri: format!("{WRITE_REVIEW_URL}?placeid={encoded_id}"),
+            reviews_uri: format!("{REVIEWS_URL}?placeid={encoded_id}"),
+            photos_uri: None,
+        }) // GoogleMapsLinks
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Returns the links that open this place in Google Maps. Returns `None`
+    /// if the place has no `place_id`.
+
+    #[must_use]
+    pub fn google_maps_links(&self) -> Option<GoogleMapsLinks> {
+        GoogleMapsLinks::from_place(self)
+    } // fn
+
+    /// Returns a link that shows directions to this place from the user's
+    /// current location. Returns `None` if the place has no `place_id`.
+
+    #[must_use]
+    pub fn directions_link(&self) -> Option<String> {
+        self.google_maps_links().map(|links| links.directions_uri)
+    } // fn
+
+    /// Returns a link that opens the form to write a review of this place.
+    /// Returns `None` if the place has no `place_id`.
+
+    #[must_use]
+    pub fn write_review_link(&self) -> Option<String> {
+        self.google_maps_links().map(|links| links.write_a_review_uri)
+    } // fn
+
+    /// Returns a link that lists the reviews of this place. Returns `None` if
+    /// the place has no `place_id`.
+
+    #[must_use]
+    pub fn reviews_link(&self) -> Option<String> {
+        self.google_maps_links().map(|links| links.reviews_uri)
+    } // fn
+
+    /// Returns a link that shows the photos of this place. The Places API
+    /// doesn't return one, so this returns `None` for now. See
+    /// `GoogleMapsLinks::photos_uri`.
+
+    #[must_use]
+    pub fn photos_link(&self) -> Option<String> {
+        self.google_maps_links().and_then(|links| links.photos_uri)
+    } // fn
+} // impl
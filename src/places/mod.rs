@@ -79,7 +79,10 @@ pub mod query_autocomplete;
 #[cfg(feature = "autocomplete")]
 pub use crate::places::place_autocomplete::{
     error::Error as AutocompleteError,
-    request::{autocomplete_type::AutocompleteType, Request as PlaceAutocompleteRequest}, // request
+    request::{
+        autocomplete_type::AutocompleteType, params::Params as PlaceAutocompleteParams,
+        Request as PlaceAutocompleteRequest,
+    }, // request
     response::{
         matched_substring::MatchedSubstring, prediction::Prediction,
         status::Status as AutocompleteStatus, structured_format::StructuredFormat, term::Term,
@@ -87,18 +90,32 @@ pub use crate::places::place_autocomplete::{
     }, // response
 }; // crate::places::place_autocomplete
 
+#[cfg(all(feature = "autocomplete", feature = "places", feature = "reqwest"))]
+pub use crate::places::place_autocomplete::response::with_context::{
+    ResponseWithContext as AutocompleteResponseWithContext, Suggestion as AutocompleteSuggestion,
+}; // crate::places::place_autocomplete::response::with_context
+
 #[cfg(feature = "autocomplete")]
 pub use crate::places::place_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::place_autocomplete
 
+#[cfg(feature = "autocomplete")]
+pub use crate::places::query_autocomplete::request::params::Params as QueryAutocompleteParams; // crate::places::query_autocomplete
+
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub mod attributions;
 #[cfg(feature = "places")]
 pub mod business_status;
 #[cfg(feature = "places")]
 pub mod error;
 #[cfg(feature = "places")]
+pub mod google_maps_links;
+#[cfg(feature = "places")]
 pub mod place;
 #[cfg(feature = "places")]
+pub mod place_delta;
+#[cfg(feature = "places")]
 pub mod place_details;
 #[cfg(feature = "places")]
 pub mod place_editorial_summary;
@@ -117,18 +134,28 @@ pub mod place_search;
 #[cfg(feature = "places")]
 pub mod place_special_day;
 #[cfg(feature = "places")]
+pub mod place_summary;
+#[cfg(feature = "places")]
+pub mod price_level;
+#[cfg(feature = "places")]
 pub mod rank_by;
 #[cfg(feature = "places")]
+pub mod ranker;
+#[cfg(feature = "places")]
 pub mod secondary_hours_type;
 #[cfg(feature = "places")]
 pub mod status;
+#[cfg(feature = "places")]
+pub mod string_interner;
 
 #[cfg(feature = "places")]
 pub use crate::places::{
-    business_status::BusinessStatus, error::Error, place::Place,
+    attributions::{Attributed, Attributions, AuthorAttribution},
+    business_status::BusinessStatus, error::Error, google_maps_links::GoogleMapsLinks, place::Place, place_delta::PlaceDelta,
     place_editorial_summary::PlaceEditorialSummary, place_opening_hours::PlaceOpeningHours,
     place_opening_hours_period::PlaceOpeningHoursPeriod,
     place_opening_hours_period_detail::PlaceOpeningHoursPeriodDetail, place_photo::PlacePhoto,
-    place_review::PlaceReview, place_special_day::PlaceSpecialDay, rank_by::RankBy,
-    secondary_hours_type::SecondaryHoursType, status::Status,
+    place_review::PlaceReview, place_special_day::PlaceSpecialDay, place_summary::PlaceSummary, price_level::PriceLevel, rank_by::RankBy,
+    ranker::{Ranker, WeightedRanker}, secondary_hours_type::SecondaryHoursType, status::Status,
+    string_interner::{InternStrings, StringInterner},
 }; // crate::places
@@ -4,9 +4,11 @@
 use crate::places::{
     BusinessStatus, PlaceEditorialSummary, PlaceOpeningHours, PlacePhoto, PlaceReview,
 };
-use crate::types::{AddressComponent, Geometry, PlaceType};
+use crate::types::{AddressComponent, Geometry, LatLng, PlaceType};
+use chrono::{DateTime, Duration, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 // -----------------------------------------------------------------------------
 //
@@ -98,16 +100,16 @@ pub struct Place {
     /// Contains the URL of a suggested icon which may be displayed to the user
     /// when indicating this result on a map.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon: Option<String>,
+    pub icon: Option<Arc<str>>,
 
     /// Contains the default HEX color code for the place's category.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon_background_color: Option<String>,
+    pub icon_background_color: Option<Arc<str>>,
 
     /// Contains the URL of a recommended icon, minus the `.svg` or `.png` file
     /// type extension.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub icon_mask_base_uri: Option<String>,
+    pub icon_mask_base_uri: Option<Arc<str>>,
 
     /// Contains the place's phone number in international format. International
     /// format includes the country code, and is prefixed with the plus, +,
@@ -286,6 +288,127 @@ pub struct Place {
 
 /// ----------------------------------------------------------------------------
 
+impl Place {
+    /// Returns whether the place is open at `at` and stays open for at least
+    /// `duration` afterwards. For example, a booking application can ask
+    /// whether a place is open for the next two hours.
+    ///
+    /// The place's `utc_offset` is used to convert `at` to the place's local
+    /// time, and `current_opening_hours` is preferred over `opening_hours`
+    /// because it accounts for holidays and other special days. Note that the
+    /// offset is the place's _current_ offset, so a daylight saving time
+    /// change within `duration` is not accounted for.
+    ///
+    /// Returns `None` if the place's `utc_offset` or opening hours were not
+    /// returned by Google.
+    ///
+    /// ```rust
+    /// let open_for_two_hours = place.is_open_for_at_least(Duration::hours(2), Utc::now());
+    /// ```
+
+    #[must_use]
+    pub fn is_open_for_at_least(&self, duration: Duration, at: DateTime<Utc>) -> Option<bool> {
+        let utc_offset = self.utc_offset?;
+        let opening_hours = self
+            .current_opening_hours
+            .as_ref()
+            .or(self.opening_hours.as_ref())?;
+        let local_time = at.naive_utc().checked_add_signed(Duration::minutes(i64::from(utc_offset)))?;
+        Some(opening_hours.is_open_for_at_least(local_time, duration))
+    } // fn
+
+    /// Returns the most specific type of the place, for example `Restaurant`
+    /// rather than `PointOfInterest`. The generic `Establishment`,
+    /// `PointOfInterest` and `Political` types are only returned when the
+    /// place has no other type. Returns `None` if no types were returned by
+    /// Google.
+
+    #[must_use]
+    pub fn primary_type(&self) -> Option<PlaceType> {
+        self.types
+            .iter()
+            .find(|place_type| !is_generic(**place_type))
+            .or_else(|| self.types.first())
+            .copied()
+    } // fn
+
+    /// Returns a human-readable, English label of the place's primary type,
+    /// for example "Restaurant", so that lists of places can always show a
+    /// category.
+    ///
+    /// ```rust
+    /// let category = place.primary_type_label().unwrap_or_default();
+    /// ```
+
+    #[must_use]
+    pub fn primary_type_label(&self) -> Option<String> {
+        self.primary_type().map(|place_type| place_type.display().to_string())
+    } // fn
+
+    /// Returns the place's location, or `None` if Google did not return its
+    /// geometry.
+
+    #[must_use]
+    pub fn location(&self) -> Option<LatLng> {
+        self.geometry.as_ref().map(|geometry| geometry.location)
+    } // fn
+
+    /// Returns the place's address: its `formatted_address` or, for nearby
+    /// search results, its `vicinity`. Returns `None` if neither was returned
+    /// or both are empty.
+
+    #[must_use]
+    pub fn address(&self) -> Option<&str> {
+        self.formatted_address
+            .as_deref()
+            .filter(|address| !address.is_empty())
+            .or_else(|| self.vicinity.as_deref().filter(|vicinity| !vicinity.is_empty()))
+    } // fn
+
+    /// Returns whether the place has both a location and an address, so that
+    /// it can be shown as a pin on a map and visited. Places without one, such
+    /// as service-area businesses, should be shown in lists only.
+    ///
+    /// ```rust
+    /// if let (true, Some(location)) = (place.has_physical_location(), place.location()) {
+    ///     map.add_pin(location);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn has_physical_location(&self) -> bool {
+        self.geometry.is_some() && self.address().is_some()
+    } // fn
+
+    /// Returns whether the place looks like a pure service-area business: a
+    /// business, such as a plumber or a mobile car wash, that serves
+    /// customers at their locations and has no storefront. Google hides the
+    /// address of these businesses, so they are recognized as places with a
+    /// name but no address. The location, if any, is only approximate.
+    ///
+    /// A _Place Details_ request that did not ask for the `formatted_address`
+    /// field also returns no address, so only use this on results that would
+    /// otherwise include one.
+
+    #[must_use]
+    pub fn is_pure_service_area_business(&self) -> bool {
+        self.name.is_some() && self.address().is_none()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns whether a place type is too generic to describe a place, because
+/// Google adds it to most places.
+const fn is_generic(place_type: PlaceType) -> bool {
+    matches!(
+        place_type,
+        PlaceType::Establishment | PlaceType::PointOfInterest | PlaceType::Political
+    ) // matches!
+} // fn
+
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Place {
     type Err = simd_json::Error;
     /// Parse a Google Maps Places API JSON response into a usable `Place`
@@ -50,9 +50,14 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::places::place_autocomplete::{
     error::Error,
-    request::{autocomplete_type::AutocompleteType, Request}, // request
+    request::{autocomplete_type::AutocompleteType, params::Params, Request}, // request
     response::{
         matched_substring::MatchedSubstring, prediction::Prediction, status::Status,
         structured_format::StructuredFormat, term::Term, Response,
     }, // response
 }; // place_autocomplete
+
+#[cfg(all(feature = "places", feature = "reqwest"))]
+pub use crate::places::place_autocomplete::response::with_context::{
+    ResponseWithContext, Suggestion,
+}; // crate::places::place_autocomplete::response::with_context
@@ -196,3 +196,25 @@ impl AutocompleteType {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl AutocompleteType {
+    /// Returns the code that Google uses for this `AutocompleteType`, for example
+    /// `geocode`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `AutocompleteType` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = AUTOCOMPLETE_TYPES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -2,6 +2,8 @@ use crate::error::Error as GoogleMapsError;
 use crate::places::place_autocomplete::{
     request::Request as PlaceAutocompleteRequest, response::Response as PlaceAutocompleteResponse,
 }; // crate::places::place_autocomplete
+#[cfg(feature = "places")]
+use crate::places::place_autocomplete::response::with_context::ResponseWithContext;
 
 // =============================================================================
 
@@ -23,4 +25,30 @@ impl<'a> PlaceAutocompleteRequest<'a> {
     pub async fn execute(&'a mut self) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, and keeps the session token and
+    /// language of the request with the response.
+    ///
+    /// ## Description
+    ///
+    /// The returned `ResponseWithContext` can send the _Place Details_ request
+    /// for the place the user picks with `Suggestion::fetch_place`, in the
+    /// same autocomplete session.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[cfg(feature = "places")]
+    pub async fn execute_with_context(
+        &'a mut self
+    ) -> Result<ResponseWithContext<'a>, GoogleMapsError> {
+        let client = self.client;
+        let sessiontoken = self.sessiontoken.as_ref().map(ToString::to_string);
+        let language = self.language;
+        let response = self.build().get().await?;
+        Ok(ResponseWithContext::new(client, response, sessiontoken, language))
+    } // fn
 } // impl
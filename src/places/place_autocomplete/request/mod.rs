@@ -5,12 +5,17 @@
 
 pub mod autocomplete_type;
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_channel;
 mod with_components;
 mod with_language;
 mod with_location;
@@ -25,6 +30,7 @@ mod with_types;
 use crate::client::GoogleMapsClient;
 use crate::places::place_autocomplete::request::autocomplete_type::AutocompleteType;
 use crate::types::{Country, Language, LatLng, Region};
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -43,7 +49,7 @@ pub struct Request<'a> {
     /// The text string on which to search. The Place Autocomplete service will
     /// return candidate matches based on this string and order results based on
     /// their perceived relevance.
-    input: String,
+    input: Cow<'a, str>,
 
     // Optional parameters:
     // --------------------
@@ -87,6 +93,11 @@ pub struct Request<'a> {
     ///   _utca_ and _tér_ are synonyms for street in Hungarian.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// The point around which to retrieve place information.
     ///
     /// * When using the Text Search API, the 'location' parameter may be
@@ -166,7 +177,7 @@ pub struct Request<'a> {
     /// * Be sure to pass a unique session token for each new session. Using the
     ///   same token for more than one session will result in each request being
     ///   billed individually.
-    sessiontoken: Option<String>,
+    sessiontoken: Option<Cow<'a, str>>,
 
     /// Returns only those places that are strictly within the region defined by
     /// `location` and `radius`. This is a restriction, rather than a bias,
@@ -183,6 +194,11 @@ pub struct Request<'a> {
     /// specifying no types.
     types: Vec<AutocompleteType>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
@@ -1,9 +1,10 @@
 use crate::client::GoogleMapsClient;
 use crate::places::place_autocomplete::request::Request;
+use std::borrow::Cow;
 
 // =============================================================================
 
-impl Request<'_> {
+impl<'a> Request<'a> {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Place API Place Autocomplete query
@@ -16,7 +17,10 @@ impl Request<'_> {
     /// * `input` ‧ The text string on which to search.
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request {
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        input: impl Into<Cow<'a, str>>
+    ) -> Self {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -25,6 +29,7 @@ impl Request<'_> {
             // Optional parameters:
             components: vec![],
             language: None,
+            accept_language: None,
             location: None,
             offset: None,
             origin: None,
@@ -33,6 +38,7 @@ impl Request<'_> {
             sessiontoken: None,
             strictbounds: None,
             types: vec![],
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
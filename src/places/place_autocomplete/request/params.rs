@@ -0,0 +1,137 @@
+//! Contains the `Params` struct. It holds the parameters of a Place
+//! Autocomplete request without the client, so that the request can be
+//! described in a configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::places::place_autocomplete::request::{autocomplete_type::AutocompleteType, Request};
+use crate::types::{Country, Language, LatLng, Region};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Place Autocomplete_ request, without the client. Unlike
+/// the request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: PlaceAutocompleteParams = serde_json::from_str(r#"{ "input": "Toronto" }"#)?;
+/// let predictions = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The text string on which to search.
+    pub input: String,
+
+    /// The countries to restrict the results to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Country>,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The point around which to retrieve place information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LatLng>,
+
+    /// The position of the last character of the input that is used for
+    /// predictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u8>,
+
+    /// The origin point from which to calculate straight-line distances.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<LatLng>,
+
+    /// The distance, in meters, within which to return place results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<u32>,
+
+    /// The region code, specified as a ccTLD two-character value, used to bias
+    /// results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// A random string that groups the requests of a session for billing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sessiontoken: Option<String>,
+
+    /// Whether to return only places that are strictly within the region.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strictbounds: Option<bool>,
+
+    /// The types of places to restrict the results to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<AutocompleteType>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            input: Cow::Owned(self.input),
+            components: self.components,
+            language: self.language,
+            accept_language: self.accept_language,
+            location: self.location,
+            offset: self.offset,
+            origin: self.origin,
+            radius: self.radius,
+            region: self.region,
+            sessiontoken: self.sessiontoken.map(Cow::Owned),
+            strictbounds: self.strictbounds,
+            types: self.types,
+            channel: self.channel,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            input: self.input.to_string(),
+            components: self.components.clone(),
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            location: self.location,
+            offset: self.offset,
+            origin: self.origin,
+            radius: self.radius,
+            region: self.region,
+            sessiontoken: self.sessiontoken.as_deref().map(str::to_string),
+            strictbounds: self.strictbounds,
+            types: self.types.clone(),
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
@@ -0,0 +1,37 @@
+use crate::built_request::BuiltRequest;
+use crate::canonical_request::CanonicalRequest;
+use crate::places::place_autocomplete::request::Request;
+use crate::places::place_autocomplete::error::Error;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string of the Places API _Place Autocomplete_ request.
+    ///
+    /// ## Errors
+    ///
+    /// * This request currently always builds.
+
+    pub fn try_build(&mut self) -> Result<BuiltRequest, Error> {
+        self.build();
+        Ok(BuiltRequest::new(
+            self.service_url(),
+            self.query.clone().unwrap_or_default(),
+        )) // BuiltRequest
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the request like `try_build`, with the headers that `execute`
+    /// sends, including the `Accept-Language` header.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the same errors as `try_build`.
+
+    pub fn inspect(&mut self) -> Result<BuiltRequest, Error> {
+        Ok(self.try_build()?.with_client_headers(self.client, self.accept_language.as_deref()))
+    } // fn
+} // impl
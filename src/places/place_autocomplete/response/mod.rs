@@ -8,6 +8,8 @@ pub mod prediction;
 pub mod status;
 pub mod structured_format;
 pub mod term;
+#[cfg(all(feature = "places", feature = "reqwest"))]
+pub mod with_context;
 
 // -----------------------------------------------------------------------------
 
@@ -73,3 +75,16 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::places::place_autocomplete::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
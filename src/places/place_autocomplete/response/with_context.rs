@@ -0,0 +1,161 @@
+//! Contains the `ResponseWithContext` and `Suggestion` structs. They carry the
+//! session token and language of a _Place Autocomplete_ request along with its
+//! response, so that the _Place Details_ request for the place the user picks
+//! can be sent with the same session.
+
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_autocomplete::response::{prediction::Prediction, Response};
+use crate::places::place_details::{Field, Response as PlaceDetailsResponse};
+use crate::types::Language;
+
+// -----------------------------------------------------------------------------
+//
+/// A _Place Autocomplete_ response, along with the client, session token and
+/// language of the request that produced it. Returned by
+/// `place_autocomplete::Request::execute_with_context`.
+///
+/// ## Example
+///
+/// ```rust
+/// let predictions = google_maps_client
+///     .place_autocomplete("51 Dundas")
+///     .with_sessiontoken(session_token)
+///     .execute_with_context()
+///     .await?;
+///
+/// if let Some(suggestion) = predictions.suggestions().next() {
+///     let details = suggestion
+///         .fetch_place([Field::Name, Field::FormattedAddress])
+///         .await?;
+/// }
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct ResponseWithContext<'a> {
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The _Place Autocomplete_ response.
+    pub response: Response,
+
+    /// The session token of the _Place Autocomplete_ request, if any.
+    sessiontoken: Option<String>,
+
+    /// The language of the _Place Autocomplete_ request, if any.
+    language: Option<Language>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> ResponseWithContext<'a> {
+    /// Wraps a response with the context of the request that produced it.
+
+    pub(crate) const fn new(
+        client: &'a GoogleMapsClient,
+        response: Response,
+        sessiontoken: Option<String>,
+        language: Option<Language>,
+    ) -> Self {
+        Self { client, response, sessiontoken, language }
+    } // fn
+
+    /// Returns the session token of the _Place Autocomplete_ request.
+
+    #[must_use]
+    pub fn sessiontoken(&self) -> Option<&str> {
+        self.sessiontoken.as_deref()
+    } // fn
+
+    /// Returns the language of the _Place Autocomplete_ request.
+
+    #[must_use]
+    pub const fn language(&self) -> Option<Language> {
+        self.language
+    } // fn
+
+    /// Returns the predictions that have a place ID, each with the context
+    /// needed to fetch its details.
+
+    pub fn suggestions(&self) -> impl Iterator<Item = Suggestion<'_>> {
+        self.response
+            .predictions
+            .iter()
+            .filter_map(|prediction| {
+                prediction.place_id.as_deref().map(|place_id| Suggestion {
+                    client: self.client,
+                    prediction,
+                    place_id,
+                    sessiontoken: self.sessiontoken.as_deref(),
+                    language: self.language,
+                }) // map
+            }) // filter_map
+    } // fn
+
+    /// Discards the context and returns the _Place Autocomplete_ response.
+
+    #[must_use]
+    pub fn into_response(self) -> Response {
+        self.response
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// One prediction of a `ResponseWithContext`, with the context needed to
+/// fetch its details.
+
+#[derive(Clone, Copy, Debug)]
+pub struct Suggestion<'a> {
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The prediction.
+    pub prediction: &'a Prediction,
+
+    /// The place ID of the prediction.
+    place_id: &'a str,
+
+    /// The session token of the _Place Autocomplete_ request, if any.
+    sessiontoken: Option<&'a str>,
+
+    /// The language of the _Place Autocomplete_ request, if any.
+    language: Option<Language>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Suggestion<'_> {
+    /// Sends a _Place Details_ request for the suggested place, with the
+    /// session token and language of the _Place Autocomplete_ request. This
+    /// concludes the autocomplete session.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fields` ‧ The place data fields to return. See
+    ///   `place_details::Request::with_fields`. If no fields are specified,
+    ///   all fields are returned and billed.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the error of the _Place Details_ request, if it fails.
+
+    pub async fn fetch_place<C, F>(&self, fields: C) -> Result<PlaceDetailsResponse, GoogleMapsError>
+    where
+        C: IntoIterator<Item = F>,
+        F: Into<Field> {
+        let mut place_details = self.client.place_details(self.place_id);
+        let request = place_details.with_fields(fields);
+        let request = match self.sessiontoken {
+            Some(sessiontoken) => request.with_sessiontoken(sessiontoken),
+            None => request,
+        }; // match
+        let request = match self.language {
+            Some(language) => request.with_language(language),
+            None => request,
+        }; // match
+        request.execute().await
+    } // fn
+} // impl
@@ -0,0 +1,174 @@
+//! Contains the `PlaceDelta` struct which describes how a place changed between
+//! two snapshots. Useful for change-feed pipelines that periodically poll
+//! place details.
+
+use crate::places::{place_details::Field, Place};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The differences between two snapshots of the same place, as produced by
+/// `Place::diff`.
+///
+/// Only the fields present in `changed_fields` differ. Fields that were not
+/// requested in either snapshot compare as equal (both absent) and are
+/// therefore never reported as changed.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PlaceDelta {
+    /// The fields whose values differ between the old and the new snapshot, in
+    /// the order they're declared on `Place`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed_fields: Vec<Field>,
+    /// The change in star rating (`new - old`). Only present when both
+    /// snapshots have a rating and the rating changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating_delta: Option<Decimal>,
+    /// The change in the total number of user ratings (`new - old`). Only
+    /// present when both snapshots have a rating count and the count changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_ratings_total_delta: Option<i64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PlaceDelta {
+    /// Returns `true` if no fields changed between the two snapshots.
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changed_fields.is_empty()
+    } // fn
+
+    /// Returns `true` if the specified field changed between the two
+    /// snapshots.
+
+    #[must_use]
+    pub fn contains(&self, field: &Field) -> bool {
+        self.changed_fields.contains(field)
+    } // fn
+
+    /// Returns `true` if the regular, current, or secondary opening hours
+    /// changed.
+
+    #[must_use]
+    pub fn hours_changed(&self) -> bool {
+        self.contains(&Field::OpeningHours)
+            || self.contains(&Field::CurrentOpeningHours)
+            || self.contains(&Field::SecondaryOpeningHours)
+    } // fn
+
+    /// Returns `true` if the formatted or international phone number changed.
+
+    #[must_use]
+    pub fn phone_changed(&self) -> bool {
+        self.contains(&Field::FormattedPhoneNumber)
+            || self.contains(&Field::InternationalPhoneNumber)
+    } // fn
+
+    /// Returns `true` if the business status changed. For example, a place
+    /// that went from `Operational` to `ClosedPermanently`.
+
+    #[must_use]
+    pub fn status_changed(&self) -> bool {
+        self.contains(&Field::BusinessStatus)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Compares two snapshots of the same place and returns the fields that
+    /// changed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `old` ‧ The earlier snapshot, for example from last night's poll.
+    ///
+    /// * `new` ‧ The most recent snapshot.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let delta = Place::diff(&yesterday, &today);
+    /// if delta.status_changed() || delta.hours_changed() {
+    ///     notify(&today, &delta);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn diff(old: &Self, new: &Self) -> PlaceDelta {
+        let mut changed_fields = Vec::new();
+
+        let mut compare = |changed: bool, field: Field| {
+            if changed {
+                changed_fields.push(field);
+            } // if
+        }; // closure
+
+        compare(old.address_components != new.address_components, Field::AddressComponent);
+        compare(old.adr_address != new.adr_address, Field::AdrAddress);
+        compare(old.business_status != new.business_status, Field::BusinessStatus);
+        compare(old.curbside_pickup != new.curbside_pickup, Field::CurbsidePickup);
+        compare(old.current_opening_hours != new.current_opening_hours, Field::CurrentOpeningHours);
+        compare(old.delivery != new.delivery, Field::Delivery);
+        compare(old.dine_in != new.dine_in, Field::DineIn);
+        compare(old.editorial_summary != new.editorial_summary, Field::EditorialSummary);
+        compare(old.formatted_address != new.formatted_address, Field::FormattedAddress);
+        compare(old.formatted_phone_number != new.formatted_phone_number, Field::FormattedPhoneNumber);
+        compare(old.geometry != new.geometry, Field::Geometry);
+        compare(old.icon != new.icon, Field::Icon);
+        compare(old.icon_background_color != new.icon_background_color, Field::IconBackgroundColor);
+        compare(old.icon_mask_base_uri != new.icon_mask_base_uri, Field::IconMaskBaseUri);
+        compare(old.international_phone_number != new.international_phone_number, Field::InternationalPhoneNumber);
+        compare(old.name != new.name, Field::Name);
+        compare(old.opening_hours != new.opening_hours, Field::OpeningHours);
+        compare(old.photos != new.photos, Field::Photo);
+        compare(old.place_id != new.place_id, Field::PlaceId);
+        compare(old.price_level != new.price_level, Field::PriceLevel);
+        compare(old.rating != new.rating, Field::Rating);
+        compare(old.reservable != new.reservable, Field::Reservable);
+        compare(old.reviews != new.reviews, Field::Reviews);
+        compare(old.secondary_opening_hours != new.secondary_opening_hours, Field::SecondaryOpeningHours);
+        compare(old.serves_beer != new.serves_beer, Field::ServesBeer);
+        compare(old.serves_breakfast != new.serves_breakfast, Field::ServesBreakfast);
+        compare(old.serves_brunch != new.serves_brunch, Field::ServesBrunch);
+        compare(old.serves_dinner != new.serves_dinner, Field::ServesDinner);
+        compare(old.serves_lunch != new.serves_lunch, Field::ServesLunch);
+        compare(old.serves_vegetarian_food != new.serves_vegetarian_food, Field::ServesVegetarianFood);
+        compare(old.serves_wine != new.serves_wine, Field::ServesWine);
+        compare(old.takeout != new.takeout, Field::Takeout);
+        compare(old.types != new.types, Field::Type);
+        compare(old.url != new.url, Field::Url);
+        compare(old.user_ratings_total != new.user_ratings_total, Field::UserRatingsTotal);
+        compare(old.utc_offset != new.utc_offset, Field::UtcOffset);
+        compare(old.vicinity != new.vicinity, Field::Vicinity);
+        compare(old.website != new.website, Field::Website);
+        compare(
+            old.wheelchair_accessible_entrance != new.wheelchair_accessible_entrance,
+            Field::WheelchairAccessibleEntrance,
+        );
+
+        let rating_delta = match (old.rating, new.rating) {
+            (Some(old), Some(new)) if old != new => Some(new - old),
+            _ => None,
+        }; // match
+
+        let user_ratings_total_delta = match (old.user_ratings_total, new.user_ratings_total) {
+            (Some(old), Some(new)) if old != new => {
+                let old = i64::try_from(old).unwrap_or(i64::MAX);
+                let new = i64::try_from(new).unwrap_or(i64::MAX);
+                Some(new.saturating_sub(old))
+            }
+            _ => None,
+        }; // match
+
+        PlaceDelta {
+            changed_fields,
+            rating_delta,
+            user_ratings_total_delta,
+        } // PlaceDelta
+    } // fn
+} // impl
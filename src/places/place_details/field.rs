@@ -71,6 +71,7 @@ pub enum Field {
     ServesBeer = 32,
     ServesBreakfast = 33,
     ServesBrunch = 34,
+    ServesDinner = 41,
     ServesLunch = 35,
     ServesVegetarianFood = 36,
     ServesWine = 37,
@@ -158,6 +159,7 @@ impl<'a> std::convert::From<&'a Field> for &'a str {
             Field::ServesBeer => "serves_beer",
             Field::ServesBreakfast => "serves_breakfast",
             Field::ServesBrunch => "serves_brunch",
+            Field::ServesDinner => "serves_dinner",
             Field::ServesLunch => "serves_lunch",
             Field::ServesVegetarianFood => "serves_vegetarian_food",
             Field::ServesWine => "serves_wine",
@@ -240,6 +242,7 @@ static FIELD_TYPES_BY_CODE: phf::Map<&'static str, Field> = phf_map! {
     "serves_beer" => Field::ServesBeer,
     "serves_breakfast" => Field::ServesBreakfast,
     "serves_brunch" => Field::ServesBrunch,
+    "serves_dinner" => Field::ServesDinner,
     "serves_lunch" => Field::ServesLunch,
     "serves_vegetarian_food" => Field::ServesVegetarianFood,
     "serves_wine" => Field::ServesWine,
@@ -323,6 +326,7 @@ impl Field {
             Self::ServesBeer => "Serves Beer",
             Self::ServesBreakfast => "Serves Breakfast",
             Self::ServesBrunch => "Serves Brunch",
+            Self::ServesDinner => "Serves Dinner",
             Self::ServesLunch => "Serves Lunch",
             Self::ServesVegetarianFood => "Serves Vegetarian Food",
             Self::ServesWine => "Serves Wine",
@@ -347,4 +351,34 @@ impl Field {
             .collect::<Vec<String>>()
             .join(",")
     } // fn
+
+    /// Returns the fields that `Place::google_maps_links` uses to build the
+    /// links that open a place in Google Maps. All of them are Basic fields.
+
+    #[must_use]
+    pub fn google_maps_links() -> Vec<Self> {
+        vec![Self::Name, Self::PlaceId, Self::Url]
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Field {
+    /// Returns the code that Google uses for this `Field`, for example
+    /// `address_component`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Field` variant, in declaration
+    /// order. Variants that hold an unrecognized code are not included.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = FIELD_TYPES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
 } // impl
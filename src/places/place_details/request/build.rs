@@ -4,7 +4,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     /// Builds the query string for the Google Maps Places API _Place
     /// Details_ query based on the input provided by the client.
     ///
@@ -12,7 +12,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -32,13 +32,13 @@ impl<'a> Request<'a> {
         }
 
         // Language key/value pair:
-        if let Some(language) = &self.language {
+        if let Some(language) = self.language.as_ref().or(self.client.language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
 
         // Region key/value pair:
-        if let Some(region) = &self.region {
+        if let Some(region) = self.region.as_ref().or(self.client.region.as_ref()) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         }
@@ -58,7 +58,13 @@ impl<'a> Request<'a> {
         // Session Token key/value pair:
         if let Some(sessiontoken) = &self.sessiontoken {
             query.push_str("&sessiontoken=");
-            query.push_str(&String::from(sessiontoken));
+            query.push_str(sessiontoken);
+        }
+
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
         }
 
         // Set query string in Request struct.
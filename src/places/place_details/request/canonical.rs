@@ -0,0 +1,21 @@
+use crate::canonical_request::{accept_language_variant, CanonicalRequest};
+use crate::places::place_details::{request::Request, OUTPUT_FORMAT, SERVICE_URL};
+
+// =============================================================================
+
+impl CanonicalRequest for Request<'_> {
+    /// Returns the URL of the service, without the query string.
+    fn service_url(&self) -> String {
+        format!("{SERVICE_URL}/{OUTPUT_FORMAT}")
+    } // fn
+
+    /// Returns the query string built by `build`, if any.
+    fn built_query(&self) -> Option<&str> {
+        self.query.as_deref()
+    } // fn
+
+    /// Returns the `Accept-Language` header sent with the request, if any.
+    fn cache_variants(&self) -> Vec<String> {
+        accept_language_variant(self.client, self.accept_language.as_deref())
+    } // fn
+} // impl
@@ -4,12 +4,17 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_channel;
 mod with_fields;
 mod with_language;
 mod with_no_review_translations;
@@ -21,6 +26,7 @@ mod with_sessiontoken;
 
 use crate::places::place_details::{Field, SortOrder};
 use crate::{client::GoogleMapsClient, types::Language, types::Region};
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -40,7 +46,7 @@ pub struct Request<'a> {
     /// [Place Search](https://developers.google.com/maps/documentation/places/web-service/search).
     /// For more information about place IDs, see the
     /// [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
-    place_id: String,
+    place_id: Cow<'a, str>,
 
     // Optional parameters:
     // --------------------
@@ -94,6 +100,11 @@ pub struct Request<'a> {
     ///   _utca_ and _tér_ are synonyms for street in Hungarian.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// The region code, specified as a [ccTLD ("top-level
     /// domain")](https://en.wikipedia.org/wiki/List_of_Internet_top-level_domains#Country_code_top-level_domains)
     /// two-character value. Most ccTLD codes are identical to ISO 3166-1 codes,
@@ -155,7 +166,12 @@ pub struct Request<'a> {
     /// * Be sure to pass a unique session token for each new session. Using the
     ///   same token for more than one session will result in each request being
     ///   billed individually.
-    sessiontoken: Option<String>,
+    sessiontoken: Option<Cow<'a, str>>,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
 
     // Internal use only:
     // ------------------
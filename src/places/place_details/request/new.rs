@@ -1,9 +1,10 @@
 use crate::client::GoogleMapsClient;
 use crate::places::place_details::request::Request;
+use std::borrow::Cow;
 
 // =============================================================================
 
-impl Request<'_> {
+impl<'a> Request<'a> {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Places API _Place Details_ query
@@ -20,7 +21,10 @@ impl Request<'_> {
     ///   [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, place_id: impl Into<String>) -> Request {
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        place_id: impl Into<Cow<'a, str>>
+    ) -> Self {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -29,10 +33,12 @@ impl Request<'_> {
             // Optional parameters:
             fields: Vec::new(),
             language: None,
+            accept_language: None,
             region: None,
             reviews_no_translations: None,
             reviews_sort: None,
             sessiontoken: None,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
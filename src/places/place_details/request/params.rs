@@ -0,0 +1,113 @@
+//! Contains the `Params` struct. It holds the parameters of a Place Details
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::places::place_details::{request::Request, Field, SortOrder};
+use crate::types::{Language, Region};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Place Details_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: PlaceDetailsParams = serde_json::from_str(job)?;
+/// let place = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The place ID of the place for which details are being requested.
+    pub place_id: String,
+
+    /// The place data fields to return. All fields are returned when this is
+    /// empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field>,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The region code, specified as a ccTLD two-character value, used to bias
+    /// results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// Whether reviews are returned in their original language.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviews_no_translations: Option<bool>,
+
+    /// How to sort the reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviews_sort: Option<SortOrder>,
+
+    /// A random string that groups the requests of a session for billing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sessiontoken: Option<String>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            place_id: Cow::Owned(self.place_id),
+            fields: self.fields,
+            language: self.language,
+            accept_language: self.accept_language,
+            region: self.region,
+            reviews_no_translations: self.reviews_no_translations,
+            reviews_sort: self.reviews_sort,
+            sessiontoken: self.sessiontoken.map(Cow::Owned),
+            channel: self.channel,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            place_id: self.place_id.to_string(),
+            fields: self.fields.clone(),
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            region: self.region,
+            reviews_no_translations: self.reviews_no_translations,
+            reviews_sort: self.reviews_sort.clone(),
+            sessiontoken: self.sessiontoken.as_deref().map(str::to_string),
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
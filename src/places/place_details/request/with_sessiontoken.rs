@@ -1,4 +1,5 @@
 use crate::places::place_details::request::Request;
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -38,7 +39,7 @@ impl<'a> Request<'a> {
 
     pub fn with_sessiontoken(
         &'a mut self,
-        sessiontoken: impl Into<String>
+        sessiontoken: impl Into<Cow<'a, str>>
     ) -> &'a mut Self {
         // Set session token in Request struct.
         self.sessiontoken = Some(sessiontoken.into());
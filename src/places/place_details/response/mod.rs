@@ -58,3 +58,16 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::places::error::Error::GoogleMapsService(self.status.clone(), None))?
+        } // if
+    } // fn
+} // impl
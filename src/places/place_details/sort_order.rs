@@ -137,3 +137,25 @@ impl SortOrder {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl SortOrder {
+    /// Returns the code that Google uses for this `SortOrder`, for example
+    /// `most_relevant`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `SortOrder` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = SORT_ORDER_TYPES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
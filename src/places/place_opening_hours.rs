@@ -1,7 +1,7 @@
 //! An object describing the opening hours of a place.
 
 use crate::places::{PlaceOpeningHoursPeriod, PlaceSpecialDay, SecondaryHoursType};
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -62,8 +62,8 @@ impl std::str::FromStr for PlaceOpeningHours {
 //
 /// A helper function that returns the dates of upcoming special days for a
 /// place. This is meant to be used with the `Place.current_opening_hours`
-/// field. Using this with the `Place.current_opening_hours` will likely just
-/// return an empty `HashSet`.
+/// field. Using this with the `Place.opening_hours` will likely just return
+/// an empty `HashSet`.
 
 impl PlaceOpeningHours {
     #[must_use]
@@ -73,4 +73,106 @@ impl PlaceOpeningHours {
             .filter_map(|place_special_day| place_special_day.date)
             .collect::<HashSet<NaiveDate>>()
     } // fn
+
+    /// Returns the special day entry for `date`, if Google listed one. Only
+    /// `Place.current_opening_hours` and `Place.secondary_opening_hours` have
+    /// special days, and only for the next seven days.
+
+    #[must_use]
+    pub fn special_day(&self, date: NaiveDate) -> Option<&PlaceSpecialDay> {
+        self.special_days
+            .iter()
+            .find(|place_special_day| place_special_day.date == Some(date))
+    } // fn
+
+    /// Returns `true` if the place keeps exceptional hours on `date`, such as
+    /// holiday hours, so that its `periods` for that date differ from its
+    /// regular week. A special day without an `exceptional_hours` flag is
+    /// treated as exceptional.
+    ///
+    /// ```rust
+    /// if hours.is_holiday_schedule(today) {
+    ///     println!("Holiday hours may apply");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn is_holiday_schedule(&self, date: NaiveDate) -> bool {
+        self.special_day(date)
+            .is_some_and(|place_special_day| place_special_day.exceptional_hours != Some(false))
+    } // fn
+
+    /// Returns `true` if any period was cut off at the edge of the seven days
+    /// that `Place.current_opening_hours` covers. This happens for places that
+    /// are open across midnight at either end of the window, or open for more
+    /// than seven days in a row, so the place may open earlier or close later
+    /// than its periods show.
+
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.periods.iter().any(|period| {
+            period.open.truncated == Some(true)
+                || period.close.as_ref().is_some_and(|close| close.truncated == Some(true))
+        }) // any
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PlaceOpeningHours {
+    /// Returns `true` if the place is open at `local_time` and stays open for
+    /// at least `duration` afterwards. Consecutive periods, such as a place
+    /// that closes at midnight and re-opens at midnight, are treated as one.
+    ///
+    /// `local_time` must be in the place's local time. Periods with a `date`,
+    /// as found in `Place.current_opening_hours`, are used as-is. Other
+    /// periods are assumed to repeat every week.
+
+    #[must_use]
+    pub fn is_open_for_at_least(&self, local_time: NaiveDateTime, duration: Duration) -> bool {
+        // An always-open place is represented by a period without a close:
+        if self.periods.iter().any(|period| period.close.is_none()) {
+            return true;
+        } // if
+
+        let week_start = local_time.date()
+            - Duration::days(i64::from(local_time.weekday().num_days_from_sunday()));
+
+        let mut intervals: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+        for period in &self.periods {
+            let Some(close) = &period.close else { continue };
+            if let (Some(open_date), Some(close_date)) = (period.open.date, close.date) {
+                intervals.push((open_date.and_time(period.open.time), close_date.and_time(close.time)));
+                continue;
+            } // if
+            // Weekly periods are expanded over the previous, current, and next
+            // week so that periods spanning the end of a week are found:
+            for week in -1..=1_i64 {
+                let open_date = week_start
+                    + Duration::days(7 * week + i64::from(period.open.day.num_days_from_sunday()));
+                let opens = open_date.and_time(period.open.time);
+                let close_date = open_date + Duration::days(i64::from(close.day.days_since(period.open.day)));
+                let mut closes = close_date.and_time(close.time);
+                if closes <= opens {
+                    closes += Duration::days(7);
+                } // if
+                intervals.push((opens, closes));
+            } // for
+        } // for
+
+        intervals.sort();
+
+        let required_until = local_time + duration;
+        let mut open_until = local_time;
+        for (opens, closes) in intervals {
+            if opens <= open_until && closes > open_until {
+                open_until = closes;
+                if open_until >= required_until {
+                    return true;
+                } // if
+            } // if
+        } // for
+
+        false
+    } // fn
 } // impl
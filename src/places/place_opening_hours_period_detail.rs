@@ -2,7 +2,7 @@
 
 use chrono::{NaiveDate, NaiveTime, Weekday};
 use serde::de::{Unexpected, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // -----------------------------------------------------------------------------
 //
@@ -12,12 +12,12 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub struct PlaceOpeningHoursPeriodDetail {
     /// A number from 0–6, corresponding to the days of the week, starting on
     /// Sunday. For example, 2 means Tuesday.
-    #[serde(deserialize_with = "integer_as_weekday")]
+    #[serde(deserialize_with = "integer_as_weekday", serialize_with = "weekday_as_integer")]
     pub day: Weekday,
 
     /// May contain a time of day in 24-hour hhmm format. Values are in the
     /// range 0000–2359. The time will be reported in the place’s time zone.
-    #[serde(deserialize_with = "str_as_naive_time")]
+    #[serde(deserialize_with = "str_as_naive_time", serialize_with = "naive_time_as_str")]
     pub time: NaiveTime,
 
     /// A date expressed in RFC3339 format in the local timezone for the place,
@@ -35,6 +35,17 @@ pub struct PlaceOpeningHoursPeriodDetail {
 
 // -----------------------------------------------------------------------------
 
+// Serde passes the field by reference:
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn weekday_as_integer<S>(weekday: &Weekday, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // Serialize in the same form Google uses, so that a serialized period can
+    // be deserialized again:
+    serializer.serialize_u8(weekday.num_days_from_sunday().try_into().unwrap_or_default())
+} // fn weekday_as_integer
+
 fn integer_as_weekday<'de, D>(deserializer: D) -> Result<Weekday, D::Error>
 where
     D: Deserializer<'de>,
@@ -71,10 +82,38 @@ impl Visitor<'_> for WeekdayVisitor {
             ), // _
         } // match
     } // fn visit_u64
+
+    // Buffered deserializers, such as the one `#[serde(flatten)]` uses, may
+    // present the weekday as a signed integer:
+    fn visit_i64<E>(self, value: i64) -> Result<Weekday, E>
+    where
+        E: serde::de::Error,
+    {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_error) => Err(
+                E::invalid_value(
+                    Unexpected::Signed(value),
+                    &"weekday between 0 and 6, where 0 is sunday",
+                ), // invalid_value
+            ), // Err
+        } // match
+    } // fn visit_i64
 } // impl Visitor
 
 // -----------------------------------------------------------------------------
 
+// Serde passes the field by reference:
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn naive_time_as_str<S>(time: &NaiveTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // Serialize in the same form Google uses, so that a serialized period can
+    // be deserialized again:
+    serializer.serialize_str(&time.format("%H%M").to_string())
+} // fn naive_time_as_str
+
 fn str_as_naive_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
 where
     D: Deserializer<'de>,
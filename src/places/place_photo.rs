@@ -2,6 +2,16 @@
 /// [Place Photo](https://developers.google.com/places/web-service/photos) API
 /// using a URL.
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+
+/// The URL of the Google Maps Place Photo service.
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/photo";
+
+/// The largest width or height, in pixels, that the Place Photo service will
+/// return. Larger values are clamped to this value by Google.
+pub const MAX_PHOTO_DIMENSION: u16 = 1_600;
 
 // -----------------------------------------------------------------------------
 //
@@ -22,7 +32,7 @@ pub struct PlacePhoto {
     /// The HTML attributions for the photo.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub html_attributions: Vec<String>,
+    pub html_attributions: Vec<Arc<str>>,
     /// A string used to identify the photo when you perform a Photo request.
     pub photo_reference: String,
     /// The width of the photo.
@@ -40,3 +50,177 @@ impl std::str::FromStr for PlacePhoto {
         simd_json::serde::from_slice(&mut bytes)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl PlacePhoto {
+    /// Returns the URL of this photo from the
+    /// [Place Photo](https://developers.google.com/maps/documentation/places/web-service/photos)
+    /// service, scaled to fit within the given maximum dimensions.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Your application's API key.
+    ///
+    /// * `max_width` ‧ The maximum desired width, in pixels, of the image. If
+    ///   the image is smaller than the value specified, the original image
+    ///   will be returned. If the image is larger, it will be scaled to match
+    ///   the smaller of the two dimensions, restricted to its original aspect
+    ///   ratio.
+    ///
+    /// * `max_height` ‧ The maximum desired height, in pixels, of the image.
+    ///
+    /// Google requires at least one of `max_width` or `max_height`. If neither
+    /// is provided, the photo's own width is used. Both values are clamped to
+    /// the range `1..=1600`.
+    ///
+    /// Note that the returned URL contains your API key. Avoid exposing it to
+    /// end users; consider using `download` to proxy the image instead.
+    #[must_use]
+    pub fn uri_with_size(
+        &self,
+        key: &str,
+        max_width: Option<u16>,
+        max_height: Option<u16>
+    ) -> String {
+        let clamp = |dimension: u16| dimension.clamp(1, MAX_PHOTO_DIMENSION);
+
+        let mut url = format!("{SERVICE_URL}?photo_reference={}", self.photo_reference);
+
+        match (max_width, max_height) {
+            (None, None) => {
+                url.push_str("&maxwidth=");
+                url.push_str(&clamp(self.width).to_string());
+            } // case
+            (max_width, max_height) => {
+                if let Some(max_width) = max_width {
+                    url.push_str("&maxwidth=");
+                    url.push_str(&clamp(max_width).to_string());
+                } // if
+                if let Some(max_height) = max_height {
+                    url.push_str("&maxheight=");
+                    url.push_str(&clamp(max_height).to_string());
+                } // if
+            } // case
+        } // match
+
+        url.push_str("&key=");
+        url.push_str(key);
+        url
+    } // fn
+
+    /// Returns the photo's attributions as a single HTML fragment, suitable for
+    /// display next to the photo. Google requires these attributions to be
+    /// shown whenever the photo is displayed. Returns `None` if the photo has
+    /// no attributions.
+    #[must_use]
+    pub fn attributions_html(&self) -> Option<String> {
+        if self.html_attributions.is_empty() {
+            None
+        } else {
+            Some(self.html_attributions.join(", "))
+        } // if
+    } // fn
+
+    /// Returns the photo's attributions as plain text, with HTML tags removed.
+    /// Useful for surfaces that can't render HTML, such as terminal output or
+    /// image captions. Returns `None` if the photo has no attributions.
+    #[must_use]
+    pub fn attributions_text(&self) -> Option<String> {
+        self.attributions_html().map(|html| strip_html_tags(&html))
+    } // fn
+
+    /// Downloads this photo from the Place Photo service and returns the raw
+    /// image bytes. The request observes the client's rate limits for the
+    /// Places API.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `max_width` & `max_height` ‧ The maximum desired dimensions of the
+    ///   image. See `uri_with_size` for details.
+    #[cfg(feature = "reqwest")]
+    pub async fn download(
+        &self,
+        client: &crate::client::GoogleMapsClient,
+        max_width: Option<u16>,
+        max_height: Option<u16>
+    ) -> Result<Vec<u8>, crate::error::Error> {
+        use crate::places::error::Error as PlacesError;
+        use crate::request_rate::api::Api;
+
+        let url = self.uri_with_size(&client.key, max_width, max_height);
+
+        client
+            .rate_limit
+            .limit_apis(vec![&Api::All, &Api::Places, &Api::PlacePhoto])
+            .await;
+
+        // Fail fast if the API has been failing:
+        client.check_circuit(&Api::Places)?;
+
+        tracing::info!("making HTTP GET request to Google Maps Place Photo service");
+
+        let response = client.get_request(&url).await;
+        client.record_circuit_outcome(&Api::Places, &response);
+        let response = response.map_err(PlacesError::Reqwest)?;
+
+        if !response.status().is_success() {
+            return Err(PlacesError::HttpUnsuccessful(response.status().to_string()).into());
+        } // if
+
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|error| PlacesError::Reqwest(crate::ReqError::from(error)))?;
+
+        Ok(bytes.to_vec())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The HTML entities that are decoded in attribution text, and the characters
+/// they stand for.
+const HTML_ENTITIES: [(&str, char); 5] = [
+    ("&amp;", '&'),
+    ("&lt;", '<'),
+    ("&gt;", '>'),
+    ("&quot;", '"'),
+    ("&#39;", '\''),
+];
+
+/// Removes HTML tags from an attribution string, leaving only its text. For
+/// example, `<a href="https://maps.google.com/maps/contrib/1">Jane Doe</a>`
+/// becomes `Jane Doe`.
+///
+/// Entities are decoded in the same pass, so that decoded text is never
+/// decoded again: `&amp;lt;` becomes `&lt;`, not `<`.
+pub(crate) fn strip_html_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    let mut rest = html;
+
+    while let Some(character) = rest.chars().next() {
+        let mut length = character.len_utf8();
+        match character {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            '&' if !in_tag => {
+                match HTML_ENTITIES.iter().find(|(entity, _)| rest.starts_with(entity)) {
+                    Some((entity, decoded)) => {
+                        text.push(*decoded);
+                        length = entity.len();
+                    } // Some
+                    None => text.push('&'),
+                } // match
+            } // '&'
+            _ if !in_tag => text.push(character),
+            _ => (),
+        } // match
+        rest = &rest[length..];
+    } // while
+
+    text
+} // fn
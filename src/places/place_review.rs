@@ -3,7 +3,7 @@
 use crate::types::Language;
 use chrono::{DateTime, LocalResult, TimeZone, Utc};
 use serde::de::{Unexpected, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // -----------------------------------------------------------------------------
 //
@@ -25,7 +25,7 @@ pub struct PlaceReview {
 
     /// The time that the review was submitted, measured in the number of
     /// seconds since since midnight, January 1, 1970 UTC.
-    #[serde(deserialize_with = "integer_as_date_time")]
+    #[serde(deserialize_with = "integer_as_date_time", serialize_with = "date_time_as_integer")]
     pub time: DateTime<Utc>,
 
     /// The URL to the user's Google Maps Local Guides profile, if available.
@@ -71,6 +71,15 @@ pub struct PlaceReview {
 
 // -----------------------------------------------------------------------------
 
+fn date_time_as_integer<S>(date_time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // Serialize in the same form Google uses, so that a serialized review can
+    // be deserialized again:
+    serializer.serialize_i64(date_time.timestamp())
+} // fn date_time_as_integer
+
 fn integer_as_date_time<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 where
     D: Deserializer<'de>,
@@ -104,6 +113,15 @@ impl Visitor<'_> for DateTimeUtcVisitor {
             } // Err
         }; // match
 
+        self.visit_i64(value)
+    } // fn visit_u64
+
+    // Buffered deserializers, such as the one `#[serde(flatten)]` uses, may
+    // present the timestamp as a signed integer:
+    fn visit_i64<E>(self, value: i64) -> Result<DateTime<Utc>, E>
+    where
+        E: serde::de::Error,
+    {
         match Utc.timestamp_opt(value, 0) {
             LocalResult::Single(date_time_utc) => Ok(date_time_utc),
             _ => Err(
@@ -46,5 +46,17 @@
 //! Nearby Search and Text Search allow additional parameters to filter results,
 //! (e.g. `minprice`, `maxprice`, `opennow`, and `type`).
 
+pub mod multi_area_search;
 pub mod nearby_search;
+pub mod pagination;
 pub mod text_search;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::place_search::multi_area_search::{
+    MultiAreaSearch, MultiAreaSearchResponse, MultiAreaSearchTile,
+}; // multi_area_search
+
+pub use crate::places::place_search::pagination::{
+    BudgetExhausted, PageBudget, PageToken, SearchPage,
+}; // pagination
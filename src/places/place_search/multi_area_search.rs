@@ -0,0 +1,312 @@
+//! Contains the `MultiAreaSearch` struct. It covers a bounding box that is too
+//! large for a single _Nearby Search_ with several overlapping circles, and
+//! merges the results.
+
+use crate::client::GoogleMapsClient;
+use crate::places::{Place, Ranker};
+use crate::types::{Bounds, Language, LatLng, PlaceType};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The largest radius, in meters, accepted by the _Nearby Search_ service.
+const MAX_RADIUS: u32 = 50_000;
+
+/// The approximate length of one degree of latitude, in meters.
+const METERS_PER_DEGREE: f64 = 111_320.0;
+
+/// The most tiles a search may have, unless changed with `with_max_tiles`.
+/// Each tile costs at least one _Nearby Search_ request.
+const DEFAULT_MAX_TILES: usize = 100;
+
+// -----------------------------------------------------------------------------
+//
+/// Searches a bounding box with several _Nearby Search_ requests.
+///
+/// The bounding box is tiled with circles of the given radius, spaced so that
+/// every point of the box is inside at least one circle. Each circle is
+/// searched in turn, honouring the client's rate limits, and places found by
+/// more than one circle are only returned once.
+///
+/// A single _Nearby Search_ returns at most 20 places per page. Tiles that had
+/// more results than were returned are reported as truncated, so that callers
+/// can search those areas again with a smaller radius.
+///
+/// Each tile is a billable request, so a search that needs more than
+/// `max_tiles` tiles (100 by default) fails before any request is sent. Bounds
+/// whose west edge is east of their east edge cross the antimeridian, and are
+/// tiled across it.
+///
+/// ## Example
+///
+/// ```rust
+/// let search = MultiAreaSearch::new(&google_maps_client, downtown_bounds, 1_000)
+///     .with_type(PlaceType::Cafe)
+///     .execute()
+///     .await?;
+///
+/// for tile in search.truncated_tiles() {
+///     println!("refine the search around {}", tile.center);
+/// }
+/// ```
+
+#[derive(Debug)]
+pub struct MultiAreaSearch<'a> {
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    client: &'a GoogleMapsClient,
+
+    /// The area to search.
+    bounds: Bounds,
+
+    /// The radius of each tile, in meters.
+    radius: u32,
+
+    /// The most tiles that the search may have.
+    max_tiles: usize,
+
+    /// A term to be matched against all content that Google has indexed for
+    /// each place.
+    keyword: Option<String>,
+
+    /// The language in which to return results.
+    language: Option<Language>,
+
+    /// Restricts the results to places matching the specified type.
+    place_type: Option<PlaceType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The result of searching one tile of a `MultiAreaSearch`.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct MultiAreaSearchTile {
+    /// The centre of the tile's circle.
+    pub center: LatLng,
+
+    /// The radius of the tile's circle, in meters.
+    pub radius: u32,
+
+    /// The number of places Google returned for this tile, including places
+    /// that were also found by other tiles.
+    pub result_count: usize,
+
+    /// `true` if Google had more results for this tile than it returned. The
+    /// area should be searched again with a smaller radius to find them all.
+    pub truncated: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The merged results of a `MultiAreaSearch`.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct MultiAreaSearchResponse {
+    /// The places found in all tiles, without duplicates, in the order they
+    /// were first found.
+    pub places: Vec<Place>,
+
+    /// The outcome of each tile that was searched.
+    pub tiles: Vec<MultiAreaSearchTile>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MultiAreaSearchResponse {
+    /// Returns the tiles that had more results than Google returned.
+    pub fn truncated_tiles(&self) -> impl Iterator<Item = &MultiAreaSearchTile> {
+        self.tiles.iter().filter(|tile| tile.truncated)
+    } // fn
+
+    /// Re-orders the merged places using the specified `Ranker`.
+    pub fn rank_places(&mut self, ranker: &impl Ranker) {
+        ranker.rank(&mut self.places);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> MultiAreaSearch<'a> {
+    /// Initializes a search of the given bounding box.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `bounds` ‧ The area to search.
+    ///
+    /// * `radius` ‧ The radius of each tile, in meters. Values above 50,000
+    ///   meters, the maximum accepted by the _Nearby Search_ service, are
+    ///   clamped.
+
+    #[must_use]
+    pub fn new(client: &'a GoogleMapsClient, bounds: Bounds, radius: u32) -> Self {
+        Self {
+            client,
+            bounds,
+            radius: radius.clamp(1, MAX_RADIUS),
+            max_tiles: DEFAULT_MAX_TILES,
+            keyword: None,
+            language: None,
+            place_type: None,
+        } // MultiAreaSearch
+    } // fn
+
+    /// Sets the most tiles, and so _Nearby Search_ requests, that the search
+    /// may have. Defaults to 100.
+
+    #[must_use]
+    pub const fn with_max_tiles(mut self, max_tiles: usize) -> Self {
+        self.max_tiles = max_tiles;
+        self
+    } // fn
+
+    /// Adds a keyword to each _Nearby Search_ request. See
+    /// `nearby_search::Request::with_keyword`.
+
+    #[must_use]
+    pub fn with_keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keyword = Some(keyword.into());
+        self
+    } // fn
+
+    /// Sets the language of each _Nearby Search_ request. See
+    /// `nearby_search::Request::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    } // fn
+
+    /// Restricts each _Nearby Search_ request to a place type. See
+    /// `nearby_search::Request::with_type`.
+
+    #[must_use]
+    pub fn with_type(mut self, place_type: impl Into<PlaceType>) -> Self {
+        self.place_type = Some(place_type.into());
+        self
+    } // fn
+
+    /// Returns the centres of the circles that tile the bounding box. The
+    /// circles are laid out on a square grid with a spacing of `radius × √2`,
+    /// which leaves no gaps between them.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TooManyTiles` if the bounding box needs more than
+    ///   `max_tiles` circles.
+
+    pub fn tiles(&self) -> Result<Vec<LatLng>, crate::places::Error> {
+        let south = self.bounds.southwest.lat.to_f64().unwrap_or_default();
+        let west = self.bounds.southwest.lng.to_f64().unwrap_or_default();
+        let north = self.bounds.northeast.lat.to_f64().unwrap_or_default();
+        let mut east = self.bounds.northeast.lng.to_f64().unwrap_or_default();
+
+        // Bounds that cross the antimeridian are tiled eastwards past 180°,
+        // and the centres wrapped back into range:
+        if east < west {
+            east += 360.0;
+        } // if
+
+        let spacing_m = f64::from(self.radius) * std::f64::consts::SQRT_2;
+        let lat_step = spacing_m / METERS_PER_DEGREE;
+
+        let mut centers = Vec::new();
+        let mut lat = south + lat_step / 2.0;
+        loop {
+            // Space the row's circles for the latitude furthest from the
+            // equator that the row covers, where meridians are closest:
+            let row_lat = lat.min(north);
+            let polar_lat = (row_lat.abs() + lat_step / 2.0).min(89.0);
+            let lng_step = spacing_m / (METERS_PER_DEGREE * polar_lat.to_radians().cos());
+
+            let mut lng = west + lng_step / 2.0;
+            loop {
+                let center_lng = lng.min(east);
+                let center_lng = if center_lng > 180.0 { center_lng - 360.0 } else { center_lng };
+                if let Ok(center) = LatLng::try_from_f64(row_lat, center_lng) {
+                    if centers.len() == self.max_tiles {
+                        return Err(crate::places::Error::TooManyTiles(self.max_tiles));
+                    } // if
+                    centers.push(center);
+                } // if
+                if lng >= east {
+                    break;
+                } // if
+                lng += lng_step;
+            } // loop
+
+            if lat >= north {
+                break;
+            } // if
+            lat += lat_step;
+        } // loop
+
+        Ok(centers)
+    } // fn
+
+    /// Searches every tile and merges the results.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TooManyTiles` if the bounding box needs more than
+    ///   `max_tiles` circles. No requests are sent in that case.
+    ///
+    /// * Returns the first error encountered, other than a tile with no
+    ///   results.
+
+    #[cfg(feature = "reqwest")]
+    pub async fn execute(&self) -> Result<MultiAreaSearchResponse, crate::GoogleMapsError> {
+        let mut response = MultiAreaSearchResponse::default();
+        let mut seen = std::collections::HashSet::new();
+
+        for center in self.tiles()? {
+            let mut nearby_search = self.client.nearby_search(center, self.radius);
+            let request = &mut nearby_search;
+            let request = match &self.keyword {
+                Some(keyword) => request.with_keyword(keyword.clone()),
+                None => request,
+            }; // match
+            let request = match &self.language {
+                Some(language) => request.with_language(*language),
+                None => request,
+            }; // match
+            let request = match &self.place_type {
+                Some(place_type) => request.with_type(*place_type),
+                None => request,
+            }; // match
+
+            let (places, truncated) = match request.execute().await {
+                Ok(tile) => (tile.results, tile.next_page_token.is_some()),
+                Err(crate::GoogleMapsError::Places(crate::places::Error::GoogleMapsService(
+                    crate::places::Status::ZeroResults,
+                    _,
+                ))) => (Vec::new(), false),
+                Err(error) => return Err(error),
+            }; // match
+
+            response.tiles.push(MultiAreaSearchTile {
+                center,
+                radius: self.radius,
+                result_count: places.len(),
+                truncated,
+            }); // push
+
+            for place in places {
+                let is_new = place
+                    .place_id
+                    .as_ref()
+                    .map_or(true, |place_id| seen.insert(place_id.clone()));
+                if is_new {
+                    response.places.push(place);
+                } // if
+            } // for
+        } // for
+
+        Ok(response)
+    } // fn
+} // impl
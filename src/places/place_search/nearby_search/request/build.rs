@@ -3,7 +3,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     /// Builds the query string for the Google Maps Places API _Nearby Search_
     /// query based on the input provided by the client.
     ///
@@ -11,7 +11,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -30,7 +30,7 @@ impl<'a> Request<'a> {
             query.push_str(&utf8_percent_encode(keyword, NON_ALPHANUMERIC).to_string());
         }
 
-        if let Some(language) = &self.language {
+        if let Some(language) = self.language.as_ref().or(self.client.language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
@@ -53,7 +53,7 @@ impl<'a> Request<'a> {
 
         if let Some(pagetoken) = &self.pagetoken {
             query.push_str("&pagetoken=");
-            query.push_str(pagetoken);
+            query.push_str(&pagetoken.token);
         }
 
         if let Some(rankby) = &self.rankby {
@@ -66,6 +66,12 @@ impl<'a> Request<'a> {
             query.push_str(&String::from(place_type));
         }
 
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
         // Set query string in Request struct.
         self.query = Some(query);
 
@@ -3,11 +3,12 @@ use crate::places::place_search::nearby_search::{
     request::Request as PlacesTextSearchRequest, response::Response as PlacesTextSearchResponse,
     Error as PlacesTextSearchError, OUTPUT_FORMAT, SERVICE_URL,
 };
+use crate::places::place_search::pagination::PAGE_TOKEN_DELAY;
 use crate::places::status::Status as PlacesStatus;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
+use crate::runtime::{retry_with_circuit, DefaultRuntime, Runtime};
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
+use std::time::Instant;
 
 // -----------------------------------------------------------------------------
 
@@ -35,19 +36,37 @@ impl<'a> PlacesTextSearchRequest<'a> {
 
         self.client
             .rate_limit
-            .limit_apis(vec![&Api::All, &Api::Places])
+            .limit_apis(vec![&Api::All, &Api::Places, &Api::NearbySearch])
             .await;
 
         tracing::debug!("{url}");
 
+        // A page token only becomes valid a short time after it was issued.
+        // Until then, Google reports the request as invalid. Wait for the
+        // token if it is known to be too fresh, and allow retries until the
+        // page token window has elapsed. A window too long to be represented
+        // has no deadline:
+        let page_token_deadline = match &self.pagetoken {
+            Some(pagetoken) => {
+                let delay = pagetoken.activation_delay();
+                if !delay.is_zero() {
+                    tracing::debug!("waiting {delay:?} for the page token to activate");
+                    DefaultRuntime::sleep(delay).await;
+                } // if
+                Some(pagetoken.issued_at.unwrap_or_else(Instant::now).checked_add(self.page_token_window))
+            }, // Some
+            None => None,
+        }; // match
+
         // Retries the get request until successful, an error ineligible for
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Places, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+            let response = self.client.get_request_with_language(&url, self.accept_language.as_deref()).await;
+            self.client.record_circuit_outcome(&Api::Places, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -65,10 +84,14 @@ impl<'a> PlacesTextSearchRequest<'a> {
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == PlacesStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
+                                        if deserialized.status == PlacesStatus::Ok
+                                            || (deserialized.status == PlacesStatus::ZeroResults
+                                            && !self.client.zero_results_as_error)
+                                        {
+                                            // If Google's response was "Ok", or
+                                            // found nothing and the client does
+                                            // not treat that as an error, return
+                                            // the struct deserialized from JSON:
                                             Ok(deserialized)
                                         // Google API returned an error. This
                                         // indicates an issue with the request.
@@ -89,6 +112,17 @@ impl<'a> PlacesTextSearchRequest<'a> {
                                                     err: error,
                                                     retry_after: None,
                                                 })
+                                            } else if deserialized.status == PlacesStatus::InvalidRequest
+                                                && page_token_deadline.is_some_and(|deadline| deadline.map_or(true, |deadline| Instant::now() < deadline))
+                                            {
+                                                // The page token may not have
+                                                // been activated yet. Wait for
+                                                // it, then retry:
+                                                tracing::warn!("{}", error);
+                                                Err(Transient {
+                                                    err: error,
+                                                    retry_after: Some(PAGE_TOKEN_DELAY),
+                                                })
                                             } else {
                                                 // Not an "Unknown Error." The
                                                 // error is permanent, do not
@@ -106,9 +140,7 @@ impl<'a> PlacesTextSearchRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(PlacesTextSearchError::ReqwestMessage(
-                                    error.to_string(),
-                                )))
+                                Err(Permanent(PlacesTextSearchError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
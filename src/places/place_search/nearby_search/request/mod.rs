@@ -4,13 +4,21 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod is_open_now;
 mod new;
+pub mod params;
+#[cfg(feature = "reqwest")]
+mod pages;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_budget;
+mod with_channel;
 mod with_keyword;
 mod with_language;
 mod with_max_price;
@@ -21,9 +29,12 @@ mod with_type;
 
 // -----------------------------------------------------------------------------
 
+use crate::places::place_search::pagination::{PageBudget, PageToken};
 use crate::places::RankBy;
 use crate::LatLng;
 use crate::{client::GoogleMapsClient, types::Language, types::PlaceType};
+use std::time::Duration;
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -79,7 +90,7 @@ pub struct Request<'a> {
     ///
     /// If this parameter is omitted, places with a `business_status` of
     /// `CLOSED_TEMPORARILY` or `CLOSED_PERMANENTLY` will not be returned.
-    keyword: Option<String>,
+    keyword: Option<Cow<'a, str>>,
 
     /// The language in which to return results.
     ///
@@ -108,6 +119,11 @@ pub struct Request<'a> {
     ///   _utca_ and _tér_ are synonyms for street in Hungarian.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// Restricts results to only those places within the specified range. Valid
     /// values range between 0 (most affordable) to 4 (most expensive),
     /// inclusive. The exact amount indicated by a specific value will vary from
@@ -129,7 +145,7 @@ pub struct Request<'a> {
     /// Returns up to 20 results from a previously run search. Setting a
     /// pagetoken parameter will execute a search with the same parameters used
     /// previously — all parameters other than pagetoken will be ignored.
-    pagetoken: Option<String>,
+    pagetoken: Option<PageToken>,
 
     /// Specifies the order in which results are listed. Possible values are:
     ///
@@ -160,6 +176,22 @@ pub struct Request<'a> {
     ///   `ZERO_RESULTS`.
     place_type: Option<PlaceType>,
 
+    // Paging:
+    // -------
+    /// Limits on the requests sent by the `pages()` stream. See method
+    /// `with_budget()` for more information.
+    budget: PageBudget,
+
+    /// How long after the page token was issued that the request is retried,
+    /// if Google reports the token as invalid. See method
+    /// `with_page_token_window()` for more information.
+    page_token_window: Duration,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
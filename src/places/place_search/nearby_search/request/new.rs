@@ -1,4 +1,5 @@
 use crate::client::GoogleMapsClient;
+use crate::places::place_search::pagination::{PageBudget, PAGE_TOKEN_WINDOW};
 use crate::places::place_search::nearby_search::request::Request;
 use crate::LatLng;
 
@@ -48,12 +49,17 @@ impl Request<'_> {
             // Optional parameters:
             keyword: None,
             language: None,
+            accept_language: None,
             maxprice: None,
             minprice: None,
             opennow: None,
             pagetoken: None,
             rankby: None,
             place_type: None,
+            // Paging:
+            budget: PageBudget::new(),
+            page_token_window: PAGE_TOKEN_WINDOW,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
@@ -0,0 +1,131 @@
+//! Contains the `Params` struct. It holds the parameters of a Nearby Search
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::places::place_search::nearby_search::request::Request;
+use crate::places::place_search::pagination::{PageBudget, PAGE_TOKEN_WINDOW};
+use crate::places::RankBy;
+use crate::types::{Language, LatLng, PlaceType};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Nearby Search_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// Page tokens and the paging budget are not parameters. A request built from
+/// `Params` always starts at the first page.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: nearby_search::Params = serde_json::from_str(job)?;
+/// let places = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The point around which to retrieve place information.
+    pub location: LatLng,
+
+    /// The distance, in meters, within which to return place results.
+    pub radius: u32,
+
+    /// A term to be matched against all content that Google has indexed for the
+    /// place.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword: Option<String>,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The most expensive price level to return, from 0 to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxprice: Option<u8>,
+
+    /// The least expensive price level to return, from 0 to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minprice: Option<u8>,
+
+    /// Whether to return only places that are open for business now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opennow: Option<bool>,
+
+    /// The order in which results are listed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rankby: Option<RankBy>,
+
+    /// The type of place to restrict the results to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_type: Option<PlaceType>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            location: self.location,
+            radius: self.radius,
+            keyword: self.keyword.map(Cow::Owned),
+            language: self.language,
+            accept_language: self.accept_language,
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            rankby: self.rankby,
+            place_type: self.place_type,
+            channel: self.channel,
+            pagetoken: None,
+            budget: PageBudget::new(),
+            page_token_window: PAGE_TOKEN_WINDOW,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            location: self.location,
+            radius: self.radius,
+            keyword: self.keyword.as_deref().map(str::to_string),
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            rankby: self.rankby.clone(),
+            place_type: self.place_type,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
@@ -0,0 +1,45 @@
+use crate::places::place_search::nearby_search::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the `Accept-Language` header for this request, overriding the
+    /// client's `Accept-Language` header.
+    ///
+    /// Google uses the header when `with_language()` is not set. Unlike a
+    /// single `language` parameter, the header may list several languages
+    /// with quality values, so it can pass on the preferences of the end
+    /// user that a server is making the request for.
+    ///
+    /// ## Arguments
+    ///
+    /// * `accept_language` ‧ The header value, for example `fr-CA, fr;q=0.9,
+    ///   en;q=0.5`.
+    ///
+    /// ## Errors
+    ///
+    /// * `InvalidHeaderValue` if the value can't be sent as a header, for
+    ///   example because it contains a line break.
+    ///
+    /// ## Example:
+    ///
+    /// * Forward the end user's browser preferences:
+    /// ```rust
+    /// .with_accept_language(incoming_request.headers()["accept-language"].to_str()?)?
+    /// ```
+
+    pub fn with_accept_language(
+        &'a mut self,
+        accept_language: impl Into<String>
+    ) -> Result<&'a mut Self, crate::error::Error> {
+        // Set Accept-Language header in Request struct.
+        self.accept_language = Some(crate::client::header_value::validate_header_value(
+            "accept-language",
+            accept_language.into(),
+        )?);
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
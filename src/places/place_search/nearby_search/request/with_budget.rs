@@ -0,0 +1,37 @@
+use crate::places::place_search::{pagination::PageBudget, nearby_search::request::Request};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Limits the number of requests, and the time, that the `pages()` stream
+    /// may spend paging through results.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_requests` ‧ The maximum number of requests, including the first
+    ///   one. Google returns at most 3 pages of 20 results.
+    ///
+    /// * `max_duration` ‧ The maximum time since the first request was sent.
+    ///   Note that Google requires a delay of about 2 seconds before each
+    ///   subsequent page may be requested.
+    ///
+    /// When the budget is used up before the last page, the stream ends with a
+    /// `SearchPage::BudgetExhausted` item rather than silently truncating the
+    /// results.
+    ///
+    /// Unlike the other builder methods, the request is only borrowed for the
+    /// duration of the call, so that `pages()` may be called on it afterwards.
+
+    pub fn with_budget(
+        &mut self,
+        max_requests: usize,
+        max_duration: Duration
+    ) -> &mut Self {
+        self.budget = PageBudget {
+            max_requests: Some(max_requests),
+            max_duration: Some(max_duration),
+        }; // PageBudget
+        self
+    } // fn
+} // impl
@@ -1,4 +1,5 @@
 use crate::places::place_search::nearby_search::request::Request;
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -23,7 +24,7 @@ impl<'a> Request<'a> {
 
     pub fn with_keyword(
         &'a mut self,
-        keyword: impl Into<String>
+        keyword: impl Into<Cow<'a, str>>
     ) -> &'a mut Self {
         // Set maximum price in Request struct.
         self.keyword = Some(keyword.into());
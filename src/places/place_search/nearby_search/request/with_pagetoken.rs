@@ -1,4 +1,5 @@
-use crate::places::place_search::nearby_search::request::Request;
+use crate::places::place_search::{pagination::PageToken, nearby_search::request::Request};
+use std::time::Duration;
 
 // -----------------------------------------------------------------------------
 
@@ -10,15 +11,41 @@ impl<'a> Request<'a> {
     /// * `pagetoken` ‧ Returns up to 20 results from a previously run search.
     ///   Setting a `pagetoken` parameter will execute a search with the same
     ///   parameters used previously — all parameters other than pagetoken will
-    ///   be ignored.
+    ///   be ignored. Either a `PageToken`, or the `next_page_token` string.
+    ///
+    /// A page token only becomes valid about 2 seconds after it is issued.
+    /// When given a `PageToken` from `Response::page_token()`, the request
+    /// waits until the token is active before it is sent. In any case, if
+    /// Google reports the request as invalid, it is retried until the page
+    /// token window has elapsed, in case the token was not active yet. See also
+    /// `pages()`, which requests every page in turn.
 
     pub fn with_pagetoken(
         &'a mut self,
-        pagetoken: impl Into<String>
+        pagetoken: impl Into<PageToken>
     ) -> &'a mut Self {
         // Set page token in Request struct.
         self.pagetoken = Some(pagetoken.into());
         // Return modified Request struct to caller.
         self
     } // fn
+
+    /// Sets how long after the page token was issued that the request is
+    /// retried, if Google reports the page token as invalid. Defaults to 10
+    /// seconds. When the issuance time of the page token is unknown, the
+    /// window starts when the request is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `window` ‧ The retry window. `Duration::ZERO` disables the retries,
+    ///   and `Duration::MAX` retries for as long as the client's retry policy
+    ///   allows.
+
+    pub fn with_page_token_window(
+        &'a mut self,
+        window: Duration
+    ) -> &'a mut Self {
+        self.page_token_window = window;
+        self
+    } // fn
 } // impl
@@ -6,7 +6,9 @@
 // -----------------------------------------------------------------------------
 
 use crate::places::status::Status;
-use crate::places::Place;
+use crate::places::place_search::pagination::PageToken;
+use crate::places::{Place, PlaceSummary, Ranker};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Places API _Nearby Search_ request will be
@@ -80,3 +82,75 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Re-orders the results using the specified `Ranker`.
+    ///
+    /// ```rust
+    /// response.rank_results(&WeightedRanker::new().with_origin(user_location));
+    /// ```
+    pub fn rank_results(&mut self, ranker: &impl Ranker) {
+        ranker.rank(&mut self.results);
+    } // fn
+
+    /// Returns the token for the next page of results, if there is one, with
+    /// the current time as its issuance time. Call this as soon as the
+    /// response is received, so that the next page is not requested before the
+    /// token is active.
+
+    #[must_use]
+    pub fn page_token(&self) -> Option<PageToken> {
+        self.next_page_token.as_ref().map(PageToken::issued_now)
+    } // fn
+
+    /// Returns a `PlaceSummary` of each result. Results without a place ID,
+    /// name, or location are skipped.
+
+    #[must_use]
+    pub fn summaries(&self) -> Vec<PlaceSummary> {
+        self.results.iter().filter_map(Place::summary).collect()
+    } // fn
+
+    /// Returns the places that are open at `at` and stay open for at least
+    /// `duration` afterwards. Places whose opening hours or UTC offset are
+    /// unknown are excluded. See `Place::is_open_for_at_least`.
+
+    #[must_use]
+    pub fn open_for_at_least(&self, duration: Duration, at: DateTime<Utc>) -> Vec<&Place> {
+        self.results
+            .iter()
+            .filter(|place| place.is_open_for_at_least(duration, at) == Some(true))
+            .collect()
+    } // fn
+
+    /// Returns the places that have both a location and an address, and can
+    /// be shown on a map. See `Place::has_physical_location`.
+
+    #[must_use]
+    pub fn physical_places(&self) -> Vec<&Place> {
+        self.results.iter().filter(|place| place.has_physical_location()).collect()
+    } // fn
+
+    /// Returns the places that look like pure service-area businesses, which
+    /// have no address. See `Place::is_pure_service_area_business`.
+
+    #[must_use]
+    pub fn service_area_businesses(&self) -> Vec<&Place> {
+        self.results.iter().filter(|place| place.is_pure_service_area_business()).collect()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::places::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
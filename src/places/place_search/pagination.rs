@@ -0,0 +1,186 @@
+//! Contains the types used to page through _Text Search_ and _Nearby Search_
+//! results: the `PageBudget` that limits how many pages are requested, and the
+//! `SearchPage` items produced by the `pages()` streams.
+
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+
+/// How long Google takes to activate a `next_page_token` after it is issued.
+/// Requesting the next page any sooner returns an `INVALID_REQUEST` status.
+#[cfg(feature = "reqwest")]
+pub(crate) const PAGE_TOKEN_DELAY: Duration = Duration::from_secs(2);
+
+/// How long after a page token was issued that a request using it is retried,
+/// if Google reports it as invalid, unless configured with
+/// `with_page_token_window()`.
+pub(crate) const PAGE_TOKEN_WINDOW: Duration = Duration::from_secs(10);
+
+// -----------------------------------------------------------------------------
+//
+/// A token for the next page of _Text Search_ or _Nearby Search_ results.
+///
+/// Google only accepts a page token a short time after issuing it. A
+/// `PageToken` records when it was issued, so that a request using it can wait
+/// until the token is active rather than fail with `INVALID_REQUEST`. Obtain
+/// one with `page_token()` on a search response, and pass it to
+/// `with_pagetoken()`.
+///
+/// A `PageToken` converted from a `String` has no issuance time. Requests
+/// using it are sent immediately, and retried if Google reports the token as
+/// invalid.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PageToken {
+    /// The token, as returned by Google in `next_page_token`.
+    pub token: String,
+
+    /// When the token was issued, if known.
+    pub issued_at: Option<Instant>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PageToken {
+    /// Initializes a `PageToken` that was issued just now.
+    #[must_use]
+    pub fn issued_now(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            issued_at: Some(Instant::now()),
+        } // PageToken
+    } // fn
+
+    /// Returns how much longer to wait before the token is active. Returns
+    /// `Duration::ZERO` if the issuance time is unknown.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn activation_delay(&self) -> Duration {
+        self.issued_at.map_or(Duration::ZERO, |issued_at| {
+            PAGE_TOKEN_DELAY.saturating_sub(issued_at.elapsed())
+        }) // map_or
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<String> for PageToken {
+    /// Converts a page token `String` into a `PageToken` with an unknown
+    /// issuance time.
+    fn from(token: String) -> Self {
+        Self {
+            token,
+            issued_at: None,
+        } // PageToken
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&String> for PageToken {
+    /// Converts a borrowed page token `&String` into a `PageToken` with an
+    /// unknown issuance time.
+    fn from(token: &String) -> Self {
+        Self::from(token.clone())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&str> for PageToken {
+    /// Converts a page token `&str` into a `PageToken` with an unknown
+    /// issuance time.
+    fn from(token: &str) -> Self {
+        Self::from(token.to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PageToken {
+    /// Formats the `PageToken` as the token string.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.token)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Limits the number of requests, and the time, spent paging through search
+/// results. See `with_budget()` on the _Text Search_ and _Nearby Search_
+/// requests.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PageBudget {
+    /// The maximum number of requests, including the first one. `None` for no
+    /// limit.
+    pub max_requests: Option<usize>,
+
+    /// The maximum time since the first request was sent. A page is not
+    /// requested once this has elapsed. `None` for no limit.
+    pub max_duration: Option<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PageBudget {
+    /// Returns a `PageBudget` without limits.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_requests: None,
+            max_duration: None,
+        } // PageBudget
+    } // fn
+
+    /// Returns a `BudgetExhausted` marker if another page may not be
+    /// requested.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn check(
+        &self,
+        requests: usize,
+        started: Instant,
+        next_page_token: &PageToken,
+    ) -> Option<BudgetExhausted> {
+        let elapsed = started.elapsed();
+        let exhausted = self.max_requests.is_some_and(|max_requests| requests >= max_requests)
+            || self.max_duration.is_some_and(|max_duration| elapsed >= max_duration);
+        exhausted.then(|| BudgetExhausted {
+            requests,
+            elapsed,
+            next_page_token: next_page_token.clone(),
+        }) // then
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Marks that paging stopped because the `PageBudget` was used up, although
+/// Google had more results.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BudgetExhausted {
+    /// The number of requests that were sent.
+    pub requests: usize,
+
+    /// The time elapsed since the first request was sent.
+    pub elapsed: Duration,
+
+    /// The token of the next, unrequested page. It may be passed to
+    /// `with_pagetoken()` to resume paging later, as long as the token has not
+    /// expired.
+    pub next_page_token: PageToken,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// An item produced by the `pages()` streams of the _Text Search_ and _Nearby
+/// Search_ requests.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SearchPage<R> {
+    /// A page of results.
+    Results(R),
+    /// The `PageBudget` was used up before all pages were requested. This is
+    /// always the last item of the stream.
+    BudgetExhausted(BudgetExhausted),
+} // enum
@@ -42,4 +42,6 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::places::{error::Error, status::Status}; // place_search
 
-pub use crate::places::place_search::text_search::{request::Request, response::Response}; // text_search
+pub use crate::places::place_search::text_search::{
+    request::{params::Params, Request}, response::Response,
+}; // text_search
@@ -0,0 +1,74 @@
+use crate::places::place_search::text_search::request::Request;
+use crate::types::Region;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Turns the Places API _Text Search_ query into a phone number lookup.
+    ///
+    /// ## Arguments
+    ///
+    /// * `number` ‧ The phone number to search for, as typed by the user. For
+    ///   example, `+1 (650) 253-0000`, `0044 20 7031 3000` or
+    ///   `(650) 253-0000`.
+    ///
+    /// * `region` ‧ The region the phone number belongs to. This is sent as
+    ///   the `region` parameter so that national-format numbers are resolved
+    ///   against the correct country.
+    ///
+    /// ## Description
+    ///
+    /// Google recommends that phone numbers be searched in the
+    /// [E.164](https://en.wikipedia.org/wiki/E.164) international format. This
+    /// method normalizes the number before it is sent:
+    ///
+    /// * Spaces, dashes, dots, parentheses and other formatting are removed.
+    ///
+    /// * Numbers starting with `+` or the `00` international call prefix are
+    ///   sent in E.164 format, i.e. `+442070313000`.
+    ///
+    /// * National-format numbers cannot be converted to E.164 without knowing
+    ///   the country calling code, so only their digits are sent and Google
+    ///   resolves them using the `region` parameter.
+    ///
+    /// This replaces the query that was passed to `text_search`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let places = google_maps_client.text_search("", 0)
+    ///     .for_phone_number("+1 (650) 253-0000", Region::UnitedStates)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    pub fn for_phone_number(
+        &'a mut self,
+        number: impl AsRef<str>,
+        region: impl Into<Region>
+    ) -> &'a mut Self {
+        // Set normalized phone number as the query in Request struct.
+        self.input = normalize_phone_number(number.as_ref()).into();
+        // Set region in Request struct.
+        self.region = Some(region.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Strips formatting from a phone number and converts international numbers
+/// (those starting with `+` or `00`) to E.164 format.
+fn normalize_phone_number(number: &str) -> String {
+    let number = number.trim();
+    let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+
+    if number.starts_with('+') {
+        format!("+{digits}")
+    } else if let Some(international) = digits.strip_prefix("00") {
+        format!("+{international}")
+    } else {
+        digits
+    } // if
+} // fn
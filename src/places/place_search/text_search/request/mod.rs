@@ -4,13 +4,22 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod for_phone_number;
 #[cfg(feature = "reqwest")]
 mod get;
 mod is_open_now;
 mod new;
+pub mod params;
+#[cfg(feature = "reqwest")]
+mod pages;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_budget;
+mod with_channel;
 mod with_language;
 mod with_location;
 mod with_max_price;
@@ -21,9 +30,12 @@ mod with_type;
 
 // -----------------------------------------------------------------------------
 
+use crate::places::place_search::pagination::{PageBudget, PageToken};
 use crate::{
     client::GoogleMapsClient, types::Language, types::LatLng, types::PlaceType, types::Region,
 };
+use std::time::Duration;
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 
@@ -45,7 +57,7 @@ pub struct Request<'a> {
     /// guaranteed to return valid results. The Google Places service will
     /// return candidate matches based on this string and order the results
     /// based on their perceived relevance.
-    input: String,
+    input: Cow<'a, str>,
 
     /// Defines the distance (in meters) within which to return place results.
     /// You may bias results to a specified circle by passing a location and a
@@ -97,6 +109,11 @@ pub struct Request<'a> {
     ///   _utca_ and _tér_ are synonyms for street in Hungarian.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// The point around which to retrieve place information.
     ///
     /// * When using the Text Search API, the 'location' parameter may be
@@ -125,7 +142,7 @@ pub struct Request<'a> {
     /// Returns up to 20 results from a previously run search. Setting a
     /// pagetoken parameter will execute a search with the same parameters used
     /// previously — all parameters other than pagetoken will be ignored.
-    pagetoken: Option<String>,
+    pagetoken: Option<PageToken>,
 
     /// The region code, specified as a [ccTLD ("top-level
     /// domain")](https://en.wikipedia.org/wiki/List_of_Internet_top-level_domains#Country_code_top-level_domains)
@@ -149,6 +166,22 @@ pub struct Request<'a> {
     ///   `ZERO_RESULTS`.
     place_type: Option<PlaceType>,
 
+    // Paging:
+    // -------
+    /// Limits on the requests sent by the `pages()` stream. See method
+    /// `with_budget()` for more information.
+    budget: PageBudget,
+
+    /// How long after the page token was issued that the request is retried,
+    /// if Google reports the token as invalid. See method
+    /// `with_page_token_window()` for more information.
+    page_token_window: Duration,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
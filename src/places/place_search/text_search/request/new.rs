@@ -1,9 +1,11 @@
 use crate::client::GoogleMapsClient;
+use crate::places::place_search::pagination::{PageBudget, PAGE_TOKEN_WINDOW};
 use crate::places::place_search::text_search::request::Request;
+use std::borrow::Cow;
 
 // =============================================================================
 
-impl Request<'_> {
+impl<'a> Request<'a> {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Places API _Text Search_ query
@@ -41,7 +43,11 @@ impl Request<'_> {
     /// * Nearby Search: 50,000 meters
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, query: impl Into<String>, radius: u32) -> Request {
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        query: impl Into<Cow<'a, str>>,
+        radius: u32
+    ) -> Self {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -50,6 +56,7 @@ impl Request<'_> {
             radius,
             // Optional parameters:
             language: None,
+            accept_language: None,
             location: None,
             maxprice: None,
             minprice: None,
@@ -57,6 +64,10 @@ impl Request<'_> {
             pagetoken: None,
             region: None,
             place_type: None,
+            // Paging:
+            budget: PageBudget::new(),
+            page_token_window: PAGE_TOKEN_WINDOW,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
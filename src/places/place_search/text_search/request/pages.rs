@@ -0,0 +1,100 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::{
+    pagination::{PageBudget, PageToken, SearchPage},
+    text_search::{request::Request, response::Response},
+}; // crate::places::place_search
+use futures::stream::{self, Stream};
+use std::time::Instant;
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+
+/// The progress of a `pages()` stream.
+struct Pages<'a> {
+    /// The request for the first page. `None` once it has been sent.
+    first: Option<&'a mut Request<'a>>,
+    /// The client used to request subsequent pages.
+    client: &'a GoogleMapsClient,
+    /// The text string that was searched for.
+    input: Cow<'a, str>,
+    /// The radius that was searched.
+    radius: u32,
+    /// The limits on the requests that may be sent.
+    budget: PageBudget,
+    /// The token of the next page, if there is one.
+    next_page_token: Option<PageToken>,
+    /// The number of requests sent so far.
+    requests: usize,
+    /// When the first request was sent.
+    started: Instant,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns a stream of result pages: the first page of this search,
+    /// followed by each subsequent page that Google offers, requested with its
+    /// `next_page_token`. Google returns at most 3 pages of 20 results.
+    ///
+    /// The stream waits for the page token to activate before requesting each
+    /// subsequent page. If a budget was set with `with_budget()` and is used up
+    /// before the last page, the stream ends with a
+    /// `SearchPage::BudgetExhausted` item. The stream ends after the first
+    /// error.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut request = google_maps_client.text_search("pizza in New York", 5_000);
+    /// request.with_budget(2, std::time::Duration::from_secs(10));
+    /// let mut pages = std::pin::pin!(request.pages());
+    ///
+    /// while let Some(page) = pages.next().await {
+    ///     match page? {
+    ///         SearchPage::Results(response) => places.extend(response.results),
+    ///         SearchPage::BudgetExhausted(exhausted) =>
+    ///             println!("stopped after {} requests", exhausted.requests),
+    ///     }
+    /// }
+    /// ```
+
+    pub fn pages(&'a mut self) -> impl Stream<Item = Result<SearchPage<Response>, GoogleMapsError>> + 'a {
+        let pages = Pages {
+            client: self.client,
+            input: self.input.clone(),
+            radius: self.radius,
+            budget: self.budget,
+            first: Some(self),
+            next_page_token: None,
+            requests: 0,
+            started: Instant::now(),
+        }; // Pages
+
+        stream::unfold(pages, |mut pages| async move {
+            let response = if let Some(first) = pages.first.take() {
+                first.execute().await
+            } else {
+                let next_page_token = pages.next_page_token.take()?;
+                if let Some(exhausted) = pages.budget.check(pages.requests, pages.started, &next_page_token) {
+                    return Some((Ok(SearchPage::BudgetExhausted(exhausted)), pages));
+                } // if
+                Request::new(pages.client, pages.input.clone(), pages.radius)
+                    .with_pagetoken(next_page_token)
+                    .execute()
+                    .await
+            }; // if
+            pages.requests += 1;
+
+            match response {
+                Ok(response) => {
+                    pages.next_page_token = response.page_token();
+                    Some((Ok(SearchPage::Results(response)), pages))
+                }, // Ok
+                Err(error) => Some((Err(error), pages)),
+            } // match
+        }) // unfold
+    } // fn
+} // impl
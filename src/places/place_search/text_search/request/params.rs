@@ -0,0 +1,130 @@
+//! Contains the `Params` struct. It holds the parameters of a Text Search
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::places::place_search::pagination::{PageBudget, PAGE_TOKEN_WINDOW};
+use crate::places::place_search::text_search::request::Request;
+use crate::types::{Language, LatLng, PlaceType, Region};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Text Search_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// Page tokens and the paging budget are not parameters. A request built from
+/// `Params` always starts at the first page.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: TextSearchParams = serde_json::from_str(r#"{ "input": "coffee", "radius": 5000 }"#)?;
+/// let places = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The text string on which to search, for example "restaurant".
+    pub input: String,
+
+    /// The distance, in meters, within which to bias place results.
+    pub radius: u32,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The point around which to retrieve place information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LatLng>,
+
+    /// The most expensive price level to return, from 0 to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxprice: Option<u8>,
+
+    /// The least expensive price level to return, from 0 to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minprice: Option<u8>,
+
+    /// Whether to return only places that are open for business now.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opennow: Option<bool>,
+
+    /// The region code, specified as a ccTLD two-character value, used to bias
+    /// results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+
+    /// The type of place to restrict the results to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_type: Option<PlaceType>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            input: Cow::Owned(self.input),
+            radius: self.radius,
+            language: self.language,
+            accept_language: self.accept_language,
+            location: self.location,
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            region: self.region,
+            place_type: self.place_type,
+            channel: self.channel,
+            pagetoken: None,
+            budget: PageBudget::new(),
+            page_token_window: PAGE_TOKEN_WINDOW,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            input: self.input.to_string(),
+            radius: self.radius,
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            location: self.location,
+            maxprice: self.maxprice,
+            minprice: self.minprice,
+            opennow: self.opennow,
+            region: self.region,
+            place_type: self.place_type,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
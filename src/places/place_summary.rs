@@ -0,0 +1,89 @@
+//! Contains the `PlaceSummary` struct. It holds the fields that every Places
+//! API search returns for a place, so that results from _Text Search_,
+//! _Nearby Search_ and _Place Details_ can be handled the same way.
+
+use crate::places::{BusinessStatus, Place};
+use crate::types::{LatLng, PlaceType};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The essential attributes of a place, as returned by the Places API search
+/// services.
+///
+/// Unlike `Place`, whose fields depend on the service and the fields that were
+/// requested, a `PlaceSummary` always has a place ID, a name and a location.
+/// Places without them can not be summarized.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PlaceSummary {
+    /// A textual identifier that uniquely identifies a place.
+    pub place_id: String,
+
+    /// The human-readable name of the place.
+    pub name: String,
+
+    /// The location of the place.
+    pub location: LatLng,
+
+    /// The address of the place. This is the `formatted_address` returned by
+    /// _Text Search_ and _Place Details_, or the `vicinity` returned by
+    /// _Nearby Search_.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// The operational status of the place, if it is a business.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_status: Option<BusinessStatus>,
+
+    /// Whether the place was open when the search was run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub open_now: Option<bool>,
+
+    /// The price level of the place, on a scale of 0 to 4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_level: Option<u8>,
+
+    /// The place's rating, from 1.0 to 5.0, based on aggregated user reviews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<Decimal>,
+
+    /// The total number of reviews, with or without text, for this place.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_ratings_total: Option<u64>,
+
+    /// The feature types describing the place.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<PlaceType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Returns the essential attributes of the place as a `PlaceSummary`.
+    /// Returns `None` if the place has no place ID, name, or location. A
+    /// place without an address, such as a service-area business, has a
+    /// summary with no `address`.
+
+    #[must_use]
+    pub fn summary(&self) -> Option<PlaceSummary> {
+        Some(PlaceSummary {
+            place_id: self.place_id.clone()?,
+            name: self.name.clone()?,
+            location: self.geometry.as_ref()?.location,
+            address: self.address().map(str::to_string),
+            business_status: self.business_status.clone(),
+            open_now: self
+                .current_opening_hours
+                .as_ref()
+                .or(self.opening_hours.as_ref())
+                .and_then(|opening_hours| opening_hours.open_now),
+            price_level: self.price_level,
+            rating: self.rating,
+            user_ratings_total: self.user_ratings_total,
+            types: self.types.clone(),
+        }) // PlaceSummary
+    } // fn
+} // impl
@@ -0,0 +1,252 @@
+//! Contains the `PriceLevel` enum. It gives the legacy _Places API_ 0 to 4
+//! `price_level` integer the names used by the _Places API (New)_, so that code
+//! handling both can compare prices.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places::error::Error as PlacesError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The price level of a place, as used by the _Places API (New)_: for example
+/// `PRICE_LEVEL_MODERATE`. The exact amount indicated by a specific value
+/// will vary from region to region.
+///
+/// Every legacy price level from `0` to `4` has an equivalent `PriceLevel`,
+/// and every `PriceLevel` other than `Unspecified` has an equivalent legacy
+/// price level, so conversions in either direction are lossless when they
+/// succeed. They fail for:
+///
+/// * Legacy price levels above `4`, which Google does not return.
+/// * `PriceLevel::Unspecified`, which has no legacy equivalent. The legacy API
+///   omits the `price_level` field instead.
+///
+/// A _Places API (New)_ `priceRange` is an amount of money in a local
+/// currency, and cannot be converted to a price level without knowing the
+/// price bands of the region.
+///
+/// ## Example
+///
+/// ```rust
+/// let price_level = PriceLevel::try_from(2_u8)?;
+/// assert_eq!(price_level, PriceLevel::Moderate);
+/// assert_eq!(u8::try_from(price_level)?, 2);
+/// ```
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PriceLevel {
+    /// The price level is unspecified or unknown.
+    #[default]
+    Unspecified,
+    /// The place provides free services. Legacy price level `0`.
+    Free,
+    /// The place provides inexpensive services. Legacy price level `1`.
+    Inexpensive,
+    /// The place provides moderately priced services. Legacy price level `2`.
+    Moderate,
+    /// The place provides expensive services. Legacy price level `3`.
+    Expensive,
+    /// The place provides very expensive services. Legacy price level `4`.
+    VeryExpensive,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for PriceLevel {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for PriceLevel {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PriceLevel> for &str {
+    /// Converts a `PriceLevel` enum to a `String` that contains a [price
+    /// level](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#pricelevel)
+    /// code.
+    fn from(price_level: &PriceLevel) -> Self {
+        match price_level {
+            PriceLevel::Unspecified => "PRICE_LEVEL_UNSPECIFIED",
+            PriceLevel::Free => "PRICE_LEVEL_FREE",
+            PriceLevel::Inexpensive => "PRICE_LEVEL_INEXPENSIVE",
+            PriceLevel::Moderate => "PRICE_LEVEL_MODERATE",
+            PriceLevel::Expensive => "PRICE_LEVEL_EXPENSIVE",
+            PriceLevel::VeryExpensive => "PRICE_LEVEL_VERY_EXPENSIVE",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PriceLevel {
+    /// Converts a `PriceLevel` enum to a `String` that contains a [price
+    /// level](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#pricelevel)
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PriceLevel> for String {
+    /// Converts a `PriceLevel` enum to a `String` that contains a [price
+    /// level](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#pricelevel)
+    /// code.
+    fn from(price_level: &PriceLevel) -> Self {
+        std::convert::Into::<&str>::into(price_level).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static PRICE_LEVELS_BY_CODE: phf::Map<&'static str, PriceLevel> = phf_map! {
+    "PRICE_LEVEL_UNSPECIFIED" => PriceLevel::Unspecified,
+    "PRICE_LEVEL_FREE" => PriceLevel::Free,
+    "PRICE_LEVEL_INEXPENSIVE" => PriceLevel::Inexpensive,
+    "PRICE_LEVEL_MODERATE" => PriceLevel::Moderate,
+    "PRICE_LEVEL_EXPENSIVE" => PriceLevel::Expensive,
+    "PRICE_LEVEL_VERY_EXPENSIVE" => PriceLevel::VeryExpensive,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for PriceLevel {
+    // Error definitions are contained in the `google_maps\src\places\error.rs`
+    // module.
+    type Error = GoogleMapsError;
+    /// Gets a `PriceLevel` enum from a `String` that contains a valid [price
+    /// level](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#pricelevel)
+    /// code.
+    fn try_from(price_level_code: &str) -> Result<Self, Self::Error> {
+        Ok(PRICE_LEVELS_BY_CODE
+            .get(price_level_code)
+            .copied()
+            .ok_or_else(|| PlacesError::InvalidPriceLevelCode(price_level_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for PriceLevel {
+    // Error definitions are contained in the `google_maps\src\places\error.rs`
+    // module.
+    type Err = GoogleMapsError;
+    /// Gets a `PriceLevel` enum from a `String` that contains a valid [price
+    /// level](https://developers.google.com/maps/documentation/places/web-service/reference/rest/v1/places#pricelevel)
+    /// code.
+    fn from_str(price_level_code: &str) -> Result<Self, Self::Err> {
+        Self::try_from(price_level_code)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<u8> for PriceLevel {
+    // Error definitions are contained in the `google_maps\src\places\error.rs`
+    // module.
+    type Error = GoogleMapsError;
+    /// Converts a legacy _Places API_ price level, from `0` to `4`, into a
+    /// `PriceLevel` enum.
+    fn try_from(price_level: u8) -> Result<Self, Self::Error> {
+        match price_level {
+            0 => Ok(Self::Free),
+            1 => Ok(Self::Inexpensive),
+            2 => Ok(Self::Moderate),
+            3 => Ok(Self::Expensive),
+            4 => Ok(Self::VeryExpensive),
+            _ => Err(PlacesError::InvalidPriceLevel(price_level))?,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<PriceLevel> for u8 {
+    // Error definitions are contained in the `google_maps\src\places\error.rs`
+    // module.
+    type Error = GoogleMapsError;
+    /// Converts a `PriceLevel` enum into a legacy _Places API_ price level,
+    /// from `0` to `4`. Fails for `PriceLevel::Unspecified`.
+    fn try_from(price_level: PriceLevel) -> Result<Self, Self::Error> {
+        match price_level {
+            PriceLevel::Unspecified => Err(PlacesError::UnspecifiedPriceLevel)?,
+            PriceLevel::Free => Ok(0),
+            PriceLevel::Inexpensive => Ok(1),
+            PriceLevel::Moderate => Ok(2),
+            PriceLevel::Expensive => Ok(3),
+            PriceLevel::VeryExpensive => Ok(4),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PriceLevel {
+    /// Formats a `PriceLevel` enum into a string that is presentable to the
+    /// end user.
+    #[must_use]
+    pub const fn display(&self) -> &str {
+        match self {
+            Self::Unspecified => "Unspecified",
+            Self::Free => "Free",
+            Self::Inexpensive => "Inexpensive",
+            Self::Moderate => "Moderate",
+            Self::Expensive => "Expensive",
+            Self::VeryExpensive => "Very Expensive",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::places::Place {
+    /// Returns the place's legacy `price_level` as a `PriceLevel` enum, or
+    /// `None` if the place has no price level or it is out of range.
+
+    #[must_use]
+    pub fn price_level_enum(&self) -> Option<PriceLevel> {
+        self.price_level.and_then(|price_level| PriceLevel::try_from(price_level).ok())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PriceLevel {
+    /// Returns the code that Google uses for this `PriceLevel`, for example
+    /// `PRICE_LEVEL_UNSPECIFIED`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `PriceLevel` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = PRICE_LEVELS_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
@@ -32,4 +32,4 @@ pub use crate::places::place_autocomplete::{
     }, // response
 }; // place_autocomplete
 
-pub use crate::places::query_autocomplete::request::Request; // place_autocomplete
+pub use crate::places::query_autocomplete::request::{params::Params, Request}; // place_autocomplete
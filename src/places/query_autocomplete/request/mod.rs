@@ -4,12 +4,17 @@
 //! structs, methods) for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
+mod with_accept_language;
+mod with_channel;
 mod with_language;
 mod with_location;
 mod with_offset;
@@ -17,6 +22,7 @@ mod with_offset;
 // -----------------------------------------------------------------------------
 
 use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
+use std::borrow::Cow;
 
 // -----------------------------------------------------------------------------
 //
@@ -35,7 +41,7 @@ pub struct Request<'a> {
     /// The text string on which to search. The Query Autocomplete service will
     /// return candidate matches based on this string and order results based on
     /// their perceived relevance.
-    input: String,
+    input: Cow<'a, str>,
 
     // Optional parameters:
     // --------------------
@@ -66,6 +72,11 @@ pub struct Request<'a> {
     ///   _utca_ and _tér_ are synonyms for street in Hungarian.
     language: Option<Language>,
 
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header. See `with_accept_language()`.
+    #[cfg_attr(not(feature = "reqwest"), allow(dead_code))]
+    accept_language: Option<String>,
+
     /// The point around which to retrieve place information.
     ///
     /// * When using the Text Search API, the 'location' parameter may be
@@ -104,6 +115,11 @@ pub struct Request<'a> {
     /// * Text Search: 50,000 meters
     radius: Option<u32>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
@@ -1,9 +1,10 @@
 use crate::client::GoogleMapsClient;
 use crate::places::query_autocomplete::request::Request;
+use std::borrow::Cow;
 
 // =============================================================================
 
-impl Request<'_> {
+impl<'a> Request<'a> {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Place API _Query Autocomplete_
@@ -16,7 +17,10 @@ impl Request<'_> {
     /// * `input` ‧ The text string on which to search.
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request {
+    pub fn new(
+        client: &'a GoogleMapsClient,
+        input: impl Into<Cow<'a, str>>
+    ) -> Self {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -24,9 +28,11 @@ impl Request<'_> {
             input: input.into(),
             // Optional parameters:
             language: None,
+            accept_language: None,
             location: None,
             offset: None,
             radius: None,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
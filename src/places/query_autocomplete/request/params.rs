@@ -0,0 +1,100 @@
+//! Contains the `Params` struct. It holds the parameters of a Query
+//! Autocomplete request without the client, so that the request can be
+//! described in a configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::places::query_autocomplete::request::Request;
+use crate::types::{Language, LatLng};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Query Autocomplete_ request, without the client. Unlike
+/// the request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: QueryAutocompleteParams = serde_json::from_str(r#"{ "input": "pizza near" }"#)?;
+/// let predictions = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The text string on which to search.
+    pub input: String,
+
+    /// The language in which to return results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+
+    /// The `Accept-Language` header to send with the request. Overrides the
+    /// client's `Accept-Language` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+
+    /// The point around which to retrieve place information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LatLng>,
+
+    /// The position of the last character of the input that is used for
+    /// predictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u8>,
+
+    /// The distance, in meters, within which to return place results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius: Option<u32>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            input: Cow::Owned(self.input),
+            language: self.language,
+            accept_language: self.accept_language,
+            location: self.location,
+            offset: self.offset,
+            radius: self.radius,
+            channel: self.channel,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            input: self.input.to_string(),
+            language: self.language,
+            accept_language: self.accept_language.clone(),
+            location: self.location,
+            offset: self.offset,
+            radius: self.radius,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
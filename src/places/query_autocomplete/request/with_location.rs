@@ -1,5 +1,5 @@
 use crate::places::query_autocomplete::request::Request;
-use crate::types::LatLng;
+use crate::types::{Circle, LatLng};
 
 // -----------------------------------------------------------------------------
 
@@ -47,3 +47,27 @@ impl<'a> Request<'a> {
         self
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Biases the results of the Place API _Query Autocomplete_ query to a
+    /// circle.
+    ///
+    /// ## Arguments
+    ///
+    /// * `circle` ‧ The area to prefer. Every `Circle` has a radius that the
+    ///   _Autocomplete_ endpoints accept, so this method can't fail.
+
+    pub fn with_circle(
+        &'a mut self,
+        circle: Circle
+    ) -> &'a mut Self {
+        // Set location in Request struct.
+        self.location = Some(circle.center());
+        // Set radius in Request struct.
+        self.radius = Some(circle.radius_m());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,178 @@
+//! Contains the `Ranker` trait and the built-in `WeightedRanker`. They re-order
+//! search results with application-specific scoring, so that every screen of
+//! an application orders places the same way.
+
+use crate::places::Place;
+use crate::types::LatLng;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Scores places so that search results can be re-ranked.
+///
+/// Implement `score` to define a custom ordering. Places with higher scores
+/// are ranked first. `rank` sorts a slice of places by score, and may be
+/// overridden if a ranker needs to consider the whole result set at once.
+///
+/// Rankers can be applied to search responses with `rank_results`, for example
+/// `text_search::Response::rank_results`.
+///
+/// ## Example
+///
+/// ```rust
+/// struct MostReviewed;
+///
+/// impl Ranker for MostReviewed {
+///     fn score(&self, place: &Place) -> f64 {
+///         place.user_ratings_total.unwrap_or_default() as f64
+///     }
+/// }
+///
+/// response.rank_results(&MostReviewed);
+/// ```
+
+pub trait Ranker {
+    /// Returns the score of a place. Higher scores are ranked first.
+    fn score(&self, place: &Place) -> f64;
+
+    /// Sorts the places by descending score. The sort is stable, so places
+    /// with equal scores keep Google's relative ordering.
+    fn rank(&self, places: &mut [Place]) {
+        places.sort_by(|a, b| self.score(b).total_cmp(&self.score(a)));
+    } // fn
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// A `Ranker` that scores places with a weighted sum of their distance from an
+/// origin, their rating, and their price level.
+///
+/// Each factor is normalized to the range `0.0` to `1.0` before it is
+/// weighted:
+///
+/// * Distance ‧ `1.0` at the origin, falling to `0.5` at `distance_scale_m`
+///   meters and towards `0.0` beyond. Only used when an origin is set.
+/// * Rating ‧ The star rating divided by 5.
+/// * Price level ‧ `1.0` for free places, falling to `0.0` for very expensive
+///   places. Use a negative weight to prefer more expensive places.
+///
+/// A factor that is missing from a place, for example a place without a
+/// rating, contributes nothing to its score.
+///
+/// ## Example
+///
+/// ```rust
+/// let ranker = WeightedRanker::new()
+///     .with_origin(user_location)
+///     .with_distance_weight(2.0)
+///     .with_rating_weight(1.0);
+///
+/// response.rank_results(&ranker);
+/// ```
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WeightedRanker {
+    /// The location that distances are measured from. Distance is ignored if
+    /// this is `None`.
+    pub origin: Option<LatLng>,
+
+    /// The distance, in meters, at which the distance factor is `0.5`.
+    /// Defaults to 1,000 meters.
+    pub distance_scale_m: f64,
+
+    /// The weight of the distance factor. Defaults to `1.0`.
+    pub distance_weight: f64,
+
+    /// The weight of the rating factor. Defaults to `1.0`.
+    pub rating_weight: f64,
+
+    /// The weight of the price level factor. Defaults to `0.0`.
+    pub price_level_weight: f64,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Default for WeightedRanker {
+    /// Returns a `WeightedRanker` that ranks by rating only, until an origin
+    /// is set.
+    fn default() -> Self {
+        Self::new()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl WeightedRanker {
+    /// Initializes a `WeightedRanker` with the default weights.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            origin: None,
+            distance_scale_m: 1_000.0,
+            distance_weight: 1.0,
+            rating_weight: 1.0,
+            price_level_weight: 0.0,
+        } // WeightedRanker
+    } // fn
+
+    /// Sets the location that distances are measured from.
+    #[must_use]
+    pub fn with_origin(mut self, origin: impl Into<LatLng>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    } // fn
+
+    /// Sets the distance, in meters, at which the distance factor is `0.5`.
+    #[must_use]
+    pub const fn with_distance_scale(mut self, distance_scale_m: f64) -> Self {
+        self.distance_scale_m = distance_scale_m;
+        self
+    } // fn
+
+    /// Sets the weight of the distance factor.
+    #[must_use]
+    pub const fn with_distance_weight(mut self, distance_weight: f64) -> Self {
+        self.distance_weight = distance_weight;
+        self
+    } // fn
+
+    /// Sets the weight of the rating factor.
+    #[must_use]
+    pub const fn with_rating_weight(mut self, rating_weight: f64) -> Self {
+        self.rating_weight = rating_weight;
+        self
+    } // fn
+
+    /// Sets the weight of the price level factor.
+    #[must_use]
+    pub const fn with_price_level_weight(mut self, price_level_weight: f64) -> Self {
+        self.price_level_weight = price_level_weight;
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Ranker for WeightedRanker {
+    fn score(&self, place: &Place) -> f64 {
+        let mut score = 0.0;
+
+        if let (Some(origin), Some(geometry)) = (&self.origin, &place.geometry) {
+            let distance_m = origin.haversine_distance(&geometry.location);
+            let scale_m = self.distance_scale_m.max(f64::EPSILON);
+            score += self.distance_weight * (scale_m / (scale_m + distance_m));
+        } // if
+
+        if let Some(rating) = place.rating.and_then(|rating| rating.to_f64()) {
+            score += self.rating_weight * (rating / 5.0).clamp(0.0, 1.0);
+        } // if
+
+        if let Some(price_level) = place.price_level {
+            let price_level = f64::from(price_level.min(4));
+            score += self.price_level_weight * (1.0 - price_level / 4.0);
+        } // if
+
+        score
+    } // fn
+} // impl
@@ -184,3 +184,25 @@ impl SecondaryHoursType {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl SecondaryHoursType {
+    /// Returns the code that Google uses for this `SecondaryHoursType`, for example
+    /// `DRIVE_THROUGH`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `SecondaryHoursType` variant, in declaration
+    /// order. Variants that hold an unrecognized code are not included.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
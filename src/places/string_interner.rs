@@ -0,0 +1,146 @@
+//! Contains the `StringInterner` struct and the `InternStrings` trait. Many of
+//! the strings in Places API responses, such as icon URLs, icon colours and
+//! photo attributions, are identical from one place to the next. Interning
+//! them makes every copy share a single allocation, which adds up when
+//! thousands of places are held in memory, for example for clustering.
+
+use crate::places::{Place, PlacePhoto};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A set of shared strings. Each distinct string is stored once, and
+/// `intern` returns a reference-counted handle to the stored copy.
+///
+/// Use one interner for all of the responses whose places are kept together.
+///
+/// ## Example
+///
+/// ```rust
+/// let mut interner = StringInterner::new();
+/// for response in responses.iter_mut() {
+///     response.intern_strings(&mut interner);
+/// }
+/// ```
+
+#[derive(Clone, Debug, Default)]
+pub struct StringInterner {
+    strings: HashSet<Arc<str>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl StringInterner {
+    /// Initializes an empty `StringInterner`.
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Returns the stored copy of the string, storing it first if this is
+    /// the first time it has been seen.
+
+    pub fn intern(&mut self, string: &Arc<str>) -> Arc<str> {
+        if let Some(interned) = self.strings.get(string) {
+            interned.clone()
+        } else {
+            self.strings.insert(string.clone());
+            string.clone()
+        } // if
+    } // fn
+
+    /// Replaces the string in place with the stored copy.
+
+    pub fn intern_in_place(&mut self, string: &mut Arc<str>) {
+        *string = self.intern(string);
+    } // fn
+
+    /// Returns the number of distinct strings stored.
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    } // fn
+
+    /// Returns `true` if no strings have been stored.
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Places API data whose repetitive strings can be shared through a
+/// `StringInterner`.
+
+pub trait InternStrings {
+    /// Replaces the repetitive strings of this value, and of everything it
+    /// contains, with the interner's shared copies.
+    fn intern_strings(&mut self, interner: &mut StringInterner);
+} // trait
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for PlacePhoto {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        for html in &mut self.html_attributions {
+            interner.intern_in_place(html);
+        } // for
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for Place {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        for string in [&mut self.icon, &mut self.icon_background_color, &mut self.icon_mask_base_uri]
+            .into_iter()
+            .flatten()
+        {
+            interner.intern_in_place(string);
+        } // for
+        for photo in &mut self.photos {
+            photo.intern_strings(interner);
+        } // for
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for crate::places::place_details::Response {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        if let Some(place) = &mut self.result {
+            place.intern_strings(interner);
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for crate::places::place_search::text_search::Response {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        self.results.intern_strings(interner);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for crate::places::place_search::nearby_search::Response {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        self.results.intern_strings(interner);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InternStrings for [Place] {
+    fn intern_strings(&mut self, interner: &mut StringInterner) {
+        for place in self {
+            place.intern_strings(interner);
+        } // for
+    } // fn
+} // impl
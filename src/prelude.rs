@@ -21,8 +21,13 @@ pub use rust_decimal_macros::dec;
 // Common structures:
 
 pub use crate::{
+    built_request::BuiltRequest,
+    canonical_request::CanonicalRequest,
+    classified_error::{ClassifiableError, ClassifiedError},
     client::GoogleMapsClient as ClientSettings, client::GoogleMapsClient,
-    error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
+    error::Error as GoogleMapsError, error::Error, error::IntoResult,
+    stored::{Storable, Stored, Upgrades},
+    types::error::Error as TypeError,
 }; // crate
 
 #[cfg(any(feature = "geocoding", feature = "places"))]
@@ -34,6 +39,8 @@ pub use crate::types::address_component::AddressComponent;
     feature = "places"
 ))]
 pub use crate::types::bounds::Bounds;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub use crate::types::circle::{Circle, CircleEndpoint};
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -42,14 +49,8 @@ pub use crate::types::bounds::Bounds;
 pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places",
-    feature = "time_zone"
-))]
+#[cfg(any(feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub use crate::types::input_index::{InputIndex, OriginalIndex};
 pub use crate::types::language::Language;
 #[cfg(any(
     feature = "autocomplete",
@@ -72,14 +73,22 @@ pub use crate::types::location_type::LocationType;
     feature = "places"
 ))]
 pub use crate::types::place_type::PlaceType;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places"
-))]
 pub use crate::types::region::Region;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_modifiers::{RouteModifiers, VehicleEmissionType};
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_restrictions::RouteRestrictions;
+#[cfg(all(
+    any(feature = "h3", feature = "geohash", feature = "geo"),
+    any(feature = "geocoding", feature = "places")
+))]
+pub use crate::types::spatial_bucket::Locatable;
+#[cfg(all(feature = "geo", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_cluster::{cluster_places, PlaceCluster};
+#[cfg(all(feature = "h3", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_h3;
+#[cfg(all(feature = "geohash", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_geohash;
 
 // =============================================================================
 //
@@ -89,6 +98,23 @@ pub use crate::types::region::Region;
 
 #[cfg(feature = "reqwest")]
 pub use crate::request_rate::api::Api;
+#[cfg(feature = "reqwest")]
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+#[cfg(feature = "reqwest")]
+pub use crate::executable::Executable;
+#[cfg(feature = "reqwest")]
+pub use crate::join::{execute_all, join2, join3};
+#[cfg(feature = "reqwest")]
+pub use crate::trace_context::{TraceContext, TraceContextProvider};
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::config::{ClientConfig, RateLimitConfig};
+#[cfg(feature = "reqwest")]
+pub use crate::client::transport_info::{TlsBackend, TlsRoots, TransportInfo};
+#[cfg(feature = "reqwest")]
+pub use crate::client::resolver::{IpPreference, ResolverOptions};
+#[cfg(feature = "reqwest")]
+pub use crate::client::retry_policy::RetryPolicy;
 
 // -----------------------------------------------------------------------------
 
@@ -101,12 +127,16 @@ pub use crate::directions::{
         waypoint::Waypoint,
     }, // request
     response::{
-        driving_maneuver::DrivingManeuver, leg::Leg, overview_polyline::OverviewPolyline,
-        polyline::Polyline, route::Route, status::Status as DirectionsStatus, step::Step,
+        departure_sweep::{departure_times, DepartureSweep, SweepPoint},
+        driving_maneuver::DrivingManeuver, eta::{Eta, EtaBasis}, leg::Leg, overview_polyline::OverviewPolyline,
+        parse_mode::ParseMode,
+        polyline::Polyline, route::Route, route_comparison::RouteComparison,
+        status::Status as DirectionsStatus, step::Step,
+        stored_route::StoredRoute,
         transit_agency::TransitAgency, transit_currency::TransitCurrency,
         transit_details::TransitDetails, transit_fare::TransitFare, transit_line::TransitLine,
         transit_stop::TransitStop, transit_time::TransitTime, transit_vehicle::TransitVehicle,
-        Response as DirectionsResponse,
+        warning::Warning as DirectionsWarning, Response as DirectionsResponse,
     }, // response
     travel_mode::TravelMode,
     vehicle_type::VehicleType,
@@ -117,15 +147,21 @@ pub use crate::directions::{
 #[cfg(feature = "directions")]
 pub use crate::directions::{
     error::Error as DirectionsError,
+    request::params::Params as DirectionsParams,
+    request::typed::TypedRequest as TypedDirectionsRequest,
     request::Request as DirectionsRequest,
     response::{directions_distance::DirectionsDistance, directions_duration::DirectionsDuration}, // response
 }; // crate::directions
 
+#[cfg(feature = "render")]
+pub use crate::directions::response::svg::SvgOptions;
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "distance_matrix")]
 pub use crate::distance_matrix::{
-    error::Error as DistanceMatrixError, request::Request as DistanceMatrixRequest,
+    error::Error as DistanceMatrixError, request::params::Params as DistanceMatrixParams,
+    request::Request as DistanceMatrixRequest,
     response::status::Status as DistanceMatrixStatus, response::Response as DistanceMatrixResponse,
 }; // use crate::distance_matrix
 
@@ -134,7 +170,10 @@ pub use crate::distance_matrix::{
 #[cfg(feature = "elevation")]
 pub use crate::elevation::{
     error::Error as ElevationError,
-    request::{locations::Locations as ElevationLocations, Request as ElevationRequest}, // request
+    request::{
+        locations::Locations as ElevationLocations, params::Params as ElevationParams,
+        Request as ElevationRequest,
+    }, // request
     response::{point::Point, status::Status as ElevationStatus, Response as ElevationResponse}, // response
 }; // crate::elevation
 
@@ -142,21 +181,30 @@ pub use crate::elevation::{
 
 #[cfg(feature = "geocoding")]
 pub use crate::geocoding::{
+    address_normalizer::AddressNormalizer,
     error::Error as GeocodingError,
-    forward::{component::Component as GeocodingComponent, ForwardRequest as GeocodingRequest}, // forward
+    forward::{
+        component::Component as GeocodingComponent, params::Params as GeocodingParams,
+        ForwardRequest as GeocodingRequest,
+    }, // forward
     response::{
-        geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
-        Response as GeocodingResponse,
+        address_descriptor::{AddressDescriptor, Area, Containment, Landmark, LocalizedText, SpatialRelationship},
+        geocoding::Geocoding, geocoding_delta::GeocodingDelta,
+        navigation_point::{NavigationPoint, NavigationTravelMode}, plus_code::PlusCode,
+        status::Status as GeocodingStatus, Response as GeocodingResponse,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{params::Params as GeocodingReverseParams, ReverseRequest as GeocodingReverseRequest},
 }; // crate::geocoding
 
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+pub use crate::geocoding::reverse_batch::ReverseGeocodeMatch;
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
-    request::Request as TimeZoneRequest,
+    request::{params::Params as TimeZoneParams, Request as TimeZoneRequest},
     response::{status::Status as TimeZoneStatus, Response as TimeZoneResponse}, // reponse
 }; // crate::time_zone
 
@@ -165,7 +213,10 @@ pub use crate::time_zone::{
 #[cfg(feature = "autocomplete")]
 pub use crate::places::place_autocomplete::{
     error::Error as AutocompleteError,
-    request::{autocomplete_type::AutocompleteType, Request as PlaceAutocompleteRequest}, // request
+    request::{
+        autocomplete_type::AutocompleteType, params::Params as PlaceAutocompleteParams,
+        Request as PlaceAutocompleteRequest,
+    }, // request
     response::{
         matched_substring::MatchedSubstring, prediction::Prediction,
         status::Status as AutocompleteStatus, structured_format::StructuredFormat, term::Term,
@@ -173,35 +224,62 @@ pub use crate::places::place_autocomplete::{
     }, // response
 }; // crate::places::place_autocomplete
 
+#[cfg(all(feature = "autocomplete", feature = "places", feature = "reqwest"))]
+pub use crate::places::place_autocomplete::response::with_context::{
+    ResponseWithContext as AutocompleteResponseWithContext, Suggestion as AutocompleteSuggestion,
+}; // crate::places::place_autocomplete::response::with_context
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "autocomplete")]
 pub use crate::places::place_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::place_autocomplete
 
+#[cfg(feature = "autocomplete")]
+pub use crate::places::query_autocomplete::request::params::Params as QueryAutocompleteParams; // crate::places::query_autocomplete
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "places")]
 pub use crate::places::{
-    business_status::BusinessStatus, error::Error as PlacesError, place::Place,
+    attributions::{Attributed, Attributions, AuthorAttribution},
+    business_status::BusinessStatus, error::Error as PlacesError,
+    google_maps_links::GoogleMapsLinks, place::Place,
+    place_delta::PlaceDelta,
     place_editorial_summary::PlaceEditorialSummary, place_opening_hours::PlaceOpeningHours,
     place_opening_hours_period::PlaceOpeningHoursPeriod,
     place_opening_hours_period_detail::PlaceOpeningHoursPeriodDetail, place_photo::PlacePhoto,
-    place_review::PlaceReview, place_special_day::PlaceSpecialDay,
+    place_review::PlaceReview, place_special_day::PlaceSpecialDay, place_summary::PlaceSummary,
+    price_level::PriceLevel, ranker::{Ranker, WeightedRanker},
     secondary_hours_type::SecondaryHoursType, status::Status as PlacesStatus,
+    string_interner::{InternStrings, StringInterner},
 }; // crate::places
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::place_search::multi_area_search::{
+    MultiAreaSearch, MultiAreaSearchResponse, MultiAreaSearchTile,
+}; // multi_area_search
+
+#[cfg(feature = "places")]
+pub use crate::places::place_search::pagination::{
+    BudgetExhausted, PageBudget, PageToken, SearchPage,
+}; // pagination
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub use crate::places::place_search::text_search::{
-    request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+    request::params::Params as TextSearchParams, request::Request as TextSearchRequest,
+    response::Response as TextSearchResponse,
 }; // text_search
 
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "places")]
 pub use crate::places::place_details::{
-    field::Field, request::Request as PlaceDetailsRequest,
+    field::Field, request::params::Params as PlaceDetailsParams,
+    request::Request as PlaceDetailsRequest,
     response::Response as PlaceDetailsResponse, sort_order::SortOrder,
 }; // place_details
 
@@ -209,20 +287,39 @@ pub use crate::places::place_details::{
 
 #[cfg(feature = "roads")]
 pub use crate::roads::{
-    error::Error as RoadsError, error_response::ErrorResponse as RoadsErrorResponse,
+    error::Error as RoadsError, error_detail::ErrorDetail as RoadsErrorDetail,
+    error_detail::HelpLink, error_reason::ErrorReason as RoadsErrorReason,
+    error_response::ErrorResponse as RoadsErrorResponse,
     snapped_point::SnappedPoint, status::Status as RoadsStatus,
 }; // crate::roads
 
+#[cfg(all(feature = "roads", feature = "chrono"))]
+pub use crate::roads::map_match::{MapMatchReport, MatchedSegment};
+
+#[cfg(all(feature = "roads", feature = "directions"))]
+pub use crate::roads::route_analysis::{RouteAnalysis, StepAnalysis};
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "roads")]
 pub use crate::roads::snap_to_roads::{
-    request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse,
+    request::params::Params as SnapToRoadsParams, request::Request as SnapToRoadsRequest,
+    response::Response as SnapToRoadsResponse,
 }; // crate::roads::snap_to_roads
 
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "roads")]
 pub use crate::roads::nearest_roads::{
-    request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse,
+    request::params::Params as NearestRoadsParams, request::Request as NearestRoadsRequest,
+    response::Response as NearestRoadsResponse,
 }; // crate::roads::nearest_roads
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "roads")]
+pub use crate::roads::speed_limits::{
+    request::params::Params as SpeedLimitsParams, request::Request as SpeedLimitsRequest,
+    response::speed_limit::SpeedLimit,
+    response::Response as SpeedLimitsResponse, units::Units as SpeedLimitUnits, SpeedLimitCache,
+}; // crate::roads::speed_limits
@@ -9,7 +9,15 @@ use serde::{Deserialize, Serialize};
 /// Client can be set to have different request rates for `Directions` and
 /// `Elevation` requests. This `enum` is used to select which Google Maps API
 /// you would like to configure.
+///
+/// The `Places` and `Roads` variants cover every service of their API. Each
+/// service also has its own variant, such as `PlaceAutocomplete` or
+/// `SpeedLimits`, so that it can be throttled independently. A request
+/// observes the limits set for `All`, for its API, and for its service.
+///
+/// New variants may be added as Google Maps Platform adds services.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Api {
     /// This variant is used to select settings that are observed for _all_
     /// APIs. These settings are observed in addition to the per-API settings.
@@ -19,8 +27,28 @@ pub enum Api {
     Elevation,
     Geocoding,
     TimeZone,
+    /// Every service of the Places API.
     Places,
+    /// Every service of the Roads API.
     Roads,
+    /// The Places API _Place Autocomplete_ service.
+    PlaceAutocomplete,
+    /// The Places API _Query Autocomplete_ service.
+    QueryAutocomplete,
+    /// The Places API _Place Details_ service.
+    PlaceDetails,
+    /// The Places API _Place Photos_ service.
+    PlacePhoto,
+    /// The Places API _Text Search_ service.
+    TextSearch,
+    /// The Places API _Nearby Search_ service.
+    NearbySearch,
+    /// The Roads API _Snap to Roads_ service.
+    SnapToRoads,
+    /// The Roads API _Nearest Roads_ service.
+    NearestRoads,
+    /// The Roads API _Speed Limits_ service.
+    SpeedLimits,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -37,6 +65,15 @@ impl std::convert::From<&Api> for String {
             Api::TimeZone => Self::from("Time Zone"),
             Api::Places => Self::from("Places"),
             Api::Roads => Self::from("Roads"),
+            Api::PlaceAutocomplete => Self::from("Place Autocomplete"),
+            Api::QueryAutocomplete => Self::from("Query Autocomplete"),
+            Api::PlaceDetails => Self::from("Place Details"),
+            Api::PlacePhoto => Self::from("Place Photo"),
+            Api::TextSearch => Self::from("Text Search"),
+            Api::NearbySearch => Self::from("Nearby Search"),
+            Api::SnapToRoads => Self::from("Snap to Roads"),
+            Api::NearestRoads => Self::from("Nearest Roads"),
+            Api::SpeedLimits => Self::from("Speed Limits"),
         } // match
     } // fn
 } // impl
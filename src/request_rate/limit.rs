@@ -1,4 +1,4 @@
-use crate::request_rate::{api::Api, duration_to_string::duration_to_string, RequestRate};
+use crate::request_rate::{api::Api, api_rate::ApiRate, duration_to_string::duration_to_string, RequestRate};
 use futures::future;
 use std::time::SystemTime;
 
@@ -17,12 +17,15 @@ impl RequestRate {
     ///
     /// * `apis` ‧ The APIs for which to observe the request rate limit.
     pub async fn limit_apis(&self, apis: Vec<&Api>) {
-        let mut limit_futures = Vec::new();
-        for (key, val) in &self.rate_map {
-            if apis.contains(&key) {
-                limit_futures.push(val.limit());
-            }
-        }
+        // The throttles are cloned out of the rate map so that the lock isn't
+        // held while waiting:
+        let api_rates: Vec<ApiRate> = self
+            .read()
+            .iter()
+            .filter(|(key, _val)| apis.contains(key))
+            .map(|(_key, val)| val.clone())
+            .collect();
+        let limit_futures = api_rates.iter().map(ApiRate::limit);
         let start = SystemTime::now();
         future::join_all(limit_futures).await;
         let wait_time = SystemTime::now().duration_since(start);
@@ -19,19 +19,26 @@ mod with_rate;
 use crate::request_rate::api::Api;
 use crate::request_rate::api_rate::ApiRate;
 use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 // -----------------------------------------------------------------------------
 //
 /// Contains the request rates for the Google Maps Platform and the individual
 /// Google Maps APIs.
+///
+/// The rates are kept behind a lock so that they can be changed through a
+/// shared reference. Clones of a `RequestRate` share the same throttles, and
+/// share the same rates until `with_rate` is called on one of them: it gives
+/// that clone its own copy of the rates. `set_rate` changes the rates of
+/// every clone that still shares them.
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct RequestRate {
     /// Used to specify the request rate for _all_ APIs in addition to the
     /// per-API request rates. The `Api::All` request rate will be observed
     /// first, then the per-API request rate such as `Api::Directions` will be
     /// observed afterward.
-    pub rate_map: HashMap<Api, ApiRate>,
+    pub rate_map: Arc<RwLock<HashMap<Api, ApiRate>>>,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -40,7 +47,34 @@ impl std::default::Default for RequestRate {
     /// Returns default values (empty) for the `RequestRate` struct.
     fn default() -> Self {
         Self {
-            rate_map: HashMap::new(),
+            rate_map: Arc::new(RwLock::new(HashMap::new())),
         } // struct
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::cmp::PartialEq for RequestRate {
+    /// Compares the target request rates of two `RequestRate` structs.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.rate_map, &other.rate_map) || *self.read() == *other.read()
+    } // fn
+} // impl
+
+impl std::cmp::Eq for RequestRate {}
+
+// -----------------------------------------------------------------------------
+
+impl RequestRate {
+    /// Locks the rate map for reading. The rate map is never left in an
+    /// inconsistent state, so a poisoned lock is recovered from.
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<Api, ApiRate>> {
+        self.rate_map.read().unwrap_or_else(PoisonError::into_inner)
+    } // fn
+
+    /// Locks the rate map for writing. The rate map is never left in an
+    /// inconsistent state, so a poisoned lock is recovered from.
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<Api, ApiRate>> {
+        self.rate_map.write().unwrap_or_else(PoisonError::into_inner)
+    } // fn
+} // impl
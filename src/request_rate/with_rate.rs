@@ -1,4 +1,5 @@
 use crate::request_rate::{api::Api, api_rate::ApiRate, target_rate::TargetRate, RequestRate}; // use crate::request_rate
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use stream_throttle::{ThrottlePool, ThrottleRate};
 
@@ -47,38 +48,44 @@ impl RequestRate {
     /// ```
 
     pub fn with_rate(&mut self, api: &Api, requests: u16, duration: Duration) -> &mut Self {
-        // Select `RequestRate` field for the API specified by the caller.
-        let api_ref = self.rate_map.get_mut(api);
-        let throttle_pool = if requests == 0 {
-            None
-        } else {
-            let throttle_rate = ThrottleRate::new(requests as usize, duration);
-            Some(ThrottlePool::new(throttle_rate))
-        };
+        // Rates set while building a client apply only to this `RequestRate`,
+        // not to the clones it was made from. The throttles themselves are
+        // still shared:
+        let mut rate_map = self.read().clone();
+        rate_map.insert(api.clone(), api_rate(requests, duration));
+        self.rate_map = Arc::new(RwLock::new(rate_map));
+        self
+    } // fn
 
-        // Has the ApiRate been set already?
-        match api_ref {
-            // If not, initialize the structure:
-            None => {
-                self.rate_map.insert(
-                    api.clone(),
-                    ApiRate {
-                        target_rate: TargetRate { requests, duration },
-                        throttle_pool,
-                    },
-                );
-            }
-            // If it has, set the new target request rate but preserve the
-            // current effective request rate:
-            Some(api_rate) => {
-                *api_rate = ApiRate {
-                    // Set new target request rate:
-                    target_rate: TargetRate { requests, duration },
-                    throttle_pool,
-                };
-            } // ApiRate
-        } // match
+    // -------------------------------------------------------------------------
+    //
+    /// Changes the request rate for the selected API through a shared
+    /// reference. _Do not use this method to set request rate limits, use
+    /// `GoogleMapsClient.set_rate()` instead_.
+    ///
+    /// Unlike `with_rate`, the change is seen by every clone that shares this
+    /// `RequestRate`. Requests that are already waiting under the old rate
+    /// finish waiting under it.
 
-        self
+    pub fn set_rate(&self, api: &Api, requests: u16, duration: Duration) {
+        self.write().insert(api.clone(), api_rate(requests, duration));
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns a new `ApiRate` for the target request rate. A rate of `0`
+/// requests is not throttled.
+fn api_rate(requests: u16, duration: Duration) -> ApiRate {
+    let throttle_pool = if requests == 0 {
+        None
+    } else {
+        let throttle_rate = ThrottleRate::new(requests as usize, duration);
+        Some(ThrottlePool::new(throttle_rate))
+    };
+
+    ApiRate {
+        target_rate: TargetRate { requests, duration },
+        throttle_pool,
+    } // ApiRate
+} // fn
@@ -13,6 +13,10 @@ use thiserror::Error;
 #[derive(Debug, Diagnostic, Error)]
 #[diagnostic(code(google_maps::roads::error), url(docsrs))]
 pub enum Error {
+    /// Exactly one of a path or a list of place IDs must be specified for a
+    /// Speed Limits request.
+    EitherPathOrPlaceIds,
+
     /// Google Maps Roads API server generated an error. See the `Status`
     /// enum for more information.
     GoogleMapsService(Status, Option<String>),
@@ -25,13 +29,18 @@ pub enum Error {
     /// information.
     InvalidStatusCode(String),
 
+    /// API client library attempted to parse a string that contained an invalid
+    /// speed limit units code. See `google_maps\src\roads\speed_limits\units.rs`
+    /// for more information.
+    InvalidUnitsCode(String),
+
     /// The query string must be built before the request may be sent to the
     /// Google Maps Roads API server.
     QueryNotBuilt,
 
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
-    Reqwest(crate::ReqError),
+    Reqwest(#[source] crate::ReqError),
 
     /// The dependency library Reqwest generated an error. The error could
     /// not be passed normally so a `String` representation is passed instead.
@@ -39,7 +48,7 @@ pub enum Error {
     ReqwestMessage(String),
 
     /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    SimdJson(#[source] simd_json::Error),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -49,6 +58,9 @@ impl std::fmt::Display for Error {
     /// to the user.
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::EitherPathOrPlaceIds => write!(f, "Google Maps Roads API client: \
+                A Speed Limits request must specify either a path or a list of place IDs, but not both. \
+                Try again with only `for_path` or only `for_place_ids`."),
             Self::GoogleMapsService(status, error_message) => match error_message {
                 // If the Google Maps Roads API server generated an error
                 // message, return that:
@@ -86,6 +98,9 @@ impl std::fmt::Display for Error {
                 `{status_code}` is not a valid status code. \
                 Valid codes are `INVALID_ARGUMENT`, `PERMISSION_DENIED`, \
                 `NOT_FOUND`, and `RESOURCE_EXHAUSTED`."),
+            Self::InvalidUnitsCode(units_code) => write!(f, "Google Maps Roads API client: \
+                `{units_code}` is not a valid speed limit units code. \
+                Valid codes are `KPH` and `MPH`."),
             Self::QueryNotBuilt => write!(f, "Google Maps Roads API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
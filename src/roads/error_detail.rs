@@ -0,0 +1,59 @@
+//! Contains the `ErrorDetail` struct. Roads API error responses may carry a
+//! list of these with additional, machine-readable information.
+
+use crate::roads::error_reason::ErrorReason;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+//
+/// An entry in the `details` list of a Roads API error response.
+///
+/// Google sends several kinds of details, identified by `type_url`. This
+/// struct holds the fields of the `google.rpc.ErrorInfo` and `google.rpc.Help`
+/// kinds; fields that don't apply to a detail's kind are left empty.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct ErrorDetail {
+    /// Identifies the kind of detail. For example,
+    /// `type.googleapis.com/google.rpc.ErrorInfo`.
+    #[serde(rename = "@type")]
+    pub type_url: String,
+
+    /// The cause of the error. Only present on `ErrorInfo` details.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<ErrorReason>,
+
+    /// The logical grouping the `reason` belongs to, usually
+    /// `googleapis.com`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Additional structured details, such as the `service` or `consumer`
+    /// involved.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+
+    /// Links to documentation or to the Google Cloud console that may help
+    /// resolve the error. Only present on `Help` details.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<HelpLink>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A link provided by Google in a `Help` error detail.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct HelpLink {
+    /// Describes what the link offers.
+    #[serde(default)]
+    pub description: String,
+
+    /// The URL of the link.
+    pub url: String,
+} // struct
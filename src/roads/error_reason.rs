@@ -0,0 +1,206 @@
+//! Contains the `ErrorReason` enum. It is the machine-readable cause reported
+//! in the `ErrorInfo` details of a Roads API error response.
+
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The `reason` of an `ErrorInfo` error detail. Lets callers branch on the
+/// cause of an error without matching on strings.
+///
+/// See [Google API error
+/// reasons](https://cloud.google.com/apis/design/errors#error_info) for more
+/// information.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ErrorReason {
+    /// The API key is not valid.
+    ApiKeyInvalid,
+    /// The API key has expired.
+    ApiKeyExpired,
+    /// The API key is not allowed to call this API.
+    ApiKeyServiceBlocked,
+    /// The API key's HTTP referrer restriction blocked the request.
+    ApiKeyHttpReferrerBlocked,
+    /// The API key's IP address restriction blocked the request.
+    ApiKeyIpAddressBlocked,
+    /// The API key's Android app restriction blocked the request.
+    ApiKeyAndroidAppBlocked,
+    /// The API key's iOS app restriction blocked the request.
+    ApiKeyIosAppBlocked,
+    /// The API has not been enabled on the Google Cloud project.
+    ServiceDisabled,
+    /// Billing has not been enabled on the Google Cloud project.
+    BillingDisabled,
+    /// The request rate exceeded a per-minute or per-user quota.
+    RateLimitExceeded,
+    /// A quota other than the request rate has been exhausted.
+    ResourceExhausted,
+    /// The project was not found or is not valid.
+    ConsumerInvalid,
+    /// A reason that this client library does not recognize yet.
+    Unknown(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl ErrorReason {
+    /// Returns `true` if the error was caused by a missing, invalid, expired or
+    /// restricted API key.
+
+    #[must_use]
+    pub const fn is_key_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::ApiKeyInvalid
+                | Self::ApiKeyExpired
+                | Self::ApiKeyServiceBlocked
+                | Self::ApiKeyHttpReferrerBlocked
+                | Self::ApiKeyIpAddressBlocked
+                | Self::ApiKeyAndroidAppBlocked
+                | Self::ApiKeyIosAppBlocked
+        )
+    } // fn
+
+    /// Returns `true` if the error was caused by exceeding a rate limit or
+    /// quota. These errors usually go away after waiting.
+
+    #[must_use]
+    pub const fn is_quota_problem(&self) -> bool {
+        matches!(self, Self::RateLimitExceeded | Self::ResourceExhausted)
+    } // fn
+
+    /// Returns `true` if the error was caused by the Google Cloud project's
+    /// configuration: the API is not enabled, billing is not enabled, or the
+    /// project is not valid.
+
+    #[must_use]
+    pub const fn is_project_problem(&self) -> bool {
+        matches!(
+            self,
+            Self::ServiceDisabled | Self::BillingDisabled | Self::ConsumerInvalid
+        )
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for ErrorReason {
+    /// Manual implementation of `Deserialize` for `serde`. Unrecognized reasons
+    /// are kept in the `Unknown` variant rather than producing an error.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(Self::from(string.as_str()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for ErrorReason {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a ErrorReason> for &'a str {
+    /// Converts an `ErrorReason` enum to a `String` that contains a Google API
+    /// error reason code.
+    fn from(reason: &'a ErrorReason) -> Self {
+        match reason {
+            ErrorReason::ApiKeyInvalid => "API_KEY_INVALID",
+            ErrorReason::ApiKeyExpired => "API_KEY_EXPIRED",
+            ErrorReason::ApiKeyServiceBlocked => "API_KEY_SERVICE_BLOCKED",
+            ErrorReason::ApiKeyHttpReferrerBlocked => "API_KEY_HTTP_REFERRER_BLOCKED",
+            ErrorReason::ApiKeyIpAddressBlocked => "API_KEY_IP_ADDRESS_BLOCKED",
+            ErrorReason::ApiKeyAndroidAppBlocked => "API_KEY_ANDROID_APP_BLOCKED",
+            ErrorReason::ApiKeyIosAppBlocked => "API_KEY_IOS_APP_BLOCKED",
+            ErrorReason::ServiceDisabled => "SERVICE_DISABLED",
+            ErrorReason::BillingDisabled => "BILLING_DISABLED",
+            ErrorReason::RateLimitExceeded => "RATE_LIMIT_EXCEEDED",
+            ErrorReason::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            ErrorReason::ConsumerInvalid => "CONSUMER_INVALID",
+            ErrorReason::Unknown(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ErrorReason {
+    /// Formats an `ErrorReason` enum as its Google API error reason code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static ERROR_REASONS_BY_CODE: phf::Map<&'static str, ErrorReason> = phf_map! {
+    "API_KEY_INVALID" => ErrorReason::ApiKeyInvalid,
+    "API_KEY_EXPIRED" => ErrorReason::ApiKeyExpired,
+    "API_KEY_SERVICE_BLOCKED" => ErrorReason::ApiKeyServiceBlocked,
+    "API_KEY_HTTP_REFERRER_BLOCKED" => ErrorReason::ApiKeyHttpReferrerBlocked,
+    "API_KEY_IP_ADDRESS_BLOCKED" => ErrorReason::ApiKeyIpAddressBlocked,
+    "API_KEY_ANDROID_APP_BLOCKED" => ErrorReason::ApiKeyAndroidAppBlocked,
+    "API_KEY_IOS_APP_BLOCKED" => ErrorReason::ApiKeyIosAppBlocked,
+    "SERVICE_DISABLED" => ErrorReason::ServiceDisabled,
+    "BILLING_DISABLED" => ErrorReason::BillingDisabled,
+    "RATE_LIMIT_EXCEEDED" => ErrorReason::RateLimitExceeded,
+    "RESOURCE_EXHAUSTED" => ErrorReason::ResourceExhausted,
+    "CONSUMER_INVALID" => ErrorReason::ConsumerInvalid,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&str> for ErrorReason {
+    /// Gets an `ErrorReason` enum from a Google API error reason code.
+    /// Unrecognized codes are kept in the `Unknown` variant.
+    fn from(reason_code: &str) -> Self {
+        ERROR_REASONS_BY_CODE
+            .get(reason_code)
+            .cloned()
+            .unwrap_or_else(|| Self::Unknown(reason_code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for ErrorReason {
+    type Err = std::convert::Infallible;
+    /// Gets an `ErrorReason` enum from a Google API error reason code.
+    /// Unrecognized codes are kept in the `Unknown` variant, so this never
+    /// fails.
+    fn from_str(reason_code: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(reason_code))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ErrorReason {
+    /// Returns the code that Google uses for this `ErrorReason`, for example
+    /// `API_KEY_INVALID`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `ErrorReason` variant, in declaration
+    /// order. Variants that hold an unrecognized code are not included.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = ERROR_REASONS_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
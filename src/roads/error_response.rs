@@ -3,7 +3,7 @@
 
 // -----------------------------------------------------------------------------
 
-use crate::roads::status::Status;
+use crate::roads::{error_detail::{ErrorDetail, HelpLink}, error_reason::ErrorReason, status::Status};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -26,4 +26,39 @@ pub struct ErrorResponse {
 
     /// A status code indicating the nature of the error.
     pub status: Status,
+
+    /// Additional, machine-readable information about the error.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<ErrorDetail>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl ErrorResponse {
+    /// Returns the reason of the first `ErrorInfo` detail, if Google provided
+    /// one.
+    /// ```rust
+    /// if error.reason().is_some_and(ErrorReason::is_key_problem) {
+    ///     rotate_api_key();
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn reason(&self) -> Option<&ErrorReason> {
+        self.details.iter().find_map(|detail| detail.reason.as_ref())
+    } // fn
+
+    /// Returns the help links Google attached to this error, if any. These
+    /// typically point to the Google Cloud console page where the problem can
+    /// be fixed, such as enabling the API.
+    /// ```rust
+    /// for link in error.help_links() {
+    ///     tracing::error!("{}: {}", link.description, link.url);
+    /// }
+    /// ```
+
+    pub fn help_links(&self) -> impl Iterator<Item = &HelpLink> {
+        self.details.iter().flat_map(|detail| detail.links.iter())
+    } // fn
+} // impl
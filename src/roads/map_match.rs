@@ -0,0 +1,228 @@
+//! Contains the `MapMatchReport` struct. It matches a timestamped GPS trace to
+//! the road network with the _Snap To Roads_ service, and breaks the snapped
+//! path down into the road segments that were travelled, with the time spent
+//! and the average speed on each. This is a building block for fleet
+//! analytics, such as speeding reports or time spent per road.
+
+use crate::roads::snapped_point::SnappedPoint;
+use crate::types::LatLng;
+use chrono::{DateTime, Utc};
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of points that the _Snap To Roads_ service accepts in a
+/// single request.
+#[cfg(feature = "reqwest")]
+const MAX_POINTS: usize = 100;
+
+// -----------------------------------------------------------------------------
+//
+/// A stretch of a trace that was matched to a single road segment.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchedSegment {
+    /// The place ID of the road segment.
+    pub place_id: String,
+
+    /// The positions, in the trace, of the points that were matched to this
+    /// road segment, in ascending order. May be empty if only interpolated
+    /// points fell on the segment.
+    pub trace_indices: Vec<usize>,
+
+    /// The length, in meters, of the snapped path along this road segment,
+    /// including interpolated points.
+    pub distance_meters: f64,
+
+    /// The timestamp of the first trace point on this road segment.
+    pub entered_at: Option<DateTime<Utc>>,
+
+    /// The timestamp of the last trace point on this road segment.
+    pub exited_at: Option<DateTime<Utc>>,
+
+    /// The average speed, in kilometers per hour, between the first and last
+    /// trace points on this road segment. `None` if fewer than two trace
+    /// points, with different timestamps, were matched to it.
+    pub average_speed_kph: Option<f64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The result of matching a GPS trace to the road network. See
+/// `GoogleMapsClient::map_match`.
+///
+/// ## Example
+///
+/// ```rust
+/// let report = google_maps_client.map_match(trace).await?;
+/// for segment in &report.segments {
+///     if segment.average_speed_kph.is_some_and(|speed| speed > 100.0) {
+///         println!("fast on {}", segment.place_id);
+///     }
+/// }
+/// ```
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MapMatchReport {
+    /// The snapped path, including interpolated points, as returned by the
+    /// _Snap To Roads_ service. `origin_index` refers to the whole trace.
+    pub snapped_points: Vec<SnappedPoint>,
+
+    /// The road segments that were travelled, in the order they were
+    /// travelled. A road that was left and later re-entered appears twice.
+    pub segments: Vec<MatchedSegment>,
+
+    /// The positions, in the trace, of the points that Google could not match
+    /// to a road.
+    pub unmatched: Vec<usize>,
+
+    /// The total length, in meters, of the snapped path.
+    pub distance_meters: f64,
+
+    /// The warnings returned by the _Snap To Roads_ service.
+    pub warnings: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl MapMatchReport {
+    /// Builds a report from a trace and the points it was snapped to. Use this
+    /// to analyze _Snap To Roads_ responses that were retrieved earlier. The
+    /// `origin_index` of each snapped point must refer to the whole trace.
+
+    #[must_use]
+    pub fn from_snapped(trace: &[(LatLng, DateTime<Utc>)], snapped_points: Vec<SnappedPoint>) -> Self {
+        let mut segments: Vec<MatchedSegment> = Vec::new();
+        let mut distance_meters = 0.0;
+        // The trace index and distance along the segment of the first and last
+        // timed points of the current segment:
+        let mut timed: Option<((usize, f64), (usize, f64))> = None;
+        let mut previous: Option<&SnappedPoint> = None;
+
+        for point in &snapped_points {
+            let step = previous.map_or(0.0, |previous| previous.location.haversine_distance(&point.location));
+            distance_meters += step;
+
+            let same_segment = segments.last().is_some_and(|segment|
+                point.place_id.as_deref().map_or(true, |place_id| place_id == segment.place_id));
+
+            if same_segment {
+                if let Some(segment) = segments.last_mut() {
+                    segment.distance_meters += step;
+                } // if
+            } else if let Some(place_id) = &point.place_id {
+                close_segment(segments.last_mut(), timed.take(), trace);
+                segments.push(MatchedSegment {
+                    place_id: place_id.clone(),
+                    trace_indices: Vec::new(),
+                    distance_meters: 0.0,
+                    entered_at: None,
+                    exited_at: None,
+                    average_speed_kph: None,
+                }); // push
+            } // if
+
+            if let (Some(segment), Some(index)) = (segments.last_mut(), point.origin_index) {
+                if index < trace.len() {
+                    segment.trace_indices.push(index);
+                    let position = (index, segment.distance_meters);
+                    timed = Some(timed.map_or((position, position), |(first, _)| (first, position)));
+                } // if
+            } // if
+
+            previous = Some(point);
+        } // for
+        close_segment(segments.last_mut(), timed, trace);
+
+        let mut matched = vec![false; trace.len()];
+        snapped_points
+            .iter()
+            .filter_map(|point| point.origin_index)
+            .filter(|index| *index < trace.len())
+            .for_each(|index| matched[index] = true);
+
+        Self {
+            snapped_points,
+            segments,
+            unmatched: (0..trace.len()).filter(|index| !matched[*index]).collect(),
+            distance_meters,
+            warnings: Vec::new(),
+        } // MapMatchReport
+    } // fn
+} // impl
+
+/// Sets the entry and exit times and the average speed of a finished segment,
+/// from its first and last timed points.
+fn close_segment(
+    segment: Option<&mut MatchedSegment>,
+    timed: Option<((usize, f64), (usize, f64))>,
+    trace: &[(LatLng, DateTime<Utc>)],
+) {
+    let (Some(segment), Some(((first, start), (last, end)))) = (segment, timed) else {
+        return;
+    }; // let
+
+    let (entered_at, exited_at) = (trace[first].1, trace[last].1);
+    segment.entered_at = Some(entered_at);
+    segment.exited_at = Some(exited_at);
+
+    // Millisecond precision is plenty for an average speed:
+    #[allow(clippy::cast_precision_loss)]
+    let seconds = (exited_at - entered_at).num_milliseconds() as f64 / 1_000.0;
+    if seconds > 0.0 {
+        segment.average_speed_kph = Some((end - start) / seconds * 3.6);
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::GoogleMapsClient {
+    /// Matches a timestamped GPS trace to the road network, and reports the
+    /// road segments that were travelled with the time spent and the average
+    /// speed on each.
+    ///
+    /// The trace is snapped with interpolation, in requests of up to 100
+    /// points. Points should be in chronological order and, as Google
+    /// recommends for _Snap To Roads_, no more than about 300 meters apart.
+    ///
+    /// ## Arguments
+    ///
+    /// * `trace` ‧ The GPS fixes, each with the time it was recorded.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the error of the first _Snap To Roads_ request that fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let report = google_maps_client.map_match(trace).await?;
+    /// println!("{:.1} km on {} roads", report.distance_meters / 1_000.0, report.segments.len());
+    /// ```
+
+    pub async fn map_match(
+        &self,
+        trace: Vec<(LatLng, DateTime<Utc>)>,
+    ) -> Result<MapMatchReport, crate::error::Error> {
+        let mut snapped_points = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (chunk_index, chunk) in trace.chunks(MAX_POINTS).enumerate() {
+            let response = self
+                .snap_to_roads(chunk.iter().map(|(location, _)| *location))
+                .with_interpolation(true)
+                .execute()
+                .await?;
+            snapped_points.extend(response.snapped_points.into_iter().map(|mut point| {
+                point.offset_original_index(chunk_index * MAX_POINTS);
+                point
+            })); // extend
+            warnings.extend(response.warning_message);
+        } // for
+
+        Ok(MapMatchReport {
+            warnings,
+            ..MapMatchReport::from_snapped(&trace, snapped_points)
+        }) // MapMatchReport
+    } // fn
+} // impl
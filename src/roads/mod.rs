@@ -40,7 +40,6 @@
 //!   part of a continuous path.
 //!
 //! * [Speed limits](https://developers.google.com/maps/documentation/roads/speed-limits)
-//!   **(Not yet implemented in this client.)**
 //!   This service returns the posted speed limit for a road segment. The Speed
 //!   Limit service is available to all customers with an Asset Tracking license.
 //!   For [Google Maps Platform Premium Plan customers](https://developers.google.com/maps/premium)
@@ -55,23 +54,46 @@
 //! request throttling and automatic retry.
 
 pub mod error;
+pub mod error_detail;
+pub mod error_reason;
 pub mod error_response;
+#[cfg(feature = "chrono")]
+pub mod map_match;
 pub mod nearest_roads;
+#[cfg(feature = "directions")]
+pub mod route_analysis;
 pub mod snap_to_roads;
 pub mod snapped_point;
+pub mod speed_limits;
 pub mod status;
 
 // -----------------------------------------------------------------------------
 
 pub use crate::roads::{
-    error::Error as RoadsError, error_response::ErrorResponse as RoadsErrorResponse,
+    error::Error as RoadsError, error_detail::ErrorDetail as RoadsErrorDetail,
+    error_detail::HelpLink, error_reason::ErrorReason as RoadsErrorReason,
+    error_response::ErrorResponse as RoadsErrorResponse,
     snapped_point::SnappedPoint, status::Status as RoadsStatus,
 }; // crate::roads
 
+#[cfg(feature = "chrono")]
+pub use crate::roads::map_match::{MapMatchReport, MatchedSegment};
+
+#[cfg(feature = "directions")]
+pub use crate::roads::route_analysis::{RouteAnalysis, StepAnalysis};
+
 pub use crate::roads::snap_to_roads::{
-    request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse,
+    request::params::Params as SnapToRoadsParams, request::Request as SnapToRoadsRequest,
+    response::Response as SnapToRoadsResponse,
 }; // crate::roads::snap_to_roads
 
 pub use crate::roads::nearest_roads::{
-    request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse,
+    request::params::Params as NearestRoadsParams, request::Request as NearestRoadsRequest,
+    response::Response as NearestRoadsResponse,
 }; // crate::roads::nearest_roads
+
+pub use crate::roads::speed_limits::{
+    request::params::Params as SpeedLimitsParams, request::Request as SpeedLimitsRequest,
+    response::speed_limit::SpeedLimit,
+    response::Response as SpeedLimitsResponse, units::Units as SpeedLimitUnits, SpeedLimitCache,
+}; // crate::roads::speed_limits
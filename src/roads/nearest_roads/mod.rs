@@ -26,5 +26,6 @@ const SERVICE_URL: &str = "https://roads.googleapis.com/v1/nearestRoads";
 
 // -----------------------------------------------------------------------------
 
+pub use crate::roads::nearest_roads::request::params::Params as NearestRoadsParams;
 pub use crate::roads::nearest_roads::request::Request as NearestRoadsRequest;
 pub use crate::roads::nearest_roads::response::Response as NearestRoadsResponse;
@@ -3,7 +3,7 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request<'_> {
     // -------------------------------------------------------------------------
     //
     /// Builds the query string for the Google Maps Nearest Roads request based
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -29,7 +29,13 @@ impl<'a> Request<'a> {
         let points: String = utf8_percent_encode(&points, NON_ALPHANUMERIC).to_string();
 
         // Build "required parameters" portion of the query string:
-        let query = format!("key={key}&points={points}", key = self.client.key,);
+        let mut query = format!("key={key}&points={points}", key = self.client.key,);
+
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
 
         // Set query string in Request struct.
         self.query = Some(query);
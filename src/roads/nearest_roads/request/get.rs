@@ -5,9 +5,8 @@ use crate::roads::nearest_roads::{
     request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse,
     SERVICE_URL,
 };
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -35,7 +34,7 @@ impl<'a> NearestRoadsRequest<'a> {
 
         self.client
             .rate_limit
-            .limit_apis(vec![&Api::All, &Api::Roads])
+            .limit_apis(vec![&Api::All, &Api::Roads, &Api::NearestRoads])
             .await;
 
         tracing::debug!("{url}");
@@ -44,10 +43,11 @@ impl<'a> NearestRoadsRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Roads, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            self.client.record_circuit_outcome(&Api::Roads, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -92,7 +92,7 @@ impl<'a> NearestRoadsRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(RoadsError::ReqwestMessage(error.to_string())))
+                                Err(Permanent(RoadsError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
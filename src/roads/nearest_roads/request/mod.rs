@@ -6,12 +6,16 @@
 // -----------------------------------------------------------------------------
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
+mod with_channel;
 
 // -----------------------------------------------------------------------------
 
@@ -39,6 +43,11 @@ pub struct Request<'a> {
     /// `points=60.170880,24.942795|60.170879,24.942796|60.170877,24.942796`.
     points: Vec<LatLng>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
@@ -32,6 +32,7 @@ impl Request<'_> {
             // Required parameters:
             client,
             points,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
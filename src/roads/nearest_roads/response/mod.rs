@@ -50,3 +50,18 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        match &self.error {
+            Some(error) => Err(crate::roads::error::Error::GoogleMapsService(
+                error.status.clone(),
+                Some(error.message.clone()),
+            ))?,
+            None => Ok(self),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,276 @@
+//! Contains the `RouteAnalysis` struct. It combines a _Directions API_ route
+//! with the _Roads API_ speed limits along it, and estimates the speed that
+//! the route's travel times imply on each step. Steps where that speed is over
+//! the posted limit are flagged. This is a building block for safety
+//! analytics, such as checking whether a planned schedule is only achievable
+//! by speeding.
+
+use crate::directions::response::{route::Route, step::Step};
+use crate::roads::speed_limits::response::Response as SpeedLimitsResponse;
+use crate::roads::speed_limits::units::Units;
+use crate::types::LatLng;
+use chrono::Duration;
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+
+/// The number of kilometers in a mile, for converting speed limits.
+const KILOMETERS_PER_MILE: f64 = 1.609_344;
+
+// -----------------------------------------------------------------------------
+//
+/// The expected speed and the speed limit on one step of a route.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StepAnalysis {
+    /// The position of the step's leg in the route.
+    pub leg_index: usize,
+
+    /// The position of the step in its leg.
+    pub step_index: usize,
+
+    /// The length of the step, in meters.
+    pub distance_meters: u32,
+
+    /// The expected travel time of the step. When the leg has a
+    /// `duration_in_traffic`, the step's typical duration is scaled by the
+    /// leg's ratio of traffic to typical duration.
+    pub duration: Duration,
+
+    /// The average speed, in kilometers per hour, needed to cover the step in
+    /// its expected travel time. `None` if the step has no duration.
+    pub expected_speed_kph: Option<f64>,
+
+    /// The speed limit, in kilometers per hour, on the step. When the step
+    /// covers several road segments, the highest limit is used so that a
+    /// step is only flagged if it is over every limit along it. `None` if
+    /// Google has no speed limit for the step's roads.
+    pub speed_limit_kph: Option<f64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl StepAnalysis {
+    /// Returns by how much, in kilometers per hour, the expected speed is over
+    /// the speed limit. Negative if it is under. `None` if the expected speed
+    /// or the speed limit is unknown.
+
+    #[must_use]
+    pub fn excess_kph(&self) -> Option<f64> {
+        Some(self.expected_speed_kph? - self.speed_limit_kph?)
+    } // fn
+
+    /// Returns whether the expected speed is more than `tolerance_kph` over
+    /// the speed limit. Steps with an unknown speed or limit are never over
+    /// the limit.
+
+    #[must_use]
+    pub fn exceeds_limit(&self, tolerance_kph: f64) -> bool {
+        self.excess_kph().is_some_and(|excess| excess > tolerance_kph)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The speed profile of a route: the expected speed and the speed limit on
+/// each of its steps. See `GoogleMapsClient::analyze_route`.
+///
+/// ## Example
+///
+/// ```rust
+/// let analysis = google_maps_client.analyze_route(&directions.routes[0]).await?;
+/// for step in analysis.speeding_steps(5.0) {
+///     println!("step {} of leg {} needs {:.0} km/h", step.step_index, step.leg_index, step.expected_speed_kph.unwrap_or_default());
+/// }
+/// ```
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RouteAnalysis {
+    /// The analysis of each step of the route, in route order.
+    pub steps: Vec<StepAnalysis>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RouteAnalysis {
+    /// Builds the speed profile of a route from the speed limits along it.
+    /// The speed limits must have been requested for the route's path, as
+    /// with `SpeedLimitsRequest::for_route`, so that their snapped points
+    /// refer to the route's steps.
+
+    #[must_use]
+    pub fn new(route: &Route, speed_limits: &SpeedLimitsResponse) -> Self {
+        let (path, spans) = route_path(route);
+
+        // The highest speed limit, in km/h, at each point of the path:
+        let mut limits: Vec<Option<f64>> = vec![None; path.len()];
+        for (point, speed_limit) in speed_limits.speed_limits_along_path() {
+            let (Some(index), Some(speed_limit)) = (point.original_index(), speed_limit) else {
+                continue;
+            }; // let
+            let Some(kph) = speed_limit.speed_limit.to_f64() else {
+                continue;
+            }; // let
+            let kph = match speed_limit.units {
+                Units::Kph => kph,
+                Units::Mph => kph * KILOMETERS_PER_MILE,
+            }; // match
+            if let Some(limit) = limits.get_mut(index.get()) {
+                *limit = Some(limit.map_or(kph, |limit| limit.max(kph)));
+            } // if
+        } // for
+
+        let mut spans = spans.into_iter();
+        let mut steps = Vec::new();
+        for (leg_index, leg) in route.legs.iter().enumerate() {
+            // Millisecond precision is plenty for a traffic ratio:
+            #[allow(clippy::cast_precision_loss)]
+            let traffic_ratio = leg.duration_in_traffic.as_ref()
+                .filter(|_| leg.duration.value > Duration::zero())
+                .map_or(1.0, |duration_in_traffic|
+                    duration_in_traffic.value.num_milliseconds() as f64
+                        / leg.duration.value.num_milliseconds() as f64);
+
+            for (step_index, step) in leg.steps.iter().enumerate() {
+                let Some((start, end)) = spans.next() else {
+                    break;
+                }; // let
+
+                // Millisecond precision is plenty for an average speed:
+                #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+                let duration = Duration::milliseconds(
+                    (step.duration.value.num_milliseconds() as f64 * traffic_ratio).round() as i64
+                ); // milliseconds
+                #[allow(clippy::cast_precision_loss)]
+                let seconds = duration.num_milliseconds() as f64 / 1_000.0;
+                let expected_speed_kph = (seconds > 0.0)
+                    .then(|| f64::from(step.distance.value) / seconds * 3.6);
+
+                let speed_limit_kph = limits
+                    .get(start..=end)
+                    .unwrap_or_default()
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .reduce(f64::max);
+
+                steps.push(StepAnalysis {
+                    leg_index,
+                    step_index,
+                    distance_meters: step.distance.value,
+                    duration,
+                    expected_speed_kph,
+                    speed_limit_kph,
+                }); // push
+            } // for
+        } // for
+
+        Self { steps }
+    } // fn
+
+    /// Returns the steps whose expected speed is more than `tolerance_kph`
+    /// over the speed limit.
+
+    pub fn speeding_steps(&self, tolerance_kph: f64) -> impl Iterator<Item = &StepAnalysis> {
+        self.steps.iter().filter(move |step| step.exceeds_limit(tolerance_kph))
+    } // fn
+
+    /// Returns the share, from 0 to 1, of the route's distance that has a
+    /// known speed limit. A low coverage means the analysis says little about
+    /// the route.
+
+    #[must_use]
+    pub fn coverage(&self) -> f64 {
+        let total: u64 = self.steps.iter().map(|step| u64::from(step.distance_meters)).sum();
+        let known: u64 = self.steps
+            .iter()
+            .filter(|step| step.speed_limit_kph.is_some())
+            .map(|step| u64::from(step.distance_meters))
+            .sum();
+        // Route lengths are far below 2⁵² meters:
+        #[allow(clippy::cast_precision_loss)]
+        if total == 0 { 0.0 } else { known as f64 / total as f64 }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns the path of a route, made up of the start location of each leg and
+/// the points of each step's polyline, with consecutive duplicate points
+/// removed. Also returns, for each step in route order, the positions in the
+/// path of its start and end points.
+///
+/// Polylines are only decoded with the `polyline` and `geo` features.
+/// Otherwise, and for a step whose polyline can't be decoded, only the step's
+/// end location is used.
+pub(crate) fn route_path(route: &Route) -> (Vec<LatLng>, Vec<(usize, usize)>) {
+    let mut path: Vec<LatLng> = Vec::new();
+    let mut spans = Vec::new();
+    let mut push = |point: LatLng| -> usize {
+        // Consecutive duplicate points add nothing to the snapped path:
+        if path.last() != Some(&point) {
+            path.push(point);
+        } // if
+        path.len() - 1
+    }; // closure
+
+    for leg in &route.legs {
+        let mut start = push(leg.start_location);
+        for step in &leg.steps {
+            for point in step_polyline(step) {
+                push(point);
+            } // for
+            let end = push(step.end_location);
+            spans.push((start, end));
+            start = end;
+        } // for
+    } // for
+
+    (path, spans)
+} // fn
+
+/// Decodes the polyline of a step. Returns no points if the polyline is
+/// invalid.
+#[cfg(all(feature = "polyline", feature = "geo"))]
+fn step_polyline(step: &Step) -> Vec<LatLng> {
+    step.polyline
+        .decode(5)
+        .map(|line| line.0.iter().filter_map(|coord| LatLng::try_from(coord).ok()).collect())
+        .unwrap_or_default()
+} // fn
+
+/// Returns no points: polylines can't be decoded without the `polyline` and
+/// `geo` features.
+#[cfg(not(all(feature = "polyline", feature = "geo")))]
+const fn step_polyline(_step: &Step) -> Vec<LatLng> {
+    Vec::new()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::GoogleMapsClient {
+    /// Looks up the speed limits along a route, and returns its speed profile:
+    /// the speed that the route's travel times imply on each step, and the
+    /// speed limit there.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route` ‧ A route returned by the _Directions API_.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the error of the _Speed Limits_ request if it fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let analysis = google_maps_client.analyze_route(&directions.routes[0]).await?;
+    /// println!("{} steps over the limit", analysis.speeding_steps(0.0).count());
+    /// ```
+
+    pub async fn analyze_route(&self, route: &Route) -> Result<RouteAnalysis, crate::error::Error> {
+        let speed_limits = self.speed_limits_for_route(route).execute().await?;
+        Ok(RouteAnalysis::new(route, &speed_limits))
+    } // fn
+} // impl
@@ -37,5 +37,6 @@ const SERVICE_URL: &str = "https://roads.googleapis.com/v1/snapToRoads";
 
 // -----------------------------------------------------------------------------
 
+pub use crate::roads::snap_to_roads::request::params::Params as SnapToRoadsParams;
 pub use crate::roads::snap_to_roads::request::Request as SnapToRoadsRequest;
 pub use crate::roads::snap_to_roads::response::Response as SnapToRoadsResponse;
@@ -4,9 +4,8 @@ use crate::roads::error::Error as RoadsError;
 use crate::roads::snap_to_roads::{
     request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse, SERVICE_URL,
 };
-use backoff::future::retry;
+use crate::runtime::retry_with_circuit;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
@@ -34,7 +33,7 @@ impl<'a> SnapToRoadsRequest<'a> {
 
         self.client
             .rate_limit
-            .limit_apis(vec![&Api::All, &Api::Roads])
+            .limit_apis(vec![&Api::All, &Api::Roads, &Api::SnapToRoads])
             .await;
 
         tracing::debug!("{url}");
@@ -43,10 +42,11 @@ impl<'a> SnapToRoadsRequest<'a> {
         // retries is returned, or we have reached the maximum retries. Note:
         // errors wrapped in `Transient()` will retried by the `backoff` crate
         // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        let response = retry_with_circuit(self.client, &Api::Roads, || async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            self.client.record_circuit_outcome(&Api::Roads, &response);
 
             // Check response from the HTTP client:
             match response {
@@ -91,7 +91,7 @@ impl<'a> SnapToRoadsRequest<'a> {
                             } // Ok(text)
                             Err(error) => {
                                 tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(RoadsError::ReqwestMessage(error.to_string())))
+                                Err(Permanent(RoadsError::Reqwest(crate::ReqError::from(error))))
                             } // Err
                         } // match
                           // We got a response from the server but it was not OK.
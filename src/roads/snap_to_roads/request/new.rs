@@ -31,6 +31,7 @@ impl Request<'_> {
             path,
             // Optional parameters:
             interpolate: None,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
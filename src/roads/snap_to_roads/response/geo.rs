@@ -0,0 +1,53 @@
+//! Provides `LineString` conversions for the _Snap To Roads_ response, for the
+//! [geo](https://crates.io/crates/geo) crate.
+
+use crate::{error::Error as GoogleMapsError, roads::snap_to_roads::response::Response};
+use geo_types::geometry::{Coord, LineString};
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Attempts to convert the snapped points to a
+    /// `geo_types::geometry::LineString<f64>` struct. Interpolated points are
+    /// included, in the order Google returned them. Use `original_indices` to
+    /// map the points back to the request's path.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a snapped point's coordinates can not be
+    ///   converted to `f64`.
+
+    pub fn to_line_string(&self) -> Result<LineString<f64>, GoogleMapsError> {
+        self.snapped_points
+            .iter()
+            .map(|snapped_point| Coord::try_from(&snapped_point.location))
+            .collect::<Result<Vec<Coord>, _>>()
+            .map(LineString::new)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TryFrom<&Response> for LineString<f64> {
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = GoogleMapsError;
+
+    /// Attempts to convert a borrowed `&Response` struct to a
+    /// `geo_types::geometry::LineString<f64>` struct.
+    fn try_from(response: &Response) -> Result<Self, Self::Error> {
+        response.to_line_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TryFrom<Response> for LineString<f64> {
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = GoogleMapsError;
+
+    /// Attempts to convert an owned `Response` struct into a
+    /// `geo_types::geometry::LineString<f64>` struct.
+    fn try_from(response: Response) -> Result<Self, Self::Error> {
+        response.to_line_string()
+    } // fn
+} // impl
@@ -2,6 +2,9 @@
 //! the Google Maps Platform. Look in here for more information about the data
 //! returned from Google's server and how to parse it with your program.
 
+#[cfg(feature = "geo")]
+mod geo;
+
 // -----------------------------------------------------------------------------
 
 use crate::roads::error_response::ErrorResponse;
@@ -50,3 +53,63 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns, for each snapped point, the position of the point in the
+    /// request's path that it was snapped from. Points that were added by
+    /// interpolation have no corresponding point in the path and are `None`.
+    ///
+    /// ```rust
+    /// for (snapped, original) in response.snapped_points.iter().zip(response.original_indices()) {
+    ///     if let Some(original) = original {
+    ///         println!("{} was snapped to {}", path[original], snapped.location);
+    ///     }
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn original_indices(&self) -> Vec<Option<usize>> {
+        self.snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.origin_index)
+            .collect()
+    } // fn
+
+    /// Returns each snapped point with the point of the request's path that it
+    /// was snapped from. Points that were added by interpolation are paired
+    /// with `None`. `path` must be the path that was passed to the request.
+    ///
+    /// ```rust
+    /// for (snapped, original) in response.with_originals(&path) {
+    ///     if let Some(original) = original {
+    ///         println!("{original} was snapped to {}", snapped.location);
+    ///     }
+    /// }
+    /// ```
+
+    pub fn with_originals<'a, T>(
+        &self,
+        path: &'a [T],
+    ) -> impl Iterator<Item = (&SnappedPoint, Option<&'a T>)> {
+        self.snapped_points
+            .iter()
+            .map(|snapped_point| (snapped_point, snapped_point.original(path)))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        match &self.error {
+            Some(error) => Err(crate::roads::error::Error::GoogleMapsService(
+                error.status.clone(),
+                Some(error.message.clone()),
+            ))?,
+            None => Ok(self),
+        } // match
+    } // fn
+} // impl
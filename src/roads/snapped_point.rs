@@ -4,7 +4,7 @@
 
 // -----------------------------------------------------------------------------
 
-use crate::types::LatLng;
+use crate::types::{InputIndex, LatLng, OriginalIndex};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -33,3 +33,41 @@ pub struct SnappedPoint {
     #[serde(alias = "originalIndex")]
     pub origin_index: Option<usize>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl SnappedPoint {
+    /// Returns the position, in the request's path, of the point that this
+    /// point was snapped from. Interpolated points have no such position and
+    /// return `None`.
+
+    #[must_use]
+    pub fn original_index(&self) -> Option<OriginalIndex> {
+        self.origin_index.map(InputIndex::new)
+    } // fn
+
+    /// Returns the point, of the path that was passed to the request, that
+    /// this point was snapped from. Returns `None` for interpolated points,
+    /// and if `path` is shorter than the request's path.
+    ///
+    /// ```rust
+    /// for snapped in &response.snapped_points {
+    ///     if let Some(original) = snapped.original(&path) {
+    ///         println!("{original} was snapped to {}", snapped.location);
+    ///     }
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn original<'a, T>(&self, path: &'a [T]) -> Option<&'a T> {
+        self.original_index()?.of(path)
+    } // fn
+
+    /// Moves the original index forward by `offset` positions, so that a point
+    /// snapped from a chunk of a path refers to the whole path. `offset` is the
+    /// position of the chunk's first point.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn offset_original_index(&mut self, offset: usize) {
+        self.origin_index = self.original_index().map(|index| index.offset(offset).get());
+    } // fn
+} // impl
@@ -0,0 +1,137 @@
+//! Contains the `SpeedLimitCache` struct. It is an opt-in, in-memory cache of
+//! speed limits keyed by road segment place ID.
+
+// -----------------------------------------------------------------------------
+
+use crate::roads::speed_limits::response::speed_limit::SpeedLimit;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// An in-memory cache of speed limits keyed by road segment place ID.
+///
+/// Posted speed limits rarely change, and the Speed Limits API is billed per
+/// request. When a cache is attached to a _Speed Limits_ request with
+/// `with_cache`, place IDs that are already cached are served locally and only
+/// the remaining place IDs are sent to Google. Speed limits returned by Google
+/// for both `path` and `placeId` requests are added to the cache.
+///
+/// Entries expire after the configured time-to-live. The cache uses interior
+/// mutability, so it may be shared between requests (or wrapped in an `Arc`
+/// and shared between tasks) by reference.
+///
+/// ```rust
+/// let cache = SpeedLimitCache::new(std::time::Duration::from_secs(60 * 60 * 24 * 30));
+///
+/// let speed_limits = google_maps_client
+///     .speed_limits()
+///     .for_place_ids(vec!["ChIJX12duJAwGQ0Ra0d4Oi4jOGE"])
+///     .with_cache(&cache)
+///     .execute()
+///     .await?;
+/// ```
+
+#[derive(Debug)]
+pub struct SpeedLimitCache {
+    /// How long a cached speed limit is considered fresh.
+    ttl: Duration,
+
+    /// Cached speed limits keyed by road segment place ID, along with the
+    /// instant they were inserted into the cache.
+    entries: RwLock<HashMap<String, (SpeedLimit, Instant)>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl SpeedLimitCache {
+    /// Initializes an empty speed limit cache.
+    ///
+    /// ## Arguments
+    ///
+    /// * `ttl` ‧ The time-to-live for each cached speed limit. Once a speed
+    ///   limit is older than this, it is requested from Google again.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        } // SpeedLimitCache
+    } // fn
+
+    /// Returns the time-to-live for cached speed limits.
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    } // fn
+
+    /// Returns the cached speed limit for the given road segment place ID, if
+    /// it's present and has not expired.
+    #[must_use]
+    pub fn get(&self, place_id: &str) -> Option<SpeedLimit> {
+        let entries = self.entries.read().ok()?;
+        entries
+            .get(place_id)
+            .filter(|(_, inserted)| inserted.elapsed() < self.ttl)
+            .map(|(speed_limit, _)| speed_limit.clone())
+    } // fn
+
+    /// Inserts a speed limit into the cache, replacing any existing entry for
+    /// the same road segment place ID.
+    pub fn insert(&self, speed_limit: SpeedLimit) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.insert(speed_limit.place_id.clone(), (speed_limit, Instant::now()));
+        } // if
+    } // fn
+
+    /// Inserts several speed limits into the cache.
+    pub fn extend(&self, speed_limits: impl IntoIterator<Item = SpeedLimit>) {
+        if let Ok(mut entries) = self.entries.write() {
+            let now = Instant::now();
+            entries.extend(
+                speed_limits
+                    .into_iter()
+                    .map(|speed_limit| (speed_limit.place_id.clone(), (speed_limit, now)))
+            );
+        } // if
+    } // fn
+
+    /// Removes all expired entries from the cache. Expired entries are never
+    /// returned by `get`, so calling this method is only necessary to reclaim
+    /// memory.
+    pub fn purge_expired(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.retain(|_, (_, inserted)| inserted.elapsed() < self.ttl);
+        } // if
+    } // fn
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.write() {
+            entries.clear();
+        } // if
+    } // fn
+
+    /// Returns the number of entries in the cache, including expired entries
+    /// that have not yet been purged.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.read().map_or(0, |entries| entries.len())
+    } // fn
+
+    /// Returns `true` if the cache contains no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for SpeedLimitCache {
+    /// Returns a speed limit cache with a time-to-live of 30 days.
+    fn default() -> Self {
+        Self::new(Duration::from_secs(60 * 60 * 24 * 30))
+    } // fn
+} // impl
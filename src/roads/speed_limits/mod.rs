@@ -0,0 +1,50 @@
+//! The Roads API **Speed Limits** service returns the posted speed limit for a
+//! road segment. The Speed Limit service is available to all customers with an
+//! Asset Tracking license.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/roads/speed-limits#required-parameters)
+//!
+//! Use either the `path` or the `placeId` parameter, not both:
+//!
+//! * `path` - A list of up to 100 latitude/longitude pairs representing a
+//!   path. The Roads API snaps the path to the most likely roads and returns
+//!   the speed limit for each road segment.
+//!
+//! * `placeId` - The place ID(s) representing one or more road segments. Place
+//!   IDs are returned by the _Snap To Roads_ and _Nearest Roads_ services. You
+//!   can pass up to 100 place IDs with each request.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+//!
+//! * `units` - Whether to return speed limits in kilometers or miles per hour.
+//!   This can be set to either `KPH` or `MPH`. Defaults to `KPH`.
+//!
+//! # Caching
+//!
+//! Speed limit data changes rarely. A [`SpeedLimitCache`] may be attached to a
+//! request with `with_cache`. Speed limits for road segments found in the
+//! cache are not requested from Google again until their time-to-live has
+//! expired.
+
+pub mod cache;
+pub mod request;
+pub mod response;
+pub mod units;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://roads.googleapis.com/v1/speedLimits";
+
+/// The maximum number of points or place IDs that the Google Maps Speed Limits
+/// API accepts in a single request.
+pub const MAX_POINTS: usize = 100;
+
+// -----------------------------------------------------------------------------
+
+pub use crate::roads::speed_limits::cache::SpeedLimitCache;
+pub use crate::roads::speed_limits::request::params::Params as SpeedLimitsParams;
+pub use crate::roads::speed_limits::request::Request as SpeedLimitsRequest;
+pub use crate::roads::speed_limits::response::{
+    speed_limit::SpeedLimit, Response as SpeedLimitsResponse,
+}; // crate::roads::speed_limits::response
+pub use crate::roads::speed_limits::units::Units as SpeedLimitUnits;
@@ -0,0 +1,65 @@
+use crate::roads::speed_limits::request::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Speed Limits request based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &mut Self {
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!("key={key}", key = self.client.key);
+
+        // Path key/value pair:
+        if !self.path.is_empty() {
+            // Convert `Vec<LatLng>` to `String`:
+            let path: String = self
+                .path
+                .iter()
+                .map(String::from)
+                .collect::<Vec<String>>()
+                .join("|");
+
+            // URL encode path `String`:
+            query.push_str("&path=");
+            query.push_str(&utf8_percent_encode(&path, NON_ALPHANUMERIC).to_string());
+        } // if
+
+        // Place ID key/value pairs. The `placeId` parameter is repeated once
+        // for each road segment:
+        for place_id in &self.place_ids {
+            query.push_str("&placeId=");
+            query.push_str(&utf8_percent_encode(place_id, NON_ALPHANUMERIC).to_string());
+        } // for
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Units key/value pair:
+        if let Some(units) = &self.units {
+            query.push_str("&units=");
+            query.push_str(&String::from(units));
+        } // if
+
+        // Channel key/value pair:
+        if let Some(channel) = self.channel.as_ref().or(self.client.channel.as_ref()) {
+            query.push_str("&channel=");
+            query.push_str(&utf8_percent_encode(channel, NON_ALPHANUMERIC).to_string());
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query);
+
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,16 @@
+use crate::canonical_request::CanonicalRequest;
+use crate::roads::speed_limits::{request::Request, SERVICE_URL};
+
+// =============================================================================
+
+impl CanonicalRequest for Request<'_> {
+    /// Returns the URL of the service, without the query string.
+    fn service_url(&self) -> String {
+        SERVICE_URL.to_string()
+    } // fn
+
+    /// Returns the query string built by `build`, if any.
+    fn built_query(&self) -> Option<&str> {
+        self.query.as_deref()
+    } // fn
+} // impl
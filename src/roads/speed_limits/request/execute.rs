@@ -0,0 +1,136 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::speed_limits::{
+    request::Request as SpeedLimitsRequest, response::Response as SpeedLimitsResponse, MAX_POINTS,
+}; // crate::roads::speed_limits
+
+// =============================================================================
+
+impl SpeedLimitsRequest<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build().get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// Paths and place ID lists longer than 100 entries are split into several
+    /// requests, and their responses are merged into a single response. The
+    /// `original_index` of each snapped point refers to the full path that was
+    /// passed to the request.
+    ///
+    /// If a cache was attached with `with_cache`, cached road segments are
+    /// served from the cache, and newly retrieved speed limits are stored in
+    /// it.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&mut self) -> Result<SpeedLimitsResponse, GoogleMapsError> {
+        self.validate()?;
+
+        let path = std::mem::take(&mut self.path);
+        let place_ids = std::mem::take(&mut self.place_ids);
+        let units = self.units.unwrap_or_default();
+
+        // Serve any road segments that are already cached, in the requested
+        // units, from the cache:
+        let (cached, uncached): (Vec<_>, Vec<_>) = place_ids
+            .iter()
+            .map(|place_id| {
+                let speed_limit = self
+                    .cache
+                    .and_then(|cache| cache.get(place_id))
+                    .filter(|speed_limit| speed_limit.units == units);
+                (place_id, speed_limit)
+            })
+            .partition(|(_, speed_limit)| speed_limit.is_some());
+
+        let uncached: Vec<String> = uncached
+            .into_iter()
+            .map(|(place_id, _)| place_id.clone())
+            .collect();
+
+        let mut response = SpeedLimitsResponse::default();
+
+        // Query Google for the path, in chunks of up to 100 points:
+        for (chunk_index, chunk) in path.chunks(MAX_POINTS).enumerate() {
+            self.path = chunk.to_vec();
+            let result = self.build().get().await;
+            let chunk_response = match result {
+                Ok(chunk_response) => chunk_response,
+                Err(error) => {
+                    self.path = path;
+                    self.place_ids = place_ids;
+                    return Err(error);
+                } // Err
+            }; // match
+            merge(&mut response, chunk_response, chunk_index * MAX_POINTS);
+        } // for
+
+        // Query Google for the uncached place IDs, in chunks of up to 100:
+        self.path = Vec::new();
+        for chunk in uncached.chunks(MAX_POINTS) {
+            self.place_ids = chunk.to_vec();
+            let result = self.build().get().await;
+            let chunk_response = match result {
+                Ok(chunk_response) => chunk_response,
+                Err(error) => {
+                    self.path = path;
+                    self.place_ids = place_ids;
+                    return Err(error);
+                } // Err
+            }; // match
+            merge(&mut response, chunk_response, 0);
+        } // for
+
+        // Remember the newly retrieved speed limits:
+        if let Some(cache) = self.cache {
+            cache.extend(response.speed_limits.iter().cloned());
+        } // if
+
+        // Add the cached speed limits to the response:
+        response
+            .speed_limits
+            .extend(cached.into_iter().filter_map(|(_, speed_limit)| speed_limit));
+
+        // Restore the request's original parameters, so that it may be
+        // inspected or executed again:
+        self.path = path;
+        self.place_ids = place_ids;
+
+        Ok(response)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Merges the response for a chunk of a request into the combined response.
+/// The `original_index` of each snapped point is offset so that it refers to
+/// the full path rather than to the chunk.
+
+fn merge(
+    response: &mut SpeedLimitsResponse,
+    chunk_response: SpeedLimitsResponse,
+    index_offset: usize
+) {
+    for speed_limit in chunk_response.speed_limits {
+        if response.speed_limit_for(&speed_limit.place_id).is_none() {
+            response.speed_limits.push(speed_limit);
+        } // if
+    } // for
+
+    response
+        .snapped_points
+        .extend(chunk_response.snapped_points.into_iter().map(|mut point| {
+            point.offset_original_index(index_offset);
+            point
+        }));
+
+    if response.warning_message.is_none() {
+        response.warning_message = chunk_response.warning_message;
+    } // if
+} // fn
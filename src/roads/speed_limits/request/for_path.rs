@@ -0,0 +1,106 @@
+use crate::roads::speed_limits::request::Request;
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies a path to be snapped to roads. The speed limit of each road
+    /// segment along the path will be returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ A list of latitude/longitude pairs representing a path.
+    ///   Paths longer than 100 points are split into several requests when the
+    ///   request is executed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .for_path(vec![
+    ///     LatLng::try_from_dec(dec!(60.170880), dec!(24.942795))?,
+    ///     LatLng::try_from_dec(dec!(60.170879), dec!(24.942796))?,
+    ///     LatLng::try_from_dec(dec!(60.170877), dec!(24.942796))?,
+    /// ])
+    /// ```
+
+    pub fn for_path<C, L>(
+        &mut self,
+        path: C
+    ) -> &mut Self
+    where
+        C: IntoIterator<Item = L>,
+        L: Into<LatLng> {
+        // Set path in Request struct.
+        self.path = path.into_iter().map(Into::into).collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the road segments to query by place ID.
+    ///
+    /// ## Arguments
+    ///
+    /// * `place_ids` ‧ The place ID(s) representing one or more road segments.
+    ///   Make sure each place ID refers to a road segment and not to some other
+    ///   type of place. Place IDs are returned by the _Snap To Roads_ and
+    ///   _Nearest Roads_ services. More than 100 place IDs are split into
+    ///   several requests when the request is executed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .for_place_ids(vec![
+    ///     "ChIJX12duJAwGQ0Ra0d4Oi4jOGE",
+    ///     "ChIJLQcticc0GQ0RoHqCVjmGwbY",
+    /// ])
+    /// ```
+
+    pub fn for_place_ids<C, S>(
+        &mut self,
+        place_ids: C
+    ) -> &mut Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        // Set place IDs in Request struct.
+        self.place_ids = place_ids.into_iter().map(Into::into).collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies a _Directions API_ route whose speed limits are to be looked
+    /// up. The route's path is snapped to roads and the speed limits queried in
+    /// the same call.
+    ///
+    /// The path is made up of the start location of each leg and the points
+    /// of each step's polyline. Without the `polyline` and `geo` features, the
+    /// polylines can't be decoded and only the end location of each step is
+    /// used. A path of more than 100 points is requested in several parts.
+    ///
+    /// ## Arguments
+    ///
+    /// * `route` ‧ A route returned by the _Directions API_.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .for_route(&directions.routes[0])
+    /// ```
+
+    #[cfg(feature = "directions")]
+    pub fn for_route(
+        &mut self,
+        route: &crate::directions::response::route::Route
+    ) -> &mut Self {
+        // Set path in Request struct.
+        self.path = crate::roads::route_analysis::route_path(route).0;
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
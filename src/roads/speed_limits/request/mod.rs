@@ -0,0 +1,89 @@
+//! **Look in this module for documentation on building your _Speed Limits_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+mod canonical;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod for_path;
+#[cfg(feature = "reqwest")]
+mod get;
+mod new;
+pub mod params;
+mod query_url;
+mod try_build;
+mod validate;
+mod with_cache;
+mod with_channel;
+mod with_units;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::roads::speed_limits::{cache::SpeedLimitCache, units::Units};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Speed Limits_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+///
+/// ## Example
+///
+/// ```rust
+/// let speed_limits = google_maps_client
+///     .speed_limits()
+///     .for_path(vec![
+///         LatLng::try_from_dec(dec!(38.75807927603043), dec!(-9.03741754643809))?,
+///         LatLng::try_from_dec(dec!(38.6896537), dec!(-9.1770515))?,
+///         LatLng::try_from_dec(dec!(41.1399289), dec!(-8.6094075))?,
+///     ])
+///     .with_units(SpeedLimitUnits::Mph)
+///     .execute()
+///     .await?;
+/// ```
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// A list of latitude/longitude pairs representing a path. The Roads API
+    /// snaps the path to the most likely roads and returns the speed limit of
+    /// each road segment. Paths longer than 100 points are split into several
+    /// requests when executed.
+    path: Vec<LatLng>,
+
+    /// The place ID(s) representing one or more road segments. Only one of
+    /// `path` or `place_ids` may be specified. More than 100 place IDs are
+    /// split into several requests when executed.
+    place_ids: Vec<String>,
+
+    // Optional parameters:
+    // --------------------
+    /// Whether to return speed limits in kilometers or miles per hour. Defaults
+    /// to `KPH`.
+    units: Option<Units>,
+
+    /// An optional cache of previously retrieved speed limits. Cached road
+    /// segments will not be requested from Google again until they expire.
+    cache: Option<&'a SpeedLimitCache>,
+
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<String>,
+} // struct
@@ -0,0 +1,33 @@
+use crate::client::GoogleMapsClient;
+use crate::roads::speed_limits::request::Request;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Speed Limits query. Use the
+    /// `for_path`, `for_place_ids` or `for_route` methods to select the road
+    /// segments to query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            path: Vec::new(),
+            place_ids: Vec::new(),
+            // Optional parameters:
+            units: None,
+            cache: None,
+            channel: None,
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
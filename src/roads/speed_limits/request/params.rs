@@ -0,0 +1,84 @@
+//! Contains the `Params` struct. It holds the parameters of a Speed Limits
+//! request without the client, so that the request can be described in a
+//! configuration file or a job queue and built later.
+
+use crate::client::GoogleMapsClient;
+use crate::roads::speed_limits::{request::Request, units::Units};
+use crate::types::LatLng;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a _Speed Limits_ request, without the client. Unlike the
+/// request, `Params` derives `Default`, `Serialize` and `Deserialize`, so a
+/// request can be read from a YAML or JSON job description. Omitted fields take
+/// their default values.
+///
+/// The speed limit cache is not a parameter. Set it on the request with
+/// `with_cache` after calling `Params::into_request`.
+///
+/// ## Example
+///
+/// ```rust
+/// let params: SpeedLimitsParams = serde_json::from_str(job)?;
+/// let speed_limits = params.into_request(&google_maps_client).execute().await?;
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Params {
+    /// The path for which to return speed limits.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<LatLng>,
+
+    /// The place IDs of the road segments for which to return speed limits.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub place_ids: Vec<String>,
+
+    /// The units in which to return speed limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<Units>,
+
+    /// A tag that is added to the request's usage reports. Overrides the
+    /// client's channel, if one is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Params {
+    /// Builds a request from the parameters. The request is sent with the
+    /// specified client.
+
+    #[must_use]
+    pub fn into_request(self, client: &GoogleMapsClient) -> Request<'_> {
+        Request {
+            client,
+            path: self.path,
+            place_ids: self.place_ids,
+            units: self.units,
+            channel: self.channel,
+            cache: None,
+            query: None,
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Request<'_> {
+    /// Returns the parameters of the request, without the client. They can
+    /// be serialized and turned back into a request with
+    /// `Params::into_request`.
+
+    #[must_use]
+    pub fn to_params(&self) -> Params {
+        Params {
+            path: self.path.clone(),
+            place_ids: self.place_ids.clone(),
+            units: self.units,
+            channel: self.channel.clone(),
+        } // Params
+    } // fn
+} // impl
@@ -0,0 +1,25 @@
+use crate::roads::{error::Error, speed_limits::request::Request};
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures the built query is valid. This function checks that exactly one
+    /// of a path or a list of place IDs has been specified, since the Speed
+    /// Limits API will not accept both in the same request.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+        // Either a path or place IDs must be set, but not both:
+        if self.path.is_empty() == self.place_ids.is_empty() {
+            return Err(Error::EitherPathOrPlaceIds);
+        } // if
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,38 @@
+use crate::roads::speed_limits::{cache::SpeedLimitCache, request::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Attaches a speed limit cache to the request.
+    ///
+    /// When the request is executed, place IDs whose speed limits are already
+    /// in the cache (in the requested units) are served from the cache and are
+    /// not sent to Google. Speed limits returned by Google are added to the
+    /// cache.
+    ///
+    /// Path requests are always sent to Google, since the path must be snapped
+    /// to roads, but their results are added to the cache.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cache` ‧ The cache to read speed limits from and write speed limits
+    ///   to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_cache(&speed_limit_cache)
+    /// ```
+
+    pub fn with_cache(
+        &mut self,
+        cache: &'a SpeedLimitCache
+    ) -> &mut Self {
+        // Set cache in Request struct.
+        self.cache = Some(cache);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::roads::speed_limits::{request::Request, units::Units};
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies whether speed limits are returned in kilometers or miles per
+    /// hour.
+    ///
+    /// ## Arguments
+    ///
+    /// * `units` ‧ Either `SpeedLimitUnits::Kph` or `SpeedLimitUnits::Mph`.
+    ///   Defaults to `KPH`.
+    ///
+    /// ## Example
+    ///
+    /// * Return speed limits in miles per hour:
+    /// ```rust
+    /// .with_units(SpeedLimitUnits::Mph)
+    /// ```
+
+    pub fn with_units(
+        &mut self,
+        units: impl Into<Units>
+    ) -> &mut Self {
+        // Set units in Request struct.
+        self.units = Some(units.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
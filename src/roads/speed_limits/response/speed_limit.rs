@@ -0,0 +1,29 @@
+//! Contains the `SpeedLimit` struct. It describes the posted speed limit for a
+//! single road segment.
+
+// -----------------------------------------------------------------------------
+
+use crate::roads::speed_limits::units::Units;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// [SpeedLimit](https://developers.google.com/maps/documentation/roads/speed-limits#SpeedLimit)
+///
+/// The posted speed limit for a road segment.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SpeedLimit {
+    /// A unique identifier for a place. All place IDs returned by the Roads API
+    /// correspond to road segments.
+    #[serde(alias = "placeId")]
+    pub place_id: String,
+
+    /// The speed limit for that road segment.
+    #[serde(alias = "speedLimit")]
+    pub speed_limit: Decimal,
+
+    /// Returns either `KPH` or `MPH`.
+    pub units: Units,
+} // struct
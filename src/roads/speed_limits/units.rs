@@ -0,0 +1,154 @@
+//! Contains the `Units` enum and its associated traits. It is used to specify
+//! whether speed limits are returned in kilometers or miles per hour.
+
+use crate::error::Error as GoogleMapsError;
+use crate::roads::error::Error as RoadsError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+
+/// Whether to return speed limits in kilometers or miles per hour.
+///
+/// This can be set to either `KPH` or `MPH`. Defaults to `KPH`.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Units {
+    /// Speed limits will be expressed in kilometers per hour.
+    #[default]
+    Kph = 0,
+    /// Speed limits will be expressed in miles per hour.
+    Mph = 1,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Units {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Units {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Units> for &str {
+    /// Converts a `Units` enum to a `String` that contains a speed limit unit
+    /// code.
+    fn from(units: &Units) -> Self {
+        match units {
+            Units::Kph => "KPH",
+            Units::Mph => "MPH",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Units {
+    /// Converts a `Units` enum to a `String` that contains a speed limit unit
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Units> for String {
+    /// Converts a `Units` enum to a `String` that contains a speed limit unit
+    /// code.
+    fn from(units: &Units) -> Self {
+        std::convert::Into::<&str>::into(units).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static UNITS_BY_CODE: phf::Map<&'static str, Units> = phf_map! {
+    "KPH" => Units::Kph,
+    "MPH" => Units::Mph,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for Units {
+    // Error definitions are contained in the `google_maps\src\roads\error.rs`
+    // module.
+    type Error = GoogleMapsError;
+    /// Gets a `Units` enum from a `String` that contains a valid speed limit
+    /// unit code.
+    fn try_from(units_code: &str) -> Result<Self, Self::Error> {
+        Ok(UNITS_BY_CODE
+            .get(units_code)
+            .copied()
+            .ok_or_else(|| RoadsError::InvalidUnitsCode(units_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Units {
+    // Error definitions are contained in the `google_maps\src\roads\error.rs`
+    // module.
+    type Err = GoogleMapsError;
+    /// Gets a `Units` enum from a `String` that contains a valid speed limit
+    /// unit code.
+    fn from_str(units_code: &str) -> Result<Self, Self::Err> {
+        Self::try_from(units_code)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Units {
+    /// Formats a `Units` enum into a string that is presentable to the end
+    /// user.
+    #[must_use]
+    pub const fn display(&self) -> &str {
+        match self {
+            Self::Kph => "km/h",
+            Self::Mph => "mph",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Units {
+    /// Returns the code that Google uses for this `Units`, for example
+    /// `KPH`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Units` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = UNITS_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
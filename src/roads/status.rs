@@ -86,12 +86,7 @@ impl std::convert::From<&Status> for String {
     /// Converts a `Status` enum to a `String` that contains a status
     /// code.
     fn from(status: &Status) -> Self {
-        match status {
-            Status::InvalidArgument => Self::from("INVALID_ARGUMENT"),
-            Status::PermissionDenied => Self::from("PERMISSION_DENIED"),
-            Status::NotFound => Self::from("NOT_FOUND"),
-            Status::ResourceExhausted => Self::from("RESOURCE_EXHAUSTED"),
-        } // match
+        Self::from(status.as_wire_str())
     } // fn
 } // impl
 
@@ -155,3 +150,33 @@ impl std::fmt::Display for Status {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns the code that Google uses for this `Status`, for example
+    /// `INVALID_ARGUMENT`. `FromStr` parses the code back into the same variant.
+    ///
+    /// Unlike `Display`, which is meant for people, this returns the code as
+    /// Google sends it.
+
+    #[must_use]
+    pub const fn as_wire_str(&self) -> &'static str {
+        match self {
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::NotFound => "NOT_FOUND",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
+        } // match
+    } // fn
+
+    /// Returns an iterator over every `Status` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = STATUSES_BY_CODE.values().cloned().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
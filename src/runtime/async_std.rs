@@ -0,0 +1,16 @@
+use crate::runtime::Runtime;
+use std::{future::Future, pin::Pin, time::Duration};
+
+// -----------------------------------------------------------------------------
+//
+/// Runs the client's timers on the `async-std` runtime.
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsyncStdRuntime;
+
+impl Runtime for AsyncStdRuntime {
+    type Sleep = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+    fn sleep(duration: Duration) -> Self::Sleep {
+        Box::pin(::async_std::task::sleep(duration))
+    } // fn
+} // impl
@@ -0,0 +1,123 @@
+//! Contains the `Runtime` trait, which abstracts the async timer used by the
+//! client's retry layer, so that the async client is not tied to a specific
+//! executor. The rate limiter uses `futures-timer`, which works with any
+//! executor.
+//!
+//! The runtime is selected at compile time with these features:
+//!
+//! * `rt-tokio` ‧ Uses `tokio` timers. This is the default, and is also used
+//!   when both or neither of the features are enabled.
+//!
+//! * `rt-async-std` ‧ Uses `async-std` timers. Disable default features to use
+//!   it. Note that `reqwest` still performs its I/O on a `tokio` reactor, so
+//!   `async-std` applications should enable `async-std`'s `tokio1` feature.
+
+#[cfg(all(feature = "rt-async-std", not(feature = "rt-tokio")))]
+mod async_std;
+#[cfg(any(feature = "rt-tokio", not(feature = "rt-async-std")))]
+mod tokio;
+
+// -----------------------------------------------------------------------------
+
+use crate::{client::GoogleMapsClient, error::Error as GoogleMapsError, request_rate::api::Api};
+use backoff::{backoff::Backoff, future::{Retry, Sleeper}, Error::{Permanent, Transient}, Notify};
+use std::{future::Future, marker::PhantomData, time::Duration};
+
+#[cfg(all(feature = "rt-async-std", not(feature = "rt-tokio")))]
+pub use crate::runtime::async_std::AsyncStdRuntime;
+#[cfg(any(feature = "rt-tokio", not(feature = "rt-async-std")))]
+pub use crate::runtime::tokio::TokioRuntime;
+
+// -----------------------------------------------------------------------------
+
+/// The runtime selected by the enabled `rt-*` features. `tokio` takes priority
+/// when both are enabled, since `reqwest` always depends on it.
+#[cfg(any(feature = "rt-tokio", not(feature = "rt-async-std")))]
+pub type DefaultRuntime = TokioRuntime;
+
+/// The runtime selected by the enabled `rt-*` features.
+#[cfg(all(feature = "rt-async-std", not(feature = "rt-tokio")))]
+pub type DefaultRuntime = AsyncStdRuntime;
+
+// -----------------------------------------------------------------------------
+//
+/// The async primitives the client needs from an executor. The client does not
+/// spawn tasks, so only a timer is required.
+
+pub trait Runtime {
+    /// The future returned by `sleep`.
+    type Sleep: Future<Output = ()> + Send + 'static;
+
+    /// Returns a future that completes after `duration` has elapsed.
+    fn sleep(duration: Duration) -> Self::Sleep;
+} // trait
+
+// -----------------------------------------------------------------------------
+
+/// Adapts a `Runtime` to the `backoff` crate's `Sleeper` trait.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RuntimeSleeper<R: Runtime>(PhantomData<R>);
+
+impl<R: Runtime> Sleeper for RuntimeSleeper<R> {
+    type Sleep = R::Sleep;
+    fn sleep(&self, dur: Duration) -> Self::Sleep {
+        R::sleep(dur)
+    } // fn
+} // impl
+
+/// Logs each failed attempt before the next retry.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TraceNotify;
+
+impl<E> Notify<E> for TraceNotify {
+    fn notify(&mut self, _err: E, duration: Duration) {
+        tracing::debug!("request failed, retrying in {duration:?}");
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Retries the given `operation` according to the `backoff` policy, sleeping
+/// between attempts with the `DefaultRuntime` timer. A drop-in replacement for
+/// `backoff::future::retry`.
+pub fn retry<I, E, Fn, Fut, B>(
+    backoff: B,
+    operation: Fn,
+) -> Retry<RuntimeSleeper<DefaultRuntime>, B, TraceNotify, Fn, Fut>
+where
+    B: Backoff,
+    Fn: FnMut() -> Fut,
+    Fut: Future<Output = Result<I, backoff::Error<E>>>,
+{
+    Retry::new(RuntimeSleeper(PhantomData), backoff, TraceNotify, operation)
+} // fn
+
+/// Retries the given `operation` according to the client's retry policy,
+/// checking the client's circuit breaker for `api` before every attempt,
+/// including the first. Once the circuit opens, for example because this
+/// request's own retries pushed it over the failure threshold, the next
+/// attempt isn't sent and `GoogleMapsError::CircuitOpen` is returned.
+pub async fn retry_with_circuit<I, E, Fn, Fut>(
+    client: &GoogleMapsClient,
+    api: &Api,
+    mut operation: Fn,
+) -> Result<I, GoogleMapsError>
+where
+    E: Into<GoogleMapsError>,
+    Fn: FnMut() -> Fut,
+    Fut: Future<Output = Result<I, backoff::Error<E>>>,
+{
+    retry(client.retry_policy.backoff(), || {
+        let attempt = client.check_circuit(api).map(|()| operation());
+        async move {
+            match attempt {
+                Ok(attempt) => attempt.await.map_err(|error| match error {
+                    Permanent(err) => Permanent(err.into()),
+                    Transient { err, retry_after } => Transient { err: err.into(), retry_after },
+                }), // map_err
+                Err(error) => Err(Permanent(error)),
+            } // match
+        } // async
+    })
+    .await
+} // fn
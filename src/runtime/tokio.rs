@@ -0,0 +1,16 @@
+use crate::runtime::Runtime;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Runs the client's timers on the `tokio` runtime.
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioRuntime;
+
+impl Runtime for TokioRuntime {
+    type Sleep = ::tokio::time::Sleep;
+    fn sleep(duration: Duration) -> Self::Sleep {
+        ::tokio::time::sleep(duration)
+    } // fn
+} // impl
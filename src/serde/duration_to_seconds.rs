@@ -4,6 +4,7 @@
 use chrono::Duration;
 use serde::Serializer;
 
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub fn duration_to_seconds<S>(data: &Duration, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -11,3 +12,19 @@ where
     let seconds = data.num_seconds();
     serializer.serialize_i64(seconds)
 } // fn
+
+/// This trait converts an optional `Duration` struct into an optional
+/// quantity of seconds.
+
+#[cfg(feature = "time_zone")]
+// `serialize_with` always passes the field by reference:
+#[allow(clippy::ref_option)]
+pub fn option_duration_to_seconds<S>(data: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match data {
+        Some(data) => serializer.serialize_some(&data.num_seconds()),
+        None => serializer.serialize_none(),
+    } // match
+} // fn
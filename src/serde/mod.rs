@@ -1,6 +1,6 @@
 //! This module contains custom serializers and deserializers for Serde.
 
-#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+#[cfg(any(feature = "directions", feature = "distance_matrix", feature = "time_zone"))]
 pub mod duration_to_seconds;
-#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+#[cfg(any(feature = "directions", feature = "distance_matrix", feature = "time_zone"))]
 pub mod seconds_to_duration;
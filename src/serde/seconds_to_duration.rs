@@ -8,6 +8,7 @@ use serde::{Deserialize, Deserializer};
 /// `time::Duration` struct. The Google Maps Platform returns duration fields in
 /// seconds and it's handier to be able to use them as a `time::Duration` structs.
 
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub fn seconds_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
@@ -23,3 +24,22 @@ where
         ))
     })
 } // fn
+
+/// This trait converts an optional quantity of seconds into an optional
+/// `Duration` struct. It is used for fields that Google omits from some
+/// responses, and must be paired with `#[serde(default)]`.
+
+#[cfg(feature = "time_zone")]
+pub fn option_seconds_to_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let seconds: Option<i64> = Deserialize::deserialize(deserializer)?;
+    seconds
+        .map(|seconds| Duration::try_seconds(seconds).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "could not deserialize a duration from seconds: {seconds}"
+            ))
+        }))
+        .transpose()
+} // fn
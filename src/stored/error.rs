@@ -0,0 +1,59 @@
+//! Stored response envelope error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced while reading or writing a stored response.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::stored::error), url(docsrs))]
+pub enum Error {
+    /// The stored payload is not a `Stored` envelope. The `String` explains
+    /// what is missing.
+    InvalidEnvelope(String),
+    /// The stored payload has a schema version for which no upgrade step was
+    /// registered.
+    MissingUpgrade(u32),
+    /// The stored payload was written by a newer version of this crate. The
+    /// first value is the stored schema version, the second the newest
+    /// version this crate can read.
+    NewerSchema(u32, u32),
+    /// An upgrade step failed. The `String` explains why.
+    UpgradeFailed(String),
+    /// The dependency library Serde JSON generated an error.
+    SimdJson(#[source] simd_json::Error),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidEnvelope(reason) => write!(f, "Google Maps stored response: \
+                the payload is not a stored response envelope: {reason}."),
+            Self::MissingUpgrade(version) => write!(f, "Google Maps stored response: \
+                no upgrade step was registered for schema version {version}. \
+                Register one with `Upgrades::with_step`."),
+            Self::NewerSchema(stored, supported) => write!(f, "Google Maps stored response: \
+                the payload has schema version {stored}, \
+                but this version of the crate only reads up to version {supported}."),
+            Self::UpgradeFailed(reason) => write!(f, "Google Maps stored response: \
+                an upgrade step failed: {reason}."),
+            Self::SimdJson(error) => write!(f, "Google Maps stored response in the Serde JSON library: {error}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<simd_json::Error> for Error {
+    fn from(error: simd_json::Error) -> Self {
+        Self::SimdJson(error)
+    } // fn
+} // impl
@@ -0,0 +1,244 @@
+//! Contains the `Stored` envelope, the `Storable` trait and the `Upgrades`
+//! registry. Together they let applications persist responses for a long time
+//! and still read them after the response structs of this crate change.
+
+pub mod error;
+
+// -----------------------------------------------------------------------------
+
+use crate::canonical_request::CanonicalRequest;
+use crate::stored::error::Error;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use simd_json::{prelude::ValueAsScalar, OwnedValue};
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+//
+/// A response type that can be wrapped in a `Stored` envelope.
+
+pub trait Storable: Serialize + DeserializeOwned {
+    /// The Google Maps Platform API, and its version, that the response came
+    /// from. For the legacy web services, which are not versioned, this is the
+    /// service path and output format, for example `maps/api/geocode/json`.
+    const API_VERSION: &'static str;
+
+    /// The version of this crate's layout of the response. It is increased
+    /// whenever the response struct changes in a way that requires stored
+    /// payloads to be upgraded.
+    const SCHEMA_VERSION: u32;
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// A response, wrapped with the information needed to read it back years
+/// later: the crate version that wrote it, the API it came from, the schema
+/// version of the response, a hash of the request, and when it was fetched.
+///
+/// ## Example
+///
+/// ```rust
+/// let response = request.execute().await?;
+/// let stored = Stored::new(response).with_request(&request);
+/// database.put(key, stored.to_json()?);
+///
+/// // Years later:
+/// let upgrades = Upgrades::new().with_step(1, rename_formatted_address);
+/// let stored: Stored<GeocodingResponse> = Stored::from_json(&mut bytes, &upgrades)?;
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Stored<T> {
+    /// The version of the `google_maps` crate that stored the response.
+    pub crate_version: String,
+
+    /// The API, and its version, that the response came from. See
+    /// `Storable::API_VERSION`.
+    pub api_version: String,
+
+    /// The schema version of the response. See `Storable::SCHEMA_VERSION`.
+    pub schema_version: u32,
+
+    /// A stable hash of the request's cache key, if the request was recorded.
+    /// See `CanonicalRequest::cache_key`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_hash: Option<String>,
+
+    /// When the response was fetched, in seconds since the Unix epoch.
+    pub fetched_at: u64,
+
+    /// The response.
+    pub response: T,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<T: Storable> Stored<T> {
+    /// Wraps a response that was fetched just now.
+
+    #[must_use]
+    pub fn new(response: T) -> Self {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            api_version: T::API_VERSION.to_string(),
+            schema_version: T::SCHEMA_VERSION,
+            request_hash: None,
+            fetched_at: unix_seconds(SystemTime::now()),
+            response,
+        } // Stored
+    } // fn
+
+    /// Records a hash of the request that produced the response. The request
+    /// must have been built, otherwise no hash is recorded.
+
+    #[must_use]
+    pub fn with_request(mut self, request: &impl CanonicalRequest) -> Self {
+        self.request_hash = request.cache_key().map(|cache_key| request_hash(&cache_key));
+        self
+    } // fn
+
+    /// Sets when the response was fetched.
+
+    #[must_use]
+    pub fn with_fetched_at(mut self, fetched_at: SystemTime) -> Self {
+        self.fetched_at = unix_seconds(fetched_at);
+        self
+    } // fn
+
+    /// Returns when the response was fetched.
+
+    #[must_use]
+    pub fn fetched_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.fetched_at)
+    } // fn
+
+    /// Serializes the envelope to JSON.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `Error::SimdJson` if the response could not be serialized.
+
+    pub fn to_json(&self) -> Result<Vec<u8>, Error> {
+        Ok(simd_json::to_vec(self)?)
+    } // fn
+
+    /// Reads an envelope from JSON, upgrading a payload stored with an older
+    /// schema version one step at a time.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `Error::InvalidEnvelope` if the JSON is not an envelope.
+    /// * Returns `Error::NewerSchema` if the payload was stored by a newer
+    ///   version of this crate.
+    /// * Returns `Error::MissingUpgrade` if an upgrade step is missing.
+    /// * Returns the error of a failed upgrade step.
+
+    pub fn from_json(json: &mut [u8], upgrades: &Upgrades) -> Result<Self, Error> {
+        let mut envelope = simd_json::to_owned_value(json)?;
+        let OwnedValue::Object(object) = &mut envelope else {
+            return Err(Error::InvalidEnvelope("expected a JSON object".to_string()));
+        }; // let
+
+        let schema_version = object
+            .get("schema_version")
+            .and_then(ValueAsScalar::as_u64)
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| Error::InvalidEnvelope("missing `schema_version`".to_string()))?;
+
+        if schema_version > T::SCHEMA_VERSION {
+            return Err(Error::NewerSchema(schema_version, T::SCHEMA_VERSION));
+        } // if
+
+        let response = object
+            .get_mut("response")
+            .ok_or_else(|| Error::InvalidEnvelope("missing `response`".to_string()))?;
+        for version in schema_version..T::SCHEMA_VERSION {
+            let upgrade = upgrades.steps.get(&version).ok_or(Error::MissingUpgrade(version))?;
+            upgrade(response)?;
+        } // for
+
+        object.insert("schema_version".to_string(), OwnedValue::from(T::SCHEMA_VERSION));
+        Ok(simd_json::serde::from_owned_value(envelope)?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// An upgrade step. It rewrites the JSON of a stored response from one schema
+/// version to the next.
+pub type Upgrade = fn(&mut OwnedValue) -> Result<(), Error>;
+
+// -----------------------------------------------------------------------------
+//
+/// The upgrade steps that `Stored::from_json` may apply to older payloads.
+
+#[derive(Clone, Debug, Default)]
+pub struct Upgrades {
+    /// The upgrade steps, by the schema version they upgrade from.
+    steps: BTreeMap<u32, Upgrade>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Upgrades {
+    /// Initializes an empty set of upgrade steps.
+
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    } // fn
+
+    /// Registers the step that upgrades a response from `from_version` to
+    /// `from_version + 1`.
+
+    #[must_use]
+    pub fn with_step(mut self, from_version: u32, upgrade: Upgrade) -> Self {
+        self.steps.insert(from_version, upgrade);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns the number of seconds since the Unix epoch. Times before the epoch
+/// are returned as `0`.
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs())
+} // fn
+
+/// Returns the 64-bit FNV-1a hash of a cache key, as 16 hexadecimal digits.
+/// Unlike the standard library's hasher, FNV-1a is specified and gives the
+/// same result on every platform and release.
+fn request_hash(cache_key: &str) -> String {
+    let hash = cache_key.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    }); // fold
+    format!("{hash:016x}")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Implements `Storable` for a response type.
+macro_rules! storable {
+    ($feature:literal, $response:ty, $api_version:literal) => {
+        #[cfg(feature = $feature)]
+        impl Storable for $response {
+            const API_VERSION: &'static str = $api_version;
+            const SCHEMA_VERSION: u32 = 1;
+        } // impl
+    };
+} // macro_rules
+
+storable!("directions", crate::directions::response::Response, "maps/api/directions/json");
+storable!("distance_matrix", crate::distance_matrix::response::Response, "maps/api/distancematrix/json");
+storable!("elevation", crate::elevation::response::Response, "maps/api/elevation/json");
+storable!("geocoding", crate::geocoding::response::Response, "maps/api/geocode/json");
+storable!("time_zone", crate::time_zone::response::Response, "maps/api/timezone/json");
+storable!("autocomplete", crate::places::place_autocomplete::response::Response, "maps/api/place/autocomplete/json");
+storable!("places", crate::places::place_search::nearby_search::response::Response, "maps/api/place/nearbysearch/json");
+storable!("places", crate::places::place_search::text_search::response::Response, "maps/api/place/textsearch/json");
+storable!("places", crate::places::place_details::response::Response, "maps/api/place/details/json");
+storable!("roads", crate::roads::snap_to_roads::response::Response, "v1/snapToRoads");
+storable!("roads", crate::roads::nearest_roads::response::Response, "v1/nearestRoads");
+storable!("roads", crate::roads::speed_limits::response::Response, "v1/speedLimits");
@@ -0,0 +1,491 @@
+//! Anonymized Google Maps API responses for use in tests. Enabled with the
+//! `testing` feature.
+//!
+//! Every endpoint has a fixture for a successful response, for an empty result
+//! where the API has one, and for its quota and authorization errors. The
+//! `malformed` fixtures are bodies that no endpoint should accept. The JSON
+//! files live in the crate's `tests/fixtures` directory, where they are also
+//! used by this crate's own deserialization tests.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use google_maps::testing::geocoding;
+//!
+//! let response: google_maps::prelude::GeocodingResponse = geocoding::OK.parse()?;
+//! assert_eq!(response.results.len(), 1);
+//!
+//! for fixture in google_maps::testing::all() {
+//!     println!("{}/{}: {} bytes", fixture.api, fixture.name, fixture.json.len());
+//! }
+//! ```
+
+// -----------------------------------------------------------------------------
+//
+/// A canned API response.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Fixture {
+    /// The API or endpoint the response is from, for example `geocoding`.
+    pub api: &'static str,
+
+    /// The name of the case, for example `zero_results`.
+    pub name: &'static str,
+
+    /// The response body.
+    pub json: &'static str,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Fixture {
+    /// Parses the fixture into a response type, such as
+    /// `google_maps::prelude::GeocodingResponse`.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns the response type's parse error if the fixture could not be
+    ///   parsed, which is expected for the `malformed` fixtures.
+
+    pub fn parse<T: std::str::FromStr>(&self) -> Result<T, T::Err> {
+        self.json.parse()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns every fixture for the APIs enabled by the crate's feature flags.
+
+#[must_use]
+pub fn all() -> Vec<Fixture> {
+    let mut fixtures = Vec::new();
+    #[cfg(feature = "geocoding")]
+    fixtures.extend_from_slice(geocoding::ALL);
+    #[cfg(feature = "directions")]
+    fixtures.extend_from_slice(directions::ALL);
+    #[cfg(feature = "distance_matrix")]
+    fixtures.extend_from_slice(distance_matrix::ALL);
+    #[cfg(feature = "elevation")]
+    fixtures.extend_from_slice(elevation::ALL);
+    #[cfg(feature = "time_zone")]
+    fixtures.extend_from_slice(time_zone::ALL);
+    #[cfg(feature = "places")]
+    fixtures.extend_from_slice(place_details::ALL);
+    #[cfg(feature = "places")]
+    fixtures.extend_from_slice(text_search::ALL);
+    #[cfg(feature = "places")]
+    fixtures.extend_from_slice(nearby_search::ALL);
+    #[cfg(feature = "autocomplete")]
+    fixtures.extend_from_slice(place_autocomplete::ALL);
+    #[cfg(feature = "roads")]
+    fixtures.extend_from_slice(snap_to_roads::ALL);
+    #[cfg(feature = "roads")]
+    fixtures.extend_from_slice(nearest_roads::ALL);
+    #[cfg(feature = "roads")]
+    fixtures.extend_from_slice(speed_limits::ALL);
+    fixtures.extend_from_slice(malformed::ALL);
+    fixtures
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Geocoding API.
+#[cfg(feature = "geocoding")]
+pub mod geocoding {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/geocoding/ok.json");
+
+    /// A successful response whose only result partially matched the query.
+    pub const PARTIAL_MATCH: &str = include_str!("../tests/fixtures/geocoding/partial_match.json");
+
+    /// A successful reverse geocoding response with an address descriptor and
+    /// navigation points.
+    pub const ADDRESS_DESCRIPTOR: &str = include_str!("../tests/fixtures/geocoding/address_descriptor.json");
+
+    /// A successful response to a "Winnetka" query biased to a viewport in
+    /// Los Angeles with `with_bounds`. The result inside the viewport is listed
+    /// first.
+    pub const BOUNDS: &str = include_str!("../tests/fixtures/geocoding/bounds.json");
+
+    /// A successful response to a "Toledo" query biased to Spain with
+    /// `with_region`.
+    pub const REGION: &str = include_str!("../tests/fixtures/geocoding/region.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/geocoding/zero_results.json");
+
+    /// An `INVALID_REQUEST` response: a required parameter was missing.
+    pub const INVALID_REQUEST: &str = include_str!("../tests/fixtures/geocoding/invalid_request.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/geocoding/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/geocoding/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "geocoding", name: "ok", json: OK },
+        Fixture { api: "geocoding", name: "partial_match", json: PARTIAL_MATCH },
+        Fixture { api: "geocoding", name: "address_descriptor", json: ADDRESS_DESCRIPTOR },
+        Fixture { api: "geocoding", name: "bounds", json: BOUNDS },
+        Fixture { api: "geocoding", name: "region", json: REGION },
+        Fixture { api: "geocoding", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "geocoding", name: "invalid_request", json: INVALID_REQUEST },
+        Fixture { api: "geocoding", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "geocoding", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Directions API.
+#[cfg(feature = "directions")]
+pub mod directions {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/directions/ok.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/directions/zero_results.json");
+
+    /// A `NOT_FOUND` response: a referenced location or place could not be found.
+    pub const NOT_FOUND: &str = include_str!("../tests/fixtures/directions/not_found.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/directions/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/directions/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "directions", name: "ok", json: OK },
+        Fixture { api: "directions", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "directions", name: "not_found", json: NOT_FOUND },
+        Fixture { api: "directions", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "directions", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Distance Matrix API.
+#[cfg(feature = "distance_matrix")]
+pub mod distance_matrix {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/distance_matrix/ok.json");
+
+    /// A `MAX_ELEMENTS_EXCEEDED` response: too many origins × destinations.
+    pub const MAX_ELEMENTS_EXCEEDED: &str = include_str!("../tests/fixtures/distance_matrix/max_elements_exceeded.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/distance_matrix/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/distance_matrix/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "distance_matrix", name: "ok", json: OK },
+        Fixture { api: "distance_matrix", name: "max_elements_exceeded", json: MAX_ELEMENTS_EXCEEDED },
+        Fixture { api: "distance_matrix", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "distance_matrix", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Elevation API.
+#[cfg(feature = "elevation")]
+pub mod elevation {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/elevation/ok.json");
+
+    /// An `INVALID_REQUEST` response: a required parameter was missing.
+    pub const INVALID_REQUEST: &str = include_str!("../tests/fixtures/elevation/invalid_request.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/elevation/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/elevation/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "elevation", name: "ok", json: OK },
+        Fixture { api: "elevation", name: "invalid_request", json: INVALID_REQUEST },
+        Fixture { api: "elevation", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "elevation", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Time Zone API.
+#[cfg(feature = "time_zone")]
+pub mod time_zone {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/time_zone/ok.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/time_zone/zero_results.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/time_zone/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/time_zone/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "time_zone", name: "ok", json: OK },
+        Fixture { api: "time_zone", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "time_zone", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "time_zone", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Places API _Place Details_.
+#[cfg(feature = "places")]
+pub mod place_details {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/place_details/ok.json");
+
+    /// A `NOT_FOUND` response: a referenced location or place could not be found.
+    pub const NOT_FOUND: &str = include_str!("../tests/fixtures/place_details/not_found.json");
+
+    /// An `INVALID_REQUEST` response: a required parameter was missing.
+    pub const INVALID_REQUEST: &str = include_str!("../tests/fixtures/place_details/invalid_request.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/place_details/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/place_details/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "place_details", name: "ok", json: OK },
+        Fixture { api: "place_details", name: "not_found", json: NOT_FOUND },
+        Fixture { api: "place_details", name: "invalid_request", json: INVALID_REQUEST },
+        Fixture { api: "place_details", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "place_details", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Places API _Text Search_.
+#[cfg(feature = "places")]
+pub mod text_search {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/text_search/ok.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/text_search/zero_results.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/text_search/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/text_search/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "text_search", name: "ok", json: OK },
+        Fixture { api: "text_search", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "text_search", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "text_search", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Places API _Nearby Search_.
+#[cfg(feature = "places")]
+pub mod nearby_search {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/nearby_search/ok.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/nearby_search/zero_results.json");
+
+    /// An `INVALID_REQUEST` response: a required parameter was missing.
+    pub const INVALID_REQUEST: &str = include_str!("../tests/fixtures/nearby_search/invalid_request.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/nearby_search/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/nearby_search/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "nearby_search", name: "ok", json: OK },
+        Fixture { api: "nearby_search", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "nearby_search", name: "invalid_request", json: INVALID_REQUEST },
+        Fixture { api: "nearby_search", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "nearby_search", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Places API _Place Autocomplete_.
+#[cfg(feature = "autocomplete")]
+pub mod place_autocomplete {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/place_autocomplete/ok.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/place_autocomplete/zero_results.json");
+
+    /// An `OVER_QUERY_LIMIT` response: the quota was exceeded.
+    pub const OVER_QUERY_LIMIT: &str = include_str!("../tests/fixtures/place_autocomplete/over_query_limit.json");
+
+    /// A `REQUEST_DENIED` response: the API key was rejected.
+    pub const REQUEST_DENIED: &str = include_str!("../tests/fixtures/place_autocomplete/request_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "place_autocomplete", name: "ok", json: OK },
+        Fixture { api: "place_autocomplete", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "place_autocomplete", name: "over_query_limit", json: OVER_QUERY_LIMIT },
+        Fixture { api: "place_autocomplete", name: "request_denied", json: REQUEST_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Roads API _Snap to Roads_.
+#[cfg(feature = "roads")]
+pub mod snap_to_roads {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/snap_to_roads/ok.json");
+
+    /// A successful response carrying a warning message.
+    pub const WARNING: &str = include_str!("../tests/fixtures/snap_to_roads/warning.json");
+
+    /// A response with no results.
+    pub const ZERO_RESULTS: &str = include_str!("../tests/fixtures/snap_to_roads/zero_results.json");
+
+    /// An `INVALID_ARGUMENT` error: a parameter could not be parsed.
+    pub const INVALID_ARGUMENT: &str = include_str!("../tests/fixtures/snap_to_roads/invalid_argument.json");
+
+    /// A `RESOURCE_EXHAUSTED` error: the quota was exceeded.
+    pub const RESOURCE_EXHAUSTED: &str = include_str!("../tests/fixtures/snap_to_roads/resource_exhausted.json");
+
+    /// A `PERMISSION_DENIED` error: the API key was rejected.
+    pub const PERMISSION_DENIED: &str = include_str!("../tests/fixtures/snap_to_roads/permission_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "snap_to_roads", name: "ok", json: OK },
+        Fixture { api: "snap_to_roads", name: "warning", json: WARNING },
+        Fixture { api: "snap_to_roads", name: "zero_results", json: ZERO_RESULTS },
+        Fixture { api: "snap_to_roads", name: "invalid_argument", json: INVALID_ARGUMENT },
+        Fixture { api: "snap_to_roads", name: "resource_exhausted", json: RESOURCE_EXHAUSTED },
+        Fixture { api: "snap_to_roads", name: "permission_denied", json: PERMISSION_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Roads API _Nearest Roads_.
+#[cfg(feature = "roads")]
+pub mod nearest_roads {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/nearest_roads/ok.json");
+
+    /// A `PERMISSION_DENIED` error: the API key was rejected.
+    pub const PERMISSION_DENIED: &str = include_str!("../tests/fixtures/nearest_roads/permission_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "nearest_roads", name: "ok", json: OK },
+        Fixture { api: "nearest_roads", name: "permission_denied", json: PERMISSION_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Roads API _Speed Limits_.
+#[cfg(feature = "roads")]
+pub mod speed_limits {
+    use super::Fixture;
+
+    /// A successful response.
+    pub const OK: &str = include_str!("../tests/fixtures/speed_limits/ok.json");
+
+    /// A successful response with speed limits in miles per hour.
+    pub const MPH: &str = include_str!("../tests/fixtures/speed_limits/mph.json");
+
+    /// A `NOT_FOUND` response: a referenced location or place could not be found.
+    pub const NOT_FOUND: &str = include_str!("../tests/fixtures/speed_limits/not_found.json");
+
+    /// A `PERMISSION_DENIED` error: the API key was rejected.
+    pub const PERMISSION_DENIED: &str = include_str!("../tests/fixtures/speed_limits/permission_denied.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "speed_limits", name: "ok", json: OK },
+        Fixture { api: "speed_limits", name: "mph", json: MPH },
+        Fixture { api: "speed_limits", name: "not_found", json: NOT_FOUND },
+        Fixture { api: "speed_limits", name: "permission_denied", json: PERMISSION_DENIED },
+    ]; // ALL
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Malformed responses, shared by every API.
+pub mod malformed {
+    use super::Fixture;
+
+    /// A response body that was cut off part-way through.
+    pub const TRUNCATED: &str = include_str!("../tests/fixtures/malformed/truncated.json");
+
+    /// A response with a status code this crate does not know.
+    pub const UNKNOWN_STATUS: &str = include_str!("../tests/fixtures/malformed/unknown_status.json");
+
+    /// A response without a status code.
+    pub const MISSING_STATUS: &str = include_str!("../tests/fixtures/malformed/missing_status.json");
+
+    /// An HTML error page, as served by some proxies and Google's front end.
+    pub const HTML_ERROR_PAGE: &str = include_str!("../tests/fixtures/malformed/html_error_page.json");
+
+    /// An empty response body.
+    pub const EMPTY: &str = include_str!("../tests/fixtures/malformed/empty.json");
+
+    /// Every fixture in this module.
+    pub const ALL: &[Fixture] = &[
+        Fixture { api: "malformed", name: "truncated", json: TRUNCATED },
+        Fixture { api: "malformed", name: "unknown_status", json: UNKNOWN_STATUS },
+        Fixture { api: "malformed", name: "missing_status", json: MISSING_STATUS },
+        Fixture { api: "malformed", name: "html_error_page", json: HTML_ERROR_PAGE },
+        Fixture { api: "malformed", name: "empty", json: EMPTY },
+    ]; // ALL
+} // mod
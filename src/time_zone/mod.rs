@@ -77,6 +77,6 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
-    request::Request as TimeZoneRequest,
+    request::{params::Params as TimeZoneParams, Request as TimeZoneRequest},
     response::{status::Status as TimeZoneStatus, Response as TimeZoneResponse}, // reponse
 }; // crate::time_zone
@@ -4,12 +4,16 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+mod canonical;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod params;
 mod query_url;
+mod try_build;
+mod with_channel;
 mod with_language;
 
 use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
@@ -48,6 +52,11 @@ pub struct Request<'a> {
     /// exhaustive. Defaults to `Language::English`.
     language: Option<Language>,
 
+    /// A tag that is added to the request's usage reports, for example to
+    /// separate the usage of different applications that share an API key.
+    /// Overrides the client's channel, if one is set.
+    channel: Option<String>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
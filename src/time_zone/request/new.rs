@@ -51,6 +51,7 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            channel: None,
             // Internal use only:
             query: None,
         } // struct
@@ -86,6 +87,7 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            channel: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -121,6 +123,7 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            channel: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -4,7 +4,12 @@
 
 pub mod status;
 
+use crate::serde::{
+    duration_to_seconds::option_duration_to_seconds,
+    seconds_to_duration::option_seconds_to_duration,
+}; // crate::serde
 use crate::time_zone::response::status::Status;
+use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
@@ -23,12 +28,15 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Response {
-    /// The offset for daylight-savings time in seconds. This will be zero if
-    /// the time zone is not in Daylight Savings Time during the specified
-    /// `time`.
+    /// The offset for daylight-savings time. This will be zero if the time
+    /// zone is not in Daylight Savings Time during the specified `time`.
+    /// Google sends this in seconds.
     #[serde(alias = "dstOffset")]
+    #[serde(default)]
+    #[serde(deserialize_with = "option_seconds_to_duration")]
+    #[serde(serialize_with = "option_duration_to_seconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dst_offset: Option<i16>,
+    pub dst_offset: Option<Duration>,
 
     /// More detailed information about the reasons behind the given status
     /// code, if other than `OK`.
@@ -39,11 +47,14 @@ pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 
-    /// The offset from UTC (in seconds) for the given location. This does not
-    /// take into effect daylight savings.
+    /// The offset from UTC for the given location. This does not take into
+    /// effect daylight savings. Google sends this in seconds.
     #[serde(alias = "rawOffset")]
+    #[serde(default)]
+    #[serde(deserialize_with = "option_seconds_to_duration")]
+    #[serde(serialize_with = "option_duration_to_seconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raw_offset: Option<i16>,
+    pub raw_offset: Option<Duration>,
 
     /// The status of the response.
     pub status: Status,
@@ -68,6 +79,44 @@ pub struct Response {
     pub time_zone_name: Option<String>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the total offset from UTC at the requested `time`: the sum of
+    /// `raw_offset` and `dst_offset`. Returns `None` if Google did not return
+    /// a `raw_offset`, for example when the response status is not `OK`, or if
+    /// the offset is out of range.
+
+    #[must_use]
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        let offset = self.raw_offset?.checked_add(&self.dst_offset.unwrap_or_else(Duration::zero))?;
+        FixedOffset::east_opt(i32::try_from(offset.num_seconds()).ok()?)
+    } // fn
+
+    /// Converts a UTC time into the location's local time, using the offsets
+    /// in this response.
+    ///
+    /// The offsets are those in effect at the `time` the request was made
+    /// for. A time on the other side of a daylight-savings transition will be
+    /// off by the daylight-savings offset. Use `time_zone_id` with
+    /// `DateTime::with_timezone` to convert times that may cross a
+    /// transition.
+    ///
+    /// Returns `None` if Google did not return a `raw_offset`.
+    ///
+    /// ```rust
+    /// let local = time_zone.local_time(Utc::now()).unwrap();
+    /// println!("It is {} in {}", local.format("%H:%M"), time_zone.time_zone_name.unwrap());
+    /// ```
+
+    #[must_use]
+    pub fn local_time(&self, utc: DateTime<Utc>) -> Option<DateTime<FixedOffset>> {
+        self.utc_offset().map(|offset| offset.from_utc_datetime(&utc.naive_utc()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Response {
     type Err = simd_json::Error;
     /// Parse a Google Maps Time Zone API JSON `String` response into a
@@ -77,3 +126,16 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl crate::error::IntoResult for Response {
+    /// Returns an error if Google's response status was not `OK`.
+    fn error_for_status_ref(&self) -> Result<&Self, crate::GoogleMapsError> {
+        if self.status == Status::Ok {
+            Ok(self)
+        } else {
+            Err(crate::time_zone::error::Error::GoogleMapsService(self.status.clone(), self.error_message.clone()))?
+        } // if
+    } // fn
+} // impl
@@ -0,0 +1,133 @@
+//! Contains the `TraceContext` struct. A trace context connects the requests
+//! this client sends to Google with the distributed trace of the application
+//! that sent them, using the headers defined by the [W3C Trace
+//! Context](https://www.w3.org/TR/trace-context/) recommendation.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the trace context of the current span, or `None` if there is no
+/// trace to propagate. Use `GoogleMapsClient::with_trace_context` to install
+/// one.
+///
+/// The provider is called once for every HTTP request, including retries,
+/// from inside the request's `tracing` span.
+
+pub type TraceContextProvider = fn() -> Option<TraceContext>;
+
+// -----------------------------------------------------------------------------
+//
+/// The W3C trace context headers to send with a request.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TraceContext {
+    /// The `traceparent` header. For example,
+    /// `00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01`.
+    pub traceparent: String,
+
+    /// The `tracestate` header, which carries vendor-specific trace data.
+    /// Optional.
+    pub tracestate: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TraceContext {
+    /// Initializes a `TraceContext` with a `traceparent` header and no
+    /// `tracestate` header.
+
+    #[must_use]
+    pub fn new(traceparent: impl Into<String>) -> Self {
+        Self { traceparent: traceparent.into(), tracestate: None }
+    } // fn
+
+    /// Adds a `tracestate` header to the trace context.
+
+    #[must_use]
+    pub fn with_tracestate(mut self, tracestate: impl Into<String>) -> Self {
+        self.tracestate = Some(tracestate.into());
+        self
+    } // fn
+
+    /// Returns whether the headers are well-formed.
+    ///
+    /// The `traceparent` header must have a version, trace ID, parent ID and
+    /// flags field, separated by hyphens and written in lowercase
+    /// hexadecimal. All-zero trace and parent IDs are invalid. The
+    /// `tracestate` header, if any, must be a comma-separated list of at
+    /// most 32 `key=value` entries.
+
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_traceparent()
+            && self.tracestate.as_deref().map_or(true, is_valid_tracestate)
+    } // fn
+
+    /// Returns whether the `traceparent` header is well-formed.
+    fn is_valid_traceparent(&self) -> bool {
+        let fields: Vec<&str> = self.traceparent.split('-').collect();
+        let is_hex = |field: &str, len: usize| {
+            field.len() == len && field.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        }; // closure
+        let is_zero = |field: &str| field.bytes().all(|b| b == b'0');
+
+        match fields.as_slice() {
+            [version, trace_id, parent_id, flags] =>
+                is_hex(version, 2)
+                    && *version != "ff"
+                    && is_hex(trace_id, 32)
+                    && !is_zero(trace_id)
+                    && is_hex(parent_id, 16)
+                    && !is_zero(parent_id)
+                    && is_hex(flags, 2),
+            _ => false,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The largest number of entries a `tracestate` header may have.
+const TRACESTATE_MAX_ENTRIES: usize = 32;
+
+/// Returns whether a `tracestate` header is well-formed. Each entry is a key,
+/// made of lowercase letters, digits and `_-*/`, optionally followed by `@`
+/// and a system name, then `=` and a value of printable ASCII characters
+/// other than `,` and `=`. Empty entries are allowed and ignored.
+fn is_valid_tracestate(tracestate: &str) -> bool {
+    let is_key_char = |b: u8| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'*' | b'/');
+    let is_key = |key: &str| {
+        let (tenant, system) = match key.split_once('@') {
+            Some((tenant, system)) => (tenant, Some(system)),
+            None => (key, None),
+        }; // match
+        let max_tenant_len = if system.is_some() { 241 } else { 256 };
+        !tenant.is_empty()
+            && tenant.len() <= max_tenant_len
+            && tenant.bytes().all(is_key_char)
+            && (tenant.as_bytes()[0].is_ascii_lowercase()
+                || (system.is_some() && tenant.as_bytes()[0].is_ascii_digit()))
+            && system.map_or(true, |system| {
+                (1..=14).contains(&system.len())
+                    && system.as_bytes()[0].is_ascii_lowercase()
+                    && system.bytes().all(is_key_char)
+            }) // map_or
+    }; // closure
+    let is_value = |value: &str| {
+        (1..=256).contains(&value.len())
+            && !value.ends_with(' ')
+            && value.bytes().all(|b| matches!(b, b' '..=b'~') && b != b',' && b != b'=')
+    }; // closure
+
+    let entries: Vec<&str> = tracestate
+        .split(',')
+        .map(|entry| entry.trim_matches(|c| c == ' ' || c == '\t'))
+        .filter(|entry| !entry.is_empty())
+        .collect();
+
+    entries.len() <= TRACESTATE_MAX_ENTRIES
+        && entries.iter().all(|entry| {
+            entry.split_once('=').is_some_and(|(key, value)| is_key(key) && is_value(value))
+        }) // all
+} // fn
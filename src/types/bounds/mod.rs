@@ -86,3 +86,45 @@ impl std::str::FromStr for Bounds {
         } // if
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns the smallest `Bounds` that contains every point in the
+    /// iterator, or `None` if the iterator is empty.
+    ///
+    /// Bounds that cross the antimeridian (180° longitude) are not supported.
+
+    pub fn from_points<'p>(points: impl IntoIterator<Item = &'p LatLng>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(points.fold(
+            Self { southwest: first, northeast: first },
+            |mut bounds, point| {
+                bounds.southwest.lat = bounds.southwest.lat.min(point.lat);
+                bounds.southwest.lng = bounds.southwest.lng.min(point.lng);
+                bounds.northeast.lat = bounds.northeast.lat.max(point.lat);
+                bounds.northeast.lng = bounds.northeast.lng.max(point.lng);
+                bounds
+            },
+        )) // Some
+    } // fn
+
+    /// Returns `true` if the point lies inside these bounds or on their edge.
+
+    #[must_use]
+    pub fn contains(&self, point: &LatLng) -> bool {
+        (self.southwest.lat..=self.northeast.lat).contains(&point.lat)
+            && (self.southwest.lng..=self.northeast.lng).contains(&point.lng)
+    } // fn
+
+    /// Returns `true` if these bounds and `other` overlap or touch.
+
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.southwest.lat <= other.northeast.lat
+            && other.southwest.lat <= self.northeast.lat
+            && self.southwest.lng <= other.northeast.lng
+            && other.southwest.lng <= self.northeast.lng
+    } // fn
+} // impl
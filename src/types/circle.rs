@@ -0,0 +1,201 @@
+//! Contains the `Circle` struct and the `CircleEndpoint` enum. A circle is the
+//! `location` and `radius` pair that biases or restricts _Places API_ results
+//! to an area. The radius is validated when the circle is created, and again
+//! against the limits of the endpoint it is sent to.
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::{error::Error as TypeError, LatLng};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+
+/// The largest radius, in meters, accepted by any _Places API_ endpoint.
+const MAX_RADIUS: u32 = 50_000;
+
+// -----------------------------------------------------------------------------
+//
+/// The _Places API_ endpoints that accept a circle. Each endpoint has its own
+/// radius limits.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum CircleEndpoint {
+    /// _Place Autocomplete_ and _Query Autocomplete_. The radius may be `0`.
+    Autocomplete,
+    /// _Nearby Search_. The radius must be at least 1 meter.
+    NearbySearch,
+    /// _Text Search_. The radius may be `0`.
+    TextSearch,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl CircleEndpoint {
+    /// Returns the smallest radius, in meters, accepted by the endpoint.
+
+    #[must_use]
+    pub const fn min_radius(self) -> u32 {
+        match self {
+            Self::NearbySearch => 1,
+            Self::Autocomplete | Self::TextSearch => 0,
+        } // match
+    } // fn
+
+    /// Returns the largest radius, in meters, accepted by the endpoint.
+
+    #[must_use]
+    pub const fn max_radius(self) -> u32 {
+        MAX_RADIUS
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for CircleEndpoint {
+    /// Formats a `CircleEndpoint` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Autocomplete => write!(f, "Autocomplete"),
+            Self::NearbySearch => write!(f, "Nearby Search"),
+            Self::TextSearch => write!(f, "Text Search"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A circle, specified by its center and its radius in meters. The radius is
+/// always between 0 and 50,000 meters.
+///
+/// ## Example
+///
+/// ```rust
+/// let downtown = Circle::from_center_km(LatLng::try_from_dec(dec!(43.65), dec!(-79.38))?, 2.5)?;
+/// downtown.validate_for(CircleEndpoint::NearbySearch)?;
+/// ```
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedCircle")]
+pub struct Circle {
+    /// The center of the circle.
+    center: LatLng,
+    /// The radius of the circle, in meters.
+    radius: u32,
+} // struct
+
+/// A deserialized circle whose radius has not been checked yet. A `Circle`
+/// is deserialized through it, so that it goes through `Circle::try_new`.
+#[derive(Deserialize)]
+struct UncheckedCircle {
+    center: LatLng,
+    radius: u32,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Circle {
+    /// Initializes a circle with a radius in meters.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TypeError::InvalidRadius` if the radius is above 50,000
+    ///   meters.
+
+    pub fn try_new(center: impl Into<LatLng>, radius_m: u32) -> Result<Self, GoogleMapsError> {
+        if radius_m > MAX_RADIUS {
+            return Err(TypeError::InvalidRadius(radius_m.to_string()).into());
+        } // if
+        Ok(Self { center: center.into(), radius: radius_m })
+    } // fn
+
+    /// Initializes a circle with a radius in meters, rounded to the nearest
+    /// meter.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TypeError::InvalidRadius` if the radius is negative, above
+    ///   50,000 meters, or not a number.
+
+    pub fn from_center_m(center: impl Into<LatLng>, radius_m: f64) -> Result<Self, GoogleMapsError> {
+        let radius = radius_m.round();
+        if !(0.0..=f64::from(MAX_RADIUS)).contains(&radius) {
+            return Err(TypeError::InvalidRadius(radius_m.to_string()).into());
+        } // if
+        // The range check above guarantees that the radius fits in a `u32`:
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let radius = radius as u32;
+        Self::try_new(center, radius)
+    } // fn
+
+    /// Initializes a circle with a radius in kilometers, rounded to the
+    /// nearest meter.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TypeError::InvalidRadius` if the radius is negative, above
+    ///   50 kilometers, or not a number.
+
+    pub fn from_center_km(center: impl Into<LatLng>, radius_km: f64) -> Result<Self, GoogleMapsError> {
+        Self::from_center_m(center, radius_km * 1_000.0)
+    } // fn
+
+    /// Returns the center of the circle.
+
+    #[must_use]
+    pub const fn center(&self) -> LatLng {
+        self.center
+    } // fn
+
+    /// Returns the radius of the circle, in meters.
+
+    #[must_use]
+    pub const fn radius_m(&self) -> u32 {
+        self.radius
+    } // fn
+
+    /// Returns the radius of the circle, in kilometers.
+
+    #[must_use]
+    pub fn radius_km(&self) -> f64 {
+        f64::from(self.radius) / 1_000.0
+    } // fn
+
+    /// Checks the radius against the limits of the specified endpoint.
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `TypeError::InvalidRadiusForEndpoint` if the endpoint does
+    ///   not accept the radius.
+
+    pub fn validate_for(&self, endpoint: CircleEndpoint) -> Result<(), GoogleMapsError> {
+        if (endpoint.min_radius()..=endpoint.max_radius()).contains(&self.radius) {
+            Ok(())
+        } else {
+            Err(TypeError::InvalidRadiusForEndpoint(self.radius, endpoint).into())
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl TryFrom<UncheckedCircle> for Circle {
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = GoogleMapsError;
+
+    /// Checks the radius of a deserialized circle.
+    fn try_from(circle: UncheckedCircle) -> Result<Self, Self::Error> {
+        Self::try_new(circle.center, circle.radius)
+    } // fn
+} // impl
+
+#[cfg(feature = "geo")]
+impl TryFrom<(geo_types::geometry::Point, f64)> for Circle {
+    // Error definitions are contained in the `google_maps\src\error.rs` module.
+    type Error = GoogleMapsError;
+
+    /// Attempts to convert a `geo_types::geometry::Point` center and a radius
+    /// in meters into a `google_maps::Circle` struct.
+    fn try_from((center, radius_m): (geo_types::geometry::Point, f64)) -> Result<Self, Self::Error> {
+        Self::from_center_m(LatLng::try_from(center)?, radius_m)
+    } // fn
+} // impl
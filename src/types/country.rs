@@ -1160,3 +1160,25 @@ impl Country {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Country {
+    /// Returns the code that Google uses for this `Country`, for example
+    /// `AF`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Country` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = COUNTRIES_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
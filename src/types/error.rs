@@ -3,6 +3,8 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+use crate::types::circle::CircleEndpoint;
 use miette::Diagnostic;
 use rust_decimal::Decimal;
 use thiserror::Error;
@@ -57,6 +59,49 @@ pub enum Error {
     /// API client library attempted to parse a string that contained an invalid
     /// location type code.
     InvalidLocationTypeCode(String),
+
+    /// API client library attempted to read its configuration from the
+    /// environment but a required variable was not set.
+    MissingEnvironmentVariable(String),
+
+    /// API client library attempted to read its configuration from the
+    /// environment but a variable contained an invalid value.
+    InvalidEnvironmentVariable(String, String),
+
+    /// API client library was given an API key that is empty or malformed. The
+    /// `String` explains the problem, without repeating the key.
+    InvalidApiKey(String),
+
+    /// API client library was given a header value that can't be sent, for
+    /// example one containing a line break. The first `String` is the name
+    /// of the header.
+    InvalidHeaderValue(String, String),
+
+    /// API client library was configured to use a TLS backend that was not
+    /// compiled in. Enable the matching `reqwest-native-tls` or
+    /// `reqwest-rustls-tls` feature.
+    #[cfg(feature = "reqwest")]
+    UnavailableTlsBackend(crate::client::transport_info::TlsBackend),
+
+    /// API client library attempted to convert a latitude/longitude pair to an
+    /// H3 cell with a resolution outside of 0 to 15.
+    #[cfg(feature = "h3")]
+    InvalidH3Resolution(u8),
+
+    /// API client library attempted to convert a latitude/longitude pair to a
+    /// geohash with a length outside of 1 to 12 characters.
+    #[cfg(feature = "geohash")]
+    InvalidGeohashLength(usize),
+
+    /// API client library attempted to create a circle with a radius that is
+    /// negative, above 50,000 meters, or not a number.
+    #[cfg(any(feature = "autocomplete", feature = "places"))]
+    InvalidRadius(String),
+
+    /// API client library attempted to send a circle with a radius that the
+    /// endpoint does not accept.
+    #[cfg(any(feature = "autocomplete", feature = "places"))]
+    InvalidRadiusForEndpoint(u32, CircleEndpoint),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -131,6 +176,63 @@ impl std::fmt::Display for Error {
                 `{location_type_code}` is not a known location type code. \
                 Valid codes are `APPROXIMATE`, `GEOMETRIC_CENTER`, \
                 `RANGE_INTERPOLATED`, and `ROOFTOP`."),
+            Self::MissingEnvironmentVariable(name) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the `{name}` environment variable is not set."
+            ),
+            Self::InvalidApiKey(reason) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the API key is invalid: {reason}."
+            ),
+            Self::InvalidHeaderValue(name, value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{value}` is not a valid value for the `{name}` header."
+            ),
+            Self::InvalidEnvironmentVariable(name, value) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{value}` is not a valid value for the `{name}` environment variable."
+            ),
+            #[cfg(feature = "reqwest")]
+            Self::UnavailableTlsBackend(backend) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the `{backend}` TLS backend was not compiled in. \
+                Enable the matching `reqwest-*-tls` feature flag."
+            ),
+            #[cfg(feature = "h3")]
+            Self::InvalidH3Resolution(resolution) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{resolution}` is not a valid H3 resolution. \
+                Resolutions range from 0 (coarsest) to 15 (finest)."
+            ),
+            #[cfg(feature = "geohash")]
+            Self::InvalidGeohashLength(length) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{length}` is not a valid geohash length. \
+                Geohashes are 1 to 12 characters long."
+            ),
+            #[cfg(any(feature = "autocomplete", feature = "places"))]
+            Self::InvalidRadius(radius) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{radius}` is not a valid radius. \
+                A radius must be between 0 and 50,000 meters."
+            ),
+            #[cfg(any(feature = "autocomplete", feature = "places"))]
+            Self::InvalidRadiusForEndpoint(radius, endpoint) => write!(
+                f,
+                "Google Maps Platform API client: \
+                a radius of `{radius}` meters is not accepted by {endpoint}. \
+                The radius must be between {min} and {max} meters.",
+                min = endpoint.min_radius(),
+                max = endpoint.max_radius(),
+            ),
         } // match
     } // fn
 } // impl
@@ -0,0 +1,92 @@
+//! Contains the `InputIndex` struct. It is the position of an item in the
+//! collection that was passed to a batch request, such as an origin of a
+//! _Distance Matrix API_ request or a point of a _Snap To Roads_ path.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The zero-based position of an item in the collection that was passed to a
+/// request. Results that refer back to their input, such as a snapped point's
+/// original index or a distance matrix element's origin and destination, use
+/// this type instead of a bare `usize`.
+///
+/// Requests that are split into several Google requests, such as _Snap To
+/// Roads_ paths longer than 100 points, report indices that refer to the
+/// whole collection, not to the chunk. An `InputIndex` can therefore always be
+/// resolved against the collection that was passed to the request with `of`.
+///
+/// ## Example
+///
+/// ```rust
+/// for (origin, destination, element) in matrix.elements() {
+///     println!("{:?} → {:?}: {:?}", origin.of(&origins), destination.of(&destinations), element.duration);
+/// }
+/// ```
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputIndex(usize);
+
+/// The index of a point in the path of a _Roads API_ request, which Google
+/// calls the `originalIndex`. This is the same type as `InputIndex`.
+pub type OriginalIndex = InputIndex;
+
+// -----------------------------------------------------------------------------
+
+impl InputIndex {
+    /// Creates an index from a zero-based position.
+
+    #[must_use]
+    pub const fn new(index: usize) -> Self {
+        Self(index)
+    } // fn
+
+    /// Returns the zero-based position.
+
+    #[must_use]
+    pub const fn get(self) -> usize {
+        self.0
+    } // fn
+
+    /// Returns the item at this position of the collection that was passed to
+    /// the request. Returns `None` if the collection is too short, which means
+    /// that it is not the collection the result came from.
+
+    #[must_use]
+    pub fn of<T>(self, inputs: &[T]) -> Option<&T> {
+        inputs.get(self.0)
+    } // fn
+
+    /// Returns this index moved forward by `offset` positions. This converts
+    /// an index into a chunk of a collection into an index into the whole
+    /// collection, when `offset` is the position of the chunk's first item.
+
+    #[must_use]
+    pub const fn offset(self, offset: usize) -> Self {
+        Self(self.0 + offset)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for InputIndex {
+    /// Formats the index as its zero-based position.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    } // fn
+} // impl
+
+impl std::convert::From<usize> for InputIndex {
+    /// Converts a zero-based position into an `InputIndex`.
+    fn from(index: usize) -> Self {
+        Self(index)
+    } // fn
+} // impl
+
+impl std::convert::From<InputIndex> for usize {
+    /// Converts an `InputIndex` into its zero-based position.
+    fn from(index: InputIndex) -> Self {
+        index.0
+    } // fn
+} // impl
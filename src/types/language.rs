@@ -525,3 +525,25 @@ impl Language {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Language {
+    /// Returns the code that Google uses for this `Language`, for example
+    /// `af`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Language` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = LANGUAGES_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
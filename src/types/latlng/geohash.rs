@@ -0,0 +1,40 @@
+//! Provides `LatLng` conversions to [geohash](https://en.wikipedia.org/wiki/Geohash)
+//! strings using the [geohash](https://crates.io/crates/geohash) crate.
+
+use crate::{error::Error as GoogleMapsError, types::Error as TypeError, LatLng};
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the geohash of this point.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` ‧ The number of characters in the geohash, from 1 (cells of
+    ///   about 5,000 km) to 12 (cells of about 4 cm).
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `InvalidGeohashLength` if the length is not between 1 and 12.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let geohash = LatLng::try_from_dec(dec!(48.8584), dec!(2.2945))?.to_geohash(7)?;
+    /// assert_eq!(geohash, "u09tunq");
+    /// ```
+    pub fn to_geohash(&self, length: usize) -> Result<String, GoogleMapsError> {
+        if !(1..=12).contains(&length) {
+            return Err(TypeError::InvalidGeohashLength(length).into());
+        } // if
+
+        let coord = geohash::Coord {
+            x: self.lng.to_f64().unwrap_or_default(),
+            y: self.lat.to_f64().unwrap_or_default(),
+        }; // Coord
+
+        Ok(geohash::encode(coord, length)
+            .map_err(|_| TypeError::InvalidLatLongString(format!("{},{}", coord.y, coord.x)))?)
+    } // fn
+} // impl
@@ -0,0 +1,40 @@
+//! Provides `LatLng` conversions to [H3](https://h3geo.org/) cells using the
+//! [h3o](https://crates.io/crates/h3o) crate.
+
+use crate::{error::Error as GoogleMapsError, types::Error as TypeError, LatLng};
+use h3o::{CellIndex, Resolution};
+use rust_decimal::prelude::ToPrimitive;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the H3 cell that contains this point.
+    ///
+    /// ## Arguments
+    ///
+    /// * `resolution` ‧ The H3 resolution, from 0 (cells of about 4,300,000
+    ///   km²) to 15 (cells of about 0.9 m²).
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `InvalidH3Resolution` if the resolution is greater than 15.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let cell = LatLng::try_from_dec(dec!(48.8584), dec!(2.2945))?.to_h3_cell(9)?;
+    /// println!("{cell}"); // 891fb466257ffff
+    /// ```
+    pub fn to_h3_cell(&self, resolution: u8) -> Result<CellIndex, GoogleMapsError> {
+        let resolution = Resolution::try_from(resolution)
+            .map_err(|_| TypeError::InvalidH3Resolution(resolution))?;
+
+        let lat = self.lat.to_f64().unwrap_or_default();
+        let lng = self.lng.to_f64().unwrap_or_default();
+
+        let latlng = h3o::LatLng::new(lat, lng)
+            .map_err(|_| TypeError::InvalidLatLongString(format!("{lat},{lng}")))?;
+
+        Ok(latlng.to_cell(resolution))
+    } // fn
+} // impl
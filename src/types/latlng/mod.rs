@@ -4,12 +4,16 @@
 
 #[cfg(feature = "geo")]
 mod geo;
+#[cfg(feature = "geohash")]
+mod geohash;
+#[cfg(feature = "h3")]
+mod h3;
 
 // -----------------------------------------------------------------------------
 
 use crate::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
-use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -328,3 +332,25 @@ impl LatLng {
         (&self.lat, &self.lng)
     }
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the great-circle distance to another point, in meters, using
+    /// the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula)
+    /// and a mean Earth radius of 6,371,008.8 meters.
+    #[must_use]
+    pub fn haversine_distance(&self, other: &Self) -> f64 {
+        const EARTH_RADIUS_M: f64 = 6_371_008.8;
+
+        let lat_1 = self.lat.to_f64().unwrap_or_default().to_radians();
+        let lat_2 = other.lat.to_f64().unwrap_or_default().to_radians();
+        let delta_lat = lat_2 - lat_1;
+        let delta_lng = (other.lng - self.lng).to_f64().unwrap_or_default().to_radians();
+
+        let a = (lat_1.cos() * lat_2.cos())
+            .mul_add((delta_lng / 2.0).sin().powi(2), (delta_lat / 2.0).sin().powi(2));
+
+        2.0 * EARTH_RADIUS_M * a.sqrt().asin()
+    } // fn
+} // impl
@@ -160,3 +160,25 @@ impl LocationType {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl LocationType {
+    /// Returns the code that Google uses for this `LocationType`, for example
+    /// `APPROXIMATE`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `LocationType` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = LOCATION_TYPES_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
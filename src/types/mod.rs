@@ -9,6 +9,8 @@ pub(super) mod address_component;
     feature = "places"
 ))]
 pub(super) mod bounds;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub(super) mod circle;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -18,14 +20,8 @@ pub(super) mod country;
 pub(super) mod error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod geometry;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places",
-    feature = "time_zone"
-))]
+#[cfg(any(feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub(super) mod input_index;
 pub(super) mod language;
 #[cfg(any(
     feature = "autocomplete",
@@ -48,14 +44,20 @@ pub(super) mod location_type;
     feature = "places"
 ))]
 pub(super) mod place_type;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places"
-))]
 pub(super) mod region;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(super) mod route_modifiers;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(super) mod route_restrictions;
+#[cfg(all(
+    any(feature = "h3", feature = "geohash", feature = "geo"),
+    any(feature = "geocoding", feature = "places")
+))]
+pub(super) mod spatial_bucket;
+#[cfg(all(feature = "geo", any(feature = "geocoding", feature = "places")))]
+pub(super) mod spatial_cluster;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(super) mod travel_mode;
 
 // -----------------------------------------------------------------------------
 
@@ -68,6 +70,8 @@ pub use crate::types::address_component::AddressComponent;
     feature = "places"
 ))]
 pub use crate::types::bounds::Bounds;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub use crate::types::circle::{Circle, CircleEndpoint};
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -77,14 +81,8 @@ pub use crate::types::country::Country;
 pub use crate::types::error::Error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places",
-    feature = "time_zone"
-))]
+#[cfg(any(feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub use crate::types::input_index::{InputIndex, OriginalIndex};
 pub use crate::types::language::Language;
 #[cfg(any(
     feature = "autocomplete",
@@ -107,11 +105,21 @@ pub use crate::types::location_type::LocationType;
     feature = "places"
 ))]
 pub use crate::types::place_type::PlaceType;
-#[cfg(any(
-    feature = "autocomplete",
-    feature = "directions",
-    feature = "distance_matrix",
-    feature = "geocoding",
-    feature = "places"
-))]
 pub use crate::types::region::Region;
+#[cfg(all(
+    any(feature = "h3", feature = "geohash", feature = "geo"),
+    any(feature = "geocoding", feature = "places")
+))]
+pub use crate::types::spatial_bucket::Locatable;
+#[cfg(all(feature = "geo", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_cluster::{cluster_places, PlaceCluster};
+#[cfg(all(feature = "h3", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_h3;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_modifiers::{RouteModifiers, VehicleEmissionType};
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::route_restrictions::RouteRestrictions;
+#[cfg(all(feature = "geohash", any(feature = "geocoding", feature = "places")))]
+pub use crate::types::spatial_bucket::bucket_by_geohash;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::travel_mode::TravelMode;
@@ -273,151 +273,165 @@ impl std::convert::From<&PlaceType> for &str {
     /// type](https://developers.google.com/places/web-service/supported_types)
     /// code.
     fn from(place_type: &PlaceType) -> Self {
-        match place_type {
-            PlaceType::Accounting => "accounting",
-            PlaceType::Airport => "airport",
-            PlaceType::AmusementPark => "amusement_park",
-            PlaceType::Aquarium => "aquarium",
-            PlaceType::ArtGallery => "art_gallery",
-            PlaceType::Atm => "atm",
-            PlaceType::Bakery => "bakery",
-            PlaceType::Bank => "bank",
-            PlaceType::Bar => "bar",
-            PlaceType::BeautySalon => "beauty_salon",
-            PlaceType::BicycleStore => "bicycle_store",
-            PlaceType::BookStore => "book_store",
-            PlaceType::BowlingAlley => "bowling_alley",
-            PlaceType::BusStation => "bus_station",
-            PlaceType::Cafe => "cafe",
-            PlaceType::Campground => "campground",
-            PlaceType::CarDealer => "car_dealer",
-            PlaceType::CarRental => "car_rental",
-            PlaceType::CarRepair => "car_repair",
-            PlaceType::CarWash => "car_wash",
-            PlaceType::Casino => "casino",
-            PlaceType::Cemetery => "cemetery",
-            PlaceType::Church => "church",
-            PlaceType::CityHall => "city_hall",
-            PlaceType::ClothingStore => "clothing_store",
-            PlaceType::ConvenienceStore => "convenience_store",
-            PlaceType::Courthouse => "courthouse",
-            PlaceType::Dentist => "dentist",
-            PlaceType::DepartmentStore => "department_store",
-            PlaceType::Doctor => "doctor",
-            PlaceType::DrugStore => "drugstore",
-            PlaceType::Electrician => "electrician",
-            PlaceType::ElectronicsStore => "electronics_store",
-            PlaceType::Embassy => "embassy",
-            PlaceType::FireStation => "fire_station",
-            PlaceType::Florist => "florist",
-            PlaceType::FuneralHome => "funeral_home",
-            PlaceType::FurnitureStore => "furniture_store",
-            PlaceType::GasStation => "gas_station",
-            PlaceType::GroceryOrSupermarket => "grocery_or_supermarket",
-            PlaceType::Gym => "gym",
-            PlaceType::HairCare => "hair_care",
-            PlaceType::HardwareStore => "hardware_store",
-            PlaceType::HinduTemple => "hindu_temple",
-            PlaceType::HomeGoodsStore => "home_goods_store",
-            PlaceType::Hospital => "hospital",
-            PlaceType::InsuranceAgency => "insurance_agency",
-            PlaceType::JewelryStore => "jewelry_store",
-            PlaceType::Laundry => "laundry",
-            PlaceType::Lawyer => "lawyer",
-            PlaceType::Library => "library",
-            PlaceType::LightRailStation => "light_rail_station",
-            PlaceType::LiquorStore => "liquor_store",
-            PlaceType::LocalGovernmentOffice => "local_government_office",
-            PlaceType::Locksmith => "locksmith",
-            PlaceType::Lodging => "lodging",
-            PlaceType::MealDelivery => "meal_delivery",
-            PlaceType::MealTakeaway => "meal_takeaway",
-            PlaceType::Mosque => "mosque",
-            PlaceType::MovieRental => "movie_rental",
-            PlaceType::MovieTheater => "movie_theater",
-            PlaceType::MovingCompany => "moving_company",
-            PlaceType::Museum => "museum",
-            PlaceType::NightClub => "night_club",
-            PlaceType::Painter => "painter",
-            PlaceType::Park => "park",
-            PlaceType::Parking => "parking",
-            PlaceType::PetStore => "pet_store",
-            PlaceType::Pharmacy => "pharmacy",
-            PlaceType::Physiotherapist => "physiotherapist",
-            PlaceType::Plumber => "plumber",
-            PlaceType::PlusCode => "plus_code",
-            PlaceType::Police => "police",
-            PlaceType::PostOffice => "post_office",
-            PlaceType::PrimarySchool => "primary_school",
-            PlaceType::RealEstateAgency => "real_estate_agency",
-            PlaceType::Restaurant => "restaurant",
-            PlaceType::RoofingContractor => "roofing_contractor",
-            PlaceType::RvPark => "rv_park",
-            PlaceType::School => "school",
-            PlaceType::SecondarySchool => "secondary_school",
-            PlaceType::ShoeStore => "shoe_store",
-            PlaceType::ShoppingMall => "shopping_mall",
-            PlaceType::Spa => "spa",
-            PlaceType::Stadium => "stadium",
-            PlaceType::Storage => "storage",
-            PlaceType::Store => "store",
-            PlaceType::SubwayStation => "subway_station",
-            PlaceType::Supermarket => "supermarket",
-            PlaceType::Synagogue => "synagogue",
-            PlaceType::TaxiStand => "taxi_stand",
-            PlaceType::TouristAttraction => "tourist_attraction",
-            PlaceType::TrainStation => "train_station",
-            PlaceType::TransitStation => "transit_station",
-            PlaceType::TravelAgency => "travel_agency",
-            PlaceType::University => "university",
-            PlaceType::VeterinaryCare => "veterinary_care",
-            PlaceType::Zoo => "zoo",
-            PlaceType::AdministrativeAreaLevel1 => "administrative_area_level_1",
-            PlaceType::AdministrativeAreaLevel2 => "administrative_area_level_2",
-            PlaceType::AdministrativeAreaLevel3 => "administrative_area_level_3",
-            PlaceType::AdministrativeAreaLevel4 => "administrative_area_level_4",
-            PlaceType::AdministrativeAreaLevel5 => "administrative_area_level_5",
-            PlaceType::Archipelago => "archipelago",
-            PlaceType::ColloquialArea => "colloquial_area",
-            PlaceType::Continent => "continent",
-            PlaceType::Country => "country",
-            PlaceType::Establishment => "establishment",
-            PlaceType::Finance => "finance",
-            PlaceType::Floor => "floor",
-            PlaceType::Food => "food",
-            PlaceType::GeneralContractor => "general_contractor",
-            PlaceType::Geocode => "geocode",
-            PlaceType::Health => "health",
-            PlaceType::Intersection => "intersection",
-            PlaceType::Locality => "locality",
-            PlaceType::NaturalFeature => "natural_feature",
-            PlaceType::Neighborhood => "neighborhood",
-            PlaceType::PlaceOfWorship => "place_of_worship",
-            PlaceType::PointOfInterest => "point_of_interest",
-            PlaceType::Political => "political",
-            PlaceType::PostBox => "post_box",
-            PlaceType::PostalCode => "postal_code",
-            PlaceType::PostalCodePrefix => "postal_code_prefix",
-            PlaceType::PostalCodeSuffix => "postal_code_suffix",
-            PlaceType::PostalTown => "postal_town",
-            PlaceType::Premise => "premise",
-            PlaceType::Room => "room",
-            PlaceType::Route => "route",
-            PlaceType::StreetAddress => "street_address",
-            PlaceType::StreetNumber => "street_number",
-            PlaceType::Sublocality => "sublocality",
-            PlaceType::SublocalityLevel1 => "sublocality_level_1",
-            PlaceType::SublocalityLevel2 => "sublocality_level_2",
-            PlaceType::SublocalityLevel3 => "sublocality_level_3",
-            PlaceType::SublocalityLevel4 => "sublocality_level_4",
-            PlaceType::SublocalityLevel5 => "sublocality_level_5",
-            PlaceType::Subpremise => "subpremise",
-            PlaceType::TownSquare => "town_square",
-            PlaceType::Address => "address",
-            PlaceType::Regions => "regions",
-            PlaceType::Cities => "cities",
-            PlaceType::Landmark => "landmark",
-            PlaceType::Other => "other",
+        place_type.as_str()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PlaceType {
+    /// Returns the [place
+    /// type](https://developers.google.com/places/web-service/supported_types)
+    /// code of this `PlaceType`, for example `street_address`. Unknown codes
+    /// returned by Google are parsed as `Other`, which returns `other`.
+
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Accounting => "accounting",
+            Self::Airport => "airport",
+            Self::AmusementPark => "amusement_park",
+            Self::Aquarium => "aquarium",
+            Self::ArtGallery => "art_gallery",
+            Self::Atm => "atm",
+            Self::Bakery => "bakery",
+            Self::Bank => "bank",
+            Self::Bar => "bar",
+            Self::BeautySalon => "beauty_salon",
+            Self::BicycleStore => "bicycle_store",
+            Self::BookStore => "book_store",
+            Self::BowlingAlley => "bowling_alley",
+            Self::BusStation => "bus_station",
+            Self::Cafe => "cafe",
+            Self::Campground => "campground",
+            Self::CarDealer => "car_dealer",
+            Self::CarRental => "car_rental",
+            Self::CarRepair => "car_repair",
+            Self::CarWash => "car_wash",
+            Self::Casino => "casino",
+            Self::Cemetery => "cemetery",
+            Self::Church => "church",
+            Self::CityHall => "city_hall",
+            Self::ClothingStore => "clothing_store",
+            Self::ConvenienceStore => "convenience_store",
+            Self::Courthouse => "courthouse",
+            Self::Dentist => "dentist",
+            Self::DepartmentStore => "department_store",
+            Self::Doctor => "doctor",
+            Self::DrugStore => "drugstore",
+            Self::Electrician => "electrician",
+            Self::ElectronicsStore => "electronics_store",
+            Self::Embassy => "embassy",
+            Self::FireStation => "fire_station",
+            Self::Florist => "florist",
+            Self::FuneralHome => "funeral_home",
+            Self::FurnitureStore => "furniture_store",
+            Self::GasStation => "gas_station",
+            Self::GroceryOrSupermarket => "grocery_or_supermarket",
+            Self::Gym => "gym",
+            Self::HairCare => "hair_care",
+            Self::HardwareStore => "hardware_store",
+            Self::HinduTemple => "hindu_temple",
+            Self::HomeGoodsStore => "home_goods_store",
+            Self::Hospital => "hospital",
+            Self::InsuranceAgency => "insurance_agency",
+            Self::JewelryStore => "jewelry_store",
+            Self::Laundry => "laundry",
+            Self::Lawyer => "lawyer",
+            Self::Library => "library",
+            Self::LightRailStation => "light_rail_station",
+            Self::LiquorStore => "liquor_store",
+            Self::LocalGovernmentOffice => "local_government_office",
+            Self::Locksmith => "locksmith",
+            Self::Lodging => "lodging",
+            Self::MealDelivery => "meal_delivery",
+            Self::MealTakeaway => "meal_takeaway",
+            Self::Mosque => "mosque",
+            Self::MovieRental => "movie_rental",
+            Self::MovieTheater => "movie_theater",
+            Self::MovingCompany => "moving_company",
+            Self::Museum => "museum",
+            Self::NightClub => "night_club",
+            Self::Painter => "painter",
+            Self::Park => "park",
+            Self::Parking => "parking",
+            Self::PetStore => "pet_store",
+            Self::Pharmacy => "pharmacy",
+            Self::Physiotherapist => "physiotherapist",
+            Self::Plumber => "plumber",
+            Self::PlusCode => "plus_code",
+            Self::Police => "police",
+            Self::PostOffice => "post_office",
+            Self::PrimarySchool => "primary_school",
+            Self::RealEstateAgency => "real_estate_agency",
+            Self::Restaurant => "restaurant",
+            Self::RoofingContractor => "roofing_contractor",
+            Self::RvPark => "rv_park",
+            Self::School => "school",
+            Self::SecondarySchool => "secondary_school",
+            Self::ShoeStore => "shoe_store",
+            Self::ShoppingMall => "shopping_mall",
+            Self::Spa => "spa",
+            Self::Stadium => "stadium",
+            Self::Storage => "storage",
+            Self::Store => "store",
+            Self::SubwayStation => "subway_station",
+            Self::Supermarket => "supermarket",
+            Self::Synagogue => "synagogue",
+            Self::TaxiStand => "taxi_stand",
+            Self::TouristAttraction => "tourist_attraction",
+            Self::TrainStation => "train_station",
+            Self::TransitStation => "transit_station",
+            Self::TravelAgency => "travel_agency",
+            Self::University => "university",
+            Self::VeterinaryCare => "veterinary_care",
+            Self::Zoo => "zoo",
+            Self::AdministrativeAreaLevel1 => "administrative_area_level_1",
+            Self::AdministrativeAreaLevel2 => "administrative_area_level_2",
+            Self::AdministrativeAreaLevel3 => "administrative_area_level_3",
+            Self::AdministrativeAreaLevel4 => "administrative_area_level_4",
+            Self::AdministrativeAreaLevel5 => "administrative_area_level_5",
+            Self::Archipelago => "archipelago",
+            Self::ColloquialArea => "colloquial_area",
+            Self::Continent => "continent",
+            Self::Country => "country",
+            Self::Establishment => "establishment",
+            Self::Finance => "finance",
+            Self::Floor => "floor",
+            Self::Food => "food",
+            Self::GeneralContractor => "general_contractor",
+            Self::Geocode => "geocode",
+            Self::Health => "health",
+            Self::Intersection => "intersection",
+            Self::Locality => "locality",
+            Self::NaturalFeature => "natural_feature",
+            Self::Neighborhood => "neighborhood",
+            Self::PlaceOfWorship => "place_of_worship",
+            Self::PointOfInterest => "point_of_interest",
+            Self::Political => "political",
+            Self::PostBox => "post_box",
+            Self::PostalCode => "postal_code",
+            Self::PostalCodePrefix => "postal_code_prefix",
+            Self::PostalCodeSuffix => "postal_code_suffix",
+            Self::PostalTown => "postal_town",
+            Self::Premise => "premise",
+            Self::Room => "room",
+            Self::Route => "route",
+            Self::StreetAddress => "street_address",
+            Self::StreetNumber => "street_number",
+            Self::Sublocality => "sublocality",
+            Self::SublocalityLevel1 => "sublocality_level_1",
+            Self::SublocalityLevel2 => "sublocality_level_2",
+            Self::SublocalityLevel3 => "sublocality_level_3",
+            Self::SublocalityLevel4 => "sublocality_level_4",
+            Self::SublocalityLevel5 => "sublocality_level_5",
+            Self::Subpremise => "subpremise",
+            Self::TownSquare => "town_square",
+            Self::Address => "address",
+            Self::Regions => "regions",
+            Self::Cities => "cities",
+            Self::Landmark => "landmark",
+            Self::Other => "other",
         } // match
     } // fn
 } // impl
@@ -783,6 +797,61 @@ impl PlaceType {
 
 // -----------------------------------------------------------------------------
 
+impl PlaceType {
+    /// Returns whether this is a place to eat or drink, such as a restaurant,
+    /// café, bakery or bar. Useful for filtering search results, which often
+    /// include places of several categories.
+    ///
+    /// ```rust
+    /// let places_to_eat = response
+    ///     .results
+    ///     .iter()
+    ///     .filter(|place| place.types.iter().any(PlaceType::is_food_and_drink));
+    /// ```
+
+    #[must_use]
+    pub const fn is_food_and_drink(&self) -> bool {
+        matches!(
+            self,
+            Self::Bakery
+                | Self::Bar
+                | Self::Cafe
+                | Self::Food
+                | Self::MealDelivery
+                | Self::MealTakeaway
+                | Self::Restaurant
+        ) // matches!
+    } // fn
+
+    /// Returns whether this is a place to stay overnight, such as a hotel,
+    /// campground or RV park.
+
+    #[must_use]
+    pub const fn is_lodging(&self) -> bool {
+        matches!(self, Self::Campground | Self::Lodging | Self::RvPark)
+    } // fn
+
+    /// Returns whether this is a public transport or travel hub, such as an
+    /// airport, a train or bus station, or a taxi stand. Services for private
+    /// vehicles, such as parking and gas stations, are not included.
+
+    #[must_use]
+    pub const fn is_transport(&self) -> bool {
+        matches!(
+            self,
+            Self::Airport
+                | Self::BusStation
+                | Self::LightRailStation
+                | Self::SubwayStation
+                | Self::TaxiStand
+                | Self::TrainStation
+                | Self::TransitStation
+        ) // matches!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl PlaceType {
     /// A helper function that converts a `Vec<PlaceType>` (i.e. an array of
     /// `PlaceType` enum) to a `String` that contains a comma-delimited list of
@@ -797,3 +866,25 @@ impl PlaceType {
             .join(",")
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl PlaceType {
+    /// Returns the code that Google uses for this `PlaceType`, for example
+    /// `accounting`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub const fn as_wire_str(&self) -> &'static str {
+        self.as_str()
+    } // fn
+
+    /// Returns an iterator over every `PlaceType` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = PLACE_TYPES_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
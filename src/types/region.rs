@@ -1232,3 +1232,25 @@ impl Region {
         } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Region {
+    /// Returns the code that Google uses for this `Region`, for example
+    /// `af`. `FromStr` parses the code back into the same variant.
+
+    #[must_use]
+    pub fn as_wire_str(&self) -> &'static str {
+        self.into()
+    } // fn
+
+    /// Returns an iterator over every `Region` variant, in declaration
+    /// order.
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        let mut variants: Vec<Self> = REGIONS_BY_CODE.values().copied().collect();
+        variants.sort();
+        variants.dedup();
+        variants.into_iter()
+    } // fn
+} // impl
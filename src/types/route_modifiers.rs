@@ -0,0 +1,146 @@
+//! Contains the `RouteModifiers` struct and the `VehicleEmissionType` enum.
+//! They describe the features a route should avoid and the vehicle that
+//! travels it, independently of the API that calculates the route.
+
+use crate::directions::request::avoid::Avoid;
+use crate::types::RouteRestrictions;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The emission type of the vehicle that travels a route.
+///
+/// Only the _Routes API_ uses the emission type, for example to find
+/// fuel-efficient routes. The _Directions API_ and the _Distance Matrix API_
+/// have no such parameter and do not send it.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VehicleEmissionType {
+    /// Gasoline or petrol fueled vehicle.
+    Gasoline,
+    /// Electric vehicle.
+    Electric,
+    /// Hybrid fuel, such as gasoline and electric, vehicle.
+    Hybrid,
+    /// Diesel fueled vehicle.
+    Diesel,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for VehicleEmissionType {
+    /// Formats a `VehicleEmissionType` enum into a string that is presentable
+    /// to the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Gasoline => write!(f, "Gasoline"),
+            Self::Electric => write!(f, "Electric"),
+            Self::Hybrid => write!(f, "Hybrid"),
+            Self::Diesel => write!(f, "Diesel"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The conditions a route should satisfy, shared by every API that calculates
+/// routes. Convert it into each API's parameters, for example with
+/// `directions::Request::with_route_modifiers`, so that business logic does
+/// not depend on a particular API. The features to avoid can also be handled
+/// as a `RouteRestrictions` set.
+///
+/// As with the `avoid` parameter, avoiding a feature biases the result towards
+/// routes without it but does not rule it out.
+///
+/// ## Example
+///
+/// ```rust
+/// let modifiers = RouteModifiers {
+///     avoid_tolls: true,
+///     avoid_ferries: true,
+///     ..RouteModifiers::default()
+/// };
+///
+/// let restrictions: Vec<Avoid> = (&modifiers).into();
+/// ```
+
+// Each flag is an independent `avoid` value, mirroring the Routes API:
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct RouteModifiers {
+    /// Avoid toll roads and bridges.
+    #[serde(default)]
+    pub avoid_tolls: bool,
+
+    /// Avoid highways.
+    #[serde(default)]
+    pub avoid_highways: bool,
+
+    /// Avoid ferries.
+    #[serde(default)]
+    pub avoid_ferries: bool,
+
+    /// Avoid indoor steps, for walking and transit routes.
+    #[serde(default)]
+    pub avoid_indoor: bool,
+
+    /// The emission type of the vehicle. Only used by the _Routes API_.
+    #[serde(default)]
+    pub vehicle_emission_type: Option<VehicleEmissionType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RouteModifiers {
+    /// Returns whether no features are avoided and no vehicle is specified.
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.avoid_tolls
+            && !self.avoid_highways
+            && !self.avoid_ferries
+            && !self.avoid_indoor
+            && self.vehicle_emission_type.is_none()
+    } // fn
+
+    /// Returns the features to avoid as `RouteRestrictions`, the format used
+    /// by the _Directions API_ and the _Distance Matrix API_.
+
+    #[must_use]
+    pub fn restrictions(&self) -> RouteRestrictions {
+        self.into()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RouteModifiers> for Vec<Avoid> {
+    /// Converts `RouteModifiers` into the `avoid` parameter of the _Directions
+    /// API_ and the _Distance Matrix API_. The vehicle emission type has no
+    /// equivalent and is dropped.
+    fn from(route_modifiers: &RouteModifiers) -> Self {
+        route_modifiers.restrictions().into()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<RouteModifiers> for Vec<Avoid> {
+    /// Converts `RouteModifiers` into the `avoid` parameter of the _Directions
+    /// API_ and the _Distance Matrix API_. The vehicle emission type has no
+    /// equivalent and is dropped.
+    fn from(route_modifiers: RouteModifiers) -> Self {
+        Self::from(&route_modifiers)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&[Avoid]> for RouteModifiers {
+    /// Converts the `avoid` parameter of the _Directions API_ and the
+    /// _Distance Matrix API_ into `RouteModifiers`.
+    fn from(restrictions: &[Avoid]) -> Self {
+        restrictions.iter().collect::<RouteRestrictions>().into()
+    } // fn
+} // impl
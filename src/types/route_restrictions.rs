@@ -0,0 +1,258 @@
+//! Contains the `RouteRestrictions` struct. It is a set of the features, such
+//! as tolls or ferries, that a route should avoid. It is shared by the
+//! _Directions API_ and the _Distance Matrix API_, and converts into the
+//! `RouteModifiers` used by the _Routes API_.
+
+use crate::directions::request::avoid::Avoid;
+use crate::types::RouteModifiers;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// A set of features that a route should avoid. Each feature is a flag, and
+/// flags are combined with `|`.
+///
+/// Unlike a list of `Avoid` values, a `RouteRestrictions` can't contain the
+/// same feature twice, and always produces the same `avoid` parameter for the
+/// same features, whatever order they were added in.
+///
+/// As with the `avoid` parameter, avoiding a feature biases the result towards
+/// routes without it but does not rule it out.
+///
+/// ## Example
+///
+/// ```rust
+/// let restrictions = RouteRestrictions::TOLLS | RouteRestrictions::FERRIES;
+/// assert!(restrictions.contains(Avoid::Tolls));
+/// assert_eq!(restrictions.to_string(), "ferries|tolls");
+/// ```
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RouteRestrictions(u8);
+
+// -----------------------------------------------------------------------------
+
+impl RouteRestrictions {
+    /// Avoids nothing.
+    pub const NONE: Self = Self(0);
+
+    /// Avoids ferries.
+    pub const FERRIES: Self = Self::flag(Avoid::Ferries);
+
+    /// Avoids highways.
+    pub const HIGHWAYS: Self = Self::flag(Avoid::Highways);
+
+    /// Avoids indoor steps, for walking and transit routes.
+    pub const INDOOR: Self = Self::flag(Avoid::Indoor);
+
+    /// Avoids toll roads and bridges.
+    pub const TOLLS: Self = Self::flag(Avoid::Tolls);
+
+    /// Avoids every feature.
+    pub const ALL: Self = Self(
+        Self::FERRIES.0 | Self::HIGHWAYS.0 | Self::INDOOR.0 | Self::TOLLS.0
+    ); // ALL
+
+    /// Returns the flag of a single feature.
+    const fn flag(avoid: Avoid) -> Self {
+        let bit = match avoid {
+            Avoid::Ferries => 0,
+            Avoid::Highways => 1,
+            Avoid::Indoor => 2,
+            Avoid::Tolls => 3,
+        }; // match
+        Self(1 << bit)
+    } // fn
+
+    /// Returns whether no features are avoided.
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    } // fn
+
+    /// Returns the number of features that are avoided.
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    } // fn
+
+    /// Returns whether the specified feature is avoided.
+
+    #[must_use]
+    pub const fn contains(&self, avoid: Avoid) -> bool {
+        self.0 & Self::flag(avoid).0 != 0
+    } // fn
+
+    /// Adds a feature to avoid.
+
+    pub fn insert(&mut self, avoid: Avoid) {
+        self.0 |= Self::flag(avoid).0;
+    } // fn
+
+    /// Stops avoiding a feature.
+
+    pub fn remove(&mut self, avoid: Avoid) {
+        self.0 &= !Self::flag(avoid).0;
+    } // fn
+
+    /// Returns these restrictions with another feature to avoid.
+
+    #[must_use]
+    pub const fn with(self, avoid: Avoid) -> Self {
+        Self(self.0 | Self::flag(avoid).0)
+    } // fn
+
+    /// Returns an iterator over the avoided features, in the order of the
+    /// `Avoid` enum's variants.
+
+    pub fn iter(&self) -> impl Iterator<Item = Avoid> {
+        let restrictions = *self;
+        Avoid::iter().filter(move |avoid| restrictions.contains(*avoid))
+    } // fn
+
+    /// Returns the restrictions as `RouteModifiers`, the format used by the
+    /// _Routes API_.
+
+    #[must_use]
+    pub const fn to_route_modifiers(&self) -> RouteModifiers {
+        RouteModifiers {
+            avoid_tolls: self.contains(Avoid::Tolls),
+            avoid_highways: self.contains(Avoid::Highways),
+            avoid_ferries: self.contains(Avoid::Ferries),
+            avoid_indoor: self.contains(Avoid::Indoor),
+            vehicle_emission_type: None,
+        } // RouteModifiers
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for RouteRestrictions {
+    /// Formats the restrictions as the `avoid` parameter of the _Directions
+    /// API_ and the _Distance Matrix API_, for example `ferries|tolls`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let codes: Vec<&str> = self.iter().map(|avoid| avoid.as_wire_str()).collect();
+        write!(f, "{}", codes.join("|"))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for RouteRestrictions {
+    /// Serializes the restrictions as a list of `avoid` codes, the same as a
+    /// `Vec<Avoid>`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    } // fn
+} // impl
+
+impl<'de> Deserialize<'de> for RouteRestrictions {
+    /// Deserializes the restrictions from a list of `avoid` codes, the same as
+    /// a `Vec<Avoid>`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<Avoid>::deserialize(deserializer)?.into_iter().collect())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::ops::BitOr for RouteRestrictions {
+    type Output = Self;
+    /// Combines two sets of restrictions.
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    } // fn
+} // impl
+
+impl std::ops::BitOr<Avoid> for RouteRestrictions {
+    type Output = Self;
+    /// Adds a feature to avoid.
+    fn bitor(self, avoid: Avoid) -> Self {
+        self.with(avoid)
+    } // fn
+} // impl
+
+impl std::ops::BitOrAssign for RouteRestrictions {
+    /// Adds the features of another set of restrictions.
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    } // fn
+} // impl
+
+impl std::ops::BitAnd for RouteRestrictions {
+    type Output = Self;
+    /// Returns the features that are avoided by both sets of restrictions.
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Avoid> for RouteRestrictions {
+    /// Converts a single `Avoid` feature into restrictions.
+    fn from(avoid: Avoid) -> Self {
+        Self::flag(avoid)
+    } // fn
+} // impl
+
+impl<A: Into<Avoid>> std::iter::FromIterator<A> for RouteRestrictions {
+    /// Collects `Avoid` features into restrictions. Duplicates are ignored.
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut restrictions = Self::NONE;
+        restrictions.extend(iter);
+        restrictions
+    } // fn
+} // impl
+
+impl<A: Into<Avoid>> std::iter::Extend<A> for RouteRestrictions {
+    /// Adds `Avoid` features to the restrictions.
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|avoid| self.insert(avoid.into()));
+    } // fn
+} // impl
+
+impl std::iter::IntoIterator for RouteRestrictions {
+    type Item = Avoid;
+    type IntoIter = std::vec::IntoIter<Avoid>;
+    /// Returns an iterator over the avoided features. See
+    /// `RouteRestrictions::iter`.
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter().collect::<Vec<Avoid>>().into_iter()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&RouteModifiers> for RouteRestrictions {
+    /// Converts the avoided features of `RouteModifiers` into restrictions.
+    /// The vehicle emission type has no equivalent and is dropped.
+    fn from(route_modifiers: &RouteModifiers) -> Self {
+        [
+            (route_modifiers.avoid_tolls, Avoid::Tolls),
+            (route_modifiers.avoid_highways, Avoid::Highways),
+            (route_modifiers.avoid_ferries, Avoid::Ferries),
+            (route_modifiers.avoid_indoor, Avoid::Indoor),
+        ]
+        .into_iter()
+        .filter_map(|(avoid, restriction)| avoid.then_some(restriction))
+        .collect()
+    } // fn
+} // impl
+
+impl std::convert::From<RouteRestrictions> for RouteModifiers {
+    /// Converts restrictions into `RouteModifiers`. See
+    /// `RouteRestrictions::to_route_modifiers`.
+    fn from(restrictions: RouteRestrictions) -> Self {
+        restrictions.to_route_modifiers()
+    } // fn
+} // impl
+
+impl std::convert::From<RouteRestrictions> for Vec<Avoid> {
+    /// Converts restrictions into a list of `Avoid` features.
+    fn from(restrictions: RouteRestrictions) -> Self {
+        restrictions.iter().collect()
+    } // fn
+} // impl
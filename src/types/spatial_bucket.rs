@@ -0,0 +1,114 @@
+//! Contains helpers that group Places and Geocoding results into spatial
+//! buckets, by [H3](https://h3geo.org/) cell or by
+//! [geohash](https://en.wikipedia.org/wiki/Geohash).
+
+use crate::types::LatLng;
+#[cfg(any(feature = "h3", feature = "geohash"))]
+use crate::error::Error as GoogleMapsError;
+#[cfg(any(feature = "h3", feature = "geohash"))]
+use std::collections::BTreeMap;
+
+// -----------------------------------------------------------------------------
+//
+/// A result that has a location on the map. Implemented for the `Geocoding`
+/// and `Place` results so they can be bucketed with `bucket_by_h3` and
+/// `bucket_by_geohash`, or clustered with `cluster_places`.
+
+pub trait Locatable {
+    /// Returns the location of the result, or `None` if it has none.
+    fn location(&self) -> Option<LatLng>;
+} // trait
+
+// -----------------------------------------------------------------------------
+
+impl Locatable for LatLng {
+    fn location(&self) -> Option<LatLng> {
+        Some(*self)
+    } // fn
+} // impl
+
+#[cfg(feature = "geocoding")]
+impl Locatable for crate::geocoding::Geocoding {
+    fn location(&self) -> Option<LatLng> {
+        Some(self.geometry.location)
+    } // fn
+} // impl
+
+#[cfg(feature = "places")]
+impl Locatable for crate::places::Place {
+    fn location(&self) -> Option<LatLng> {
+        self.geometry.as_ref().map(|geometry| geometry.location)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Groups results by the H3 cell that contains them. Results without a
+/// location are skipped.
+///
+/// ## Arguments
+///
+/// * `items` ‧ The results to group, for example `&response.results`.
+///
+/// * `resolution` ‧ The H3 resolution, from 0 to 15.
+///
+/// ## Errors
+///
+/// * Returns `InvalidH3Resolution` if the resolution is greater than 15.
+///
+/// ## Example
+///
+/// ```rust
+/// let buckets = google_maps::bucket_by_h3(&nearby.results, 8)?;
+/// for (cell, places) in &buckets {
+///     println!("{cell}: {} places", places.len());
+/// }
+/// ```
+
+#[cfg(feature = "h3")]
+pub fn bucket_by_h3<'i, T: Locatable + 'i>(
+    items: impl IntoIterator<Item = &'i T>,
+    resolution: u8,
+) -> Result<BTreeMap<h3o::CellIndex, Vec<&'i T>>, GoogleMapsError> {
+    let mut buckets: BTreeMap<h3o::CellIndex, Vec<&'i T>> = BTreeMap::new();
+    for item in items {
+        if let Some(location) = item.location() {
+            buckets.entry(location.to_h3_cell(resolution)?).or_default().push(item);
+        } // if
+    } // for
+    Ok(buckets)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Groups results by their geohash. Results without a location are skipped.
+///
+/// ## Arguments
+///
+/// * `items` ‧ The results to group, for example `&response.results`.
+///
+/// * `length` ‧ The number of geohash characters, from 1 to 12.
+///
+/// ## Errors
+///
+/// * Returns `InvalidGeohashLength` if the length is not between 1 and 12.
+///
+/// ## Example
+///
+/// ```rust
+/// let buckets = google_maps::bucket_by_geohash(&geocoding.results, 6)?;
+/// ```
+
+#[cfg(feature = "geohash")]
+pub fn bucket_by_geohash<'i, T: Locatable + 'i>(
+    items: impl IntoIterator<Item = &'i T>,
+    length: usize,
+) -> Result<BTreeMap<String, Vec<&'i T>>, GoogleMapsError> {
+    let mut buckets: BTreeMap<String, Vec<&'i T>> = BTreeMap::new();
+    for item in items {
+        if let Some(location) = item.location() {
+            buckets.entry(location.to_geohash(length)?).or_default().push(item);
+        } // if
+    } // for
+    Ok(buckets)
+} // fn
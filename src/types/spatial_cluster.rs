@@ -0,0 +1,125 @@
+//! Contains the `cluster_places` function. It groups Places and Geocoding
+//! results that are close to each other, for example to replace crowded map
+//! markers with a single marker per cluster.
+
+use crate::types::{spatial_bucket::Locatable, LatLng};
+use rust_decimal::Decimal;
+
+// -----------------------------------------------------------------------------
+//
+/// A group of results that are close to each other.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PlaceCluster {
+    /// The average location of the members of the cluster.
+    pub centroid: LatLng,
+
+    /// The indices of the members of the cluster, in ascending order. They
+    /// index the slice that was passed to `cluster_places`.
+    pub members: Vec<usize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Clusters results by proximity using
+/// [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN). A result with at least
+/// `min_points` results within `epsilon_m` meters, counting itself, starts or
+/// grows a cluster. Results that are within `epsilon_m` meters of a cluster
+/// but not crowded themselves join it on its edge.
+///
+/// Results that belong to no cluster, and results without a location, are
+/// left out. With a `min_points` of `1` every located result belongs to a
+/// cluster, so isolated results are returned as clusters of one.
+///
+/// Distances are great-circle distances, so the running time grows with the
+/// square of the number of results. This is meant for a page or a few pages
+/// of search results, not for whole datasets.
+///
+/// ## Arguments
+///
+/// * `places` ‧ The results to cluster, for example `&response.results`.
+///
+/// * `epsilon_m` ‧ The greatest distance, in meters, between neighbouring
+///   results.
+///
+/// * `min_points` ‧ The number of neighbours, counting the result itself,
+///   that makes a result the core of a cluster.
+///
+/// ## Example
+///
+/// ```rust
+/// for cluster in google_maps::cluster_places(&nearby.results, 250.0, 3) {
+///     println!("{} places around {}", cluster.members.len(), cluster.centroid);
+/// }
+/// ```
+
+#[must_use]
+pub fn cluster_places<T: Locatable>(
+    places: &[T],
+    epsilon_m: f64,
+    min_points: usize,
+) -> Vec<PlaceCluster> {
+    let locations: Vec<Option<LatLng>> = places.iter().map(Locatable::location).collect();
+
+    let neighbours = |index: usize| -> Vec<usize> {
+        locations[index].map_or_else(Vec::new, |origin| {
+            locations
+                .iter()
+                .enumerate()
+                .filter(|(_, location)| location.is_some_and(|location|
+                    origin.haversine_distance(&location) <= epsilon_m))
+                .map(|(neighbour, _)| neighbour)
+                .collect()
+        }) // map_or_else
+    }; // closure
+
+    let mut visited = vec![false; places.len()];
+    let mut assigned = vec![false; places.len()];
+    let mut clusters = Vec::new();
+
+    for index in 0..places.len() {
+        if visited[index] || locations[index].is_none() { continue; }
+        visited[index] = true;
+
+        let mut frontier = neighbours(index);
+        if frontier.len() < min_points.max(1) { continue; }
+
+        // Expand the cluster from its first core result. Neighbours of
+        // further core results are added to the frontier as they are found:
+        let mut members = Vec::new();
+        while let Some(neighbour) = frontier.pop() {
+            if !assigned[neighbour] {
+                assigned[neighbour] = true;
+                members.push(neighbour);
+            } // if
+            if !visited[neighbour] {
+                visited[neighbour] = true;
+                let reachable = neighbours(neighbour);
+                if reachable.len() >= min_points {
+                    frontier.extend(reachable.into_iter().filter(|next| !assigned[*next]));
+                } // if
+            } // if
+        } // while
+
+        members.sort_unstable();
+        clusters.push(PlaceCluster {
+            centroid: centroid(members.iter().filter_map(|member| locations[*member])),
+            members,
+        }); // push
+    } // for
+
+    clusters
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Returns the average of a non-empty set of locations. The average of valid
+/// coordinates is always a valid coordinate.
+fn centroid(locations: impl Iterator<Item = LatLng>) -> LatLng {
+    let (count, lat, lng) = locations.fold(
+        (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO),
+        |(count, lat, lng), location| (count + Decimal::ONE, lat + location.lat, lng + location.lng),
+    ); // fold
+    let count = count.max(Decimal::ONE);
+    LatLng { lat: lat / count, lng: lng / count }
+} // fn
@@ -0,0 +1,443 @@
+//! Tests for the client: shared configuration, running requests
+//! concurrently, error sources and the transport.
+
+#[macro_use]
+mod common;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "directions")]
+mod shared_client {
+    use google_maps::prelude::{Api, GoogleMapsClient};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::common;
+
+    #[test]
+    fn set_rate() {
+        let client = Arc::new(common::client());
+        let mut built = GoogleMapsClient::clone(&client);
+
+        let threads: Vec<_> = (1..=4_u16).map(|requests| {
+            let client = Arc::clone(&client);
+            std::thread::spawn(move || {
+                client.set_rate(&Api::Directions, requests, Duration::from_secs(1));
+                let mut request = common::kitchener_to_toronto(&client);
+                assert!(request.try_build().is_ok());
+            }) // spawn
+        }).collect();
+        threads.into_iter().for_each(|thread| thread.join().unwrap());
+
+        // `set_rate` is seen by clones, `with_rate` is not:
+        assert_eq!(built.rate_limit, client.rate_limit);
+        built.with_rate(&Api::Directions, 100, Duration::from_secs(1));
+        assert_ne!(built.rate_limit, client.rate_limit);
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "directions", feature = "geocoding", feature = "reqwest"))]
+mod join {
+    use google_maps::Error;
+    use super::common;
+
+    #[tokio::test]
+    async fn join2() {
+        let client = common::client();
+
+        // Both requests fail validation, so nothing is sent:
+        let mut directions = common::kitchener_to_toronto(&client);
+        let directions = common::make_invalid(&mut directions);
+        let mut geocoding = client.geocoding();
+
+        let (directions, geocoding) = google_maps::join2(directions, &mut geocoding).await;
+        assert!(matches!(directions, Err(Error::Directions(_))));
+        assert!(matches!(geocoding, Err(Error::Geocoding(_))));
+
+        let mut requests = [client.geocoding(), client.geocoding()];
+        let results = google_maps::execute_all(requests.iter_mut()).await;
+        assert_eq!(results.len(), 2);
+    } // fn
+
+    #[tokio::test]
+    async fn executable() {
+        use google_maps::Executable;
+
+        // A generic wrapper that works with any request:
+        async fn counted<'a, E: Executable<'a>>(count: &mut usize, request: E) -> Result<E::Response, Error> {
+            *count += 1;
+            request.execute_boxed().await
+        } // fn
+
+        let client = common::client();
+        let mut count = 0;
+
+        // Both requests fail validation, so nothing is sent:
+        let mut geocoding = client.geocoding();
+        assert!(matches!(counted(&mut count, &mut geocoding).await, Err(Error::Geocoding(_))));
+        let mut directions = common::kitchener_to_toronto(&client);
+        let directions = common::make_invalid(&mut directions);
+        assert!(matches!(counted(&mut count, directions).await, Err(Error::Directions(_))));
+        assert_eq!(count, 2);
+    } // fn
+
+    #[tokio::test]
+    async fn sweep_departure_times() {
+        use chrono::{Duration, NaiveDate};
+
+        let client = common::client();
+        let start = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(6, 0, 0).unwrap();
+        let times = google_maps::directions::departure_times(start, start + Duration::hours(1), Duration::minutes(30));
+
+        // Transit directions can't have waypoints, so every request fails
+        // validation and nothing is sent:
+        let mut directions = common::kitchener_to_toronto(&client);
+        let directions = common::make_invalid(&mut directions);
+
+        let sweep = directions.sweep_departure_times(times.clone(), 2).await;
+        assert!(sweep.points.is_empty());
+        assert_eq!(sweep.failures.len(), 2);
+        assert!(matches!(sweep.failures[0], (time, Error::Directions(_)) if time == times[0]));
+        assert_eq!(sweep.skipped, vec![times[2]]);
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod error_source {
+    use google_maps::geocoding::error::Error as GeocodingError;
+    use google_maps::Error;
+    use std::error::Error as _;
+
+    /// Follows the `source` chain of an error to the first `reqwest::Error`.
+    fn find_reqwest(error: &Error) -> Option<&reqwest::Error> {
+        let error: &(dyn std::error::Error + 'static) = error;
+        std::iter::successors(Some(error), |error| (*error).source())
+            .find_map(|error| error.downcast_ref::<reqwest::Error>())
+    } // fn
+
+    #[tokio::test]
+    async fn transport() {
+        // Nothing listens on port 1, so the connection is refused:
+        let refused = || async { reqwest::get("http://127.0.0.1:1/").await.unwrap_err() };
+
+        let error = Error::from(GeocodingError::from(refused().await));
+        assert!(error.source().and_then(|source| source.downcast_ref::<GeocodingError>()).is_some());
+        assert!(find_reqwest(&error).is_some_and(reqwest::Error::is_connect));
+
+        let error = Error::from(refused().await);
+        assert!(error.source().and_then(|source| source.downcast_ref::<reqwest::Error>()).is_some());
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+mod transport_info {
+    use google_maps::prelude::{ClientConfig, GoogleMapsClient, IpPreference, ResolverOptions, RetryPolicy, TlsBackend};
+    #[cfg(feature = "rustls-platform-verifier")]
+    use google_maps::prelude::TlsRoots;
+    use google_maps::Error;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use super::common;
+
+    #[test]
+    fn tls_backend() {
+        let mut client = common::client();
+        let info = client.transport_info();
+        assert_eq!(info.tls_backend, TlsBackend::default_backend());
+        assert_eq!(info.available_tls_backends, TlsBackend::available());
+        assert!(info.to_string().starts_with("tls="));
+
+        // A caller-supplied client's backend is unknown:
+        client.with_reqwest_client(reqwest::Client::new());
+        assert_eq!(client.transport_info().tls_backend, None);
+        assert!(client.transport_info().tls_roots.is_empty());
+
+        for backend in [TlsBackend::NativeTls, TlsBackend::Rustls] {
+            let config = ClientConfig {
+                key: common::KEY.to_string(),
+                tls_backend: Some(backend),
+                ..ClientConfig::default()
+            }; // ClientConfig
+            match GoogleMapsClient::from_config(&config) {
+                Ok(client) => {
+                    assert!(backend.is_available());
+                    assert_eq!(client.transport_info().tls_backend, Some(backend));
+                    assert_eq!(client.transport_info().tls_roots, backend.roots());
+                    #[cfg(feature = "rustls-platform-verifier")]
+                    if backend == TlsBackend::Rustls {
+                        assert_eq!(backend.roots(), vec![TlsRoots::Platform]);
+                    } // if
+                } // Ok
+                Err(error) => {
+                    assert!(!backend.is_available());
+                    assert!(matches!(error, Error::Type(_)));
+                    assert!(error.help().is_some());
+                } // Err
+            } // match
+        } // for
+    } // fn
+
+    /// Resolves every name to an IPv6 and an IPv4 loopback address, on a port
+    /// that nothing listens on.
+    struct Loopback;
+
+    impl reqwest::dns::Resolve for Loopback {
+        fn resolve(&self, _: reqwest::dns::Name) -> reqwest::dns::Resolving {
+            let addresses: Vec<SocketAddr> = vec!["[::1]:1".parse().unwrap(), "127.0.0.1:1".parse().unwrap()];
+            Box::pin(async move { Ok(Box::new(addresses.into_iter()) as reqwest::dns::Addrs) })
+        } // fn
+    } // impl
+
+    #[tokio::test]
+    async fn resolver() {
+        let addresses: Vec<SocketAddr> = vec!["[::1]:1".parse().unwrap(), "127.0.0.1:1".parse().unwrap()];
+        assert!(IpPreference::PreferIpv4.apply(addresses.clone())[0].is_ipv4());
+        assert!(IpPreference::Ipv6Only.apply(addresses.clone()).iter().all(SocketAddr::is_ipv6));
+        assert_eq!(IpPreference::Any.apply(addresses.clone()), addresses);
+
+        let options = ResolverOptions::new()
+            .with_ip_preference(IpPreference::Ipv4Only)
+            .with_resolver(Arc::new(Loopback));
+        let client = options.apply(reqwest::Client::builder()).build().unwrap();
+        // The name resolves to a loopback address, where the connection is
+        // refused:
+        let error = client.get("http://maps.googleapis.invalid:1/").send().await.unwrap_err();
+        assert!(error.is_connect());
+
+        // The operating system's resolver is used when there is no custom one:
+        let client = ResolverOptions::new()
+            .with_ip_preference(IpPreference::Ipv4Only)
+            .apply(reqwest::Client::builder())
+            .build()
+            .unwrap();
+        let error = client.get("http://localhost:1/").send().await.unwrap_err();
+        assert!(error.is_connect());
+
+        // A client built from a configuration keeps its other settings:
+        let client = GoogleMapsClient::from_config_with_resolver(&config(), Arc::new(Loopback)).unwrap();
+        assert_eq!(client.user_agent, "my-app/1.0");
+        assert_eq!(client.channel.as_deref(), Some("my-channel"));
+        assert_eq!(client.transport_info().tls_backend, TlsBackend::default_backend());
+    } // fn
+
+    /// Returns a configuration with a user agent and a channel, that only
+    /// connects over IPv4 and doesn't retry failed requests.
+    fn config() -> ClientConfig {
+        ClientConfig {
+            key: common::KEY.to_string(),
+            user_agent: Some("my-app/1.0".to_string()),
+            channel: Some("my-channel".to_string()),
+            ip_preference: Some(IpPreference::Ipv4Only),
+            retry_policy: Some(RetryPolicy::never()),
+            ..ClientConfig::default()
+        } // ClientConfig
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[tokio::test]
+    async fn from_config_with_resolver() {
+        let client = GoogleMapsClient::from_config_with_resolver(&config(), Arc::new(Loopback)).unwrap();
+        // Google's server resolves to a loopback address, where the
+        // connection is refused:
+        let error = client
+            .geocoding()
+            .with_address("Toronto, ON")
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(error.help().is_some(), "{error:?}");
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "directions", feature = "geocoding", feature = "reqwest"))]
+mod config {
+    use google_maps::prelude::{ClientConfig, GoogleMapsClient, Language, Region, RetryPolicy};
+    use super::common;
+
+    #[test]
+    fn debug_redacts_key() {
+        let config = ClientConfig { key: common::KEY.to_string(), ..ClientConfig::default() };
+        let debug = format!("{config:?}");
+        assert!(!debug.contains(common::KEY));
+        assert!(debug.contains("key: \"REDACTED\""));
+    } // fn
+
+    #[test]
+    fn from_json() {
+        let mut json = format!(r#"{{
+            "key": "{}",
+            "language": "fr",
+            "region": "ca",
+            "retry_policy": {{ "max_elapsed_secs": 0 }},
+            "base_url": "http://127.0.0.1:1/"
+        }}"#, common::KEY).into_bytes();
+        let config: ClientConfig = simd_json::serde::from_slice(&mut json).unwrap();
+        assert_eq!(config.retry_policy, Some(RetryPolicy::never()));
+
+        let client = GoogleMapsClient::from_config(&config).unwrap();
+        assert_eq!(client.language, Some(Language::French));
+        assert_eq!(client.region, Some(Region::Canada));
+        assert_eq!(client.retry_policy, RetryPolicy::never());
+        assert_eq!(client.base_url.as_deref(), Some("http://127.0.0.1:1"));
+    } // fn
+
+    #[test]
+    fn language_and_region() {
+        let mut client = common::client();
+        client.with_language(Language::French).with_region(Region::Canada);
+
+        let mut request = common::kitchener_to_toronto(&client);
+        let url = request.try_build().unwrap().url().to_string();
+        assert!(url.contains("&language=fr&"));
+        assert!(url.contains("&region=ca"));
+
+        // A request's own language wins:
+        let url = request.with_language(Language::German).try_build().unwrap().url().to_string();
+        assert!(url.contains("&language=de&"));
+    } // fn
+
+    #[tokio::test]
+    async fn base_url() {
+        let server = common::MockServer::start(|_| (200, fixture!("geocoding/ok").to_string()));
+        let client = common::mock_client(&server);
+
+        let response = client.geocoding().with_address("Toronto").execute().await.unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert!(server.targets()[0].starts_with("/maps/api/geocode/json?key="));
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod circuit_breaker {
+    use google_maps::prelude::{Api, CircuitState, RetryPolicy};
+    use google_maps::Error;
+    use std::time::Duration;
+    use super::common;
+
+    #[tokio::test]
+    async fn checked_before_each_retry() {
+        let server = common::MockServer::start(|_| (503, String::new()));
+        let mut client = common::mock_client(&server);
+        client
+            .with_retry_policy(RetryPolicy::default().with_initial_interval_ms(1).with_max_elapsed_secs(60))
+            .with_circuit_breaker(3, Duration::from_secs(60));
+
+        // The third failure opens the circuit, so the fourth attempt is never
+        // sent:
+        let result = client.geocoding().with_address("Toronto").execute().await;
+        assert!(matches!(result, Err(Error::CircuitOpen(Api::Geocoding))));
+        assert_eq!(server.targets().len(), 3);
+        assert_eq!(client.circuit_state(&Api::Geocoding), CircuitState::Open);
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod classified_error {
+    use google_maps::geocoding::error::Error as GeocodingError;
+    use google_maps::prelude::{Api, ClassifiableError, ClassifiedError, GeocodingResponse, GeocodingStatus};
+    use google_maps::Error;
+    use super::common::{self, MockServer};
+
+    #[tokio::test]
+    async fn zero_results() {
+        let server = MockServer::start(|_| (200, fixture!("geocoding/zero_results").to_string()));
+        let mut client = common::mock_client(&server);
+
+        // Finding nothing isn't an error by default:
+        let response = client.geocoding().with_address("Nowhere").execute().await.unwrap();
+        assert_eq!(response.status, GeocodingStatus::ZeroResults);
+        assert!(response.results.is_empty());
+
+        client.with_zero_results_as_error(true);
+        let error = client.geocoding().with_address("Nowhere").execute().await.unwrap_err();
+        assert_eq!(error.classify(), ClassifiedError::NoResults);
+        assert!(!error.is_retryable());
+    } // fn
+
+    #[tokio::test]
+    async fn transport() {
+        // Nothing listens on port 1, so the connection is refused:
+        let mut client = common::client();
+        client.with_base_url("http://127.0.0.1:1").with_retry_policy(google_maps::RetryPolicy::never());
+
+        let error = client.geocoding().with_address("Toronto").execute().await.unwrap_err();
+        assert_eq!(error.classify(), ClassifiedError::Transport);
+        assert!(error.is_retryable());
+    } // fn
+
+    #[test]
+    fn classify() {
+        assert_eq!(GeocodingStatus::OverQueryLimit.classify(), ClassifiedError::QuotaExceeded);
+        assert_eq!(GeocodingStatus::UnknownError.classify(), ClassifiedError::Transient);
+        assert_eq!(ClassifiedError::from_http_status("403 Forbidden"), ClassifiedError::Denied);
+        assert_eq!(ClassifiedError::from_http_status("503 Service Unavailable"), ClassifiedError::Transient);
+        assert_eq!(ClassifiedError::from_status_code("RESOURCE_EXHAUSTED"), ClassifiedError::QuotaExceeded);
+
+        let parse = "{".parse::<GeocodingResponse>().unwrap_err();
+        let error = Error::from(GeocodingError::SimdJson(parse));
+        assert_eq!(error.classify(), ClassifiedError::Parse);
+        assert!(!error.is_retryable());
+
+        let error = Error::from(GeocodingError::AddressOrComponentsRequired);
+        assert_eq!(error.classify(), ClassifiedError::InvalidRequest);
+
+        let error = Error::from(GeocodingError::HttpUnsuccessful("429 Too Many Requests".to_string()));
+        assert_eq!(error.classify(), ClassifiedError::QuotaExceeded);
+        assert!(error.is_retryable());
+
+        assert_eq!(Error::CircuitOpen(Api::Geocoding).classify(), ClassifiedError::Transient);
+        let error = google_maps::prelude::GoogleMapsClient::try_new("not a key").unwrap_err();
+        assert_eq!(error.classify(), ClassifiedError::Denied);
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+mod trace_context {
+    use google_maps::TraceContext;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn traceparent() {
+        assert!(TraceContext::new(TRACEPARENT).is_valid());
+        assert!(!TraceContext::new("00-00000000000000000000000000000000-00f067aa0ba902b7-01").is_valid());
+        assert!(!TraceContext::new("00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01").is_valid());
+        assert!(!TraceContext::new("00-4bf92f3577b34da6a3ce929d0e0e4736-01").is_valid());
+    } // fn
+
+    #[test]
+    fn tracestate() {
+        let valid = |tracestate: &str| TraceContext::new(TRACEPARENT).with_tracestate(tracestate).is_valid();
+
+        assert!(valid("congo=t61rcWkgMzE"));
+        assert!(valid("rojo=00f067aa0ba902b7, congo=t61rcWkgMzE"));
+        assert!(valid("fw529a3039@dt=dGhpcyBpcyBhIHRlc3Q, ,"));
+        assert!(valid(""));
+
+        // Malformed entries:
+        assert!(!valid("congo"));
+        assert!(!valid("Congo=t61rcWkgMzE"));
+        assert!(!valid("congo=t61rc=WkgMzE"));
+        assert!(!valid("congo=t61rcWkgMzE\r\nx-injected: 1"));
+        assert!(!valid("congo@=t61rcWkgMzE"));
+        assert!(!valid(&format!("congo={}", "a".repeat(257))));
+
+        // No more than 32 entries:
+        let entries: Vec<String> = (0..33).map(|index| format!("vendor{index}=x")).collect();
+        assert!(valid(&entries[..32].join(",")));
+        assert!(!valid(&entries.join(",")));
+    } // fn
+} // mod
@@ -0,0 +1,119 @@
+//! Helpers shared by the integration tests. Each test file uses a different
+//! subset of them, so unused helpers are expected.
+#![allow(dead_code, unused_macros)]
+
+/// Reads a fixture from `tests/fixtures`, for example
+/// `fixture!("geocoding/ok")`.
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!(concat!("fixtures/", $path, ".json"))
+    };
+} // macro_rules
+
+// -----------------------------------------------------------------------------
+
+/// A syntactically valid API key that Google will reject. Requests made with
+/// it never succeed, so tests only exercise the crate's own behaviour.
+pub const KEY: &str = "AIzaSyTESTxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx";
+
+/// Returns a client with the test key.
+#[cfg(feature = "reqwest")]
+pub fn client() -> google_maps::GoogleMapsClient {
+    google_maps::GoogleMapsClient::try_new(KEY).unwrap()
+} // fn
+
+/// Returns a request for directions from Kitchener to Toronto.
+#[cfg(all(feature = "directions", feature = "reqwest"))]
+pub fn kitchener_to_toronto(client: &google_maps::GoogleMapsClient) -> google_maps::prelude::DirectionsRequest<'_> {
+    use google_maps::prelude::Location;
+
+    client.directions(
+        Location::from_address("Kitchener, ON"),
+        Location::from_address("Toronto, ON"),
+    ) // directions
+} // fn
+
+/// Turns a directions request into transit directions via Guelph. Transit
+/// directions can't have waypoints, so the request always fails validation and
+/// is never sent.
+#[cfg(feature = "directions")]
+pub fn make_invalid<'a>(
+    request: &'a mut google_maps::prelude::DirectionsRequest<'a>,
+) -> &'a mut google_maps::prelude::DirectionsRequest<'a> {
+    use google_maps::prelude::{TravelMode, Waypoint};
+
+    request
+        .with_travel_mode(TravelMode::Transit)
+        .with_waypoint(Waypoint::from_address("Guelph, ON"))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// A minimal HTTP server that answers every request by calling a function
+/// with the request target, for example `/v1/speedLimits?path=...`, and
+/// records the head of every request it receives. Point a client at it with
+/// `GoogleMapsClient::with_base_url`.
+pub struct MockServer {
+    /// The server's base URL, for example `http://127.0.0.1:50123`.
+    pub url: String,
+    requests: std::sync::Arc<std::sync::Mutex<Vec<String>>>,
+} // struct
+
+impl MockServer {
+    /// Starts a server on a free local port. `respond` returns the HTTP
+    /// status code and JSON body for a request target.
+    pub fn start(respond: impl Fn(&str) -> (u16, String) + Send + 'static) -> Self {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = std::sync::Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut head = String::new();
+                while reader.read_line(&mut head).is_ok_and(|read| read > 2) {}
+                let target = head.split_whitespace().nth(1).unwrap_or_default().to_string();
+                recorded.lock().unwrap().push(head.clone());
+
+                let (status, body) = respond(&target);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\ncontent-type: application/json\r\n\
+                        content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len(),
+                ); // write!
+            } // for
+        }); // spawn
+
+        Self { url, requests }
+    } // fn
+
+    /// Returns the head of every request received so far: the request line
+    /// followed by the headers.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    } // fn
+
+    /// Returns the target of every request received so far.
+    pub fn targets(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .map(|head| head.split_whitespace().nth(1).unwrap_or_default().to_string())
+            .collect()
+    } // fn
+} // impl
+
+/// Returns a client with the test key that sends its requests to `server`,
+/// and doesn't retry failed requests.
+#[cfg(feature = "reqwest")]
+pub fn mock_client(server: &MockServer) -> google_maps::GoogleMapsClient {
+    let mut client = client();
+    client
+        .with_base_url(server.url.clone())
+        .with_retry_policy(google_maps::RetryPolicy::never());
+    client
+} // fn
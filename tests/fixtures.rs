@@ -0,0 +1,916 @@
+//! Golden deserialization tests for the response fixtures in `tests/fixtures`.
+//! The same fixtures are published to downstream crates by the `testing`
+//! feature.
+
+#[macro_use]
+mod common;
+
+/// The bodies that no endpoint should accept.
+const MALFORMED: [&str; 4] = [
+    fixture!("malformed/truncated"),
+    fixture!("malformed/missing_status"),
+    fixture!("malformed/html_error_page"),
+    fixture!("malformed/empty"),
+];
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "geocoding")]
+mod geocoding {
+    use google_maps::geocoding::{GeocodingResponse as Response, GeocodingStatus as Status};
+    use google_maps::LocationType;
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("geocoding/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        let result = &response.results[0];
+        assert_eq!(result.place_id, "ChIJF4Yf2Ry7j4AR__1AkytDyAE");
+        assert_eq!(result.geometry.location_type, Some(LocationType::RoofTop));
+        assert_eq!(result.address_components.len(), 7);
+        assert_eq!(result.partial_match, None);
+    } // fn
+
+    #[test]
+    fn partial_match() {
+        let response: Response = fixture!("geocoding/partial_match").parse().unwrap();
+        assert_eq!(response.results[0].partial_match, Some(true));
+        assert!(response.results[0].geometry.bounds.is_some());
+    } // fn
+
+    #[test]
+    fn address_descriptor() {
+        use google_maps::geocoding::{NavigationTravelMode, SpatialRelationship};
+
+        let response: Response = fixture!("geocoding/address_descriptor").parse().unwrap();
+        let descriptor = response.address_descriptor.unwrap();
+        assert_eq!(descriptor.landmarks[0].display_name.text, "Delhi Junction");
+        assert_eq!(descriptor.landmarks[0].spatial_relationship, SpatialRelationship::AcrossTheRoad);
+        assert_eq!(descriptor.areas.len(), 1);
+
+        let result = &response.results[0];
+        assert_eq!(result.navigation_points.len(), 2);
+        let curbside = result.navigation_point_for(NavigationTravelMode::Drive).unwrap();
+        assert_eq!(curbside, &result.navigation_points[1]);
+    } // fn
+
+    #[test]
+    fn localized_text() {
+        use google_maps::geocoding::LocalizedText;
+        use google_maps::Language;
+
+        let text = |text: &str, code: Option<&str>| LocalizedText {
+            text: text.to_string(),
+            language_code: code.map(str::to_string),
+        }; // closure
+        let names = [
+            text("", None),
+            text("Gare de Delhi", Some("fr")),
+            text("Delhi Junction Station", Some("en-AU")),
+            text("Delhi Junction", Some("en")),
+        ];
+
+        let pick = |preferred: &[Language]| LocalizedText::negotiate(&names, preferred).map(ToString::to_string);
+        assert_eq!(pick(&[Language::EnglishAustralian]).as_deref(), Some("Delhi Junction Station"));
+        assert_eq!(pick(&[Language::EnglishGreatBritain]).as_deref(), Some("Delhi Junction"));
+        assert_eq!(pick(&[Language::FrenchCanada, Language::English]).as_deref(), Some("Gare de Delhi"));
+        assert_eq!(pick(&[Language::Japanese]).as_deref(), Some("Gare de Delhi"));
+        assert_eq!(LocalizedText::negotiate(&[], &[Language::English]), None);
+        assert_eq!(names[2].language(), Some(Language::EnglishAustralian));
+        assert!(names[2].is_in(Language::English));
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("geocoding/zero_results"), Status::ZeroResults),
+            (fixture!("geocoding/invalid_request"), Status::InvalidRequest),
+            (fixture!("geocoding/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("geocoding/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.results.is_empty());
+        } // for
+    } // fn
+
+    /// A `bounds` viewport prefers results inside it: Google lists the
+    /// Winnetka in Los Angeles first, and `results_within` drops the one in
+    /// Illinois.
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn bounds() {
+        use google_maps::prelude::{Bounds, LatLng};
+
+        let san_fernando_valley = Bounds {
+            southwest: LatLng::try_from_f64(34.172_684, -118.604_794).unwrap(),
+            northeast: LatLng::try_from_f64(34.236_144, -118.500_938).unwrap(),
+        }; // Bounds
+        let client = super::common::client();
+        let mut request = client.geocoding();
+        let built = request.with_address("Winnetka").with_bounds(san_fernando_valley).try_build().unwrap();
+        assert!(built.url().ends_with("&bounds=34%2E172684%2C%2D118%2E604794%7C34%2E236144%2C%2D118%2E500938"));
+
+        let response: Response = fixture!("geocoding/bounds").parse().unwrap();
+        assert_eq!(response.results[0].formatted_address, "Winnetka, Los Angeles, CA, USA");
+        let local: Vec<_> = response.results_within(&san_fernando_valley).collect();
+        assert_eq!(local, vec![&response.results[0]]);
+    } // fn
+
+    /// A `region` prefers results in that country: "Toledo" is the city in
+    /// Spain rather than the one in Ohio.
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn region() {
+        use google_maps::prelude::Region;
+
+        let client = super::common::client();
+        let mut request = client.geocoding();
+        let built = request.with_address("Toledo").with_region(Region::Spain).try_build().unwrap();
+        assert!(built.url().ends_with("&address=Toledo&region=es"));
+
+        let response: Response = fixture!("geocoding/region").parse().unwrap();
+        assert_eq!(response.results.len(), 1);
+        assert_eq!(response.results[0].formatted_address, "Toledo, Spain");
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED.iter().chain([&fixture!("malformed/unknown_status")]) {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "directions")]
+mod directions {
+    use google_maps::directions::response::geocoder_status::GeocoderStatus;
+    use google_maps::directions::{DirectionsResponse as Response, DirectionsStatus as Status, ParseMode};
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("directions/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        let leg = &response.routes[0].legs[0];
+        assert_eq!(leg.distance.value, 106_734);
+        assert_eq!(leg.duration_in_traffic.as_ref().map(|d| d.value.num_seconds()), Some(5_112));
+        assert_eq!(response.routes[0].summary, "ON-401 E");
+        assert!(response.has_traffic_durations());
+        assert!(response.warnings().is_empty());
+    } // fn
+
+    #[test]
+    fn transit() {
+        let response: Response = fixture!("directions/transit").parse().unwrap();
+        let route = &response.routes[0];
+        // Two subway rides, with a walk between platforms at Bloor-Yonge:
+        assert_eq!(route.transit_steps().count(), 2);
+        assert_eq!(route.transfers(), 1);
+        assert_eq!(route.walking_distance(), 300 + 40 + 200);
+
+        // A route without transit rides has no transfers and, when driving,
+        // no walking:
+        let driving: Response = fixture!("directions/ok").parse().unwrap();
+        assert_eq!(driving.routes[0].transfers(), 0);
+        assert_eq!(driving.routes[0].walking_distance(), 0);
+    } // fn
+
+    #[test]
+    fn summary_only() {
+        let full: Response = fixture!("directions/ok").parse().unwrap();
+        let mut bytes = fixture!("directions/ok").as_bytes().to_vec();
+        let summary = ParseMode::SummaryOnly.parse(&mut bytes).unwrap();
+        assert_eq!(summary.status, Status::Ok);
+        assert_eq!(summary.routes[0].summary, full.routes[0].summary);
+        assert_eq!(summary.routes[0].legs[0].distance, full.routes[0].legs[0].distance);
+        assert!(summary.routes[0].legs[0].steps.is_empty());
+        assert!(summary.routes[0].overview_polyline.points.is_empty());
+    } // fn
+
+    #[test]
+    fn cheapest_route_by_fare() {
+        use google_maps::directions::{TransitCurrency, TransitFare};
+        use rust_decimal_macros::dec;
+
+        let fare = |currency: TransitCurrency, value| Some(TransitFare {
+            currency,
+            text: String::new(),
+            value,
+        }); // closure
+
+        let mut response: Response = fixture!("directions/ok").parse().unwrap();
+        assert!(response.cheapest_route_by_fare().is_none());
+
+        let route = response.routes[0].clone();
+        response.routes = vec![route.clone(), route.clone(), route];
+        response.routes[0].fare = fare(TransitCurrency::CanadianDollar, dec!(3.35));
+        response.routes[2].fare = fare(TransitCurrency::CanadianDollar, dec!(2.80));
+        let cheapest = response.cheapest_route_by_fare().and_then(|route| route.fare.as_ref());
+        assert_eq!(cheapest.map(|fare| fare.value), Some(dec!(2.80)));
+
+        response.routes[1].fare = fare(TransitCurrency::UnitedStatesDollar, dec!(1.00));
+        assert!(response.cheapest_route_by_fare().is_none());
+    } // fn
+
+    #[test]
+    fn compare() {
+        let mut response: Response = fixture!("directions/ok").parse().unwrap();
+        let route = response.routes[0].clone();
+        response.routes = vec![route.clone(), route];
+        {
+            let slower = &mut response.routes[1];
+            let leg = &mut slower.legs[0];
+            let in_traffic = leg.duration_in_traffic.as_mut().unwrap();
+            in_traffic.value += chrono::Duration::try_minutes(5).unwrap();
+            leg.distance.value -= 3_400;
+            slower.warnings.push("This route has tolls.".to_string());
+        }
+
+        assert!(std::ptr::eq(response.fastest_route().unwrap(), &response.routes[0]));
+        assert!(std::ptr::eq(response.shortest_route().unwrap(), &response.routes[1]));
+        assert!(response.route_with_least_turns().is_some());
+
+        let comparisons = response.compare();
+        assert!(comparisons[0].is_fastest());
+        assert_eq!(comparisons[0].to_string(), "Fastest route");
+        assert_eq!(comparisons[1].route_index, 1);
+        assert_eq!(comparisons[1].distance_delta_meters, -3_400);
+        assert_eq!(comparisons[1].to_string(), "5 min slower, 3.4 km shorter, has tolls");
+    } // fn
+
+    #[test]
+    fn has_tolls() {
+        let response: Response = fixture!("directions/ok").parse().unwrap();
+        let mut route = response.routes[0].clone();
+        route.warnings.clear();
+        route.legs.truncate(1);
+        route.legs[0].steps.truncate(1);
+
+        for (instructions, has_tolls) in [
+            ("Turn left onto <b>Tollgate Rd</b>", false),
+            ("Turn left onto <b>Atoll Road</b>", false),
+            ("Merge onto <b>ON-407 ETR</b><div style=\"font-size:0.9em\">Partial toll road</div>", true),
+        ] {
+            route.legs[0].steps[0].html_instructions = Some(instructions.to_string());
+            assert_eq!(route.has_tolls(), has_tolls, "{instructions}");
+        } // for
+
+        route.legs[0].steps[0].html_instructions = None;
+        route.warnings.push("This route has tolls.".to_string());
+        assert!(route.has_tolls());
+    } // fn
+
+    #[test]
+    fn localized_text() {
+        use google_maps::directions::response::localized_text::{parse_distance, parse_duration};
+
+        let response: Response = fixture!("directions/ok").parse().unwrap();
+        let leg = &response.routes[0].legs[0];
+        assert_eq!(parse_distance(&leg.distance.text), Some(107_000));
+        let parsed = parse_duration(&leg.duration.text).unwrap();
+        assert!((parsed - leg.duration.value).num_seconds().abs() < 60);
+
+        assert_eq!(parse_distance("1,2 km"), Some(1_200));
+        assert_eq!(parse_distance("1,200 mi"), Some(1_931_213));
+        assert_eq!(parse_distance("1 234 km"), Some(1_234_000));
+        assert_eq!(parse_distance("500 ft"), Some(152));
+        assert_eq!(parse_distance("12 parsecs"), None);
+        assert_eq!(parse_duration("1 Std. 5 Min."), chrono::Duration::try_minutes(65));
+        assert_eq!(parse_duration("2 jours 3 heures"), chrono::Duration::try_hours(51));
+        assert_eq!(parse_duration("1時間5分"), chrono::Duration::try_minutes(65));
+        assert_eq!(parse_duration("soon"), None);
+    } // fn
+
+    #[test]
+    fn departure_sweep() {
+        use google_maps::directions::{departure_times, DepartureSweep};
+        use chrono::{Duration, NaiveDate};
+
+        let start = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap().and_hms_opt(6, 0, 0).unwrap();
+        let times = departure_times(start, start + Duration::hours(1), Duration::minutes(15));
+        assert_eq!(times.len(), 5);
+        assert!(departure_times(start, start, Duration::zero()).is_empty());
+
+        let ok: Response = fixture!("directions/ok").parse().unwrap();
+        let responses = times.iter().zip([70, 62, 60, 75, 61]).map(|(time, minutes)| {
+            let mut response = ok.clone();
+            for leg in &mut response.routes[0].legs {
+                leg.duration_in_traffic.as_mut().unwrap().value = Duration::minutes(minutes);
+            } // for
+            (*time, response)
+        }); // map
+
+        let sweep = DepartureSweep::from_responses(responses);
+        assert_eq!(sweep.points.len(), 5);
+        assert_eq!(sweep.fastest().map(|point| point.departure_time), Some(times[2]));
+        assert_eq!(sweep.slowest().map(|point| point.departure_time), Some(times[3]));
+        assert_eq!(
+            sweep.windows(Duration::minutes(2)),
+            vec![(times[1], times[2]), (times[4], times[4])],
+        );
+    } // fn
+
+    #[test]
+    fn not_found() {
+        let response: Response = fixture!("directions/not_found").parse().unwrap();
+        assert_eq!(response.status, Status::NotFound);
+        assert_eq!(
+            response.geocoded_waypoints[0].geocoder_status,
+            Some(GeocoderStatus::ZeroResults),
+        );
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("directions/zero_results"), Status::ZeroResults),
+            (fixture!("directions/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("directions/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.routes.is_empty());
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "distance_matrix")]
+mod distance_matrix {
+    use google_maps::distance_matrix::response::element_status::ElementStatus;
+    use google_maps::distance_matrix::{
+        DistanceMatrixResponse as Response, DistanceMatrixStatus as Status,
+    }; // google_maps::distance_matrix
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("distance_matrix/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.rows[0].elements[0].status, ElementStatus::Ok);
+        assert_eq!(response.failed_elements(), vec![(0, 1, ElementStatus::ZeroResults)]);
+    } // fn
+
+    #[test]
+    fn addresses() {
+        let response: Response = fixture!("distance_matrix/ok").parse().unwrap();
+        assert!(response.is_aligned());
+        assert_eq!(response.origin_address(0), Some("Kitchener, ON, Canada"));
+        assert_eq!(response.destination_address(1), Some("Hamilton, ON, Canada"));
+        assert_eq!(response.destination_address(2), None);
+        assert_eq!(response.element(0, 1).map(|element| &element.status), Some(&ElementStatus::ZeroResults));
+        assert!(response.element(1, 0).is_none());
+
+        let pairs: Vec<(usize, usize)> = response
+            .elements()
+            .map(|(origin, destination, _)| (origin.get(), destination.get()))
+            .collect();
+        assert_eq!(pairs, vec![(0, 0), (0, 1)]);
+
+        let origins = ["Kitchener"];
+        let destinations = ["Toronto", "Hamilton"];
+        let inputs: Vec<(&&str, &&str)> = response
+            .with_inputs(&origins, &destinations)
+            .map(|(origin, destination, _)| (origin, destination))
+            .collect();
+        assert_eq!(inputs, vec![(&"Kitchener", &"Toronto"), (&"Kitchener", &"Hamilton")]);
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("distance_matrix/max_elements_exceeded"), Status::MaxElementsExceeded),
+            (fixture!("distance_matrix/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("distance_matrix/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.rows.is_empty());
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "elevation")]
+mod elevation {
+    use google_maps::elevation::{ElevationResponse as Response, ElevationStatus as Status};
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("elevation/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.results.len(), 2);
+        assert!(response.results[1].elevation < 0.0);
+    } // fn
+
+    #[test]
+    fn with_inputs() {
+        let response: Response = fixture!("elevation/ok").parse().unwrap();
+        let locations = ["Denver", "Death Valley"];
+        let paired = response.with_inputs(&locations).unwrap();
+        assert_eq!(paired[1].0, &"Death Valley");
+        assert!(paired[1].1.elevation < 0.0);
+        assert_eq!(response.result_for(1), Some(&response.results[1]));
+        assert!(response.with_inputs(&locations[..1]).is_none());
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("elevation/invalid_request"), Status::InvalidRequest),
+            (fixture!("elevation/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("elevation/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.error_message.is_some());
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "time_zone")]
+mod time_zone {
+    use google_maps::time_zone::{TimeZoneResponse as Response, TimeZoneStatus as Status};
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("time_zone/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.time_zone_id, Some(chrono_tz::America::Los_Angeles));
+        assert_eq!(response.raw_offset, Some(chrono::Duration::hours(-8)));
+        assert_eq!(response.dst_offset, Some(chrono::Duration::hours(1)));
+        assert_eq!(response.utc_offset().unwrap().local_minus_utc(), -25_200);
+
+        // Offsets too large to add up are out of range rather than a panic:
+        let response: Response = r#"{
+            "status": "OK",
+            "rawOffset": 9000000000000000,
+            "dstOffset": 9000000000000000
+        }"#.parse().unwrap();
+        assert_eq!(response.utc_offset(), None);
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("time_zone/zero_results"), Status::ZeroResults),
+            (fixture!("time_zone/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("time_zone/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.time_zone_id.is_none());
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+mod places {
+    use google_maps::places::{
+        place_details, place_search, BusinessStatus, InternStrings, Status, StringInterner,
+    };
+
+    #[test]
+    fn place_details_ok() {
+        let response: place_details::Response = fixture!("place_details/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        let place = response.result.unwrap();
+        assert_eq!(place.business_status, Some(BusinessStatus::Operational));
+        assert_eq!(place.reviews.len(), 1);
+        assert_eq!(place.opening_hours.unwrap().periods.len(), 1);
+    } // fn
+
+    #[test]
+    fn attributions_text() {
+        use google_maps::places::PlacePhoto;
+
+        let photo: PlacePhoto = r#"{
+            "height": 100,
+            "width": 100,
+            "photo_reference": "a",
+            "html_attributions": [
+                "<a href=\"https://maps.google.com/maps/contrib/1\">Tom &amp; Jerry</a>",
+                "Shown as &amp;lt;b&amp;gt; &#39;verbatim&#39;"
+            ]
+        }"#.parse().unwrap();
+        // Entities are decoded once, so an escaped entity stays an entity:
+        assert_eq!(
+            photo.attributions_text().unwrap(),
+            "Tom & Jerry, Shown as &lt;b&gt; 'verbatim'",
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn special_days() {
+        use chrono::NaiveDate;
+        use google_maps::places::PlaceOpeningHours;
+
+        let hours: PlaceOpeningHours = r#"{
+            "open_now": true,
+            "periods": [
+                { "open": { "day": 1, "time": "0000", "date": "2024-12-23", "truncated": true } },
+                { "open": { "day": 3, "time": "0900", "date": "2024-12-25" },
+                  "close": { "day": 3, "time": "1300", "date": "2024-12-25" } }
+            ],
+            "special_days": [
+                { "date": "2024-12-25", "exceptional_hours": true },
+                { "date": "2024-12-26", "exceptional_hours": false }
+            ]
+        }"#.parse().unwrap();
+
+        let date = |day| NaiveDate::from_ymd_opt(2024, 12, day).unwrap();
+        assert_eq!(hours.open_now, Some(true));
+        assert!(hours.is_holiday_schedule(date(25)));
+        assert!(!hours.is_holiday_schedule(date(26)));
+        assert!(!hours.is_holiday_schedule(date(27)));
+        assert!(hours.special_day(date(26)).is_some());
+        assert!(hours.is_truncated());
+    } // fn
+
+    #[test]
+    fn primary_type() {
+        use google_maps::PlaceType;
+
+        let response: place_details::Response = fixture!("place_details/ok").parse().unwrap();
+        let mut place = response.result.unwrap();
+        assert_eq!(place.primary_type(), Some(PlaceType::PointOfInterest));
+
+        place.types.insert(1, PlaceType::Restaurant);
+        assert_eq!(place.primary_type(), Some(PlaceType::Restaurant));
+        assert_eq!(place.primary_type_label().as_deref(), Some("Restaurant"));
+
+        place.types.clear();
+        assert_eq!(place.primary_type_label(), None);
+    } // fn
+
+    #[test]
+    fn google_maps_links() {
+        let response: place_details::Response = fixture!("place_details/ok").parse().unwrap();
+        let place = response.result.unwrap();
+        let links = place.google_maps_links().unwrap();
+        assert_eq!(links.place_uri, "https://maps.google.com/?cid=10281119596374313554");
+        assert_eq!(
+            links.directions_uri,
+            "https://www.google.com/maps/dir/?api=1&destination=Google%20Workplace%206\
+                &destination_place_id=ChIJN1t%5FtDeuEmsRUsoyG83frY4",
+        );
+        assert_eq!(
+            place.write_review_link().as_deref(),
+            Some("https://search.google.com/local/writereview?placeid=ChIJN1t%5FtDeuEmsRUsoyG83frY4"),
+        );
+        assert!(place.url.is_some());
+        assert_eq!(place.photos_link(), None);
+    } // fn
+
+    #[test]
+    fn place_details_errors() {
+        for (json, status) in [
+            (fixture!("place_details/not_found"), Status::NotFound),
+            (fixture!("place_details/invalid_request"), Status::InvalidRequest),
+            (fixture!("place_details/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("place_details/request_denied"), Status::RequestDenied),
+        ] {
+            let response: place_details::Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.result.is_none());
+        } // for
+    } // fn
+
+    #[test]
+    fn text_search_ok() {
+        let response: place_search::text_search::Response =
+            fixture!("text_search/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.results.len(), 1);
+        assert!(response.next_page_token.is_some());
+    } // fn
+
+    #[test]
+    fn nearby_search_ok() {
+        let response: place_search::nearby_search::Response =
+            fixture!("nearby_search/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.results.len(), 1);
+        assert!(response.next_page_token.is_none());
+    } // fn
+
+    #[test]
+    fn service_area_business() {
+        let mut response: place_search::text_search::Response =
+            fixture!("text_search/ok").parse().unwrap();
+        let storefront = &response.results[0];
+        assert!(storefront.has_physical_location());
+        assert!(!storefront.is_pure_service_area_business());
+        assert_eq!(storefront.address(), storefront.formatted_address.as_deref());
+
+        let mut plumber = storefront.clone();
+        plumber.formatted_address = Some(String::new());
+        plumber.vicinity = None;
+        response.results.push(plumber);
+
+        assert_eq!(response.physical_places().len(), 1);
+        let businesses = response.service_area_businesses();
+        assert_eq!(businesses.len(), 1);
+        assert!(!businesses[0].has_physical_location());
+        assert!(businesses[0].summary().is_some_and(|summary| summary.address.is_none()));
+    } // fn
+
+    #[test]
+    fn intern_strings() {
+        let mut first: place_search::text_search::Response =
+            fixture!("text_search/ok").parse().unwrap();
+        let mut second = first.clone();
+        second.results[0].icon = first.results[0].icon.as_deref().map(std::sync::Arc::from);
+        let mut interner = StringInterner::new();
+        first.intern_strings(&mut interner);
+        second.intern_strings(&mut interner);
+        assert!(std::sync::Arc::ptr_eq(
+            first.results[0].icon.as_ref().unwrap(),
+            second.results[0].icon.as_ref().unwrap(),
+        ));
+    } // fn
+
+    #[test]
+    fn search_errors() {
+        for (json, status) in [
+            (fixture!("text_search/zero_results"), Status::ZeroResults),
+            (fixture!("text_search/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("text_search/request_denied"), Status::RequestDenied),
+        ] {
+            let response: place_search::text_search::Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+        } // for
+        for (json, status) in [
+            (fixture!("nearby_search/zero_results"), Status::ZeroResults),
+            (fixture!("nearby_search/invalid_request"), Status::InvalidRequest),
+            (fixture!("nearby_search/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("nearby_search/request_denied"), Status::RequestDenied),
+        ] {
+            let response: place_search::nearby_search::Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<place_details::Response>().is_err());
+            assert!(json.parse::<place_search::text_search::Response>().is_err());
+            assert!(json.parse::<place_search::nearby_search::Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "autocomplete")]
+mod place_autocomplete {
+    use google_maps::places::{AutocompleteResponse as Response, AutocompleteStatus as Status};
+
+    #[test]
+    fn ok() {
+        let response: Response = fixture!("place_autocomplete/ok").parse().unwrap();
+        assert_eq!(response.status, Status::Ok);
+        assert_eq!(response.predictions[0].description, "Paris, France");
+        assert_eq!(response.predictions[0].terms.len(), 2);
+    } // fn
+
+    #[test]
+    fn errors() {
+        for (json, status) in [
+            (fixture!("place_autocomplete/zero_results"), Status::ZeroResults),
+            (fixture!("place_autocomplete/over_query_limit"), Status::OverQueryLimit),
+            (fixture!("place_autocomplete/request_denied"), Status::RequestDenied),
+        ] {
+            let response: Response = json.parse().unwrap();
+            assert_eq!(response.status, status);
+            assert!(response.predictions.is_empty());
+        } // for
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in super::MALFORMED {
+            assert!(json.parse::<Response>().is_err());
+        } // for
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "roads")]
+mod roads {
+    use google_maps::roads::{
+        NearestRoadsResponse, RoadsErrorReason, RoadsStatus, SnapToRoadsResponse,
+        SpeedLimitUnits, SpeedLimitsResponse,
+    }; // google_maps::roads
+
+    #[test]
+    fn snap_to_roads_ok() {
+        let response: SnapToRoadsResponse = fixture!("snap_to_roads/ok").parse().unwrap();
+        assert!(response.error.is_none());
+        assert_eq!(response.snapped_points.len(), 3);
+        // Interpolated points have no original index:
+        assert!(response.snapped_points[1].origin_index.is_none());
+    } // fn
+
+    #[test]
+    fn snap_to_roads_originals() {
+        use google_maps::prelude::InputIndex;
+
+        let response: SnapToRoadsResponse = fixture!("snap_to_roads/ok").parse().unwrap();
+        let path = ["first", "second"];
+        let originals: Vec<Option<&&str>> = response.with_originals(&path).map(|(_, original)| original).collect();
+        assert_eq!(originals, vec![Some(&"first"), None, Some(&"second")]);
+        assert_eq!(response.snapped_points[2].original_index(), Some(InputIndex::new(1)));
+        // A path that is too short is not the request's path:
+        assert_eq!(response.snapped_points[2].original(&path[..1]), None);
+    } // fn
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn map_match() {
+        use chrono::{TimeZone, Utc};
+        use google_maps::prelude::{LatLng, MapMatchReport, SnappedPoint};
+
+        let mut response: SnapToRoadsResponse = fixture!("snap_to_roads/ok").parse().unwrap();
+        let second_segment = response.snapped_points[2].place_id.clone();
+        response.snapped_points.push(SnappedPoint {
+            location: LatLng::try_from_f64(-35.2812415, 149.1290779).unwrap(),
+            place_id: second_segment.clone(),
+            origin_index: Some(2),
+        }); // push
+
+        let at = |seconds: i64| Utc.timestamp_opt(1_700_000_000 + seconds, 0).unwrap();
+        let trace = vec![
+            (LatLng::try_from_f64(-35.27801, 149.12958).unwrap(), at(0)),
+            (LatLng::try_from_f64(-35.28032, 149.12907).unwrap(), at(20)),
+            (LatLng::try_from_f64(-35.28124, 149.12907).unwrap(), at(30)),
+            (LatLng::try_from_f64(-35.28200, 149.12900).unwrap(), at(40)),
+        ]; // vec!
+
+        let report = MapMatchReport::from_snapped(&trace, response.snapped_points);
+        assert_eq!(report.segments.len(), 2);
+        assert_eq!(report.segments[0].trace_indices, vec![0]);
+        assert_eq!(report.segments[0].average_speed_kph, None);
+        assert_eq!(Some(&report.segments[1].place_id), second_segment.as_ref());
+        assert_eq!(report.segments[1].trace_indices, vec![1, 2]);
+        assert_eq!(report.segments[1].entered_at, Some(at(20)));
+        assert_eq!(report.segments[1].exited_at, Some(at(30)));
+        // About 100 meters in 10 seconds:
+        let speed = report.segments[1].average_speed_kph.unwrap();
+        assert!((35.0..37.0).contains(&speed), "{speed}");
+        assert_eq!(report.unmatched, vec![3]);
+        assert!(report.distance_meters > report.segments[1].distance_meters);
+    } // fn
+
+    #[test]
+    fn snap_to_roads_warning() {
+        let response: SnapToRoadsResponse = fixture!("snap_to_roads/warning").parse().unwrap();
+        assert!(response.warning_message.is_some());
+    } // fn
+
+    #[test]
+    fn snap_to_roads_zero_results() {
+        let response: SnapToRoadsResponse = fixture!("snap_to_roads/zero_results").parse().unwrap();
+        assert!(response.snapped_points.is_empty());
+        assert!(response.error.is_none());
+    } // fn
+
+    #[test]
+    fn snap_to_roads_errors() {
+        for (json, status) in [
+            (fixture!("snap_to_roads/invalid_argument"), RoadsStatus::InvalidArgument),
+            (fixture!("snap_to_roads/permission_denied"), RoadsStatus::PermissionDenied),
+            (fixture!("snap_to_roads/resource_exhausted"), RoadsStatus::ResourceExhausted),
+        ] {
+            let response: SnapToRoadsResponse = json.parse().unwrap();
+            assert_eq!(response.error.unwrap().status, status);
+        } // for
+    } // fn
+
+    #[test]
+    fn nearest_roads() {
+        let response: NearestRoadsResponse = fixture!("nearest_roads/ok").parse().unwrap();
+        assert_eq!(response.snapped_points.len(), 2);
+        let response: NearestRoadsResponse =
+            fixture!("nearest_roads/permission_denied").parse().unwrap();
+        let error = response.error.unwrap();
+        assert_eq!(error.code, 403);
+        assert_eq!(error.details[0].reason, Some(RoadsErrorReason::ApiKeyInvalid));
+    } // fn
+
+    #[test]
+    fn speed_limits() {
+        let response: SpeedLimitsResponse = fixture!("speed_limits/ok").parse().unwrap();
+        assert_eq!(response.speed_limits.len(), 2);
+        assert_eq!(response.speed_limits[0].units, SpeedLimitUnits::Kph);
+        let response: SpeedLimitsResponse = fixture!("speed_limits/mph").parse().unwrap();
+        assert_eq!(response.speed_limits[0].units, SpeedLimitUnits::Mph);
+        for (json, status) in [
+            (fixture!("speed_limits/not_found"), RoadsStatus::NotFound),
+            (fixture!("speed_limits/permission_denied"), RoadsStatus::PermissionDenied),
+        ] {
+            let response: SpeedLimitsResponse = json.parse().unwrap();
+            assert_eq!(response.error.unwrap().status, status);
+        } // for
+    } // fn
+
+    #[cfg(feature = "directions")]
+    #[test]
+    fn route_analysis() {
+        use google_maps::directions::DirectionsResponse;
+        use google_maps::roads::RouteAnalysis;
+
+        let directions: DirectionsResponse = fixture!("directions/ok").parse().unwrap();
+        let speed_limits: SpeedLimitsResponse = r#"{
+            "speedLimits": [
+                { "placeId": "a", "speedLimit": 60, "units": "KPH" },
+                { "placeId": "b", "speedLimit": 40, "units": "MPH" }
+            ],
+            "snappedPoints": [
+                { "location": { "latitude": 43.65, "longitude": -79.38 }, "originalIndex": 0, "placeId": "a" },
+                { "location": { "latitude": 45.42, "longitude": -75.69 }, "originalIndex": 1, "placeId": "b" }
+            ]
+        }"#.parse().unwrap();
+
+        let analysis = RouteAnalysis::new(&directions.routes[0], &speed_limits);
+        let step = &analysis.steps[0];
+        // 106.7 km in the leg's 5,112 seconds in traffic:
+        assert_eq!(step.duration.num_seconds(), 5_112);
+        let speed = step.expected_speed_kph.unwrap();
+        assert!((75.0..75.5).contains(&speed), "{speed}");
+        // The higher of 60 km/h and 40 mph:
+        let limit = step.speed_limit_kph.unwrap();
+        assert!((64.3..64.4).contains(&limit), "{limit}");
+        assert!(step.exceeds_limit(5.0));
+        assert!(!step.exceeds_limit(20.0));
+        assert_eq!(analysis.speeding_steps(5.0).count(), 1);
+        assert!((analysis.coverage() - 1.0).abs() < f64::EPSILON);
+
+        let empty = RouteAnalysis::new(&directions.routes[0], &SpeedLimitsResponse::default());
+        assert_eq!(empty.steps[0].speed_limit_kph, None);
+        assert_eq!(empty.speeding_steps(0.0).count(), 0);
+
+        // The path follows the step's polyline, so it has the leg's start
+        // location, the polyline's three points and the step's end location.
+        // Without the polyline, there is no fourth point:
+        let speed_limits: SpeedLimitsResponse = r#"{
+            "speedLimits": [{ "placeId": "c", "speedLimit": 100, "units": "KPH" }],
+            "snappedPoints": [
+                { "location": { "latitude": 43.25, "longitude": -126.45 }, "originalIndex": 3, "placeId": "c" }
+            ]
+        }"#.parse().unwrap();
+        let analysis = RouteAnalysis::new(&directions.routes[0], &speed_limits);
+        let expected = cfg!(all(feature = "polyline", feature = "geo")).then_some(100.0);
+        assert_eq!(analysis.steps[0].speed_limit_kph, expected);
+    } // fn
+
+    #[test]
+    fn malformed() {
+        for json in [
+            fixture!("malformed/truncated"),
+            fixture!("malformed/html_error_page"),
+            fixture!("malformed/empty"),
+        ] {
+            assert!(json.parse::<SnapToRoadsResponse>().is_err());
+            assert!(json.parse::<NearestRoadsResponse>().is_err());
+            assert!(json.parse::<SpeedLimitsResponse>().is_err());
+        } // for
+    } // fn
+} // mod
@@ -0,0 +1,233 @@
+//! Tests for the geographic helpers: search circles, clustering and route
+//! rendering.
+
+#[macro_use]
+mod common;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "places")]
+mod circle {
+    use google_maps::prelude::*;
+
+    #[test]
+    fn radius_limits() {
+        let center = LatLng::try_from_dec(dec!(43.65), dec!(-79.38)).unwrap();
+        let circle = Circle::from_center_km(center, 2.5).unwrap();
+        assert_eq!(circle.radius_m(), 2_500);
+        assert!((circle.radius_km() - 2.5).abs() < f64::EPSILON);
+
+        assert!(Circle::from_center_km(center, 50.001).is_err());
+        assert!(Circle::from_center_m(center, -1.0).is_err());
+        assert!(Circle::from_center_m(center, f64::NAN).is_err());
+
+        let point = Circle::try_new(center, 0).unwrap();
+        assert!(point.validate_for(CircleEndpoint::Autocomplete).is_ok());
+        assert!(point.validate_for(CircleEndpoint::NearbySearch).is_err());
+    } // fn
+
+    #[test]
+    fn deserialize() {
+        let parse = |radius: &str| {
+            let mut json = format!(r#"{{ "center": {{ "lat": 43.65, "lng": -79.38 }}, "radius": {radius} }}"#)
+                .into_bytes();
+            simd_json::serde::from_slice::<Circle>(&mut json)
+        }; // closure
+
+        let circle = parse("2500").unwrap();
+        assert_eq!(circle.radius_m(), 2_500);
+        let mut json = simd_json::to_vec(&circle).unwrap();
+        assert_eq!(simd_json::serde::from_slice::<Circle>(&mut json).unwrap(), circle);
+
+        // The radius is checked like it is by the constructors:
+        assert!(parse("50001").is_err());
+        assert!(parse("-1").is_err());
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "places", feature = "reqwest"))]
+mod multi_area_search {
+    use google_maps::prelude::{Bounds, LatLng, MultiAreaSearch};
+    use super::common;
+
+    fn bounds(south: f64, west: f64, north: f64, east: f64) -> Bounds {
+        Bounds {
+            southwest: LatLng::try_from_f64(south, west).unwrap(),
+            northeast: LatLng::try_from_f64(north, east).unwrap(),
+        } // Bounds
+    } // fn
+
+    #[test]
+    fn max_tiles() {
+        let client = common::client();
+        let toronto = bounds(43.58, -79.64, 43.86, -79.12);
+
+        let tiles = MultiAreaSearch::new(&client, toronto, 5_000).tiles().unwrap();
+        assert!(!tiles.is_empty() && tiles.len() <= 100);
+
+        let search = MultiAreaSearch::new(&client, toronto, 100);
+        assert!(matches!(search.tiles(), Err(google_maps::places::Error::TooManyTiles(100))));
+        let search = search.with_max_tiles(usize::MAX);
+        assert!(search.tiles().unwrap().len() > 100);
+    } // fn
+
+    #[test]
+    fn antimeridian() {
+        let client = common::client();
+
+        // Fiji, from 178° E to 178° W:
+        let fiji = MultiAreaSearch::new(&client, bounds(-18.0, 178.0, -16.0, -178.0), 50_000);
+        let tiles = fiji.tiles().unwrap();
+        assert!(tiles.iter().all(|tile| tile.lng.abs() >= rust_decimal_macros::dec!(178)));
+        assert!(tiles.iter().any(|tile| tile.lng.is_sign_positive()));
+        assert!(tiles.iter().any(|tile| tile.lng.is_sign_negative()));
+
+        // The same size of box away from the antimeridian:
+        let africa = MultiAreaSearch::new(&client, bounds(-18.0, 20.0, -16.0, 24.0), 50_000);
+        assert_eq!(tiles.len(), africa.tiles().unwrap().len());
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "geo", feature = "places"))]
+mod spatial_cluster {
+    use google_maps::prelude::{cluster_places, LatLng};
+
+    #[test]
+    fn clusters() {
+        let points = [
+            LatLng::try_from_f64(43.6426, -79.3871).unwrap(),
+            LatLng::try_from_f64(43.6430, -79.3875).unwrap(),
+            LatLng::try_from_f64(43.6422, -79.3868).unwrap(),
+            LatLng::try_from_f64(43.6532, -79.3832).unwrap(),
+            LatLng::try_from_f64(43.4516, -80.4925).unwrap(),
+            LatLng::try_from_f64(43.4520, -80.4930).unwrap(),
+        ];
+
+        let clusters = cluster_places(&points, 100.0, 2);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].members, vec![0, 1, 2]);
+        assert_eq!(clusters[0].centroid.lat, LatLng::try_from_f64(43.6426, 0.0).unwrap().lat);
+        assert!(clusters[0].centroid.haversine_distance(&points[0]) < 10.0);
+        assert_eq!(clusters[1].members, vec![4, 5]);
+
+        // Every result is its own cluster when nothing is close enough:
+        assert_eq!(cluster_places(&points, 1.0, 1).len(), points.len());
+        assert!(cluster_places(&points, 1.0, 2).is_empty());
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod reverse_geocode_batch {
+    use google_maps::prelude::LatLng;
+    use rust_decimal_macros::dec;
+    use super::common::{self, MockServer};
+
+    /// Answers a reverse geocoding request with an address at exactly the
+    /// requested location.
+    fn respond(target: &str) -> (u16, String) {
+        let latlng = target
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("latlng="))
+            .unwrap_or_default()
+            .replace("%2C", ",");
+        let (lat, lng) = latlng.split_once(',').unwrap_or_default();
+        (200, format!(
+            r#"{{ "status": "OK", "results": [{{
+                "formatted_address": "{latlng}",
+                "geometry": {{
+                    "location": {{ "lat": {lat}, "lng": {lng} }},
+                    "location_type": "ROOFTOP",
+                    "viewport": {{
+                        "northeast": {{ "lat": {lat}, "lng": {lng} }},
+                        "southwest": {{ "lat": {lat}, "lng": {lng} }}
+                    }}
+                }},
+                "place_id": "{latlng}"
+            }}] }}"#
+        )) // format!
+    } // fn
+
+    #[tokio::test]
+    async fn cluster_points() {
+        let server = MockServer::start(respond);
+        let client = common::mock_client(&server);
+        let points = [
+            LatLng::try_from_f64(43.6426, -79.3871).unwrap(),
+            LatLng::try_from_f64(43.6428, -79.3871).unwrap(),
+            LatLng::try_from_f64(43.4516, -80.4925).unwrap(),
+            LatLng::try_from_f64(43.6427, -79.3871).unwrap(),
+        ];
+
+        let matches = client.reverse_geocode_batch(points, 25.0).await.unwrap();
+
+        // The three points in Toronto are looked up once, at their centroid:
+        assert_eq!(server.targets().len(), 2);
+        assert_eq!(matches.len(), points.len());
+        let toronto = LatLng::try_from_f64(43.6427, -79.3871).unwrap();
+        for index in [0, 1, 3] {
+            assert_eq!(matches[index].point, points[index]);
+            assert_eq!(matches[index].centroid, toronto);
+        } // for
+        assert!(matches[3].distance_m.unwrap() < 0.001);
+        assert!((matches[0].distance_m.unwrap() - 11.1).abs() < 0.1);
+
+        // The point in Kitchener is its own cluster:
+        assert_eq!(matches[2].centroid, points[2]);
+        assert_eq!(matches[2].result.as_ref().unwrap().place_id, "43.4516,-80.4925");
+        assert!(matches[2].distance_m.unwrap() < 0.001);
+
+        // A precision of zero only merges identical points:
+        let matches = client.reverse_geocode_batch([points[0], points[1], points[0]], 0.0).await.unwrap();
+        assert_eq!(server.targets().len(), 4);
+        assert_eq!(matches[0].centroid, points[0]);
+        assert_eq!(matches[1].centroid, points[1]);
+        assert_eq!(matches[2].centroid, points[0]);
+    } // fn
+
+    #[tokio::test]
+    async fn centroid_across_antimeridian() {
+        let server = MockServer::start(respond);
+        let client = common::mock_client(&server);
+
+        // Two points about 22 meters apart, on either side of the antimeridian:
+        let points = [
+            LatLng::try_from_f64(-17.0, 179.9999).unwrap(),
+            LatLng::try_from_f64(-17.0, -179.9999).unwrap(),
+        ];
+
+        let matches = client.reverse_geocode_batch(points, 50.0).await.unwrap();
+        assert_eq!(server.targets().len(), 1);
+        assert_eq!(matches[0].centroid, matches[1].centroid);
+        assert_eq!(matches[0].centroid.lng.abs(), dec!(180));
+        assert!(matches[0].distance_m.unwrap() < 20.0);
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "render")]
+mod render {
+    use google_maps::directions::DirectionsResponse as Response;
+    use google_maps::prelude::SvgOptions;
+
+    #[test]
+    fn to_svg() {
+        let response: Response = fixture!("directions/ok").parse().unwrap();
+        let options = SvgOptions::new().with_size(120, 80).with_background("#fff");
+        let svg = response.routes[0].to_svg(&options).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"120\" height=\"80\""));
+        assert!(svg.contains("<rect width=\"100%\" height=\"100%\" fill=\"#fff\"/>"));
+        assert!(svg.contains("<path d=\"M"));
+        assert_eq!(svg.matches("<circle").count(), 2);
+        assert!(svg.ends_with("</svg>"));
+
+        // An empty path renders an empty image:
+        assert!(!options.with_markers(false).render(&[]).contains("<path"));
+    } // fn
+} // mod
@@ -0,0 +1,73 @@
+//! Tests for executing Places API requests against a mock server.
+
+#[macro_use]
+mod common;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "places", feature = "reqwest"))]
+mod pages {
+    use futures::StreamExt;
+    use google_maps::prelude::SearchPage;
+    use std::time::Duration;
+    use super::common::{self, MockServer};
+
+    #[tokio::test]
+    async fn with_budget() {
+        let server = MockServer::start(|_| (200, fixture!("text_search/ok").to_string()));
+        let client = common::mock_client(&server);
+
+        let mut request = client.text_search("pizza in New York", 5_000_u32);
+        request.with_budget(1, Duration::from_secs(60));
+        let mut pages = std::pin::pin!(request.pages());
+
+        assert!(matches!(pages.next().await, Some(Ok(SearchPage::Results(_)))));
+        match pages.next().await {
+            Some(Ok(SearchPage::BudgetExhausted(exhausted))) => assert_eq!(exhausted.requests, 1),
+            other => panic!("expected an exhausted budget, got {other:?}"),
+        } // match
+        assert!(pages.next().await.is_none());
+        assert_eq!(server.targets().len(), 1);
+    } // fn
+
+    #[tokio::test]
+    async fn last_page() {
+        let server = MockServer::start(|_| (200, fixture!("nearby_search/ok").to_string()));
+        let client = common::mock_client(&server);
+        let location = google_maps::prelude::LatLng::try_from_f64(-33.8670522, 151.1957362).unwrap();
+
+        let mut request = client.nearby_search(location, 1_500_u32);
+        request.with_budget(3, Duration::from_secs(60));
+        let pages: Vec<_> = request.pages().collect().await;
+
+        // Google offered no further pages, so the budget wasn't reached:
+        assert_eq!(pages.len(), 1);
+        assert!(matches!(pages[0], Ok(SearchPage::Results(_))));
+    } // fn
+
+    #[tokio::test]
+    async fn unbounded_page_token_window() {
+        use google_maps::RetryPolicy;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // The page token is reported as invalid once, as if it wasn't active
+        // yet:
+        let calls = AtomicUsize::new(0);
+        let server = MockServer::start(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+            0 => (200, r#"{ "html_attributions": [], "results": [], "status": "INVALID_REQUEST" }"#.to_string()),
+            _ => (200, fixture!("text_search/ok").to_string()),
+        }); // start
+        let mut client = common::mock_client(&server);
+        client.with_retry_policy(RetryPolicy::default().with_max_elapsed_secs(30));
+
+        // A window too long to be added to an `Instant` has no deadline:
+        let response = client
+            .text_search("pizza in New York", 5_000_u32)
+            .with_pagetoken("token".to_string())
+            .with_page_token_window(Duration::MAX)
+            .execute()
+            .await;
+        assert!(response.is_ok(), "{response:?}");
+        assert_eq!(server.targets().len(), 2);
+    } // fn
+} // mod
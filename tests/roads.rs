@@ -0,0 +1,113 @@
+//! Tests for executing Roads API requests against a mock server.
+
+mod common;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(feature = "roads", feature = "reqwest"))]
+mod speed_limits {
+    use google_maps::prelude::{LatLng, SpeedLimitCache};
+    use super::common::{self, MockServer};
+
+    /// Answers a _Speed Limits_ request. A path is snapped to its first and
+    /// last points, and every road segment has a 50 km/h limit.
+    fn respond(target: &str) -> (u16, String) {
+        let query = target.split_once('?').map(|(_, query)| query).unwrap_or_default();
+        let mut place_ids: Vec<String> = Vec::new();
+        let mut snapped_points = Vec::new();
+        for (name, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match name {
+                "path" => {
+                    let points = value.split("%7C").count();
+                    for index in [0, points - 1] {
+                        let place_id = format!("segment-{points}-{index}");
+                        snapped_points.push(format!(
+                            r#"{{ "location": {{ "latitude": 0, "longitude": 0 }},
+                                "originalIndex": {index}, "placeId": "{place_id}" }}"#
+                        )); // push
+                        place_ids.push(place_id);
+                    } // for
+                } // "path"
+                "placeId" => place_ids.push(value.to_string()),
+                _ => {},
+            } // match
+        } // for
+        let speed_limits: Vec<String> = place_ids
+            .iter()
+            .map(|place_id| format!(r#"{{ "placeId": "{place_id}", "speedLimit": 50, "units": "KPH" }}"#))
+            .collect();
+        (200, format!(
+            r#"{{ "speedLimits": [{}], "snappedPoints": [{}] }}"#,
+            speed_limits.join(","),
+            snapped_points.join(","),
+        )) // format!
+    } // fn
+
+    #[tokio::test]
+    async fn path_in_chunks() {
+        let server = MockServer::start(respond);
+        let client = common::mock_client(&server);
+        let path: Vec<LatLng> = (0..150_u32)
+            .map(|index| LatLng::try_from_f64(43.0 + f64::from(index) / 1_000.0, -80.0).unwrap())
+            .collect();
+
+        let response = client.speed_limits().for_path(path).execute().await.unwrap();
+
+        // 150 points are sent as 100 and 50:
+        let targets = server.targets();
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].matches("%7C").count(), 99);
+        assert_eq!(targets[1].matches("%7C").count(), 49);
+
+        // The second chunk's indices are offset to refer to the full path:
+        let indices: Vec<Option<usize>> = response
+            .snapped_points
+            .iter()
+            .map(|point| point.original_index().map(|index| index.get()))
+            .collect();
+        assert_eq!(indices, vec![Some(0), Some(99), Some(100), Some(149)]);
+        assert_eq!(response.speed_limits.len(), 4);
+    } // fn
+
+    #[tokio::test]
+    async fn cache() {
+        let server = MockServer::start(respond);
+        let client = common::mock_client(&server);
+        let cache = SpeedLimitCache::new(std::time::Duration::from_secs(60));
+
+        let response = client
+            .speed_limits()
+            .for_place_ids(["a", "b"])
+            .with_cache(&cache)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.speed_limits.len(), 2);
+        assert_eq!(server.targets().len(), 1);
+
+        // Only the segment that isn't cached is requested:
+        let response = client
+            .speed_limits()
+            .for_place_ids(["a", "b", "c"])
+            .with_cache(&cache)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.speed_limits.len(), 3);
+        let targets = server.targets();
+        assert_eq!(targets.len(), 2);
+        assert!(targets[1].ends_with("&placeId=c"));
+
+        // Nothing is requested when every segment is cached:
+        let response = client
+            .speed_limits()
+            .for_place_ids(["c", "a"])
+            .with_cache(&cache)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.speed_limits.len(), 2);
+        assert!(response.speed_limit_for("c").is_some());
+        assert_eq!(server.targets().len(), 2);
+    } // fn
+} // mod
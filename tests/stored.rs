@@ -0,0 +1,162 @@
+//! Tests for storing responses with their schema version, upgrading stored
+//! responses, and embedding responses in other types.
+
+#[macro_use]
+mod common;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "time_zone")]
+mod stored {
+    use google_maps::stored::error::Error;
+    use google_maps::time_zone::TimeZoneResponse;
+    use google_maps::{Storable, Stored, Upgrades};
+    use serde::{Deserialize, Serialize};
+    use simd_json::prelude::*;
+
+    #[test]
+    fn round_trip() {
+        let response: TimeZoneResponse = fixture!("time_zone/ok").parse().unwrap();
+        let stored = Stored::new(response);
+        assert_eq!(stored.api_version, "maps/api/timezone/json");
+
+        let mut json = stored.to_json().unwrap();
+        let read: Stored<TimeZoneResponse> = Stored::from_json(&mut json, &Upgrades::new()).unwrap();
+        assert_eq!(read, stored);
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[test]
+    fn nearby_search() {
+        use google_maps::places::place_search::nearby_search::response::Response as NearbySearchResponse;
+
+        let response: NearbySearchResponse = fixture!("nearby_search/ok").parse().unwrap();
+        let stored = Stored::new(response);
+        assert_eq!(stored.api_version, "maps/api/place/nearbysearch/json");
+
+        let mut json = stored.to_json().unwrap();
+        let read: Stored<NearbySearchResponse> = Stored::from_json(&mut json, &Upgrades::new()).unwrap();
+        assert_eq!(read, stored);
+    } // fn
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Renamed {
+        name: String,
+    } // struct
+
+    impl Storable for Renamed {
+        const API_VERSION: &'static str = "test";
+        const SCHEMA_VERSION: u32 = 2;
+    } // impl
+
+    #[test]
+    fn upgrade() {
+        let mut json = br#"{"crate_version":"3.7.4","api_version":"test","schema_version":1,
+            "fetched_at":0,"response":{"title":"Kitchener"}}"#.to_vec();
+
+        let missing = Stored::<Renamed>::from_json(&mut json.clone(), &Upgrades::new());
+        assert!(matches!(missing, Err(Error::MissingUpgrade(1))));
+
+        let upgrades = Upgrades::new().with_step(1, |response| {
+            let title = response.as_object_mut().and_then(|object| object.remove("title"));
+            let title = title.ok_or_else(|| Error::UpgradeFailed("missing `title`".to_string()))?;
+            response.insert("name", title).map_err(|error| Error::UpgradeFailed(error.to_string()))?;
+            Ok(())
+        });
+        let stored = Stored::<Renamed>::from_json(&mut json, &upgrades).unwrap();
+        assert_eq!(stored.schema_version, 2);
+        assert_eq!(stored.response.name, "Kitchener");
+    } // fn
+} // mod
+
+// -----------------------------------------------------------------------------
+
+/// Embedding responses in an application's own types with
+/// `#[serde(flatten)]`. Flattened fields are read through serde's buffered
+/// deserializer, which presents values differently from a JSON deserializer.
+mod flatten {
+    use serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use std::fmt::Debug;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<R> {
+        id: u64,
+        #[serde(flatten)]
+        response: R,
+    } // struct
+
+    /// Reads Google's JSON with an extra `id` field into a `Record`, then
+    /// writes the `Record` out and reads it back.
+    fn round_trip<R>(json: &str)
+    where
+        R: Debug + PartialEq + Serialize + DeserializeOwned,
+    {
+        let mut bytes = json.as_bytes().to_vec();
+        let response: R = simd_json::serde::from_slice(&mut bytes).unwrap();
+
+        let mut bytes = json.trim_start().replacen('{', r#"{ "id": 7,"#, 1).into_bytes();
+        let record: Record<R> = simd_json::serde::from_slice(&mut bytes).unwrap();
+        assert_eq!(record, Record { id: 7, response });
+
+        let mut bytes = simd_json::serde::to_vec(&record).unwrap();
+        let read: Record<R> = simd_json::serde::from_slice(&mut bytes).unwrap();
+        assert_eq!(read, record);
+    } // fn
+
+    #[cfg(feature = "directions")]
+    #[test]
+    fn directions() {
+        round_trip::<google_maps::prelude::DirectionsResponse>(fixture!("directions/ok"));
+        round_trip::<google_maps::prelude::DirectionsResponse>(fixture!("directions/not_found"));
+    } // fn
+
+    #[cfg(feature = "distance_matrix")]
+    #[test]
+    fn distance_matrix() {
+        round_trip::<google_maps::prelude::DistanceMatrixResponse>(fixture!("distance_matrix/ok"));
+    } // fn
+
+    #[cfg(feature = "elevation")]
+    #[test]
+    fn elevation() {
+        round_trip::<google_maps::prelude::ElevationResponse>(fixture!("elevation/ok"));
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn geocoding() {
+        round_trip::<google_maps::prelude::GeocodingResponse>(fixture!("geocoding/ok"));
+        round_trip::<google_maps::prelude::GeocodingResponse>(fixture!("geocoding/address_descriptor"));
+        round_trip::<google_maps::prelude::GeocodingResponse>(fixture!("geocoding/zero_results"));
+    } // fn
+
+    #[cfg(feature = "time_zone")]
+    #[test]
+    fn time_zone() {
+        round_trip::<google_maps::prelude::TimeZoneResponse>(fixture!("time_zone/ok"));
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[test]
+    fn places() {
+        use google_maps::places::place_search::nearby_search::response::Response as NearbySearchResponse;
+
+        round_trip::<google_maps::prelude::PlaceDetailsResponse>(fixture!("place_details/ok"));
+        round_trip::<NearbySearchResponse>(fixture!("nearby_search/ok"));
+        round_trip::<google_maps::prelude::TextSearchResponse>(fixture!("text_search/ok"));
+    } // fn
+
+    #[cfg(feature = "autocomplete")]
+    #[test]
+    fn place_autocomplete() {
+        round_trip::<google_maps::prelude::AutocompleteResponse>(fixture!("place_autocomplete/ok"));
+    } // fn
+
+    #[cfg(feature = "roads")]
+    #[test]
+    fn roads() {
+        round_trip::<google_maps::prelude::SnapToRoadsResponse>(fixture!("snap_to_roads/ok"));
+        round_trip::<google_maps::prelude::NearestRoadsResponse>(fixture!("nearest_roads/ok"));
+        round_trip::<google_maps::prelude::SpeedLimitsResponse>(fixture!("speed_limits/ok"));
+    } // fn
+} // mod